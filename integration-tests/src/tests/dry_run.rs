@@ -0,0 +1,244 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::mock::{ALICE, BOB};
+use bifrost_polkadot_runtime::{
+	xcm_config::XcmRouter, AssetRegistry, Block, OriginCaller, ParachainSystem, PolkadotXcm,
+	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Slpx, System, Tokens,
+};
+use bifrost_primitives::{CurrencyId, HydrationChainId};
+use bifrost_slpx::types::TargetChain;
+use frame_support::{assert_ok, traits::Hooks, weights::Weight, BoundedVec};
+use orml_traits::MultiCurrency;
+use parity_scale_codec::Encode;
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+use xcm::{v4::prelude::*, VersionedAssetId, VersionedAssets, VersionedLocation, VersionedXcm};
+use xcm_builder::InspectMessageQueues;
+use xcm_runtime_apis::{
+	dry_run::runtime_decl_for_dry_run_api::DryRunApiV1,
+	fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1,
+};
+
+const DOT: CurrencyId = CurrencyId::Token2(0);
+const VDOT: CurrencyId = CurrencyId::VToken2(0);
+
+fn bifrost_polkadot_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+	bifrost_asset_registry::GenesisConfig::<Runtime> {
+		currency: vec![(DOT, 1_000_000, None)],
+		vcurrency: vec![VDOT],
+		vsbond: vec![],
+		phantom: Default::default(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	orml_tokens::GenesisConfig::<Runtime> { balances: vec![(ALICE, DOT, 100_000_000_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetRegistry::do_register_location(DOT, &Location::parent()));
+		assert_ok!(AssetRegistry::do_register_weight(DOT, Weight::from_parts(1_000_000_000, 0)));
+	});
+	ext
+}
+
+#[test]
+fn dry_run_xtokens_transfer_to_relay_chain() {
+	bifrost_polkadot_ext().execute_with(|| {
+		let call = RuntimeCall::XTokens(orml_xtokens::Call::transfer {
+			currency_id: DOT,
+			amount: 50_000_000_000,
+			dest: Box::new(
+				Location::new(1, [AccountId32 { network: None, id: BOB.into() }]).into(),
+			),
+			dest_weight_limit: WeightLimit::Unlimited,
+		});
+		let origin = OriginCaller::system(frame_system::RawOrigin::Signed(ALICE));
+
+		let effects =
+			<Runtime as DryRunApiV1<Block, RuntimeCall, RuntimeEvent, OriginCaller>>::dry_run_call(
+				origin, call,
+			)
+			.unwrap();
+
+		assert_ok!(effects.execution_result);
+		assert_eq!(effects.forwarded_xcms.len(), 1);
+		let (destination, messages) = &effects.forwarded_xcms[0];
+		assert_eq!(*destination, VersionedLocation::V4(Location::parent()));
+		assert_eq!(messages.len(), 1);
+		assert!(effects.emitted_events.iter().any(|event| matches!(
+			event,
+			RuntimeEvent::XTokens(orml_xtokens::Event::TransferredAssets { .. })
+		)));
+	});
+}
+
+#[test]
+fn dry_run_slpx_mint() {
+	bifrost_polkadot_ext().execute_with(|| {
+		let call = RuntimeCall::Slpx(bifrost_slpx::Call::mint {
+			evm_caller: Default::default(),
+			currency_id: DOT,
+			target_chain: TargetChain::Hydradx(ALICE),
+			remark: Default::default(),
+		});
+		let origin = OriginCaller::system(frame_system::RawOrigin::Signed(ALICE));
+
+		let effects =
+			<Runtime as DryRunApiV1<Block, RuntimeCall, RuntimeEvent, OriginCaller>>::dry_run_call(
+				origin, call,
+			)
+			.unwrap();
+
+		assert_ok!(effects.execution_result);
+		assert!(effects.local_xcm.is_none());
+		// The order is only transferred to the target chain when it is handled in `on_idle`.
+		assert!(effects.forwarded_xcms.is_empty());
+		assert!(effects.emitted_events.iter().any(|event| matches!(
+			event,
+			RuntimeEvent::Slpx(bifrost_slpx::Event::CreateOrder { .. })
+		)));
+	});
+}
+
+#[test]
+fn query_weight_to_asset_fee_should_work() {
+	bifrost_polkadot_ext().execute_with(|| {
+		let dot = VersionedAssetId::V4(Location::parent().into());
+		let weight = Weight::from_parts(1_000_000_000, 0);
+
+		let assets =
+			<Runtime as XcmPaymentApiV1<Block>>::query_acceptable_payment_assets(4).unwrap();
+		assert!(assets.contains(&dot));
+
+		let fee = <Runtime as XcmPaymentApiV1<Block>>::query_weight_to_asset_fee(weight, dot);
+		assert!(fee.unwrap() > 0);
+
+		let unknown = VersionedAssetId::V4(Location::new(1, [Parachain(9999)]).into());
+		assert!(<Runtime as XcmPaymentApiV1<Block>>::query_weight_to_asset_fee(weight, unknown)
+			.is_err());
+	});
+}
+
+/// Lets vDOT be sent to Hydration: registers its location, agrees on the XCM version and opens
+/// the channel.
+fn enable_vdot_transfer_to_hydration() {
+	let vdot_location =
+		Location::new(0, [Junction::from(BoundedVec::try_from(VDOT.encode()).unwrap())]);
+	assert_ok!(AssetRegistry::do_register_location(VDOT, &vdot_location));
+	assert_ok!(PolkadotXcm::force_default_xcm_version(RuntimeOrigin::root(), Some(XCM_VERSION)));
+	ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+		HydrationChainId::get().into(),
+	);
+}
+
+#[test]
+fn dry_run_slpx_transfer_to_hydradx() {
+	// The messages slpx forwards when it handles a mint order whose vDOT goes to Hydration.
+	let (slpx_forwarded_xcms, vdot_amount) = bifrost_polkadot_ext().execute_with(|| {
+		enable_vdot_transfer_to_hydration();
+		let call = RuntimeCall::Slpx(bifrost_slpx::Call::mint {
+			evm_caller: Default::default(),
+			currency_id: DOT,
+			target_chain: TargetChain::Hydradx(BOB),
+			remark: Default::default(),
+		});
+		let origin = OriginCaller::system(frame_system::RawOrigin::Signed(ALICE));
+		assert_ok!(
+			<Runtime as DryRunApiV1<Block, RuntimeCall, RuntimeEvent, OriginCaller>>::dry_run_call(
+				origin, call,
+			)
+			.unwrap()
+			.execution_result
+		);
+
+		Slpx::on_idle(System::block_number(), Weight::MAX);
+		let vdot_amount = System::events()
+			.iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::VtokenMinting(bifrost_vtoken_minting::Event::Minted {
+					vtoken_amount,
+					..
+				}) => Some(vtoken_amount),
+				_ => None,
+			})
+			.unwrap();
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Slpx(bifrost_slpx::Event::OrderHandled { .. })
+		)));
+		assert_eq!(Tokens::free_balance(VDOT, &ALICE), 0);
+		(XcmRouter::get_messages(), vdot_amount)
+	});
+
+	bifrost_polkadot_ext().execute_with(|| {
+		enable_vdot_transfer_to_hydration();
+		assert_ok!(Tokens::deposit(VDOT, &ALICE, vdot_amount));
+		let call = RuntimeCall::XTokens(orml_xtokens::Call::transfer {
+			currency_id: VDOT,
+			amount: vdot_amount,
+			dest: Box::new(
+				Location::new(
+					1,
+					[
+						Parachain(HydrationChainId::get()),
+						AccountId32 { network: None, id: BOB.into() },
+					],
+				)
+				.into(),
+			),
+			dest_weight_limit: WeightLimit::Unlimited,
+		});
+		let origin = OriginCaller::system(frame_system::RawOrigin::Signed(ALICE));
+
+		let effects =
+			<Runtime as DryRunApiV1<Block, RuntimeCall, RuntimeEvent, OriginCaller>>::dry_run_call(
+				origin, call,
+			)
+			.unwrap();
+
+		assert_ok!(effects.execution_result);
+		// Slpx sends the order to Hydration as the dry-run transfer does.
+		assert_eq!(effects.forwarded_xcms, slpx_forwarded_xcms);
+		assert_eq!(effects.forwarded_xcms.len(), 1);
+		let (destination, messages) = &effects.forwarded_xcms[0];
+		assert_eq!(
+			*destination,
+			VersionedLocation::V4(Location::new(1, [Parachain(HydrationChainId::get())]))
+		);
+		assert_eq!(messages.len(), 1);
+		assert!(matches!(
+			&messages[0],
+			VersionedXcm::V4(message) if matches!(message.0.first(), Some(ReserveAssetDeposited(_)))
+		));
+
+		// The delivery to siblings isn't charged.
+		let delivery_fees = <Runtime as XcmPaymentApiV1<Block>>::query_delivery_fees(
+			destination.clone(),
+			messages[0].clone(),
+		)
+		.unwrap();
+		assert_eq!(delivery_fees, VersionedAssets::V4(Assets::new()));
+	});
+}
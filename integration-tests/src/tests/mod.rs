@@ -16,5 +16,6 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod dry_run;
 mod send_relay_chain_asset;
 mod slp;
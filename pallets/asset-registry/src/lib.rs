@@ -898,6 +898,46 @@ pub struct FixedRateOfAsset<T, FixedRate: Get<u128>, R: TakeRevenue> {
 	_marker: PhantomData<(T, FixedRate, R)>,
 }
 
+impl<T: Config, FixedRate: Get<u128>, R: TakeRevenue> FixedRateOfAsset<T, FixedRate, R>
+where
+	BalanceOf<T>: Into<u128>,
+{
+	/// The ratio of the asset's existential deposit to the native one, `None` if the asset at
+	/// `location` is not registered.
+	fn ed_ratio(location: xcm::v3::Location) -> Option<FixedU128> {
+		let currency_id = LocationToCurrencyIds::<T>::get(location)?;
+		let metadata = CurrencyMetadatas::<T>::get(currency_id)?;
+		// The integration tests can ensure the ed is non-zero.
+		Some(FixedU128::saturating_from_rational(
+			metadata.minimal_balance.into(),
+			T::Currency::minimum_balance().into(),
+		))
+	}
+
+	fn fee(weight: Weight, ed_ratio: FixedU128) -> u128 {
+		// The WEIGHT_REF_TIME_PER_SECOND is non-zero.
+		let weight_ratio =
+			FixedU128::saturating_from_rational(weight.ref_time(), WEIGHT_REF_TIME_PER_SECOND);
+		ed_ratio.saturating_mul_int(weight_ratio.saturating_mul_int(FixedRate::get()))
+	}
+
+	/// The amount of the asset at `location` `buy_weight` charges for `weight`.
+	pub fn weight_to_fee(weight: &Weight, location: &Location) -> Option<u128> {
+		let v3_location = xcm::v3::Location::try_from(location.clone()).ok()?;
+		Self::ed_ratio(v3_location).map(|ed_ratio| Self::fee(*weight, ed_ratio))
+	}
+
+	/// The locations of all assets registered for XCM execution fees, i.e. the currencies
+	/// with a location, a weight in `CurrencyIdToWeights` and metadata.
+	pub fn acceptable_assets() -> Vec<Location> {
+		CurrencyIdToWeights::<T>::iter_keys()
+			.filter(|currency_id| CurrencyMetadatas::<T>::contains_key(currency_id))
+			.filter_map(CurrencyIdToLocations::<T>::get)
+			.filter_map(|location| Location::try_from(location).ok())
+			.collect()
+	}
+}
+
 impl<T: Config, FixedRate: Get<u128>, R: TakeRevenue> WeightTrader
 	for FixedRateOfAsset<T, FixedRate, R>
 where
@@ -934,35 +974,21 @@ where
 		let v3_location =
 			xcm::v3::Location::try_from(location.clone()).map_err(|_| XcmError::InvalidLocation)?;
 
-		if let Some(currency_id) = LocationToCurrencyIds::<T>::get(v3_location) {
-			if let Some(currency_metadatas) = CurrencyMetadatas::<T>::get(currency_id) {
-				// The integration tests can ensure the ed is non-zero.
-				let ed_ratio = FixedU128::saturating_from_rational(
-					currency_metadatas.minimal_balance.into(),
-					T::Currency::minimum_balance().into(),
-				);
-				// The WEIGHT_REF_TIME_PER_SECOND is non-zero.
-				let weight_ratio = FixedU128::saturating_from_rational(
-					weight.ref_time(),
-					WEIGHT_REF_TIME_PER_SECOND,
-				);
-				let amount =
-					ed_ratio.saturating_mul_int(weight_ratio.saturating_mul_int(FixedRate::get()));
-
-				let required = Asset { id: asset_id.clone(), fun: Fungible(amount) };
+		if let Some(ed_ratio) = Self::ed_ratio(v3_location) {
+			let amount = Self::fee(weight, ed_ratio);
+			let required = Asset { id: asset_id.clone(), fun: Fungible(amount) };
 
-				log::trace!(
-					target: "asset-registry::weight", "buy_weight payment: {:?}, required: {:?}, fixed_rate: {:?}, ed_ratio: {:?}, weight_ratio: {:?}",
-					payment, required, FixedRate::get(), ed_ratio, weight_ratio
-				);
-				let unused =
-					payment.clone().checked_sub(required).map_err(|_| XcmError::TooExpensive)?;
-				self.weight = self.weight.saturating_add(weight.ref_time());
-				self.amount = self.amount.saturating_add(amount);
-				self.ed_ratio = ed_ratio;
-				self.location = Some(location.clone());
-				return Ok(unused);
-			}
+			log::trace!(
+				target: "asset-registry::weight", "buy_weight payment: {:?}, required: {:?}, fixed_rate: {:?}, ed_ratio: {:?}",
+				payment, required, FixedRate::get(), ed_ratio
+			);
+			let unused =
+				payment.clone().checked_sub(required).map_err(|_| XcmError::TooExpensive)?;
+			self.weight = self.weight.saturating_add(weight.ref_time());
+			self.amount = self.amount.saturating_add(amount);
+			self.ed_ratio = ed_ratio;
+			self.location = Some(location.clone());
+			return Ok(unused);
 		};
 
 		log::trace!(target: "asset-registry::weight", "no concrete fungible asset");
@@ -975,11 +1001,7 @@ where
 			weight, self.weight, self.amount, self.ed_ratio, self.location
 		);
 		let weight = weight.min(Weight::from_parts(self.weight, 0));
-		let weight_ratio =
-			FixedU128::saturating_from_rational(weight.ref_time(), WEIGHT_REF_TIME_PER_SECOND);
-		let amount = self
-			.ed_ratio
-			.saturating_mul_int(weight_ratio.saturating_mul_int(FixedRate::get()));
+		let amount = Self::fee(weight, self.ed_ratio);
 
 		self.weight = self.weight.saturating_sub(weight.ref_time());
 		self.amount = self.amount.saturating_sub(amount);
@@ -517,3 +517,37 @@ fn update_currency_metadata_nonexistent_currency_id() {
 		);
 	});
 }
+
+#[test]
+fn fixed_rate_of_asset_weight_to_fee_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		type Trader = FixedRateOfAsset<Runtime, frame_support::traits::ConstU128<1_000>, ()>;
+
+		let metadata = AssetMetadata {
+			name: b"Polkadot DOT".to_vec(),
+			symbol: b"DOT".to_vec(),
+			decimals: 10,
+			minimal_balance: 2,
+		};
+		let location = Location::parent();
+		let weight = Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 2, 0);
+
+		assert_eq!(Trader::weight_to_fee(&weight, &location), None);
+		assert!(Trader::acceptable_assets().is_empty());
+
+		assert_ok!(AssetRegistry::register_token_metadata(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Box::new(metadata)
+		));
+		assert_ok!(AssetRegistry::register_location(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			Token2(0),
+			Box::new(VersionedLocation::V4(location.clone())),
+			Weight::from_parts(2000_000_000, 0)
+		));
+
+		// ed ratio 2 * half a second * 1_000 per second.
+		assert_eq!(Trader::weight_to_fee(&weight, &location), Some(1_000));
+		assert_eq!(Trader::acceptable_assets(), vec![location]);
+	})
+}
@@ -31,13 +31,15 @@ mod benchmarking;
 pub mod weights;
 
 use bb_bnc::BbBNCInterface;
-use bifrost_primitives::{currency::BNC, CurrencyId, CurrencyIdRegister, TryConvertFrom};
+use bifrost_primitives::{
+	currency::BNC, CurrencyId, CurrencyIdRegister, Moment, TryConvertFrom, TwapProvider,
+};
 use cumulus_primitives_core::ParaId;
 use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::{
 		traits::{AccountIdConversion, One, Zero},
-		Permill, SaturatedConversion, Saturating,
+		FixedPointNumber, Permill, SaturatedConversion, Saturating,
 	},
 	transactional, PalletId,
 };
@@ -95,6 +97,14 @@ pub mod pallet {
 			BalanceOf<Self>,
 			BlockNumberFor<Self>,
 		>;
+
+		/// The TWAP of the currency against BNC that `SwapOutMin` is derived from, the spot
+		/// price is used if it is unavailable.
+		type Twap: TwapProvider;
+
+		/// The window of the TWAP in milliseconds.
+		#[pallet::constant]
+		type TwapWindow: Get<Moment>;
 	}
 
 	#[pallet::event]
@@ -405,6 +415,7 @@ pub mod pallet {
 				&path,
 				&buyback_address,
 			)?;
+			T::Twap::on_swap(&currency_id, &BNC);
 
 			if let Some(ratio) = info.destruction_ratio {
 				let bnc_balance_before_burn = T::MultiCurrency::free_balance(BNC, &buyback_address);
@@ -440,6 +451,7 @@ pub mod pallet {
 				&path,
 				&liquidity_address,
 			)?;
+			T::Twap::on_swap(&currency_id, &BNC);
 			let remaining_balance = T::MultiCurrency::free_balance(currency_id, &liquidity_address);
 			let bnc_balance = T::MultiCurrency::free_balance(BNC, &liquidity_address);
 
@@ -497,12 +509,9 @@ pub mod pallet {
 			currency_id: CurrencyId,
 			info: &Info<BalanceOf<T>, BlockNumberFor<T>>,
 		) -> DispatchResult {
-			let path = Self::get_path(currency_id)?;
-			let amounts = T::DexOperator::get_amount_out_by_path(
-				info.min_swap_value.saturated_into(),
-				&path,
-			)?;
-			SwapOutMin::<T>::insert(currency_id, amounts[amounts.len() - 1]);
+			let amount_out =
+				Self::get_amount_out(currency_id, info.min_swap_value.saturated_into())?;
+			SwapOutMin::<T>::insert(currency_id, amount_out);
 			Ok(())
 		}
 
@@ -511,14 +520,26 @@ pub mod pallet {
 			currency_id: CurrencyId,
 			info: &Info<BalanceOf<T>, BlockNumberFor<T>>,
 		) -> DispatchResult {
-			let path = Self::get_path(currency_id)?;
 			let balance = T::MultiCurrency::free_balance(currency_id, &liquidity_address);
 			let token_balance = info.proportion * balance;
 			ensure!(token_balance > Zero::zero(), Error::<T>::NotEnoughBalance);
-			let amounts =
-				T::DexOperator::get_amount_out_by_path(token_balance.saturated_into(), &path)?;
-			AddLiquiditySwapOutMin::<T>::insert(currency_id, amounts[amounts.len() - 1]);
+			let amount_out = Self::get_amount_out(currency_id, token_balance.saturated_into())?;
+			AddLiquiditySwapOutMin::<T>::insert(currency_id, amount_out);
 			Ok(())
 		}
+
+		/// The amount of BNC expected for `amount` of `currency_id`, valued by the TWAP if it
+		/// is available, otherwise by the spot price of the zenlink pair.
+		pub fn get_amount_out(
+			currency_id: CurrencyId,
+			amount: u128,
+		) -> Result<u128, DispatchError> {
+			if let Some((price, _)) = T::Twap::twap(&currency_id, &BNC, T::TwapWindow::get()) {
+				return Ok(price.saturating_mul_int(amount));
+			}
+			let path = Self::get_path(currency_id)?;
+			let amounts = T::DexOperator::get_amount_out_by_path(amount, &path)?;
+			Ok(amounts[amounts.len() - 1])
+		}
 	}
 }
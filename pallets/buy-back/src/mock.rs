@@ -23,7 +23,9 @@
 
 use bifrost_asset_registry::AssetIdMaps;
 use bifrost_primitives::MoonbeamChainId;
-pub use bifrost_primitives::{currency::*, CurrencyId, SlpxOperator, TokenSymbol};
+pub use bifrost_primitives::{
+	currency::*, CurrencyId, Moment, Price, SlpxOperator, TokenSymbol, TwapProvider,
+};
 use bifrost_slp::{QueryId, QueryResponseManager};
 pub use cumulus_primitives_core::ParaId;
 use frame_support::{
@@ -161,6 +163,21 @@ parameter_types! {
 	pub BifrostVsbondAccount: PalletId = PalletId(*b"bf/salpb");
 	pub const BuyBackAccount: PalletId = PalletId(*b"bf/bybck");
 	pub const LiquidityAccount: PalletId = PalletId(*b"bf/liqdt");
+	pub const TwapWindow: Moment = 3_600_000;
+	pub static TwapPrice: Option<Price> = None;
+}
+
+pub struct MockTwap;
+impl TwapProvider for MockTwap {
+	fn on_swap(_asset_in: &CurrencyId, _asset_out: &CurrencyId) {}
+
+	fn twap(
+		_asset_a: &CurrencyId,
+		_asset_b: &CurrencyId,
+		window: Moment,
+	) -> Option<(Price, Moment)> {
+		TwapPrice::get().map(|price| (price, window))
+	}
 }
 
 ord_parameter_types! {
@@ -180,6 +197,8 @@ impl bifrost_buy_back::Config for Runtime {
 	type ParachainId = ParaInfo;
 	type CurrencyIdRegister = AssetIdMaps<Runtime>;
 	type BbBNC = BbBNC;
+	type Twap = MockTwap;
+	type TwapWindow = TwapWindow;
 }
 
 pub struct ParaInfo;
@@ -247,6 +266,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = TreasuryAccount;
+	type RelayStateRoot = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_type_with_key! {
@@ -335,6 +335,19 @@ fn on_initialize_with_bias_should_not_work() {
 	});
 }
 
+#[test]
+fn get_amount_out_should_prefer_twap() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		init_zenlink(PARAID);
+		let path = BuyBack::get_path(VKSM).unwrap();
+		let spot_amounts = ZenlinkProtocol::get_amount_out_by_path(VALUE, &path).unwrap();
+		assert_eq!(BuyBack::get_amount_out(VKSM, VALUE), Ok(spot_amounts[1]));
+
+		TwapPrice::set(Some(Price::saturating_from_rational(1, 2)));
+		assert_eq!(BuyBack::get_amount_out(VKSM, VALUE), Ok(VALUE / 2));
+	});
+}
+
 fn init_zenlink(_para_id: u32) -> AccountIdOf<Runtime> {
 	let asset_0_currency_id: AssetId = AssetId::try_convert_from(BNC, PARAID).unwrap();
 	let asset_1_currency_id: AssetId = AssetId::try_convert_from(VKSM, PARAID).unwrap();
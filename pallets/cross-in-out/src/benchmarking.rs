@@ -25,13 +25,61 @@ use bifrost_primitives::{CurrencyId, TokenSymbol};
 use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
-use sp_runtime::traits::UniqueSaturatedFrom;
+use sp_runtime::traits::{Bounded, UniqueSaturatedFrom};
 use xcm::v2::prelude::*;
 
 use super::*;
 #[allow(unused_imports)]
 use crate::Pallet as CrossInOut;
 
+/// Registers DOT in attestation mode with a mint limit and `relayers` whitelisted relayers.
+fn setup_attestation<T: Config>(
+	relayers: u32,
+	threshold: u32,
+	challenge_period: BlockNumberFor<T>,
+) -> Result<(Vec<T::AccountId>, Box<MultiLocation>), BenchmarkError> {
+	let currency_id = CurrencyId::Token(TokenSymbol::DOT);
+	let origin =
+		|| T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless);
+	assert_ok!(CrossInOut::<T>::register_currency_for_cross_in_out(origin()?, currency_id));
+	assert_ok!(CrossInOut::<T>::set_crossing_minimum_amount(
+		origin()?,
+		currency_id,
+		100u32.into(),
+		100u32.into()
+	));
+	assert_ok!(CrossInOut::<T>::set_mint_limit(
+		origin()?,
+		currency_id,
+		Some(MintLimit { cap: BalanceOf::<T>::max_value(), window: 100u32.into() })
+	));
+
+	let mut accounts = Vec::new();
+	for i in 0..relayers {
+		let relayer: T::AccountId = account("relayer", i, 1);
+		assert_ok!(CrossInOut::<T>::add_to_issue_whitelist(
+			origin()?,
+			currency_id,
+			relayer.clone()
+		));
+		accounts.push(relayer);
+	}
+	assert_ok!(CrossInOut::<T>::set_attestation_config(
+		origin()?,
+		currency_id,
+		Some(AttestationConfig { threshold, challenge_period, attestation_period: 10u32.into() })
+	));
+
+	let location = Box::new(MultiLocation {
+		parents: 0,
+		interior: X1(AccountId32 {
+			network: Any,
+			id: T::EntrancePalletId::get().into_account_truncating(),
+		}),
+	});
+	Ok((accounts, location))
+}
+
 benchmarks! {
 	register_currency_for_cross_in_out {
 		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -204,5 +252,123 @@ benchmarks! {
 
 	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),location2,test_account)
 
+	set_attestation_config {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		for i in 0..2 {
+			assert_ok!(CrossInOut::<T>::add_to_issue_whitelist(
+				T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+				CurrencyId::Token(TokenSymbol::DOT),
+				account("relayer", i, 1)
+			));
+		}
+		let config = AttestationConfig {
+			threshold: 2,
+			challenge_period: 10u32.into(),
+			attestation_period: 10u32.into(),
+		};
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),Some(config))
+
+	set_mint_limit {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let limit = MintLimit { cap: 1000u32.into(), window: 10u32.into() };
+	}: _<T::RuntimeOrigin>(origin,CurrencyId::Token(TokenSymbol::DOT),Some(limit))
+
+	attest_cross_in {
+		let n in 2 .. T::MaxLengthLimit::get();
+		let (relayers, location) = setup_attestation::<T>(n, 2, Zero::zero())?;
+		// Every relayer but the last attests its own payload, so the last attestation
+		// executes the first payload and drops the competing ones.
+		for (i, relayer) in relayers.iter().take(n as usize - 1).enumerate() {
+			assert_ok!(CrossInOut::<T>::attest_cross_in(
+				RawOrigin::Signed(relayer.clone()).into(),
+				H256::zero(),
+				location.clone(),
+				CurrencyId::Token(TokenSymbol::DOT),
+				(100u32 + i as u32).into(),
+				0,
+				None
+			));
+		}
+		let relayer = relayers[n as usize - 1].clone();
+	}: _(RawOrigin::Signed(relayer),H256::zero(),location,CurrencyId::Token(TokenSymbol::DOT),100u32.into(),0,None)
+	verify {
+		assert!(ProcessedCrossIns::<T>::contains_key(H256::zero()));
+	}
+
+	execute_cross_in {
+		let n in 1 .. T::MaxLengthLimit::get();
+		let (relayers, location) = setup_attestation::<T>(n, 1, 1u32.into())?;
+		for (i, relayer) in relayers.iter().enumerate() {
+			assert_ok!(CrossInOut::<T>::attest_cross_in(
+				RawOrigin::Signed(relayer.clone()).into(),
+				H256::zero(),
+				location.clone(),
+				CurrencyId::Token(TokenSymbol::DOT),
+				(100u32 + i as u32).into(),
+				0,
+				None
+			));
+		}
+		let payload_hash = CrossInOut::<T>::cross_in_payload_hash(
+			H256::zero(),
+			&location,
+			CurrencyId::Token(TokenSymbol::DOT),
+			100u32.into(),
+			0,
+			&None,
+		);
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+	}: _(RawOrigin::Signed(relayers[0].clone()),H256::zero(),payload_hash)
+	verify {
+		assert!(ProcessedCrossIns::<T>::contains_key(H256::zero()));
+	}
+
+	veto_cross_in {
+		let n in 1 .. T::MaxLengthLimit::get();
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (relayers, location) = setup_attestation::<T>(n, n, 1u32.into())?;
+		// Every relayer attests its own payload, so the veto drops `n` payloads.
+		for (i, relayer) in relayers.iter().enumerate() {
+			assert_ok!(CrossInOut::<T>::attest_cross_in(
+				RawOrigin::Signed(relayer.clone()).into(),
+				H256::zero(),
+				location.clone(),
+				CurrencyId::Token(TokenSymbol::DOT),
+				(100u32 + i as u32).into(),
+				0,
+				None
+			));
+		}
+	}: _<T::RuntimeOrigin>(origin,H256::zero())
+	verify {
+		assert!(VetoedCrossIns::<T>::contains_key(H256::zero()));
+		assert_eq!(PendingCrossIns::<T>::iter_prefix(H256::zero()).count(), 0);
+	}
+
+	remove_expired_cross_in {
+		let (relayers, location) = setup_attestation::<T>(2, 2, 1u32.into())?;
+		assert_ok!(CrossInOut::<T>::attest_cross_in(
+			RawOrigin::Signed(relayers[0].clone()).into(),
+			H256::zero(),
+			location.clone(),
+			CurrencyId::Token(TokenSymbol::DOT),
+			100u32.into(),
+			0,
+			None
+		));
+		let payload_hash = CrossInOut::<T>::cross_in_payload_hash(
+			H256::zero(),
+			&location,
+			CurrencyId::Token(TokenSymbol::DOT),
+			100u32.into(),
+			0,
+			&None,
+		);
+		frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 10u32.into());
+	}: _(RawOrigin::Signed(relayers[1].clone()),H256::zero(),payload_hash)
+	verify {
+		assert!(!PendingCrossIns::<T>::contains_key(H256::zero(), payload_hash));
+	}
+
 	impl_benchmark_test_suite!(CrossInOut,crate::mock::ExtBuilder::default().build(),crate::mock::Runtime);
 }
@@ -27,6 +27,8 @@ use bifrost_primitives::CurrencyId;
 use frame_support::{ensure, pallet_prelude::*, sp_runtime::traits::AccountIdConversion, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::MultiCurrency;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash, One, Saturating, Zero};
 use sp_std::boxed::Box;
 pub use weights::WeightInfo;
 #[allow(deprecated)]
@@ -49,6 +51,53 @@ type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
 >>::Balance;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+/// Parameters of the multi-relayer attestation mode of a currency.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AttestationConfig<BlockNumber> {
+	/// Number of relayers in the issue whitelist that must attest a cross-in.
+	pub threshold: u32,
+	/// Blocks between reaching the threshold and the earliest execution, during which
+	/// governance can veto the cross-in.
+	pub challenge_period: BlockNumber,
+	/// Blocks a cross-in may wait for the threshold after its first attestation before it
+	/// expires and is dropped.
+	pub attestation_period: BlockNumber,
+}
+
+/// Number of buckets the mint window of a currency is tracked in.
+pub const MINT_WINDOW_BUCKETS: u32 = 24;
+
+/// Maximum amount that can be crossed in during any `window` consecutive blocks.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MintLimit<Balance, BlockNumber> {
+	pub cap: Balance,
+	pub window: BlockNumber,
+}
+
+/// A cross-in waiting for relayer attestations or for the end of its challenge period.
+#[allow(deprecated)]
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PendingCrossIn<AccountId, Balance, BlockNumber> {
+	pub location: MultiLocation,
+	pub currency_id: CurrencyId,
+	pub amount: Balance,
+	pub nonce: u64,
+	pub remark: Option<Vec<u8>>,
+	/// Relayers that attested this cross-in.
+	pub attestations: Vec<AccountId>,
+	/// Set once the threshold is reached.
+	pub executable_at: Option<BlockNumber>,
+	/// The cross-in is dropped if the threshold is not reached before this block.
+	pub expires_at: BlockNumber,
+}
+
+impl<AccountId, Balance, BlockNumber: PartialOrd> PendingCrossIn<AccountId, Balance, BlockNumber> {
+	/// Whether the cross-in missed the attestation threshold before its expiry.
+	pub fn is_expired(&self, now: BlockNumber) -> bool {
+		self.executable_at.is_none() && now >= self.expires_at
+	}
+}
+
 #[allow(deprecated)]
 #[frame_support::pallet]
 pub mod pallet {
@@ -86,6 +135,27 @@ pub mod pallet {
 		AmountLowerThanMinimum,
 		ExceedMaxLengthLimit,
 		FailedToConvert,
+		/// The currency is in attestation mode and cannot be crossed in by a single issuer.
+		AttestationRequired,
+		/// The currency is not in attestation mode.
+		AttestationNotEnabled,
+		/// The threshold of an attestation config must not be zero nor exceed the number of
+		/// relayers in the issue whitelist.
+		InvalidThreshold,
+		/// The relayer already attested a cross-in with this external transaction id.
+		AlreadyAttested,
+		/// The attested cross-in has been vetoed.
+		AttestationVetoed,
+		/// The external transaction has already been executed.
+		AlreadyProcessed,
+		/// The cross-in has not reached the attestation threshold.
+		NotEnoughAttestations,
+		/// The challenge period of the cross-in is not over yet.
+		InChallengePeriod,
+		/// The pending cross-in has not expired yet.
+		NotExpired,
+		/// The amount exceeds the mint limit of the rolling window.
+		ExceedMintLimit,
 	}
 
 	#[pallet::event]
@@ -136,6 +206,36 @@ pub mod pallet {
 			cross_in_minimum: BalanceOf<T>,
 			cross_out_minimum: BalanceOf<T>,
 		},
+		AttestationConfigSet {
+			currency_id: CurrencyId,
+			config: Option<AttestationConfig<BlockNumberFor<T>>>,
+		},
+		MintLimitSet {
+			currency_id: CurrencyId,
+			limit: Option<MintLimit<BalanceOf<T>, BlockNumberFor<T>>>,
+		},
+		CrossInAttested {
+			tx_id: H256,
+			payload_hash: H256,
+			relayer: AccountIdOf<T>,
+			attestations: u32,
+		},
+		CrossInApproved {
+			tx_id: H256,
+			payload_hash: H256,
+			executable_at: BlockNumberFor<T>,
+		},
+		CrossInExecuted {
+			tx_id: H256,
+			payload_hash: H256,
+		},
+		CrossInVetoed {
+			tx_id: H256,
+		},
+		CrossInExpired {
+			tx_id: H256,
+			payload_hash: H256,
+		},
 	}
 
 	/// The current storage version, we set to 2 our new version(after migrate stroage from vec t
@@ -185,6 +285,46 @@ pub mod pallet {
 	pub type CrossingMinimumAmount<T> =
 		StorageMap<_, Blake2_128Concat, CurrencyId, (BalanceOf<T>, BalanceOf<T>)>;
 
+	/// Currencies in attestation mode, which can only be crossed in by N-of-M relayers.
+	#[pallet::storage]
+	pub type AttestationConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, AttestationConfig<BlockNumberFor<T>>>;
+
+	/// Cross-ins being attested or challenged, by external transaction id and payload hash.
+	/// Relayers disagreeing on the payload of a transaction attest separate entries.
+	#[pallet::storage]
+	pub type PendingCrossIns<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H256,
+		Blake2_128Concat,
+		H256,
+		PendingCrossIn<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
+	/// External transaction ids that have been executed.
+	#[pallet::storage]
+	pub type ProcessedCrossIns<T> = StorageMap<_, Blake2_128Concat, H256, ()>;
+
+	/// External transaction ids of vetoed cross-ins, which can not be attested again.
+	#[pallet::storage]
+	pub type VetoedCrossIns<T> = StorageMap<_, Blake2_128Concat, H256, ()>;
+
+	/// Maximum cross-in amount per rolling window of blocks.
+	#[pallet::storage]
+	pub type MintLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, CurrencyId, MintLimit<BalanceOf<T>, BlockNumberFor<T>>>;
+
+	/// Amounts minted in the current mint window, by bucket start block.
+	#[pallet::storage]
+	pub type MintedInWindow<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Vec<(BlockNumberFor<T>, BalanceOf<T>)>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -206,45 +346,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let issuer = ensure_signed(origin)?;
 
+			Self::ensure_cross_in_amount(currency_id, amount)?;
 			ensure!(
-				CrossCurrencyRegistry::<T>::contains_key(currency_id),
-				Error::<T>::CurrencyNotSupportCrossInAndOut
+				!AttestationConfigs::<T>::contains_key(currency_id),
+				Error::<T>::AttestationRequired
 			);
+			Self::ensure_issuer(currency_id, &issuer)?;
 
-			let crossing_minimum_amount = CrossingMinimumAmount::<T>::get(currency_id)
-				.ok_or(Error::<T>::NoCrossingMinimumSet)?;
-			ensure!(amount >= crossing_minimum_amount.0, Error::<T>::AmountLowerThanMinimum);
-
-			let issue_whitelist =
-				IssueWhiteList::<T>::get(currency_id).ok_or(Error::<T>::NotAllowed)?;
-			ensure!(issue_whitelist.contains(&issuer), Error::<T>::NotAllowed);
-
-			let entrance_account_mutlilcaition = Box::new(MultiLocation {
-				parents: 0,
-				interior: X1(AccountId32 {
-					network: Any,
-					id: T::EntrancePalletId::get().into_account_truncating(),
-				}),
-			});
-
-			// If the cross_in destination is entrance account, it is not required to be registered.
-			let dest = if entrance_account_mutlilcaition == location {
-				T::EntrancePalletId::get().into_account_truncating()
-			} else {
-				OuterMultilocationToAccount::<T>::get(currency_id, location.clone())
-					.ok_or(Error::<T>::NoAccountIdMapping)?
-			};
-
-			T::MultiCurrency::deposit(currency_id, &dest, amount)?;
-
-			Self::deposit_event(Event::CrossedIn {
-				dest,
-				currency_id,
-				location: *location,
-				amount,
-				remark,
-			});
-			Ok(())
+			Self::do_cross_in(currency_id, *location, amount, remark)
 		}
 
 		/// Destroy some balance from an account and issue cross-out event.
@@ -441,16 +550,29 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::ControlOrigin::ensure_origin(origin)?;
 
-			IssueWhiteList::<T>::mutate(currency_id, |issue_whitelist| -> Result<(), Error<T>> {
-				match issue_whitelist {
-					Some(issue_list) if issue_list.contains(&account) => {
-						issue_list.retain(|x| x.clone() != account);
-						Self::deposit_event(Event::RemovedFromIssueList { account, currency_id });
-						Ok(())
-					},
-					_ => Err(Error::<T>::NotExist),
-				}
-			})?;
+			IssueWhiteList::<T>::try_mutate(
+				currency_id,
+				|issue_whitelist| -> Result<(), Error<T>> {
+					match issue_whitelist {
+						Some(issue_list) if issue_list.contains(&account) => {
+							issue_list.retain(|x| x.clone() != account);
+							// Keep enough relayers to reach the attestation threshold.
+							if let Some(config) = AttestationConfigs::<T>::get(currency_id) {
+								ensure!(
+									issue_list.len() as u32 >= config.threshold,
+									Error::<T>::InvalidThreshold
+								);
+							}
+							Self::deposit_event(Event::RemovedFromIssueList {
+								account,
+								currency_id,
+							});
+							Ok(())
+						},
+						_ => Err(Error::<T>::NotExist),
+					}
+				},
+			)?;
 
 			Ok(())
 		}
@@ -538,5 +660,303 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Enable, update or disable (`None`) the attestation mode of a currency.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_attestation_config())]
+		pub fn set_attestation_config(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			config: Option<AttestationConfig<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(config) = config {
+				let relayers = IssueWhiteList::<T>::get(currency_id)
+					.map_or(0, |issue_whitelist| issue_whitelist.len() as u32);
+				ensure!(
+					config.threshold > 0 && config.threshold <= relayers,
+					Error::<T>::InvalidThreshold
+				);
+			}
+			AttestationConfigs::<T>::set(currency_id, config);
+
+			Self::deposit_event(Event::AttestationConfigSet { currency_id, config });
+
+			Ok(())
+		}
+
+		/// Set or remove (`None`) the mint limit of a currency.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_mint_limit())]
+		pub fn set_mint_limit(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			limit: Option<MintLimit<BalanceOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			MintLimits::<T>::set(currency_id, limit);
+
+			Self::deposit_event(Event::MintLimitSet { currency_id, limit });
+
+			Ok(())
+		}
+
+		/// Attest a cross-in observed on the outer chain as a relayer of the issue whitelist.
+		///
+		/// Attestations are counted per payload, so a relayer attesting a different payload
+		/// for the same `tx_id` can not block the genuine one. A payload missing the threshold
+		/// for `attestation_period` blocks expires, releasing its relayers. The cross-in becomes
+		/// executable `challenge_period` blocks after the attestation threshold is reached, or
+		/// is executed immediately if the period is zero.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::attest_cross_in(T::MaxLengthLimit::get()))]
+		pub fn attest_cross_in(
+			origin: OriginFor<T>,
+			tx_id: H256,
+			location: Box<MultiLocation>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: BalanceOf<T>,
+			nonce: u64,
+			remark: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+
+			Self::ensure_cross_in_amount(currency_id, amount)?;
+			let config = AttestationConfigs::<T>::get(currency_id)
+				.ok_or(Error::<T>::AttestationNotEnabled)?;
+			Self::ensure_issuer(currency_id, &relayer)?;
+			ensure!(!ProcessedCrossIns::<T>::contains_key(tx_id), Error::<T>::AlreadyProcessed);
+			ensure!(!VetoedCrossIns::<T>::contains_key(tx_id), Error::<T>::AttestationVetoed);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expired: Vec<H256> = PendingCrossIns::<T>::iter_prefix(tx_id)
+				.filter(|(_, pending)| pending.is_expired(now))
+				.map(|(payload_hash, _)| payload_hash)
+				.collect();
+			for payload_hash in expired {
+				PendingCrossIns::<T>::remove(tx_id, payload_hash);
+				Self::deposit_event(Event::CrossInExpired { tx_id, payload_hash });
+			}
+
+			let payload_hash =
+				Self::cross_in_payload_hash(tx_id, &location, currency_id, amount, nonce, &remark);
+			// A relayer attests a single payload per external transaction.
+			ensure!(
+				!PendingCrossIns::<T>::iter_prefix_values(tx_id)
+					.any(|pending| pending.attestations.contains(&relayer)),
+				Error::<T>::AlreadyAttested
+			);
+
+			let mut pending = match PendingCrossIns::<T>::get(tx_id, payload_hash) {
+				Some(pending) => pending,
+				None => PendingCrossIn {
+					location: *location,
+					currency_id,
+					amount,
+					nonce,
+					remark,
+					attestations: Vec::new(),
+					executable_at: None,
+					expires_at: now.saturating_add(config.attestation_period),
+				},
+			};
+			pending.attestations.push(relayer.clone());
+
+			let attestations = pending.attestations.len() as u32;
+			Self::deposit_event(Event::CrossInAttested {
+				tx_id,
+				payload_hash,
+				relayer,
+				attestations,
+			});
+
+			if pending.executable_at.is_none() && attestations >= config.threshold {
+				let executable_at = now.saturating_add(config.challenge_period);
+				pending.executable_at = Some(executable_at);
+				Self::deposit_event(Event::CrossInApproved { tx_id, payload_hash, executable_at });
+
+				if config.challenge_period.is_zero() {
+					return Self::do_execute_cross_in(tx_id, payload_hash, pending);
+				}
+			}
+			PendingCrossIns::<T>::insert(tx_id, payload_hash, pending);
+
+			Ok(())
+		}
+
+		/// Execute an approved cross-in whose challenge period is over. Callable by anyone.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::execute_cross_in(T::MaxLengthLimit::get()))]
+		pub fn execute_cross_in(
+			origin: OriginFor<T>,
+			tx_id: H256,
+			payload_hash: H256,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pending =
+				PendingCrossIns::<T>::get(tx_id, payload_hash).ok_or(Error::<T>::NotExist)?;
+			let executable_at = pending.executable_at.ok_or(Error::<T>::NotEnoughAttestations)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= executable_at,
+				Error::<T>::InChallengePeriod
+			);
+
+			Self::do_execute_cross_in(tx_id, payload_hash, pending)
+		}
+
+		/// Reject an external transaction. All its pending payloads are dropped and it can not
+		/// be attested again, whatever the payload.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::veto_cross_in(T::MaxLengthLimit::get()))]
+		pub fn veto_cross_in(origin: OriginFor<T>, tx_id: H256) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ensure!(!ProcessedCrossIns::<T>::contains_key(tx_id), Error::<T>::AlreadyProcessed);
+			ensure!(!VetoedCrossIns::<T>::contains_key(tx_id), Error::<T>::AttestationVetoed);
+
+			// At most one payload per relayer of the issue whitelist.
+			let _ = PendingCrossIns::<T>::clear_prefix(tx_id, T::MaxLengthLimit::get(), None);
+			VetoedCrossIns::<T>::insert(tx_id, ());
+
+			Self::deposit_event(Event::CrossInVetoed { tx_id });
+
+			Ok(())
+		}
+
+		/// Drop a pending cross-in that missed the attestation threshold before its expiry.
+		/// Callable by anyone.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_expired_cross_in())]
+		pub fn remove_expired_cross_in(
+			origin: OriginFor<T>,
+			tx_id: H256,
+			payload_hash: H256,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let pending =
+				PendingCrossIns::<T>::get(tx_id, payload_hash).ok_or(Error::<T>::NotExist)?;
+			ensure!(
+				pending.is_expired(frame_system::Pallet::<T>::block_number()),
+				Error::<T>::NotExpired
+			);
+			PendingCrossIns::<T>::remove(tx_id, payload_hash);
+
+			Self::deposit_event(Event::CrossInExpired { tx_id, payload_hash });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The hash identifying an attested cross-in payload.
+	#[allow(deprecated)]
+	pub fn cross_in_payload_hash(
+		tx_id: H256,
+		location: &MultiLocation,
+		currency_id: CurrencyId,
+		amount: BalanceOf<T>,
+		nonce: u64,
+		remark: &Option<Vec<u8>>,
+	) -> H256 {
+		BlakeTwo256::hash_of(&(tx_id, location, currency_id, amount, nonce, remark))
+	}
+
+	fn ensure_cross_in_amount(currency_id: CurrencyId, amount: BalanceOf<T>) -> DispatchResult {
+		ensure!(
+			CrossCurrencyRegistry::<T>::contains_key(currency_id),
+			Error::<T>::CurrencyNotSupportCrossInAndOut
+		);
+
+		let crossing_minimum_amount =
+			CrossingMinimumAmount::<T>::get(currency_id).ok_or(Error::<T>::NoCrossingMinimumSet)?;
+		ensure!(amount >= crossing_minimum_amount.0, Error::<T>::AmountLowerThanMinimum);
+		Ok(())
+	}
+
+	fn ensure_issuer(currency_id: CurrencyId, issuer: &AccountIdOf<T>) -> DispatchResult {
+		let issue_whitelist =
+			IssueWhiteList::<T>::get(currency_id).ok_or(Error::<T>::NotAllowed)?;
+		ensure!(issue_whitelist.contains(issuer), Error::<T>::NotAllowed);
+		Ok(())
+	}
+
+	fn do_execute_cross_in(
+		tx_id: H256,
+		payload_hash: H256,
+		pending: PendingCrossIn<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		Self::do_cross_in(pending.currency_id, pending.location, pending.amount, pending.remark)?;
+		// Drop the competing payloads, at most one per relayer of the issue whitelist.
+		let _ = PendingCrossIns::<T>::clear_prefix(tx_id, T::MaxLengthLimit::get(), None);
+		ProcessedCrossIns::<T>::insert(tx_id, ());
+
+		Self::deposit_event(Event::CrossInExecuted { tx_id, payload_hash });
+		Ok(())
+	}
+
+	#[allow(deprecated)]
+	fn do_cross_in(
+		currency_id: CurrencyId,
+		location: MultiLocation,
+		amount: BalanceOf<T>,
+		remark: Option<Vec<u8>>,
+	) -> DispatchResult {
+		let entrance_account_mutlilcaition = MultiLocation {
+			parents: 0,
+			interior: X1(AccountId32 {
+				network: Any,
+				id: T::EntrancePalletId::get().into_account_truncating(),
+			}),
+		};
+
+		// If the cross_in destination is entrance account, it is not required to be registered.
+		let dest = if entrance_account_mutlilcaition == location {
+			T::EntrancePalletId::get().into_account_truncating()
+		} else {
+			OuterMultilocationToAccount::<T>::get(currency_id, location.clone())
+				.ok_or(Error::<T>::NoAccountIdMapping)?
+		};
+
+		Self::consume_mint_limit(currency_id, amount)?;
+		T::MultiCurrency::deposit(currency_id, &dest, amount)?;
+
+		Self::deposit_event(Event::CrossedIn { dest, currency_id, location, amount, remark });
+		Ok(())
+	}
+
+	/// Mints are grouped in `MINT_WINDOW_BUCKETS` buckets per window, and a bucket keeps
+	/// counting until its last block has left the window, so no `window` consecutive blocks
+	/// mint more than `cap`.
+	fn consume_mint_limit(currency_id: CurrencyId, amount: BalanceOf<T>) -> DispatchResult {
+		let limit = match MintLimits::<T>::get(currency_id) {
+			Some(limit) => limit,
+			None => return Ok(()),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let bucket_len = (limit.window / MINT_WINDOW_BUCKETS.into()).max(One::one());
+		let bucket_start = now.saturating_sub(now % bucket_len);
+
+		MintedInWindow::<T>::try_mutate(currency_id, |buckets| {
+			buckets.retain(|(start, _)| {
+				start.saturating_add(bucket_len).saturating_add(limit.window) >
+					now.saturating_add(One::one())
+			});
+			let minted = buckets
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |minted, (_, amount)| minted.saturating_add(*amount));
+			ensure!(minted.saturating_add(amount) <= limit.cap, Error::<T>::ExceedMintLimit);
+
+			match buckets.last_mut() {
+				Some((start, bucket_minted)) if *start == bucket_start =>
+					*bucket_minted = bucket_minted.saturating_add(amount),
+				_ => buckets.push((bucket_start, amount)),
+			}
+			Ok(())
+		})
 	}
 }
@@ -21,6 +21,7 @@
 use crate::{mock::*, *};
 use bifrost_primitives::currency::KSM;
 use frame_support::{assert_noop, assert_ok, WeakBoundedVec};
+use sp_core::H256;
 use sp_runtime::DispatchError::BadOrigin;
 #[allow(deprecated)]
 use xcm::opaque::v2::{Junction, Junctions::X1};
@@ -314,3 +315,333 @@ fn set_crossing_minimum_amount_should_work() {
 		assert_eq!(CrossingMinimumAmount::<Runtime>::get(KSM), Some((100, 100)));
 	});
 }
+
+#[allow(deprecated)]
+fn setup_attestation(challenge_period: u64) -> MultiLocation {
+	let location = MultiLocation {
+		parents: 100,
+		interior: X1(Junction::GeneralKey(WeakBoundedVec::default())),
+	};
+	System::set_block_number(1);
+	CrossCurrencyRegistry::<Runtime>::insert(KSM, ());
+	CrossingMinimumAmount::<Runtime>::insert(KSM, (1, 1));
+	IssueWhiteList::<Runtime>::insert(
+		KSM,
+		BoundedVec::try_from(vec![ALICE, BOB, CHARLIE]).unwrap(),
+	);
+	AccountToOuterMultilocation::<Runtime>::insert(KSM, BOB, location.clone());
+	OuterMultilocationToAccount::<Runtime>::insert(KSM, location.clone(), BOB);
+	assert_ok!(CrossInOut::set_attestation_config(
+		RuntimeOrigin::signed(ALICE),
+		KSM,
+		Some(AttestationConfig { threshold: 2, challenge_period, attestation_period: 20 })
+	));
+	location
+}
+
+#[allow(deprecated)]
+fn attest(
+	relayer: AccountId,
+	tx_id: H256,
+	location: &MultiLocation,
+	amount: Balance,
+) -> DispatchResult {
+	CrossInOut::attest_cross_in(
+		RuntimeOrigin::signed(relayer),
+		tx_id,
+		Box::new(location.clone()),
+		KSM,
+		amount,
+		0,
+		None,
+	)
+}
+
+#[allow(deprecated)]
+fn payload_hash(tx_id: H256, location: &MultiLocation, amount: Balance) -> H256 {
+	CrossInOut::cross_in_payload_hash(tx_id, location, KSM, amount, 0, &None)
+}
+
+#[test]
+fn attest_cross_in_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let location = setup_attestation(10);
+		let tx_id = H256::repeat_byte(1);
+		let hash = payload_hash(tx_id, &location, 100);
+
+		assert_noop!(
+			CrossInOut::cross_in(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(location.clone()),
+				KSM,
+				100,
+				None
+			),
+			Error::<Runtime>::AttestationRequired
+		);
+
+		assert_ok!(attest(ALICE, tx_id, &location, 100));
+		assert_noop!(attest(ALICE, tx_id, &location, 100), Error::<Runtime>::AlreadyAttested);
+		// A relayer can not attest a second payload for the same transaction either.
+		assert_noop!(attest(ALICE, tx_id, &location, 200), Error::<Runtime>::AlreadyAttested);
+		assert_noop!(
+			CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, hash),
+			Error::<Runtime>::NotEnoughAttestations
+		);
+
+		assert_ok!(attest(BOB, tx_id, &location, 100));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossInApproved {
+			tx_id,
+			payload_hash: hash,
+			executable_at: 11,
+		}));
+		assert_noop!(
+			CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, hash),
+			Error::<Runtime>::InChallengePeriod
+		);
+
+		System::set_block_number(11);
+		assert_ok!(CrossInOut::execute_cross_in(RuntimeOrigin::signed(CHARLIE), tx_id, hash));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 200);
+		assert_eq!(PendingCrossIns::<Runtime>::get(tx_id, hash), None);
+
+		assert_noop!(attest(CHARLIE, tx_id, &location, 100), Error::<Runtime>::AlreadyProcessed);
+	});
+}
+
+#[test]
+fn conflicting_attestation_should_not_block_cross_in() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let location = setup_attestation(10);
+		let tx_id = H256::repeat_byte(1);
+		let hash = payload_hash(tx_id, &location, 100);
+		let forged_hash = payload_hash(tx_id, &location, 1_000);
+
+		// The first attester forges the amount, the other relayers attest the real deposit.
+		assert_ok!(attest(ALICE, tx_id, &location, 1_000));
+		assert_ok!(attest(BOB, tx_id, &location, 100));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossInAttested {
+			tx_id,
+			payload_hash: hash,
+			relayer: BOB,
+			attestations: 1,
+		}));
+		assert_ok!(attest(CHARLIE, tx_id, &location, 100));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossInApproved {
+			tx_id,
+			payload_hash: hash,
+			executable_at: 11,
+		}));
+		assert_eq!(
+			PendingCrossIns::<Runtime>::get(tx_id, forged_hash).map(|p| p.executable_at),
+			Some(None)
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, forged_hash),
+			Error::<Runtime>::NotEnoughAttestations
+		);
+		assert_ok!(CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, hash));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 200);
+		assert_eq!(PendingCrossIns::<Runtime>::iter_prefix(tx_id).count(), 0);
+	});
+}
+
+#[test]
+fn veto_cross_in_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let location = setup_attestation(10);
+		let tx_id = H256::repeat_byte(1);
+		let hash = payload_hash(tx_id, &location, 100);
+		let forged_hash = payload_hash(tx_id, &location, 1_000);
+
+		assert_ok!(attest(ALICE, tx_id, &location, 1_000));
+		assert_ok!(attest(BOB, tx_id, &location, 100));
+
+		assert_noop!(CrossInOut::veto_cross_in(RuntimeOrigin::signed(BOB), tx_id), BadOrigin);
+		assert_ok!(CrossInOut::veto_cross_in(RuntimeOrigin::signed(ALICE), tx_id));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossInVetoed { tx_id }));
+		assert_eq!(PendingCrossIns::<Runtime>::iter_prefix(tx_id).count(), 0);
+		assert_eq!(VetoedCrossIns::<Runtime>::get(tx_id), Some(()));
+		assert_eq!(ProcessedCrossIns::<Runtime>::get(tx_id), None);
+		assert_noop!(
+			CrossInOut::veto_cross_in(RuntimeOrigin::signed(ALICE), tx_id),
+			Error::<Runtime>::AttestationVetoed
+		);
+
+		// The transaction can not be attested again, whatever the payload.
+		assert_noop!(attest(CHARLIE, tx_id, &location, 1_000), Error::<Runtime>::AttestationVetoed);
+		assert_noop!(attest(ALICE, tx_id, &location, 100), Error::<Runtime>::AttestationVetoed);
+		assert_noop!(
+			CrossInOut::attest_cross_in(
+				RuntimeOrigin::signed(CHARLIE),
+				tx_id,
+				Box::new(location.clone()),
+				KSM,
+				1_000,
+				1,
+				Some(b"remark".to_vec())
+			),
+			Error::<Runtime>::AttestationVetoed
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, hash),
+			Error::<Runtime>::NotExist
+		);
+		assert_noop!(
+			CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, forged_hash),
+			Error::<Runtime>::NotExist
+		);
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 100);
+
+		// Executed transactions can not be vetoed.
+		let tx_id = H256::repeat_byte(2);
+		let hash = payload_hash(tx_id, &location, 100);
+		assert_ok!(attest(ALICE, tx_id, &location, 100));
+		assert_ok!(attest(BOB, tx_id, &location, 100));
+		System::set_block_number(21);
+		assert_ok!(CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, hash));
+		assert_noop!(
+			CrossInOut::veto_cross_in(RuntimeOrigin::signed(ALICE), tx_id),
+			Error::<Runtime>::AlreadyProcessed
+		);
+	});
+}
+
+#[test]
+fn pending_cross_in_should_expire() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let location = setup_attestation(10);
+		let tx_id = H256::repeat_byte(1);
+		let hash = payload_hash(tx_id, &location, 100);
+		let forged_hash = payload_hash(tx_id, &location, 1_000);
+
+		assert_ok!(attest(ALICE, tx_id, &location, 1_000));
+		assert_ok!(attest(BOB, tx_id, &location, 100));
+		assert_eq!(
+			PendingCrossIns::<Runtime>::get(tx_id, forged_hash).map(|p| p.expires_at),
+			Some(21)
+		);
+
+		System::set_block_number(20);
+		assert_noop!(
+			CrossInOut::remove_expired_cross_in(RuntimeOrigin::signed(CHARLIE), tx_id, forged_hash),
+			Error::<Runtime>::NotExpired
+		);
+
+		System::set_block_number(21);
+		assert_ok!(CrossInOut::remove_expired_cross_in(
+			RuntimeOrigin::signed(CHARLIE),
+			tx_id,
+			forged_hash
+		));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossInExpired {
+			tx_id,
+			payload_hash: forged_hash,
+		}));
+		assert_eq!(PendingCrossIns::<Runtime>::get(tx_id, forged_hash), None);
+
+		// The expired attestation of BOB is dropped by the next attestation, so the payload
+		// starts over.
+		assert_ok!(attest(CHARLIE, tx_id, &location, 100));
+		assert_eq!(
+			PendingCrossIns::<Runtime>::get(tx_id, hash).map(|p| (p.attestations, p.expires_at)),
+			Some((vec![CHARLIE], 41))
+		);
+		assert_ok!(attest(ALICE, tx_id, &location, 100));
+		System::assert_last_event(RuntimeEvent::CrossInOut(Event::CrossInApproved {
+			tx_id,
+			payload_hash: hash,
+			executable_at: 31,
+		}));
+
+		// Approved cross-ins do not expire.
+		System::set_block_number(41);
+		assert_noop!(
+			CrossInOut::remove_expired_cross_in(RuntimeOrigin::signed(CHARLIE), tx_id, hash),
+			Error::<Runtime>::NotExpired
+		);
+		assert_ok!(CrossInOut::execute_cross_in(RuntimeOrigin::signed(BOB), tx_id, hash));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 200);
+	});
+}
+
+#[test]
+fn attestation_threshold_should_not_exceed_issue_whitelist() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		setup_attestation(10);
+		let config = |threshold| AttestationConfig {
+			threshold,
+			challenge_period: 10,
+			attestation_period: 20,
+		};
+
+		assert_noop!(
+			CrossInOut::set_attestation_config(RuntimeOrigin::signed(ALICE), KSM, Some(config(0))),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_noop!(
+			CrossInOut::set_attestation_config(RuntimeOrigin::signed(ALICE), KSM, Some(config(4))),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_ok!(CrossInOut::set_attestation_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(config(3))
+		));
+
+		// Removing a relayer must leave enough of them to reach the threshold.
+		assert_noop!(
+			CrossInOut::remove_from_issue_whitelist(RuntimeOrigin::signed(ALICE), KSM, CHARLIE),
+			Error::<Runtime>::InvalidThreshold
+		);
+		assert_ok!(CrossInOut::set_attestation_config(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(config(2))
+		));
+		assert_ok!(CrossInOut::remove_from_issue_whitelist(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			CHARLIE
+		));
+	});
+}
+
+#[test]
+fn mint_limit_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let location = setup_attestation(0);
+		assert_ok!(CrossInOut::set_mint_limit(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(MintLimit { cap: 150, window: 10 })
+		));
+
+		// Zero challenge period executes once the threshold is reached.
+		assert_ok!(attest(ALICE, H256::repeat_byte(1), &location, 50));
+		assert_ok!(attest(BOB, H256::repeat_byte(1), &location, 50));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 150);
+
+		System::set_block_number(10);
+		assert_ok!(attest(ALICE, H256::repeat_byte(2), &location, 100));
+		assert_ok!(attest(BOB, H256::repeat_byte(2), &location, 100));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 250);
+
+		// The window rolls instead of restarting, so blocks 2 to 11 still hold the mint of
+		// block 10.
+		System::set_block_number(11);
+		assert_ok!(attest(ALICE, H256::repeat_byte(3), &location, 100));
+		assert_noop!(
+			attest(BOB, H256::repeat_byte(3), &location, 100),
+			Error::<Runtime>::ExceedMintLimit
+		);
+
+		System::set_block_number(20);
+		assert_ok!(attest(BOB, H256::repeat_byte(3), &location, 100));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), 350);
+		assert_eq!(MintedInWindow::<Runtime>::get(KSM), vec![(20, 100)]);
+	});
+}
@@ -188,11 +188,14 @@ impl bifrost_fee_share::Config for Runtime {
 impl pallet_prices::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = MockDataProvider;
+	type Twap = ();
+	type Time = MockTime;
 	type FeederOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type RelayCurrency = RelayCurrencyId;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
+	type VtokenMinting = ();
 	type WeightInfo = ();
 }
 
@@ -224,6 +227,16 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for MockDataProvider {
 		Ok(())
 	}
 }
+
+pub struct MockTime;
+impl frame_support::traits::Time for MockTime {
+	type Moment = Moment;
+
+	fn now() -> Moment {
+		0
+	}
+}
+
 pub struct MockPriceFeeder;
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug)]
 pub struct CurrencyIdWrap(CurrencyId);
@@ -362,6 +375,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = TreasuryAccount;
+	type RelayStateRoot = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_type_with_key! {
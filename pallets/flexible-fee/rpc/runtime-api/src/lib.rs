@@ -18,15 +18,21 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bifrost_primitives::{Balance, CurrencyId};
+use bifrost_primitives::{Balance, CurrencyId, SwapRoute};
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
 
 decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait FlexibleFeeRuntimeApi<AccountId> where
 		AccountId: Codec,
 	{
 		/// get flexible fee token and amount to be deducted
 		fn get_fee_token_and_amount(who: AccountId, fee: Balance, utx: Block::Extrinsic) -> (CurrencyId, Balance);
+
+		/// get the extra fee tokens and amounts to be deducted, with the conversion routes used
+		#[api_version(2)]
+		fn get_extra_fee_tokens_and_routes(who: AccountId, utx: Block::Extrinsic) -> Vec<(CurrencyId, Balance, Option<SwapRoute>)>;
 	}
 }
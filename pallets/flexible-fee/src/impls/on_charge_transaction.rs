@@ -16,11 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Config, ExtraFeeByCall, Pallet};
+use crate::{Config, Pallet};
 use bifrost_primitives::{Balance, CurrencyId, Price, PriceFeeder, BNC};
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment::OnChargeTransaction;
-use parity_scale_codec::Encode;
 use sp_core::Get;
 use sp_runtime::{
 	traits::{DispatchInfoOf, PostDispatchInfoOf, Zero},
@@ -64,28 +63,11 @@ where
 		T::MultiCurrency::withdraw(fee_currency, who, fee_amount)
 			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
 
-		for (call_name, (extra_fee_currency, extra_fee_amount, extra_fee_receiver)) in
-			ExtraFeeByCall::<T>::iter()
+		for (extra_fee_currency, extra_fee_amount, extra_fee_receiver) in
+			Self::get_extra_fees_by_call(call)
 		{
-			let raw_call_name = call_name.to_vec();
-			let raw_call_name_len = raw_call_name.len();
-			if call.encode().len() >= raw_call_name_len {
-				if call.encode()[0..raw_call_name_len].eq(&raw_call_name) {
-					match Self::charge_extra_fee(
-						who,
-						extra_fee_currency,
-						extra_fee_amount,
-						&extra_fee_receiver,
-					) {
-						Ok(_) => {},
-						Err(_) => {
-							return Err(TransactionValidityError::Invalid(
-								InvalidTransaction::Payment,
-							));
-						},
-					}
-				};
-			}
+			Self::charge_extra_fee(who, extra_fee_currency, extra_fee_amount, &extra_fee_receiver)
+				.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Payment))?;
 		}
 
 		if fee_currency == BNC {
@@ -19,12 +19,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use crate::pallet::*;
+use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	currency::{VGLMR, VMANTA, WETH},
-	traits::XcmDestWeightAndFeeHandler,
-	Balance, BalanceCmp, CurrencyId, DerivativeIndex, Price, PriceFeeder, TryConvertFrom,
-	XcmOperationType, BNC, DOT, GLMR, MANTA, VBNC, VDOT,
+	traits::XcmDestWeightAndFeeHandler, Balance, BalanceCmp, CurrencyId, CurrencyIdMapping,
+	DerivativeIndex, Price, PriceFeeder, SwapRoute, TryConvertFrom, TwapProvider, XcmOperationType,
+	BNC,
 };
+use bifrost_stable_pool::traits::StablePoolHandler;
 use bifrost_xcm_interface::{polkadot::RelaychainCall, traits::parachains, PolkadotXcmCall};
 use core::convert::Into;
 use cumulus_primitives_core::ParaId;
@@ -58,6 +59,7 @@ pub mod impls;
 pub mod migrations;
 mod mock;
 mod mock_price;
+pub mod route;
 mod tests;
 pub mod weights;
 
@@ -115,6 +117,26 @@ pub mod pallet {
 		type ParachainId: Get<ParaId>;
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+		/// Notified after the swap of an extra fee
+		type Twap: TwapProvider;
+
+		/// Stable-pool pools used to convert fee currencies
+		type StablePoolHandler: StablePoolHandler<
+			Balance = Balance,
+			AccountId = Self::AccountId,
+			CurrencyId = CurrencyId,
+		>;
+
+		/// The intermediate currencies of two-hop conversion routes
+		#[pallet::constant]
+		type SwapRouteHubs: Get<Vec<CurrencyId>>;
+
+		/// Convert Location to `T::CurrencyId`, also provides the metadata of currencies.
+		type CurrencyIdConvert: CurrencyIdMapping<
+			CurrencyId,
+			xcm::v3::MultiLocation,
+			AssetMetadata<BalanceOf<Self>>,
+		>;
 	}
 
 	#[pallet::hooks]
@@ -191,6 +213,9 @@ pub mod pallet {
 		XcmExecutionFailed,
 		CurrencyNotSupport,
 		MaxCurrenciesReached,
+		NoSwapRoute,
+		/// The currency is not registered in the asset registry.
+		CurrencyMetadataNotFound,
 	}
 
 	#[pallet::call]
@@ -403,63 +428,65 @@ impl<T: Config> Pallet<T> {
 		Err(Error::<T>::NotEnoughBalance)
 	}
 
-	fn charge_extra_fee(
+	/// Get the currency, amount and conversion route the extra fee is paid with
+	pub fn get_extra_fee_currency_and_route(
 		who: &T::AccountId,
 		extra_fee_currency: CurrencyId,
 		extra_fee_amount: Balance,
-		extra_fee_receiver: &T::AccountId,
-	) -> Result<(), Error<T>> {
+	) -> Result<(CurrencyId, Balance, Option<SwapRoute>), Error<T>> {
 		let fee_currency_list = Self::get_fee_currency_list(who);
 		// charge the fee by the order of the above order list.
 		// first to check whether the user has the asset. If no, pass it. If yes, try to make
 		// transaction in the DEX in exchange for BNC
-		let mut fee_info = None;
 		for currency_id in fee_currency_list {
 			// If it is mainnet currency
 			if currency_id == extra_fee_currency {
 				if T::MultiCurrency::ensure_can_withdraw(extra_fee_currency, who, extra_fee_amount)
 					.is_ok()
 				{
-					fee_info = Some((currency_id, extra_fee_amount));
-					break;
-				}
-			} else {
-				match Self::ensure_can_swap(who, currency_id, extra_fee_currency, extra_fee_amount)
-				{
-					Ok(amount_in) => {
-						fee_info = Some((currency_id, amount_in));
-						break;
-					},
-					Err(_) => {},
+					return Ok((currency_id, extra_fee_amount, None));
 				}
+			} else if let Ok(route) =
+				Self::ensure_can_swap(who, currency_id, extra_fee_currency, extra_fee_amount)
+			{
+				return Ok((currency_id, route.amount_in(), Some(route)));
 			}
 		}
 
-		match fee_info {
-			Some((fee_currency, fee_amount)) =>
-				if fee_currency == extra_fee_currency {
-					T::MultiCurrency::transfer(fee_currency, who, extra_fee_receiver, fee_amount)
-						.map_err(|_| Error::<T>::NotEnoughBalance)?;
-					Ok(())
-				} else {
-					let from_asset_id = Self::get_currency_asset_id(fee_currency)?;
-					let to_asset_id = Self::get_currency_asset_id(extra_fee_currency)?;
-					let path = vec![from_asset_id, to_asset_id];
-
-					T::DexOperator::inner_swap_assets_for_exact_assets(
-						who,
-						extra_fee_amount,
-						fee_amount,
-						&path,
-						extra_fee_receiver,
-					)
+		Err(Error::<T>::ConversionError)
+	}
+
+	fn charge_extra_fee(
+		who: &T::AccountId,
+		extra_fee_currency: CurrencyId,
+		extra_fee_amount: Balance,
+		extra_fee_receiver: &T::AccountId,
+	) -> Result<(), Error<T>> {
+		match Self::get_extra_fee_currency_and_route(who, extra_fee_currency, extra_fee_amount)? {
+			(fee_currency, fee_amount, None) => {
+				T::MultiCurrency::transfer(fee_currency, who, extra_fee_receiver, fee_amount)
 					.map_err(|_| Error::<T>::NotEnoughBalance)?;
-					Ok(())
-				},
-			None => Err(Error::<T>::ConversionError),
+				Ok(())
+			},
+			(_, _, Some(route)) => {
+				Self::swap_by_route(who, &route, extra_fee_receiver)
+					.map_err(|_| Error::<T>::NotEnoughBalance)?;
+				Ok(())
+			},
 		}
 	}
 
+	/// The extra fees charged for `call`, as currency, amount and receiver
+	pub fn get_extra_fees_by_call(
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> Vec<(CurrencyId, Balance, T::AccountId)> {
+		let encoded_call = call.encode();
+		ExtraFeeByCall::<T>::iter()
+			.filter(|(raw_call_name, _)| encoded_call.starts_with(raw_call_name))
+			.map(|(_, fee_info)| fee_info)
+			.collect()
+	}
+
 	/// This function is for runtime-api to call
 	pub fn cal_fee_token_and_amount(
 		who: &T::AccountId,
@@ -471,6 +498,20 @@ impl<T: Config> Pallet<T> {
 		Ok((fee_currency, fee_amount))
 	}
 
+	/// This function is for runtime-api to call, the extra fees of `utx` with the currencies,
+	/// amounts and conversion routes they are paid with
+	pub fn cal_extra_fee_tokens_and_routes(
+		who: &T::AccountId,
+		utx: &<T as frame_system::Config>::RuntimeCall,
+	) -> Result<Vec<(CurrencyId, Balance, Option<SwapRoute>)>, Error<T>> {
+		Self::get_extra_fees_by_call(utx)
+			.into_iter()
+			.map(|(extra_fee_currency, extra_fee_amount, _)| {
+				Self::get_extra_fee_currency_and_route(who, extra_fee_currency, extra_fee_amount)
+			})
+			.collect()
+	}
+
 	fn get_currency_asset_id(currency_id: CurrencyId) -> Result<AssetId, Error<T>> {
 		let asset_id: AssetId =
 			AssetId::try_convert_from(currency_id, T::ParachainId::get().into())
@@ -483,24 +524,13 @@ impl<T: Config> Pallet<T> {
 		from_currency: CurrencyId,
 		to_currency: CurrencyId,
 		amount_out: Balance,
-	) -> Result<Balance, Error<T>> {
+	) -> Result<SwapRoute, Error<T>> {
 		// If it is other assets, go to exchange fee amount.
-		let from_asset_id =
-			Self::get_currency_asset_id(from_currency).map_err(|_| Error::<T>::ConversionError)?;
-
-		let to_asset_id =
-			Self::get_currency_asset_id(to_currency).map_err(|_| Error::<T>::ConversionError)?;
-
-		let path = vec![from_asset_id, to_asset_id];
-		match T::DexOperator::get_amount_in_by_path(amount_out, &path) {
-			Ok(amounts) => {
-				let amount_in = amounts[0];
-				T::MultiCurrency::ensure_can_withdraw(from_currency, who, amount_in)
-					.map_err(|_| Error::<T>::NotEnoughBalance)?;
-				Ok(amount_in)
-			},
-			Err(_) => Err(Error::<T>::NotEnoughBalance)?,
-		}
+		let route = Self::find_swap_route(from_currency, to_currency, amount_out)
+			.map_err(|_| Error::<T>::NotEnoughBalance)?;
+		T::MultiCurrency::ensure_can_withdraw(from_currency, who, route.amount_in())
+			.map_err(|_| Error::<T>::NotEnoughBalance)?;
+		Ok(route)
 	}
 }
 
@@ -515,8 +545,8 @@ impl<T: Config> BalanceCmp<T::AccountId> for Pallet<T> {
 	/// - `currency`: The currency ID to be compared.
 	/// - `amount`: The amount to compare against the account's balance, with the precision
 	///   specified by `amount_precision`.
-	/// - `amount_precision`: The precision of the `amount` specified. If greater than 18, the
-	///   precision of the `currency` will be adjusted accordingly.
+	/// - `amount_precision`: The precision of the `amount` specified. Both sides are scaled to the
+	///   largest of 18, `amount_precision` and the decimals of the `currency`.
 	///
 	/// # Returns
 	/// - `Ok(std::cmp::Ordering)`: Returns the ordering result (`Less`, `Equal`, `Greater`) based
@@ -536,21 +566,22 @@ impl<T: Config> BalanceCmp<T::AccountId> for Pallet<T> {
 			Fortitude::Polite,
 		);
 
-		// Define the standard precision as 18 decimal places.
-		let standard_precision: u32 = amount_precision.max(18);
+		// The decimals registered for the currency.
+		let balance_precision = T::CurrencyIdConvert::get_currency_metadata(*currency)
+			.map(|metadata| metadata.decimals as u32)
+			.ok_or(Error::<T>::CurrencyMetadataNotFound)?;
+
+		// Define the standard precision as at least 18 decimal places, so that neither side
+		// loses precision.
+		let standard_precision: u32 = amount_precision.max(balance_precision).max(18);
 
 		// Adjust the amount to the standard precision.
 		let precision_offset = standard_precision.saturating_sub(amount_precision);
 		let adjust_precision = 10u128.pow(precision_offset);
 		let amount = amount.saturating_mul(adjust_precision);
 
-		// Adjust the balance based on currency type.
-		let balance_precision_offset = match *currency {
-			WETH | GLMR | VGLMR | MANTA | VMANTA => standard_precision.saturating_sub(18),
-			BNC | VBNC => standard_precision.saturating_sub(12),
-			DOT | VDOT => standard_precision.saturating_sub(10),
-			_ => return Err(Error::<T>::CurrencyNotSupport),
-		};
+		// Adjust the balance to the standard precision.
+		let balance_precision_offset = standard_precision.saturating_sub(balance_precision);
 
 		// Apply precision adjustment to balance.
 		balance = balance.saturating_mul(10u128.pow(balance_precision_offset));
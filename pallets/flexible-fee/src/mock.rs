@@ -20,8 +20,10 @@
 
 use super::*;
 use crate::{self as flexible_fee, mock_price::MockPriceFeeder};
+use bifrost_asset_registry::AssetIdMaps;
 use bifrost_currencies::BasicCurrencyAdapter;
-use bifrost_primitives::{Balance, CurrencyId, TokenSymbol};
+use bifrost_primitives::{Balance, CurrencyId, TokenSymbol, DOT, USDC, VDOT, WETH};
+use bifrost_stable_pool::{PoolTokenIndex, StableAssetPoolId};
 use cumulus_primitives_core::ParaId as Pid;
 use frame_support::{
 	derive_impl, parameter_types,
@@ -168,6 +170,130 @@ impl crate::Config for Test {
 	type XcmRouter = ();
 	type PalletId = FlexibleFeePalletId;
 	type PriceFeeder = MockPriceFeeder;
+	type Twap = ();
+	type StablePoolHandler = MockStablePool;
+	type SwapRouteHubs = SwapRouteHubs;
+	type CurrencyIdConvert = AssetIdMaps<Test>;
+}
+
+parameter_types! {
+	pub SwapRouteHubs: Vec<CurrencyId> = vec![BNC, DOT, VDOT];
+	pub static StablePoolEnabled: bool = false;
+}
+
+pub const STABLE_POOL_ID: StableAssetPoolId = 0;
+
+/// A DOT/VDOT pool swapping 1:1 with a 0.1% fee, enabled by `StablePoolEnabled`.
+pub struct MockStablePool;
+impl MockStablePool {
+	fn assets() -> Vec<CurrencyId> {
+		vec![DOT, VDOT]
+	}
+
+	fn pool_account() -> AccountId {
+		PalletId(*b"bf/stblp").into_account_truncating()
+	}
+}
+
+impl StablePoolHandler for MockStablePool {
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type CurrencyId = CurrencyId;
+
+	fn add_liquidity(
+		_who: AccountId,
+		_pool_id: StableAssetPoolId,
+		_amounts: Vec<Balance>,
+		_min_mint_amount: Balance,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn swap(
+		who: &AccountId,
+		_pool_id: StableAssetPoolId,
+		currency_id_in: PoolTokenIndex,
+		currency_id_out: PoolTokenIndex,
+		amount: Balance,
+		min_dy: Balance,
+	) -> DispatchResult {
+		let dy = Self::get_swap_output(STABLE_POOL_ID, currency_id_in, currency_id_out, amount)?;
+		ensure!(dy >= min_dy, DispatchError::Other("SwapUnderMin"));
+		let assets = Self::assets();
+		Currencies::transfer(assets[currency_id_in as usize], who, &Self::pool_account(), amount)?;
+		Currencies::deposit(assets[currency_id_out as usize], who, dy)
+	}
+
+	fn redeem_single(
+		_who: AccountId,
+		_pool_id: StableAssetPoolId,
+		_amount: Balance,
+		_i: PoolTokenIndex,
+		_min_redeem_amount: Balance,
+		_asset_length: u32,
+	) -> Result<(Balance, Balance), DispatchError> {
+		unimplemented!()
+	}
+
+	fn redeem_multi(
+		_who: AccountId,
+		_pool_id: StableAssetPoolId,
+		_amounts: Vec<Balance>,
+		_max_redeem_amount: Balance,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn redeem_proportion(
+		_who: AccountId,
+		_pool_id: StableAssetPoolId,
+		_amount: Balance,
+		_min_redeem_amounts: Vec<Balance>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn get_pool_token_index(
+		_pool_id: StableAssetPoolId,
+		currency_id: CurrencyId,
+	) -> Option<PoolTokenIndex> {
+		Self::assets()
+			.iter()
+			.position(|&c| c == currency_id)
+			.map(|i| i as PoolTokenIndex)
+	}
+
+	fn get_swap_output(
+		_pool_id: StableAssetPoolId,
+		_currency_id_in: PoolTokenIndex,
+		_currency_id_out: PoolTokenIndex,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(amount * 999 / 1000)
+	}
+
+	fn get_swap_input(
+		_pool_id: StableAssetPoolId,
+		_currency_id_in: PoolTokenIndex,
+		_currency_id_out: PoolTokenIndex,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok((amount * 1000).div_ceil(999))
+	}
+
+	fn get_pool_id(
+		currency_id_in: &CurrencyId,
+		currency_id_out: &CurrencyId,
+	) -> Option<(StableAssetPoolId, PoolTokenIndex, PoolTokenIndex)> {
+		if !StablePoolEnabled::get() || currency_id_in == currency_id_out {
+			return None;
+		}
+		Some((
+			STABLE_POOL_ID,
+			Self::get_pool_token_index(STABLE_POOL_ID, *currency_id_in)?,
+			Self::get_pool_token_index(STABLE_POOL_ID, *currency_id_out)?,
+		))
+	}
 }
 
 pub struct XcmDestWeightAndFee;
@@ -291,6 +417,19 @@ pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	pallet_balances::GenesisConfig::<Test>::default()
 		.assimilate_storage(&mut t)
 		.unwrap();
+	bifrost_asset_registry::GenesisConfig::<Test> {
+		currency: vec![
+			(BNC, 1, None),
+			(DOT, 1, Some(("Polkadot DOT".to_string(), "DOT".to_string(), 10u8))),
+			(USDC, 1, Some(("USD Coin".to_string(), "USDC".to_string(), 6u8))),
+			(WETH, 1, Some(("Wrapped Ether".to_string(), "WETH".to_string(), 18u8))),
+		],
+		vcurrency: vec![VDOT],
+		vsbond: vec![],
+		phantom: Default::default(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	t.into()
 }
 
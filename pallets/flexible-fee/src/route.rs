@@ -0,0 +1,170 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversion routes of fee currencies through zenlink and stable-pool.
+
+use crate::{Config, Error, Pallet};
+use bifrost_primitives::{Balance, CurrencyId, SwapRoute, SwapStep, TwapProvider};
+use bifrost_stable_pool::traits::StablePoolHandler;
+use frame_support::{pallet_prelude::*, traits::Get};
+use orml_traits::MultiCurrency;
+use sp_std::{vec, vec::Vec};
+use zenlink_protocol::{AssetId, ExportZenlink};
+
+impl<T: Config> Pallet<T> {
+	/// Find the cheapest route to get `amount_out` of `to_currency` by selling `from_currency`.
+	///
+	/// Considered are the direct zenlink pair and stable-pool pool, and the routes through one of
+	/// `SwapRouteHubs`, combining zenlink and stable-pool hops.
+	pub fn find_swap_route(
+		from_currency: CurrencyId,
+		to_currency: CurrencyId,
+		amount_out: Balance,
+	) -> Result<SwapRoute, Error<T>> {
+		let mut routes: Vec<SwapRoute> = Self::quote_hops(from_currency, to_currency, amount_out)
+			.into_iter()
+			.map(|(step, amount_in)| SwapRoute {
+				steps: vec![step],
+				amounts: vec![amount_in, amount_out],
+			})
+			.collect();
+
+		for hub in T::SwapRouteHubs::get() {
+			if hub == from_currency || hub == to_currency {
+				continue;
+			}
+			for (last_step, amount_mid) in Self::quote_hops(hub, to_currency, amount_out) {
+				for (first_step, amount_in) in Self::quote_hops(from_currency, hub, amount_mid) {
+					routes.push(Self::join_steps(
+						first_step,
+						last_step.clone(),
+						vec![amount_in, amount_mid, amount_out],
+					));
+				}
+			}
+		}
+
+		routes
+			.into_iter()
+			.min_by_key(|route| route.amount_in())
+			.ok_or(Error::<T>::NoSwapRoute)
+	}
+
+	/// Execute the swaps of `route`, `recipient` receives the output of the last swap.
+	pub fn swap_by_route(
+		who: &T::AccountId,
+		route: &SwapRoute,
+		recipient: &T::AccountId,
+	) -> DispatchResult {
+		let last = route.steps.len().saturating_sub(1);
+		for (i, step) in route.steps.iter().enumerate() {
+			let amount_in = *route.amounts.get(i).ok_or(Error::<T>::NoSwapRoute)?;
+			let amount_out = *route.amounts.get(i + 1).ok_or(Error::<T>::NoSwapRoute)?;
+			let to = if i == last { recipient } else { who };
+
+			match step {
+				SwapStep::Zenlink(path) => {
+					let asset_path = Self::get_asset_path(path)?;
+					T::DexOperator::inner_swap_assets_for_exact_assets(
+						who,
+						amount_out,
+						amount_in,
+						&asset_path,
+						to,
+					)?;
+					for pair in path.windows(2) {
+						T::Twap::on_swap(&pair[0], &pair[1]);
+					}
+				},
+				SwapStep::StablePool { pool_id, currency_in, currency_out } => {
+					let index_in =
+						T::StablePoolHandler::get_pool_token_index(*pool_id, *currency_in)
+							.ok_or(Error::<T>::NoSwapRoute)?;
+					let index_out =
+						T::StablePoolHandler::get_pool_token_index(*pool_id, *currency_out)
+							.ok_or(Error::<T>::NoSwapRoute)?;
+					T::StablePoolHandler::swap(
+						who, *pool_id, index_in, index_out, amount_in, amount_out,
+					)?;
+					if to != who {
+						T::MultiCurrency::transfer(*currency_out, who, to, amount_out)?;
+					}
+				},
+			}
+		}
+
+		Ok(())
+	}
+
+	// the direct swaps from `from_currency` to `to_currency` with their input amounts
+	fn quote_hops(
+		from_currency: CurrencyId,
+		to_currency: CurrencyId,
+		amount_out: Balance,
+	) -> Vec<(SwapStep, Balance)> {
+		let mut hops = Vec::new();
+
+		let path = vec![from_currency, to_currency];
+		if let Some(amount_in) = Self::get_asset_path(&path)
+			.ok()
+			.and_then(|asset_path| {
+				T::DexOperator::get_amount_in_by_path(amount_out, &asset_path).ok()
+			})
+			.and_then(|amounts| amounts.first().copied())
+		{
+			hops.push((SwapStep::Zenlink(path), amount_in));
+		}
+
+		if let Some((pool_id, index_in, index_out)) =
+			T::StablePoolHandler::get_pool_id(&from_currency, &to_currency)
+		{
+			if let Ok(amount_in) =
+				T::StablePoolHandler::get_swap_input(pool_id, index_in, index_out, amount_out)
+			{
+				let step = SwapStep::StablePool {
+					pool_id,
+					currency_in: from_currency,
+					currency_out: to_currency,
+				};
+				hops.push((step, amount_in));
+			}
+		}
+
+		hops
+	}
+
+	// consecutive zenlink swaps are merged into one multi-hop path
+	fn join_steps(first: SwapStep, last: SwapStep, amounts: Vec<Balance>) -> SwapRoute {
+		match (first, last) {
+			(SwapStep::Zenlink(mut path), SwapStep::Zenlink(tail)) => {
+				path.extend(tail.into_iter().skip(1));
+				SwapRoute {
+					steps: vec![SwapStep::Zenlink(path)],
+					amounts: vec![amounts[0], amounts[2]],
+				}
+			},
+			(first, last) => SwapRoute { steps: vec![first, last], amounts },
+		}
+	}
+
+	fn get_asset_path(path: &[CurrencyId]) -> Result<Vec<AssetId>, Error<T>> {
+		path.iter()
+			.map(|currency_id| Self::get_currency_asset_id(*currency_id))
+			.collect()
+	}
+}
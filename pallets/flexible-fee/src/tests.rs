@@ -21,10 +21,12 @@
 #![cfg(test)]
 use crate::{
 	impls::on_charge_transaction::PaymentInfo, mock::*, BlockNumberFor, BoundedVec, Config,
-	DispatchError::BadOrigin, UserDefaultFeeCurrency,
+	DispatchError::BadOrigin, Error, UserDefaultFeeCurrency,
 };
+use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	AccountFeeCurrency, BalanceCmp, CurrencyId, TryConvertFrom, BNC, DOT, KSM, MANTA, VDOT, WETH,
+	AccountFeeCurrency, BalanceCmp, CurrencyId, SwapStep, TryConvertFrom, BNC, DOT, KSM, MANTA,
+	USDC, VDOT, WETH,
 };
 use frame_support::{
 	assert_noop, assert_ok,
@@ -36,8 +38,8 @@ use orml_traits::MultiCurrency;
 use pallet_transaction_payment::OnChargeTransaction;
 use sp_arithmetic::FixedU128;
 use sp_runtime::AccountId32;
-use std::cmp::Ordering::{Greater, Less};
-use zenlink_protocol::AssetId;
+use std::cmp::Ordering::{Equal, Greater, Less};
+use zenlink_protocol::{AssetId, ExportZenlink};
 
 // some common variables
 pub const CHARLIE: AccountId32 = AccountId32::new([0u8; 32]);
@@ -284,6 +286,114 @@ fn withdraw_extra_fee() {
 	})
 }
 
+fn asset_path(path: &[CurrencyId]) -> Vec<AssetId> {
+	path.iter().map(|c| AssetId::try_convert_from(*c, 2001).unwrap()).collect()
+}
+
+#[test]
+fn find_swap_route_should_use_multi_hop_zenlink_path() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		let amount_out = 10u128.pow(10);
+
+		// there is no KSM/DOT pair, the route goes through BNC
+		let route = FlexibleFee::find_swap_route(KSM, DOT, amount_out).unwrap();
+		let amounts =
+			ZenlinkProtocol::get_amount_in_by_path(amount_out, &asset_path(&[KSM, BNC, DOT]))
+				.unwrap();
+		assert_eq!(route.steps, vec![SwapStep::Zenlink(vec![KSM, BNC, DOT])]);
+		assert_eq!(route.amounts, vec![amounts[0], amount_out]);
+
+		assert_eq!(
+			FlexibleFee::find_swap_route(MANTA, DOT, amount_out),
+			Err(Error::<Test>::NoSwapRoute)
+		);
+	});
+}
+
+#[test]
+fn find_swap_route_should_choose_cheapest_quote() {
+	new_test_ext().execute_with(|| {
+		basic_setup();
+		let amount_out = 10u128.pow(10);
+
+		// VDOT -> BNC -> DOT on zenlink
+		let route = FlexibleFee::find_swap_route(VDOT, DOT, amount_out).unwrap();
+		assert_eq!(route.steps, vec![SwapStep::Zenlink(vec![VDOT, BNC, DOT])]);
+
+		// the stable pool only charges 0.1%
+		StablePoolEnabled::set(true);
+		let route = FlexibleFee::find_swap_route(VDOT, DOT, amount_out).unwrap();
+		assert_eq!(
+			route.steps,
+			vec![SwapStep::StablePool {
+				pool_id: STABLE_POOL_ID,
+				currency_in: VDOT,
+				currency_out: DOT
+			}]
+		);
+		assert_eq!(route.amounts, vec![10_010_010_011, amount_out]);
+	});
+}
+
+#[test]
+fn charge_extra_fee_should_combine_stable_pool_and_zenlink() {
+	new_test_ext().execute_with(|| {
+		StablePoolEnabled::set(true);
+		assert_ok!(Currencies::deposit(VDOT, &ALICE, 1000 * 10u128.pow(10)));
+		assert_ok!(Currencies::deposit(BNC, &DICK, 1000 * 10u128.pow(12)));
+		assert_ok!(Currencies::deposit(DOT, &DICK, 1000 * 10u128.pow(10)));
+		let [bnc_asset_id, dot_asset_id] = [BNC, DOT].map(|c| asset_path(&[c])[0]);
+		assert_ok!(ZenlinkProtocol::create_pair(
+			RuntimeOrigin::root(),
+			bnc_asset_id,
+			dot_asset_id,
+			DICK
+		));
+		assert_ok!(ZenlinkProtocol::add_liquidity(
+			RuntimeOrigin::signed(DICK),
+			bnc_asset_id,
+			dot_asset_id,
+			100 * 10u128.pow(12),
+			100 * 10u128.pow(10),
+			1,
+			1,
+			100
+		));
+		assert_ok!(FlexibleFee::set_user_default_fee_currency(
+			RuntimeOrigin::signed(ALICE),
+			Some(VDOT)
+		));
+
+		// there is no VDOT/BNC pair, VDOT is swapped to DOT in the stable pool first
+		let extra_fee = 10u128.pow(12);
+		let (currency_id, amount_in, route) =
+			FlexibleFee::get_extra_fee_currency_and_route(&ALICE, BNC, extra_fee).unwrap();
+		let route = route.unwrap();
+		let zenlink_amounts =
+			ZenlinkProtocol::get_amount_in_by_path(extra_fee, &asset_path(&[DOT, BNC])).unwrap();
+		assert_eq!(currency_id, VDOT);
+		assert_eq!(
+			route.steps,
+			vec![
+				SwapStep::StablePool {
+					pool_id: STABLE_POOL_ID,
+					currency_in: VDOT,
+					currency_out: DOT
+				},
+				SwapStep::Zenlink(vec![DOT, BNC]),
+			]
+		);
+		assert_eq!(route.amounts[1], zenlink_amounts[0]);
+		assert_eq!(amount_in, route.amount_in());
+
+		let bnc_before = Currencies::free_balance(BNC, &DICK);
+		assert_ok!(FlexibleFee::charge_extra_fee(&ALICE, BNC, extra_fee, &DICK));
+		assert_eq!(Currencies::free_balance(BNC, &DICK), bnc_before + extra_fee);
+		assert_eq!(Currencies::free_balance(VDOT, &ALICE), 1000 * 10u128.pow(10) - amount_in);
+	});
+}
+
 #[test]
 fn correct_and_deposit_fee_should_work() {
 	new_test_ext().execute_with(|| {
@@ -397,7 +507,7 @@ fn get_fee_currency_should_work_with_default_currency() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 100u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 100u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 100u128.pow(18))); // ETH
 
 		let currency = FlexibleFee::get_fee_currency(&ALICE, 10u128.pow(18).into()).unwrap();
@@ -417,7 +527,7 @@ fn get_fee_currency_should_work_with_default_currency_poor() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 1u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 100u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 100u128.pow(18))); // ETH
 
 		let currency = FlexibleFee::get_fee_currency(&ALICE, 10u128.pow(18).into()).unwrap();
@@ -431,7 +541,7 @@ fn get_fee_currency_should_work_with_weth() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 100u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 100u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 100u128.pow(18))); // ETH
 
 		let currency = FlexibleFee::get_fee_currency(&ALICE, 10u128.pow(18).into()).unwrap();
@@ -445,7 +555,7 @@ fn get_fee_currency_should_work_with_weth_poor() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 100u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 100u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 1u128.pow(18))); // ETH
 
 		let asset_order_list_vec: BoundedVec<
@@ -475,7 +585,7 @@ fn get_fee_currency_should_work_with_universal_fee_currency() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 1u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 100u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 1u128.pow(18))); // ETH
 
 		let asset_order_list_vec: BoundedVec<
@@ -499,7 +609,7 @@ fn get_fee_currency_should_work_with_universal_fee_currency_poor() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 1u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 100u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 1u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 100u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 1u128.pow(18))); // ETH
 
 		let asset_order_list_vec: BoundedVec<
@@ -529,7 +639,7 @@ fn get_fee_currency_should_work_with_all_currency_poor() {
 		assert_ok!(Currencies::deposit(BNC, &ALICE, 7u128.pow(12))); // BNC
 		assert_ok!(Currencies::deposit(DOT, &ALICE, 5u128.pow(10))); // DOT
 		assert_ok!(Currencies::deposit(VDOT, &ALICE, 4u128.pow(10))); // vDOT
-		assert_ok!(Currencies::deposit(KSM, &ALICE, 3u128.pow(12))); // KSM CurrencyNotSupport
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 3u128.pow(12))); // KSM unregistered
 		assert_ok!(Currencies::deposit(WETH, &ALICE, 2u128.pow(18))); // ETH
 
 		let asset_order_list_vec: BoundedVec<
@@ -579,3 +689,93 @@ fn cmp_with_precision_should_work_with_bnc() {
 		assert_eq!(ordering, Greater);
 	});
 }
+
+#[test]
+fn cmp_with_precision_should_work_with_mixed_decimals() {
+	new_test_ext().execute_with(|| {
+		// The existential deposit is excluded from the reducible balance.
+		assert_ok!(Currencies::deposit(USDC, &ALICE, 5 * 10u128.pow(6) + 1)); // 6 decimals
+		assert_ok!(Currencies::deposit(VDOT, &ALICE, 5 * 10u128.pow(10) + 1)); // 10 decimals
+		assert_ok!(Currencies::deposit(BNC, &ALICE, 5 * 10u128.pow(12) + 1)); // 12 decimals
+		assert_ok!(Currencies::deposit(WETH, &ALICE, 5 * 10u128.pow(18) + 1)); // 18 decimals
+
+		// Amount with 6 decimals
+		for currency in [USDC, VDOT, BNC, WETH] {
+			assert_eq!(
+				FlexibleFee::cmp_with_precision(&ALICE, &currency, 4 * 10u128.pow(6), 6u32),
+				Ok(Greater)
+			);
+			assert_eq!(
+				FlexibleFee::cmp_with_precision(&ALICE, &currency, 6 * 10u128.pow(6), 6u32),
+				Ok(Less)
+			);
+		}
+
+		// Amount with 10 and 12 decimals
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &USDC, 5 * 10u128.pow(10), 10u32),
+			Ok(Equal)
+		);
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &WETH, 5 * 10u128.pow(12), 12u32),
+			Ok(Equal)
+		);
+
+		// Amount with a precision higher than the standard precision
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &USDC, 5 * 10u128.pow(20), 20u32),
+			Ok(Equal)
+		);
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &BNC, 5 * 10u128.pow(20) + 1, 20u32),
+			Ok(Less)
+		);
+	});
+}
+
+#[test]
+fn cmp_with_precision_should_work_with_more_than_18_decimals() {
+	new_test_ext().execute_with(|| {
+		let currency = CurrencyId::Token2(100);
+		bifrost_asset_registry::CurrencyMetadatas::<Test>::insert(
+			currency,
+			AssetMetadata {
+				name: b"Token with 24 decimals".to_vec(),
+				symbol: b"T24".to_vec(),
+				decimals: 24,
+				minimal_balance: 1,
+			},
+		);
+		// The existential deposit is excluded from the reducible balance.
+		assert_ok!(Currencies::deposit(currency, &ALICE, 5 * 10u128.pow(24) + 1));
+
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &currency, 5 * 10u128.pow(18), 18u32),
+			Ok(Equal)
+		);
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &currency, 6 * 10u128.pow(6), 6u32),
+			Ok(Less)
+		);
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &currency, 4 * 10u128.pow(20), 20u32),
+			Ok(Greater)
+		);
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &currency, 5 * 10u128.pow(24) + 1, 24u32),
+			Ok(Less)
+		);
+	});
+}
+
+#[test]
+fn cmp_with_precision_should_fail_with_unregistered_currency() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::deposit(KSM, &ALICE, 10u128.pow(12)));
+
+		assert_eq!(
+			FlexibleFee::cmp_with_precision(&ALICE, &KSM, 10u128.pow(12), 12u32),
+			Err(Error::<Test>::CurrencyMetadataNotFound)
+		);
+	});
+}
@@ -25,5 +25,8 @@ sp_api::decl_runtime_apis! {
 		fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError>;
 		fn get_market_status(asset_id: CurrencyId) -> Result<(Rate, Rate, Rate, Ratio, Balance, Balance, FixedU128), DispatchError>;
 		fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError>;
+		fn get_account_emode_category(account: AccountId) -> Result<Option<u8>, DispatchError>;
+		fn get_liquidation_auction(borrower: AccountId, asset_id: CurrencyId) -> Result<Option<(Balance, Ratio)>, DispatchError>;
+		fn get_liquidation_auction_bids(borrower: AccountId, asset_id: CurrencyId) -> Option<(u32, Balance)>;
 	}
 }
@@ -51,6 +51,12 @@ where
 		account: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<(Liquidity, Shortfall, Liquidity, Shortfall)>;
+	#[method(name = "lend_market_getAccountEModeCategory")]
+	fn get_account_emode_category(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u8>>;
 }
 
 /// A struct that implements the [`LendMarketApi`].
@@ -155,6 +161,21 @@ where
 			.map_err(runtime_error_into_rpc_error)?
 			.map_err(account_liquidity_error_into_rpc_error)
 	}
+
+	fn get_account_emode_category(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		);
+		api.get_account_emode_category(at, account)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(account_liquidity_error_into_rpc_error)
+	}
 }
 
 /// Converts a runtime trap into an RPC error.
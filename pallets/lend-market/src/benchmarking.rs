@@ -51,6 +51,25 @@ fn pending_market_mock<T: Config>(lend_token_id: CurrencyId) -> Market<BalanceOf
 	market
 }
 
+fn emode_category_mock() -> EModeCategory {
+	EModeCategory {
+		collateral_factor: Ratio::from_percent(90),
+		liquidation_threshold: Ratio::from_percent(93),
+		liquidate_incentive: Rate::from_inner(Rate::DIV / 100 * 103),
+		assets: vec![KSM, VKSM],
+	}
+}
+
+fn liquidation_auction_config_mock<T: Config>() -> LiquidationAuctionConfig<BlockNumberFor<T>> {
+	LiquidationAuctionConfig {
+		initial_discount: Ratio::from_percent(2),
+		discount_per_block: Ratio::from_perthousand(5),
+		max_discount: Ratio::from_percent(15),
+		duration: 100u32.into(),
+		min_bid: Ratio::from_percent(10),
+	}
+}
+
 const INITIAL_AMOUNT: u32 = 500_000_000;
 
 fn transfer_initial_balance<
@@ -380,6 +399,118 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::<T>::LiquidationFreeCollateralsUpdated(vec![PHA]).into());
 	}
+
+	set_emode_category {
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+	}: _(SystemOrigin::Root, 1, Some(emode_category_mock()))
+	verify {
+		assert_last_event::<T>(Event::<T>::EModeCategoryUpdated { category_id: 1, category: Some(emode_category_mock()) }.into());
+	}
+
+	set_account_emode {
+		let caller: T::AccountId = whitelisted_caller();
+		transfer_initial_balance::<T>(caller.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::set_emode_category(SystemOrigin::Root.into(), 1, Some(emode_category_mock())));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(caller.clone()).into(), VKSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(caller.clone()).into(), VKSM, true));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(caller.clone()).into(), KSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::borrow(SystemOrigin::Signed(caller.clone()).into(), KSM, borrowed_amount.into()));
+	}: _(SystemOrigin::Signed(caller.clone()), Some(1))
+	verify {
+		assert_last_event::<T>(Event::<T>::AccountEModeSet { account: caller, category_id: Some(1) }.into());
+	}
+
+	write_off_bad_debt {
+		let caller: T::AccountId = whitelisted_caller();
+		let bob: T::AccountId = account("Sample", 100, SEED);
+		transfer_initial_balance::<T>(caller.clone());
+		transfer_initial_balance::<T>(bob.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(caller.clone()).into(), KSM, deposit_amount.into()));
+		// bob keeps a deposit worth less than the debt, which is seized
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(bob.clone()).into(), KSM, (borrowed_amount / 10).into()));
+		set_account_borrows::<T>(bob.clone(), KSM, borrowed_amount.into());
+	}: _(SystemOrigin::Root, bob.clone())
+	verify {
+		assert_eq!(AccountBorrows::<T>::get(KSM, &bob).principal, 0);
+		assert!(!AccountDeposits::<T>::contains_key(KSM, &bob));
+	}
+
+	flash_loan {
+		let caller: T::AccountId = whitelisted_caller();
+		transfer_initial_balance::<T>(caller.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let loan_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(caller.clone()).into(), KSM, deposit_amount.into()));
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+	}: _(SystemOrigin::Signed(caller.clone()), KSM, loan_amount.into(), Box::new(call))
+	verify {
+		let fee = T::FlashLoanFee::get().mul_ceil(loan_amount.into());
+		assert_last_event::<T>(Event::<T>::FlashLoaned { borrower: caller, asset_id: KSM, amount: loan_amount.into(), fee }.into());
+	}
+
+	set_liquidation_auction_config {
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+	}: _(SystemOrigin::Root, KSM, Some(liquidation_auction_config_mock::<T>()))
+	verify {
+		assert_eq!(LiquidationAuctionConfigs::<T>::get(KSM), Some(liquidation_auction_config_mock::<T>()));
+	}
+
+	start_liquidation_auction {
+		let alice: T::AccountId = account("Sample", 100, SEED);
+		let bob: T::AccountId = account("Sample", 101, SEED);
+		transfer_initial_balance::<T>(alice.clone());
+		transfer_initial_balance::<T>(bob.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 200_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::set_liquidation_auction_config(SystemOrigin::Root.into(), KSM, Some(liquidation_auction_config_mock::<T>())));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(bob.clone()).into(), KSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(alice.clone()).into(), VKSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(alice.clone()).into(), VKSM, true));
+		set_account_borrows::<T>(alice.clone(), KSM, borrowed_amount.into());
+	}: _(SystemOrigin::Signed(bob.clone()), alice.clone(), KSM)
+	verify {
+		assert_last_event::<T>(Event::<T>::LiquidationAuctionStarted { borrower: alice, asset_id: KSM, debt: borrowed_amount.into() }.into());
+	}
+
+	bid_liquidation_auction {
+		let alice: T::AccountId = account("Sample", 100, SEED);
+		let bob: T::AccountId = account("Sample", 101, SEED);
+		transfer_initial_balance::<T>(alice.clone());
+		transfer_initial_balance::<T>(bob.clone());
+		let deposit_amount: u32 = 200_000_000;
+		let borrowed_amount: u32 = 200_000_000;
+		let liquidate_amount: u32 = 100_000_000;
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), VKSM, pending_market_mock::<T>(VSKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), VKSM));
+		assert_ok!(LendMarket::<T>::add_market(SystemOrigin::Root.into(), KSM, pending_market_mock::<T>(LKSM)));
+		assert_ok!(LendMarket::<T>::activate_market(SystemOrigin::Root.into(), KSM));
+		assert_ok!(LendMarket::<T>::set_liquidation_auction_config(SystemOrigin::Root.into(), KSM, Some(liquidation_auction_config_mock::<T>())));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(bob.clone()).into(), KSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::mint(SystemOrigin::Signed(alice.clone()).into(), VKSM, deposit_amount.into()));
+		assert_ok!(LendMarket::<T>::collateral_asset(SystemOrigin::Signed(alice.clone()).into(), VKSM, true));
+		set_account_borrows::<T>(alice.clone(), KSM, borrowed_amount.into());
+		assert_ok!(LendMarket::<T>::start_liquidation_auction(SystemOrigin::Signed(bob.clone()).into(), alice.clone(), KSM));
+	}: _(SystemOrigin::Signed(bob.clone()), alice.clone(), KSM, liquidate_amount.into(), VKSM)
+	verify {
+		assert_eq!(LiquidationAuctions::<T>::get(KSM, &alice).map(|auction| auction.debt), Some((borrowed_amount - liquidate_amount).into()));
+	}
 }
 
 impl_benchmark_test_suite!(LendMarket, crate::mock::new_test_ext(), crate::mock::Test);
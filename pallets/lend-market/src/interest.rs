@@ -41,6 +41,15 @@ impl<T: Config> Pallet<T> {
 			borrow_index_new,
 		) = Self::get_market_status(asset_id)?;
 
+		// Adapt the rate model to the utilization since the last accrual
+		let mut market = Self::market(asset_id)?;
+		if let InterestRateModel::Adaptive(ref mut model) = market.rate_model {
+			model
+				.adapt(util, now - last_accrued_interest_time)
+				.ok_or(ArithmeticError::Overflow)?;
+			Markets::<T>::insert(asset_id, market);
+		}
+
 		Self::update_last_accrued_interest_time(asset_id, now)?;
 		TotalBorrows::<T>::insert(asset_id, total_borrows_new);
 		TotalReserves::<T>::insert(asset_id, total_reserves_new);
@@ -92,8 +101,13 @@ impl<T: Config> Pallet<T> {
 				.ok_or(ArithmeticError::Overflow)?;
 		}
 
-		let exchange_rate =
-			Self::calculate_exchange_rate(total_supply, total_cash, total_borrows, total_reserves)?;
+		let exchange_rate = Self::calculate_exchange_rate(
+			total_supply,
+			total_cash,
+			total_borrows,
+			CoveredBadDebt::<T>::get(asset_id),
+			total_reserves,
+		)?;
 
 		Ok((
 			borrow_rate,
@@ -108,14 +122,21 @@ impl<T: Config> Pallet<T> {
 
 	/// Update the exchange rate according to the totalCash, totalBorrows and totalSupply.
 	/// This function does not accrue interest before calculating the exchange rate.
-	/// exchangeRate = (totalCash + totalBorrows - totalReserves) / totalSupply
+	/// exchangeRate = (totalCash + totalBorrows + coveredBadDebt - totalReserves) / totalSupply
 	pub fn exchange_rate_stored(asset_id: AssetIdOf<T>) -> Result<Rate, DispatchError> {
 		let total_supply = TotalSupply::<T>::get(asset_id);
 		let total_cash = Self::get_total_cash(asset_id);
 		let total_borrows = TotalBorrows::<T>::get(asset_id);
+		let covered_bad_debt = CoveredBadDebt::<T>::get(asset_id);
 		let total_reserves = TotalReserves::<T>::get(asset_id);
 
-		Self::calculate_exchange_rate(total_supply, total_cash, total_borrows, total_reserves)
+		Self::calculate_exchange_rate(
+			total_supply,
+			total_cash,
+			total_borrows,
+			covered_bad_debt,
+			total_reserves,
+		)
 	}
 
 	/// Calculate the borrowing utilization ratio of the specified market
@@ -181,6 +202,7 @@ impl<T: Config> Pallet<T> {
 		total_supply: BalanceOf<T>,
 		total_cash: BalanceOf<T>,
 		total_borrows: BalanceOf<T>,
+		covered_bad_debt: BalanceOf<T>,
 		total_reserves: BalanceOf<T>,
 	) -> Result<Rate, DispatchError> {
 		if total_supply.is_zero() {
@@ -189,6 +211,7 @@ impl<T: Config> Pallet<T> {
 
 		let cash_plus_borrows_minus_reserves = total_cash
 			.checked_add(total_borrows)
+			.and_then(|r| r.checked_add(covered_bad_debt))
 			.and_then(|r| r.checked_sub(total_reserves))
 			.ok_or(ArithmeticError::Overflow)?;
 		let exchange_rate =
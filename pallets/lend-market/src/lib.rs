@@ -29,6 +29,7 @@ use bifrost_primitives::{
 	Balance, CurrencyId, Liquidity, Price, PriceFeeder, Rate, Ratio, Shortfall, Timestamp,
 };
 use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	pallet_prelude::*,
 	require_transactional,
 	traits::{
@@ -42,12 +43,12 @@ use frame_system::pallet_prelude::*;
 use num_traits::cast::ToPrimitive;
 pub use pallet::*;
 use pallet_traits::{
-	ConvertToBigUint, LendMarket as LendMarketTrait, LendMarketMarketDataProvider,
+	ConvertToBigUint, FlashLoan, LendMarket as LendMarketTrait, LendMarketMarketDataProvider,
 	LendMarketPositionDataProvider, MarketInfo, MarketStatus,
 };
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One,
+		AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Dispatchable, One,
 		SaturatedConversion, Saturating, StaticLookup, Zero,
 	},
 	ArithmeticError, FixedPointNumber, FixedU128,
@@ -56,7 +57,10 @@ use sp_std::{result::Result, vec::Vec};
 
 use log;
 use sp_io::hashing::blake2_256;
-pub use types::{BorrowSnapshot, Deposits, EarnedSnapshot, Market, MarketState, RewardMarketState};
+pub use types::{
+	BorrowSnapshot, Deposits, EModeCategory, EarnedSnapshot, LiquidationAuction,
+	LiquidationAuctionConfig, Market, MarketState, RewardMarketState,
+};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -132,6 +136,16 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type LiquidationFreeAssetId: Get<AssetIdOf<Self>>;
+
+		/// The overarching call type, dispatched while a flash loan is outstanding.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// Fee charged on the amount of a flash loan, added to the market reserves.
+		#[pallet::constant]
+		type FlashLoanFee: Get<Ratio>;
 	}
 
 	#[pallet::error]
@@ -184,7 +198,7 @@ pub mod pallet {
 		InvalidFactor,
 		/// The supply cap cannot be zero
 		InvalidSupplyCap,
-		/// The exchange rate should be greater than 0.02 and less than 1
+		/// The exchange rate should be greater than 0 and less than 1
 		InvalidExchangeRate,
 		/// Amount cannot be zero
 		InvalidAmount,
@@ -196,6 +210,28 @@ pub mod pallet {
 		CollateralReserved,
 		/// Market bond does not exist
 		MarketBondDoesNotExist,
+		/// Efficiency mode category does not exist
+		EModeCategoryDoesNotExist,
+		/// Efficiency mode category must contain at least one asset
+		EModeCategoryEmpty,
+		/// Borrower's deposits still cover the debt, which can be liquidated
+		CollateralRemaining,
+		/// Borrower has no debt to write off
+		NoBadDebt,
+		/// Flash loan plus fee was not paid back
+		FlashLoanNotRepaid,
+		/// Liquidations of the market are auctioned
+		MarketInAuctionMode,
+		/// Liquidations of the market are not auctioned
+		MarketNotInAuctionMode,
+		/// Liquidation auction does not exist
+		LiquidationAuctionDoesNotExist,
+		/// Liquidation auction has already started and has not expired
+		LiquidationAuctionAlreadyStarted,
+		/// Liquidation auction has expired and has to be restarted
+		LiquidationAuctionExpired,
+		/// Bid repays less than the minimum bid of the liquidation auction
+		LiquidationAuctionBidTooLow,
 	}
 
 	#[pallet::event]
@@ -266,6 +302,63 @@ pub mod pallet {
 			asset_id: AssetIdOf<T>,
 			market_bond: Vec<AssetIdOf<T>>,
 		},
+		/// Efficiency mode category has been set or removed
+		EModeCategoryUpdated {
+			category_id: u8,
+			category: Option<EModeCategory>,
+		},
+		/// Account has entered or left an efficiency mode category
+		AccountEModeSet {
+			account: T::AccountId,
+			category_id: Option<u8>,
+		},
+		/// Deposits of an insolvent borrower have been seized into the market reserves
+		/// before writing off the bad debt.
+		BadDebtDepositsSeized {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			voucher_amount: BalanceOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// Bad debt of an insolvent borrower has been written off. The part not covered by
+		/// the seized deposits is absorbed by the reserves first, the rest is socialized
+		/// across suppliers.
+		BadDebtWrittenOff {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			covered: BalanceOf<T>,
+			reserves_absorbed: BalanceOf<T>,
+			socialized: BalanceOf<T>,
+		},
+		/// Flash loan has been paid back
+		FlashLoaned {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// Liquidation auction parameters of a market have been set or removed
+		LiquidationAuctionConfigUpdated {
+			asset_id: AssetIdOf<T>,
+			config: Option<LiquidationAuctionConfig<BlockNumberFor<T>>>,
+		},
+		/// Liquidation auction of a borrower's debt has started
+		LiquidationAuctionStarted {
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			debt: BalanceOf<T>,
+		},
+		/// Bid has repaid part of the debt of a liquidation auction
+		LiquidationAuctionBidPlaced {
+			liquidator: T::AccountId,
+			borrower: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			collateral_asset_id: AssetIdOf<T>,
+			repay_amount: BalanceOf<T>,
+			collateral_amount: BalanceOf<T>,
+			discount: Ratio,
+		},
 	}
 
 	/// The timestamp of the last calculation of accrued interest
@@ -295,6 +388,13 @@ pub mod pallet {
 	pub type TotalReserves<T: Config> =
 		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
 
+	/// Written off borrows covered by the deposits seized from the borrowers, repaid by
+	/// `add_reserves`
+	/// CurrencyId -> Balance
+	#[pallet::storage]
+	pub type CoveredBadDebt<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
 	/// Mapping of account addresses to outstanding borrow balances
 	/// CurrencyId -> Owner -> BorrowSnapshot
 	#[pallet::storage]
@@ -436,6 +536,40 @@ pub mod pallet {
 	pub type MarketBond<T: Config> =
 		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, Vec<AssetIdOf<T>>>;
 
+	/// Cash of each market currently lent out by flash loans
+	/// CurrencyId -> Balance
+	#[pallet::storage]
+	pub type FlashLoans<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	/// Efficiency mode categories
+	/// CategoryId -> EModeCategory
+	#[pallet::storage]
+	pub type EModeCategories<T: Config> = StorageMap<_, Twox64Concat, u8, EModeCategory>;
+
+	/// The efficiency mode category each account has opted in to
+	/// Owner -> CategoryId
+	#[pallet::storage]
+	pub type AccountEMode<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u8>;
+
+	/// Dutch auction liquidation parameters of the markets liquidated by auction
+	/// CurrencyId -> LiquidationAuctionConfig
+	#[pallet::storage]
+	pub type LiquidationAuctionConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, LiquidationAuctionConfig<BlockNumberFor<T>>>;
+
+	/// Running liquidation auctions
+	/// CurrencyId -> Borrower -> LiquidationAuction
+	#[pallet::storage]
+	pub type LiquidationAuctions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		LiquidationAuction<BlockNumberFor<T>, BalanceOf<T>>,
+	>;
+
 	/// DefaultVersion is using for initialize the StorageVersion
 	#[pallet::type_value]
 	pub(super) fn DefaultVersion() -> Versions {
@@ -544,6 +678,9 @@ pub mod pallet {
 		/// Updates the rate model of a stored market. Returns `Err` if the market
 		/// currency does not exist or the rate model is invalid.
 		///
+		/// When an adaptive model replaces an adaptive model, the adapted `rate_at_target` is
+		/// kept within the bounds of the new model.
+		///
 		/// - `asset_id`: Market related currency
 		/// - `rate_model`: The new rate model to be updated
 		#[pallet::call_index(2)]
@@ -556,8 +693,23 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			ensure!(rate_model.check_model(), Error::<T>::InvalidRateModelParam);
+			// Interest accrued so far is charged with the current model
+			Self::accrue_interest(asset_id)?;
 			let market = Self::mutate_market(asset_id, |stored_market| {
-				stored_market.rate_model = rate_model;
+				stored_market.rate_model = match (stored_market.rate_model, rate_model) {
+					// Keep the adapted rate, bounded by the new model
+					(
+						InterestRateModel::Adaptive(stored_model),
+						InterestRateModel::Adaptive(mut new_model),
+					) => {
+						new_model.rate_at_target = stored_model
+							.rate_at_target
+							.max(new_model.min_rate_at_target)
+							.min(new_model.max_rate_at_target);
+						InterestRateModel::Adaptive(new_model)
+					},
+					_ => rate_model,
+				};
 				stored_market.clone()
 			})?;
 			Self::deposit_event(Event::<T>::UpdatedMarket(asset_id, market));
@@ -960,13 +1112,7 @@ pub mod pallet {
 			collateral_asset_id: AssetIdOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(
-				!LiquidationFreeCollaterals::<T>::get().contains(&collateral_asset_id),
-				Error::<T>::CollateralReserved
-			);
-			Self::accrue_interest(liquidation_asset_id)?;
-			Self::accrue_interest(collateral_asset_id)?;
-			Self::do_liquidate_borrow(
+			Self::liquidate_borrow_inner(
 				who,
 				borrower,
 				liquidation_asset_id,
@@ -978,6 +1124,8 @@ pub mod pallet {
 
 		/// Add reserves by transferring from payer.
 		///
+		/// The amount repays the `CoveredBadDebt` of the market first.
+		///
 		/// May only be called from `T::ReserveOrigin`.
 		///
 		/// - `payer`: the payer account.
@@ -1003,9 +1151,13 @@ pub mod pallet {
 				add_amount,
 				Preservation::Expendable,
 			)?;
+			let covered_bad_debt = CoveredBadDebt::<T>::get(asset_id);
+			let repaid = covered_bad_debt.min(add_amount);
+			CoveredBadDebt::<T>::insert(asset_id, covered_bad_debt - repaid);
 			let total_reserves = TotalReserves::<T>::get(asset_id);
-			let total_reserves_new =
-				total_reserves.checked_add(add_amount).ok_or(ArithmeticError::Overflow)?;
+			let total_reserves_new = total_reserves
+				.checked_add(add_amount - repaid)
+				.ok_or(ArithmeticError::Overflow)?;
 			TotalReserves::<T>::insert(asset_id, total_reserves_new);
 
 			Self::deposit_event(Event::<T>::ReservesAdded(
@@ -1131,6 +1283,224 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::MarketBonded { asset_id, market_bond });
 			Ok(().into())
 		}
+
+		/// Set or remove an efficiency mode category.
+		///
+		/// Assets of the category use the category's collateral factor, liquidation threshold
+		/// and liquidation incentive for accounts that opted in and only borrow assets of the
+		/// category.
+		///
+		/// - `category_id`: the category to set
+		/// - `category`: the new parameters, `None` removes the category
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_emode_category())]
+		#[transactional]
+		pub fn set_emode_category(
+			origin: OriginFor<T>,
+			category_id: u8,
+			category: Option<EModeCategory>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			match category {
+				Some(ref category) => {
+					ensure!(category.collateral_factor < Ratio::one(), Error::<T>::InvalidFactor);
+					ensure!(
+						category.liquidation_threshold >= category.collateral_factor &&
+							category.liquidation_threshold < Ratio::one(),
+						Error::<T>::InvalidFactor
+					);
+					ensure!(category.liquidate_incentive >= Rate::one(), Error::<T>::InvalidFactor);
+					ensure!(!category.assets.is_empty(), Error::<T>::EModeCategoryEmpty);
+					for asset_id in category.assets.iter() {
+						Self::market(*asset_id)?;
+					}
+					EModeCategories::<T>::insert(category_id, category.clone());
+				},
+				None => {
+					ensure!(
+						EModeCategories::<T>::contains_key(category_id),
+						Error::<T>::EModeCategoryDoesNotExist
+					);
+					EModeCategories::<T>::remove(category_id);
+				},
+			}
+
+			Self::deposit_event(Event::<T>::EModeCategoryUpdated { category_id, category });
+			Ok(().into())
+		}
+
+		/// Enter or leave an efficiency mode category.
+		///
+		/// The account must not have a shortfall under the parameters of the new mode.
+		///
+		/// - `category_id`: the category to enter, `None` leaves the current one
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_account_emode())]
+		#[transactional]
+		pub fn set_account_emode(
+			origin: OriginFor<T>,
+			category_id: Option<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			match category_id {
+				Some(id) => {
+					ensure!(
+						EModeCategories::<T>::contains_key(id),
+						Error::<T>::EModeCategoryDoesNotExist
+					);
+					AccountEMode::<T>::insert(&who, id);
+				},
+				None => AccountEMode::<T>::remove(&who),
+			}
+			let (_, shortfall, _, _) = Self::get_account_liquidity(&who)?;
+			ensure!(shortfall.is_zero(), Error::<T>::InsufficientLiquidity);
+
+			Self::deposit_event(Event::<T>::AccountEModeSet { account: who, category_id });
+			Ok(().into())
+		}
+
+		/// Writes off the debt of a borrower whose deposits are worth less than the debt.
+		///
+		/// The deposits of the borrower are seized into the market reserves and their value is
+		/// credited against the debt. The remaining loss is absorbed from the market reserves
+		/// first, the remainder lowers the exchange rate of the market.
+		///
+		/// The origin must conform to `UpdateOrigin`.
+		///
+		/// - `borrower`: the underwater borrower.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::write_off_bad_debt())]
+		#[transactional]
+		pub fn write_off_bad_debt(
+			origin: OriginFor<T>,
+			borrower: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_write_off_bad_debt(&borrower)?;
+			Ok(().into())
+		}
+
+		/// Sender flash borrows the cash of a market and dispatches `call`.
+		///
+		/// `amount` plus the flash loan fee must be in the sender's account once `call`
+		/// returns, otherwise the whole extrinsic fails.
+		///
+		/// - `asset_id`: the asset to be borrowed.
+		/// - `amount`: the amount to be borrowed.
+		/// - `call`: the call dispatched with the borrowed amount.
+		#[pallet::call_index(26)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			T::WeightInfo::flash_loan().saturating_add(dispatch_info.weight)
+		})]
+		#[transactional]
+		pub fn flash_loan(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			<Self as FlashLoan<_, _, _>>::flash_loan(&who, asset_id, amount, || {
+				call.dispatch(origin).map(|_| ()).map_err(|e| e.error)
+			})?;
+			Ok(().into())
+		}
+
+		/// Set or remove the liquidation auction parameters of a market.
+		///
+		/// Liquidations of a market with auction parameters can only be done by
+		/// `start_liquidation_auction` and `bid_liquidation_auction`, `liquidate_borrow`
+		/// keeps its fixed incentive for the other markets.
+		///
+		/// - `asset_id`: the market of the borrowed asset
+		/// - `config`: the new parameters, `None` switches back to fixed incentive liquidations
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_liquidation_auction_config())]
+		#[transactional]
+		pub fn set_liquidation_auction_config(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			config: Option<LiquidationAuctionConfig<BlockNumberFor<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::market(asset_id)?;
+
+			match config {
+				Some(ref config) => {
+					ensure!(
+						config.initial_discount <= config.max_discount &&
+							config.max_discount < Ratio::one(),
+						Error::<T>::InvalidFactor
+					);
+					ensure!(
+						!config.duration.is_zero() && !config.min_bid.is_zero(),
+						Error::<T>::InvalidFactor
+					);
+					LiquidationAuctionConfigs::<T>::insert(asset_id, config);
+				},
+				None => {
+					ensure!(
+						LiquidationAuctionConfigs::<T>::contains_key(asset_id),
+						Error::<T>::MarketNotInAuctionMode
+					);
+					LiquidationAuctionConfigs::<T>::remove(asset_id);
+				},
+			}
+
+			Self::deposit_event(Event::<T>::LiquidationAuctionConfigUpdated { asset_id, config });
+			Ok(().into())
+		}
+
+		/// Start the liquidation auction of a borrower's debt.
+		///
+		/// The borrower must have a shortfall. An auction that has expired can be restarted.
+		///
+		/// - `borrower`: the borrower to be liquidated.
+		/// - `liquidation_asset_id`: the borrowed asset to be auctioned.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::start_liquidation_auction())]
+		#[transactional]
+		pub fn start_liquidation_auction(
+			origin: OriginFor<T>,
+			borrower: T::AccountId,
+			liquidation_asset_id: AssetIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_start_liquidation_auction(&borrower, liquidation_asset_id)?;
+			Ok(().into())
+		}
+
+		/// The sender repays part or all of the debt of a liquidation auction and receives
+		/// the borrower's collateral at the current discount of the auction.
+		///
+		/// - `borrower`: the borrower to be liquidated.
+		/// - `liquidation_asset_id`: the borrowed asset of the auction.
+		/// - `repay_amount`: the amount to be repaid borrow, at least `min_bid` of the auctioned
+		///   debt unless less debt is left.
+		/// - `collateral_asset_id`: The collateral to seize from the borrower.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::bid_liquidation_auction())]
+		#[transactional]
+		pub fn bid_liquidation_auction(
+			origin: OriginFor<T>,
+			borrower: T::AccountId,
+			liquidation_asset_id: AssetIdOf<T>,
+			#[pallet::compact] repay_amount: BalanceOf<T>,
+			collateral_asset_id: AssetIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_bid_liquidation_auction(
+				who,
+				borrower,
+				liquidation_asset_id,
+				repay_amount,
+				collateral_asset_id,
+			)?;
+			Ok(().into())
+		}
 	}
 }
 
@@ -1151,7 +1521,7 @@ impl<T: Config> Pallet<T> {
 			.filter(|(asset_id, _)| LiquidationFreeCollaterals::<T>::get().contains(asset_id))
 		{
 			total_asset_value = total_asset_value
-				.checked_add(&Self::collateral_asset_value(account, asset_id)?)
+				.checked_add(&Self::collateral_asset_value(account, asset_id, None)?)
 				.ok_or(ArithmeticError::Overflow)?;
 		}
 		Ok(total_asset_value)
@@ -1165,17 +1535,84 @@ impl<T: Config> Pallet<T> {
 			.filter(|(asset_id, _)| LiquidationFreeCollaterals::<T>::get().contains(asset_id))
 		{
 			total_asset_value = total_asset_value
-				.checked_add(&Self::liquidation_threshold_asset_value(account, asset_id)?)
+				.checked_add(&Self::liquidation_threshold_asset_value(account, asset_id, None)?)
 				.ok_or(ArithmeticError::Overflow)?;
 		}
 		Ok(total_asset_value)
 	}
 
+	/// The efficiency mode category applied to the account, if any.
+	///
+	/// A category only applies when the account opted in to it and all its borrows are assets
+	/// of the category.
+	pub fn account_emode(account: &T::AccountId) -> Result<Option<EModeCategory>, DispatchError> {
+		let category = match AccountEMode::<T>::get(account).and_then(EModeCategories::<T>::get) {
+			Some(category) => category,
+			None => return Ok(None),
+		};
+		for (asset_id, _) in Self::active_markets() {
+			if category.assets.contains(&asset_id) {
+				continue;
+			}
+			if !Self::current_borrow_balance(account, asset_id)?.is_zero() {
+				return Ok(None);
+			}
+		}
+
+		Ok(Some(category))
+	}
+
+	/// The id of the efficiency mode category applied to the account, if any.
+	pub fn account_emode_category_id(account: &T::AccountId) -> Result<Option<u8>, DispatchError> {
+		Ok(Self::account_emode(account)?.and(AccountEMode::<T>::get(account)))
+	}
+
+	fn emode_for_asset(
+		asset_id: AssetIdOf<T>,
+		emode: Option<&EModeCategory>,
+	) -> Option<&EModeCategory> {
+		emode.filter(|category| category.assets.contains(&asset_id))
+	}
+
+	fn collateral_factor(
+		asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+		emode: Option<&EModeCategory>,
+	) -> Ratio {
+		Self::emode_for_asset(asset_id, emode)
+			.map_or(market.collateral_factor, |category| category.collateral_factor)
+	}
+
+	fn liquidation_threshold(
+		asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+		emode: Option<&EModeCategory>,
+	) -> Ratio {
+		Self::emode_for_asset(asset_id, emode)
+			.map_or(market.liquidation_threshold, |category| category.liquidation_threshold)
+	}
+
+	fn liquidate_incentive(
+		asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+		emode: Option<&EModeCategory>,
+	) -> Rate {
+		Self::emode_for_asset(asset_id, emode)
+			.map_or(market.liquidate_incentive, |category| category.liquidate_incentive)
+	}
+
 	pub fn get_account_liquidity(
 		account: &T::AccountId,
+	) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
+		Self::account_liquidity(account, Self::account_emode(account)?.as_ref())
+	}
+
+	fn account_liquidity(
+		account: &T::AccountId,
+		emode: Option<&EModeCategory>,
 	) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 		let total_borrow_value = Self::total_borrowed_value(account)?;
-		let total_collateral_value = Self::total_collateral_value(account)?;
+		let total_collateral_value = Self::total_collateral_value(account, emode)?;
 		let lf_borrowed_value = Self::get_lf_borrowed_value(account)?;
 		let lf_base_position = Self::get_lf_base_position(account)?;
 
@@ -1219,8 +1656,10 @@ impl<T: Config> Pallet<T> {
 	pub fn get_account_liquidation_threshold_liquidity(
 		account: &T::AccountId,
 	) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
+		let emode = Self::account_emode(account)?;
 		let total_borrow_value = Self::total_borrowed_value(account)?;
-		let total_collateral_value = Self::total_liquidation_threshold_value(account)?;
+		let total_collateral_value =
+			Self::total_liquidation_threshold_value(account, emode.as_ref())?;
 
 		let lf_borrowed_value = Self::get_lf_borrowed_value(account)?;
 		let lf_base_position = Self::get_lf_liquidation_base_position(account)?;
@@ -1281,6 +1720,7 @@ impl<T: Config> Pallet<T> {
 	fn current_collateral_balance(
 		supplier: &T::AccountId,
 		asset_id: AssetIdOf<T>,
+		emode: Option<&EModeCategory>,
 	) -> Result<BalanceOf<T>, DispatchError> {
 		if !AccountDeposits::<T>::contains_key(asset_id, supplier) {
 			return Ok(BalanceOf::<T>::zero());
@@ -1296,7 +1736,8 @@ impl<T: Config> Pallet<T> {
 		let underlying_amount =
 			Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
 		let market = Self::market(asset_id)?;
-		let effects_amount = market.collateral_factor.mul_ceil(underlying_amount);
+		let effects_amount =
+			Self::collateral_factor(asset_id, &market, emode).mul_ceil(underlying_amount);
 
 		Ok(BalanceOf::<T>::saturated_from(effects_amount))
 	}
@@ -1304,8 +1745,9 @@ impl<T: Config> Pallet<T> {
 	fn collateral_asset_value(
 		supplier: &T::AccountId,
 		asset_id: AssetIdOf<T>,
+		emode: Option<&EModeCategory>,
 	) -> Result<FixedU128, DispatchError> {
-		let effects_amount = Self::current_collateral_balance(supplier, asset_id)?;
+		let effects_amount = Self::current_collateral_balance(supplier, asset_id, emode)?;
 
 		Self::get_asset_value(asset_id, effects_amount)
 	}
@@ -1313,6 +1755,7 @@ impl<T: Config> Pallet<T> {
 	fn liquidation_threshold_asset_value(
 		borrower: &T::AccountId,
 		asset_id: AssetIdOf<T>,
+		emode: Option<&EModeCategory>,
 	) -> Result<FixedU128, DispatchError> {
 		if !AccountDeposits::<T>::contains_key(asset_id, borrower) {
 			return Ok(FixedU128::zero());
@@ -1328,16 +1771,20 @@ impl<T: Config> Pallet<T> {
 		let underlying_amount =
 			Self::calc_underlying_amount(deposits.voucher_balance, exchange_rate)?;
 		let market = Self::market(asset_id)?;
-		let effects_amount = market.liquidation_threshold.mul_ceil(underlying_amount);
+		let effects_amount =
+			Self::liquidation_threshold(asset_id, &market, emode).mul_ceil(underlying_amount);
 
 		Self::get_asset_value(asset_id, effects_amount)
 	}
 
-	fn total_collateral_value(supplier: &T::AccountId) -> Result<FixedU128, DispatchError> {
+	fn total_collateral_value(
+		supplier: &T::AccountId,
+		emode: Option<&EModeCategory>,
+	) -> Result<FixedU128, DispatchError> {
 		let mut total_asset_value: FixedU128 = FixedU128::zero();
 		for (asset_id, _market) in Self::active_markets() {
 			total_asset_value = total_asset_value
-				.checked_add(&Self::collateral_asset_value(supplier, asset_id)?)
+				.checked_add(&Self::collateral_asset_value(supplier, asset_id, emode)?)
 				.ok_or(ArithmeticError::Overflow)?;
 		}
 
@@ -1346,11 +1793,12 @@ impl<T: Config> Pallet<T> {
 
 	fn total_liquidation_threshold_value(
 		borrower: &T::AccountId,
+		emode: Option<&EModeCategory>,
 	) -> Result<FixedU128, DispatchError> {
 		let mut total_asset_value: FixedU128 = FixedU128::zero();
 		for (asset_id, _market) in Self::active_markets() {
 			total_asset_value = total_asset_value
-				.checked_add(&Self::liquidation_threshold_asset_value(borrower, asset_id)?)
+				.checked_add(&Self::liquidation_threshold_asset_value(borrower, asset_id, emode)?)
 				.ok_or(ArithmeticError::Overflow)?;
 		}
 
@@ -1384,7 +1832,9 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let market = Self::market(asset_id)?;
-		let effects_amount = market.collateral_factor.mul_ceil(redeem_amount);
+		let emode = Self::account_emode(redeemer)?;
+		let effects_amount =
+			Self::collateral_factor(asset_id, &market, emode.as_ref()).mul_ceil(redeem_amount);
 		let redeem_effects_value = Self::get_asset_value(asset_id, effects_amount)?;
 		log::trace!(
 			target: "lend-market::redeem_allowed",
@@ -1397,6 +1847,7 @@ impl<T: Config> Pallet<T> {
 			redeemer,
 			redeem_effects_value,
 			LiquidationFreeCollaterals::<T>::get().contains(&asset_id),
+			emode.as_ref(),
 		)?;
 
 		Ok(())
@@ -1456,10 +1907,12 @@ impl<T: Config> Pallet<T> {
 		Self::ensure_under_borrow_cap(asset_id, borrow_amount)?;
 		Self::ensure_enough_cash(asset_id, borrow_amount)?;
 		let borrow_value = Self::get_asset_value(asset_id, borrow_amount)?;
+		let emode = Self::account_emode_after_borrow(borrower, asset_id)?;
 		Self::ensure_liquidity(
 			borrower,
 			borrow_value,
 			asset_id == T::LiquidationFreeAssetId::get(),
+			emode.as_ref(),
 		)?;
 
 		Ok(())
@@ -1474,11 +1927,27 @@ impl<T: Config> Pallet<T> {
 		Self::ensure_under_borrow_cap(borrow_asset_id, borrow_amount)?;
 		Self::ensure_enough_cash(borrow_asset_id, borrow_amount)?;
 		let borrow_value = Self::get_asset_value(borrow_asset_id, borrow_amount)?;
-		Self::ensure_liquidity_for_market_bond(borrow_asset_id, borrower, borrow_value)?;
+		let emode = Self::account_emode_after_borrow(borrower, borrow_asset_id)?;
+		Self::ensure_liquidity_for_market_bond(
+			borrow_asset_id,
+			borrower,
+			borrow_value,
+			emode.as_ref(),
+		)?;
 
 		Ok(())
 	}
 
+	/// The efficiency mode category applied to the account once it borrows `asset_id`.
+	///
+	/// Borrowing an asset outside of the category leaves the efficiency mode.
+	fn account_emode_after_borrow(
+		borrower: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+	) -> Result<Option<EModeCategory>, DispatchError> {
+		Ok(Self::account_emode(borrower)?.filter(|category| category.assets.contains(&asset_id)))
+	}
+
 	#[require_transactional]
 	fn do_repay_borrow_with_amount(
 		borrower: &T::AccountId,
@@ -1582,6 +2051,25 @@ impl<T: Config> Pallet<T> {
 			repay_amount,
 			market
 		);
+		Self::ensure_liquidatable(borrower)?;
+
+		// The liquidator may not repay more than 50%(close_factor) of the borrower's borrow
+		// balance.
+		let effects_borrows_value =
+			Self::liquidatable_borrow_value(borrower, liquidation_asset_id)?;
+		let repay_value = Self::get_asset_value(liquidation_asset_id, repay_amount)?;
+
+		if market.close_factor.mul_ceil(effects_borrows_value.into_inner()) <
+			repay_value.into_inner()
+		{
+			return Err(Error::<T>::TooMuchRepay.into());
+		}
+
+		Ok(())
+	}
+
+	/// Checks if the borrower has a shortfall
+	fn ensure_liquidatable(borrower: &T::AccountId) -> DispatchResult {
 		let (liquidity, shortfall, lf_liquidity, _) =
 			Self::get_account_liquidation_threshold_liquidity(borrower)?;
 
@@ -1595,29 +2083,48 @@ impl<T: Config> Pallet<T> {
 			return Err(Error::<T>::InsufficientShortfall.into());
 		}
 
-		// The liquidator may not repay more than 50%(close_factor) of the borrower's borrow
-		// balance.
+		Ok(())
+	}
+
+	/// Value of the borrower's borrow balance that can be liquidated, the borrows of
+	/// the liquidation free asset covered by the liquidation free collaterals are excluded.
+	fn liquidatable_borrow_value(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+	) -> Result<FixedU128, DispatchError> {
 		let account_borrows = Self::current_borrow_balance(borrower, liquidation_asset_id)?;
 		let account_borrows_value = Self::get_asset_value(liquidation_asset_id, account_borrows)?;
-		let repay_value = Self::get_asset_value(liquidation_asset_id, repay_amount)?;
-		let effects_borrows_value = if liquidation_asset_id == T::LiquidationFreeAssetId::get() {
+		if liquidation_asset_id == T::LiquidationFreeAssetId::get() {
 			let base_position = Self::get_lf_base_position(borrower)?;
-			if account_borrows_value > base_position {
-				account_borrows_value - base_position
-			} else {
-				FixedU128::zero()
-			}
+			Ok(account_borrows_value.saturating_sub(base_position))
 		} else {
-			account_borrows_value
-		};
-
-		if market.close_factor.mul_ceil(effects_borrows_value.into_inner()) <
-			repay_value.into_inner()
-		{
-			return Err(Error::<T>::TooMuchRepay.into());
+			Ok(account_borrows_value)
 		}
+	}
 
-		Ok(())
+	/// Liquidates the borrower's collateral after accruing interest of both markets, returns
+	/// the amount of underlying collateral seized from the borrower.
+	#[transactional]
+	pub fn liquidate_borrow_inner(
+		liquidator: T::AccountId,
+		borrower: T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+		repay_amount: BalanceOf<T>,
+		collateral_asset_id: AssetIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(
+			!LiquidationFreeCollaterals::<T>::get().contains(&collateral_asset_id),
+			Error::<T>::CollateralReserved
+		);
+		Self::accrue_interest(liquidation_asset_id)?;
+		Self::accrue_interest(collateral_asset_id)?;
+		Self::do_liquidate_borrow(
+			liquidator,
+			borrower,
+			liquidation_asset_id,
+			repay_amount,
+			collateral_asset_id,
+		)
 	}
 
 	/// Note:
@@ -1636,10 +2143,14 @@ impl<T: Config> Pallet<T> {
 		liquidation_asset_id: AssetIdOf<T>,
 		repay_amount: BalanceOf<T>,
 		collateral_asset_id: AssetIdOf<T>,
-	) -> DispatchResult {
+	) -> Result<BalanceOf<T>, DispatchError> {
 		Self::ensure_active_market(liquidation_asset_id)?;
 		Self::ensure_active_market(collateral_asset_id)?;
 
+		ensure!(
+			!LiquidationAuctionConfigs::<T>::contains_key(liquidation_asset_id),
+			Error::<T>::MarketInAuctionMode
+		);
 		let market = Self::market(liquidation_asset_id)?;
 
 		if borrower == liquidator {
@@ -1647,7 +2158,36 @@ impl<T: Config> Pallet<T> {
 		}
 		Self::liquidate_borrow_allowed(&borrower, liquidation_asset_id, repay_amount, &market)?;
 
-		let deposits = AccountDeposits::<T>::get(collateral_asset_id, &borrower);
+		let liquidate_incentive = Self::liquidate_incentive(
+			collateral_asset_id,
+			&market,
+			Self::account_emode(&borrower)?.as_ref(),
+		);
+		Self::seize_collateral(
+			&liquidator,
+			&borrower,
+			liquidation_asset_id,
+			repay_amount,
+			collateral_asset_id,
+			&market,
+			liquidate_incentive,
+		)
+	}
+
+	/// Repays `repay_amount` of the borrower's debt and transfers the collateral worth of
+	/// the repaid value multiplied by `liquidate_incentive` to the liquidator, returns the
+	/// amount of underlying collateral seized.
+	#[require_transactional]
+	fn seize_collateral(
+		liquidator: &T::AccountId,
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+		repay_amount: BalanceOf<T>,
+		collateral_asset_id: AssetIdOf<T>,
+		market: &Market<BalanceOf<T>>,
+		liquidate_incentive: Rate,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let deposits = AccountDeposits::<T>::get(collateral_asset_id, borrower);
 		if !deposits.is_collateral {
 			return Err(Error::<T>::DepositsAreNotCollateral.into());
 		}
@@ -1659,7 +2199,7 @@ impl<T: Config> Pallet<T> {
 		let collateral_value = Self::get_asset_value(collateral_asset_id, borrower_deposit_amount)?;
 		// liquidate_value contains the incentive of liquidator and the punishment of the borrower
 		let liquidate_value = Self::get_asset_value(liquidation_asset_id, repay_amount)?
-			.checked_mul(&market.liquidate_incentive)
+			.checked_mul(&liquidate_incentive)
 			.ok_or(ArithmeticError::Overflow)?;
 
 		if collateral_value < liquidate_value {
@@ -1684,16 +2224,17 @@ impl<T: Config> Pallet<T> {
 
 		//inside transfer token
 		Self::liquidated_transfer(
-			&liquidator,
-			&borrower,
+			liquidator,
+			borrower,
 			liquidation_asset_id,
 			collateral_asset_id,
 			repay_amount,
 			real_collateral_underlying_amount,
-			&market,
+			market,
+			liquidate_incentive,
 		)?;
 
-		Ok(())
+		Ok(real_collateral_underlying_amount)
 	}
 
 	#[require_transactional]
@@ -1705,6 +2246,7 @@ impl<T: Config> Pallet<T> {
 		repay_amount: BalanceOf<T>,
 		collateral_underlying_amount: BalanceOf<T>,
 		market: &Market<BalanceOf<T>>,
+		liquidate_incentive: Rate,
 	) -> DispatchResult {
 		log::trace!(
 			target: "lend-market::liquidated_transfer",
@@ -1775,7 +2317,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 		let incentive_reserved_amount = market.liquidate_incentive_reserved_factor.mul_floor(
 			FixedU128::from_inner(collateral_amount)
-				.checked_div(&market.liquidate_incentive)
+				.checked_div(&liquidate_incentive)
 				.map(|r| r.into_inner())
 				.ok_or(ArithmeticError::Underflow)?,
 		);
@@ -1816,6 +2358,320 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Collateral discount of a liquidation auction at the current block
+	fn liquidation_auction_discount(
+		config: &LiquidationAuctionConfig<BlockNumberFor<T>>,
+		start_block: BlockNumberFor<T>,
+	) -> Ratio {
+		let elapsed: u32 = frame_system::Pallet::<T>::block_number()
+			.saturating_sub(start_block)
+			.saturated_into();
+		let increase =
+			Ratio::from_parts(config.discount_per_block.deconstruct().saturating_mul(elapsed));
+		config.initial_discount.saturating_add(increase).min(config.max_discount)
+	}
+
+	/// Returns the remaining debt and the current discount of a liquidation auction
+	pub fn liquidation_auction_status(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+	) -> Result<Option<(BalanceOf<T>, Ratio)>, DispatchError> {
+		let config = LiquidationAuctionConfigs::<T>::get(liquidation_asset_id)
+			.ok_or(Error::<T>::MarketNotInAuctionMode)?;
+		Ok(LiquidationAuctions::<T>::get(liquidation_asset_id, borrower).and_then(|auction| {
+			let now = frame_system::Pallet::<T>::block_number();
+			(now < auction.start_block.saturating_add(config.duration)).then(|| {
+				(auction.debt, Self::liquidation_auction_discount(&config, auction.start_block))
+			})
+		}))
+	}
+
+	/// Returns the bids of a liquidation auction as `(bid_count, total_repaid)`, the bids
+	/// themselves are recorded by the `LiquidationAuctionBidPlaced` events.
+	pub fn liquidation_auction_bids(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+	) -> Option<(u32, BalanceOf<T>)> {
+		LiquidationAuctions::<T>::get(liquidation_asset_id, borrower)
+			.map(|auction| (auction.bid_count, auction.total_repaid))
+	}
+
+	/// Starts the liquidation auction of the liquidatable borrows of `borrower` in the
+	/// market of `liquidation_asset_id`.
+	#[require_transactional]
+	pub fn do_start_liquidation_auction(
+		borrower: &T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+	) -> DispatchResult {
+		Self::ensure_active_market(liquidation_asset_id)?;
+		let config = LiquidationAuctionConfigs::<T>::get(liquidation_asset_id)
+			.ok_or(Error::<T>::MarketNotInAuctionMode)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(auction) = LiquidationAuctions::<T>::get(liquidation_asset_id, borrower) {
+			ensure!(
+				now >= auction.start_block.saturating_add(config.duration),
+				Error::<T>::LiquidationAuctionAlreadyStarted
+			);
+		}
+
+		Self::accrue_interest(liquidation_asset_id)?;
+		Self::ensure_liquidatable(borrower)?;
+		let debt = Self::liquidatable_borrow_value(borrower, liquidation_asset_id)?
+			.checked_div(&Self::get_price(liquidation_asset_id)?)
+			.ok_or(ArithmeticError::Underflow)?
+			.into_inner();
+		ensure!(!debt.is_zero(), Error::<T>::InsufficientShortfall);
+
+		LiquidationAuctions::<T>::insert(
+			liquidation_asset_id,
+			borrower,
+			LiquidationAuction { start_block: now, debt, total_repaid: Zero::zero(), bid_count: 0 },
+		);
+		Self::deposit_event(Event::<T>::LiquidationAuctionStarted {
+			borrower: borrower.clone(),
+			asset_id: liquidation_asset_id,
+			debt,
+		});
+		Ok(())
+	}
+
+	/// Repays `repay_amount` of the debt of a liquidation auction, the liquidator receives
+	/// the collateral at the current discount of the auction. Returns the amount of
+	/// underlying collateral seized from the borrower.
+	#[require_transactional]
+	pub fn do_bid_liquidation_auction(
+		liquidator: T::AccountId,
+		borrower: T::AccountId,
+		liquidation_asset_id: AssetIdOf<T>,
+		repay_amount: BalanceOf<T>,
+		collateral_asset_id: AssetIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!repay_amount.is_zero(), Error::<T>::InvalidAmount);
+		ensure!(
+			!LiquidationFreeCollaterals::<T>::get().contains(&collateral_asset_id),
+			Error::<T>::CollateralReserved
+		);
+		ensure!(borrower != liquidator, Error::<T>::LiquidatorIsBorrower);
+		let market = Self::ensure_active_market(liquidation_asset_id)?;
+		Self::ensure_active_market(collateral_asset_id)?;
+		let config = LiquidationAuctionConfigs::<T>::get(liquidation_asset_id)
+			.ok_or(Error::<T>::MarketNotInAuctionMode)?;
+		let mut auction = LiquidationAuctions::<T>::get(liquidation_asset_id, &borrower)
+			.ok_or(Error::<T>::LiquidationAuctionDoesNotExist)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <
+				auction.start_block.saturating_add(config.duration),
+			Error::<T>::LiquidationAuctionExpired
+		);
+		ensure!(repay_amount <= auction.debt, Error::<T>::TooMuchRepay);
+		// Bids repay at least `min_bid` of the auctioned debt, which bounds the number of bids
+		let min_bid = config.min_bid.mul_ceil(auction.debt.saturating_add(auction.total_repaid));
+		ensure!(repay_amount >= min_bid.min(auction.debt), Error::<T>::LiquidationAuctionBidTooLow);
+
+		Self::accrue_interest(liquidation_asset_id)?;
+		Self::accrue_interest(collateral_asset_id)?;
+		Self::ensure_liquidatable(&borrower)?;
+
+		// The liquidator buys the collateral at `price * (1 - discount)`
+		let discount = Self::liquidation_auction_discount(&config, auction.start_block);
+		let liquidate_incentive = Rate::one()
+			.checked_div(&Ratio::one().saturating_sub(discount).into())
+			.ok_or(ArithmeticError::Underflow)?;
+		let collateral_amount = Self::seize_collateral(
+			&liquidator,
+			&borrower,
+			liquidation_asset_id,
+			repay_amount,
+			collateral_asset_id,
+			&market,
+			liquidate_incentive,
+		)?;
+
+		auction.debt = auction.debt.saturating_sub(repay_amount);
+		auction.total_repaid = auction.total_repaid.saturating_add(repay_amount);
+		auction.bid_count = auction.bid_count.saturating_add(1);
+		// The auction is over once its debt is repaid
+		if auction.debt.is_zero() ||
+			Self::current_borrow_balance(&borrower, liquidation_asset_id)?.is_zero()
+		{
+			LiquidationAuctions::<T>::remove(liquidation_asset_id, &borrower);
+		} else {
+			LiquidationAuctions::<T>::insert(liquidation_asset_id, &borrower, auction);
+		}
+
+		Self::deposit_event(Event::<T>::LiquidationAuctionBidPlaced {
+			liquidator,
+			borrower,
+			asset_id: liquidation_asset_id,
+			collateral_asset_id,
+			repay_amount,
+			collateral_amount,
+			discount,
+		});
+		Ok(collateral_amount)
+	}
+
+	/// Writes off all the borrows of `borrower`, whose deposits, collateral or not, must be
+	/// worth less than the borrows. Such a position can not be fully liquidated anymore.
+	///
+	/// The deposits left are seized into the reserves of their markets first. Their value is
+	/// credited against the borrows, the covered part is kept in `CoveredBadDebt` and keeps
+	/// backing the exchange rate of the borrowed market until it is repaid by `add_reserves`.
+	/// The uncovered part is absorbed by `TotalReserves` first, the remaining loss reduces the
+	/// exchange rate since it is no longer backed by `TotalBorrows`.
+	#[require_transactional]
+	pub fn do_write_off_bad_debt(borrower: &T::AccountId) -> DispatchResult {
+		let mut has_borrows = false;
+		for asset_id in Markets::<T>::iter_keys() {
+			let has_deposits = AccountDeposits::<T>::contains_key(asset_id, borrower);
+			let has_borrow = !AccountBorrows::<T>::get(asset_id, borrower).principal.is_zero();
+			if has_deposits || has_borrow {
+				Self::accrue_interest(asset_id)?;
+			}
+			has_borrows = has_borrows || has_borrow;
+		}
+		ensure!(has_borrows, Error::<T>::NoBadDebt);
+		ensure!(
+			Self::total_deposit_value(borrower)? < Self::total_borrowed_value(borrower)?,
+			Error::<T>::CollateralRemaining
+		);
+
+		// The seized value is less than the value of the borrows, it is all credited
+		let mut seized = Vec::new();
+		let mut seized_value = FixedU128::zero();
+		for asset_id in Markets::<T>::iter_keys() {
+			let amount = Self::seize_deposits_to_reserves(borrower, asset_id)?;
+			if amount.is_zero() {
+				continue;
+			}
+			seized_value = Self::get_asset_value(asset_id, amount)?
+				.checked_add(&seized_value)
+				.ok_or(ArithmeticError::Overflow)?;
+			seized.push((asset_id, amount));
+		}
+
+		let mut written_off = false;
+		for asset_id in Markets::<T>::iter_keys() {
+			LiquidationAuctions::<T>::remove(asset_id, borrower);
+			if AccountBorrows::<T>::get(asset_id, borrower).principal.is_zero() {
+				continue;
+			}
+			let account_borrows = Self::current_borrow_balance(borrower, asset_id)?;
+			if account_borrows.is_zero() {
+				continue;
+			}
+
+			// settle the rewards of the borrower before removing the borrow.
+			Self::update_reward_borrow_index(asset_id)?;
+			Self::distribute_borrower_reward(asset_id, borrower)?;
+
+			let borrow_value = Self::get_asset_value(asset_id, account_borrows)?;
+			let covered = if seized_value >= borrow_value {
+				account_borrows
+			} else {
+				seized_value
+					.checked_div(&Self::get_price(asset_id)?)
+					.ok_or(ArithmeticError::Underflow)?
+					.into_inner()
+					.min(account_borrows)
+			};
+			seized_value = seized_value.saturating_sub(borrow_value);
+			if !covered.is_zero() {
+				CoveredBadDebt::<T>::try_mutate(asset_id, |covered_bad_debt| -> DispatchResult {
+					*covered_bad_debt =
+						covered_bad_debt.checked_add(covered).ok_or(ArithmeticError::Overflow)?;
+					Ok(())
+				})?;
+			}
+
+			AccountBorrows::<T>::remove(asset_id, borrower);
+			// total_borrows can be less than account_borrows, see `do_repay_borrow_with_amount`
+			TotalBorrows::<T>::mutate(asset_id, |total_borrows| {
+				*total_borrows = total_borrows.saturating_sub(account_borrows)
+			});
+			let loss = account_borrows - covered;
+			// The deposits seized into the reserves of this market are already credited
+			let seized_amount = seized
+				.iter()
+				.find(|(id, _)| *id == asset_id)
+				.map_or(Zero::zero(), |(_, amount)| *amount);
+			let total_reserves = TotalReserves::<T>::get(asset_id);
+			let reserves_absorbed = total_reserves.saturating_sub(seized_amount).min(loss);
+			TotalReserves::<T>::insert(asset_id, total_reserves - reserves_absorbed);
+			// Fails if the socialized loss brings the exchange rate out of its bounds, the
+			// reserves have to be topped up first.
+			ExchangeRate::<T>::insert(asset_id, Self::exchange_rate_stored(asset_id)?);
+			written_off = true;
+
+			Self::deposit_event(Event::<T>::BadDebtWrittenOff {
+				borrower: borrower.clone(),
+				asset_id,
+				amount: account_borrows,
+				covered,
+				reserves_absorbed,
+				socialized: loss - reserves_absorbed,
+			});
+		}
+		ensure!(written_off, Error::<T>::NoBadDebt);
+
+		Ok(())
+	}
+
+	/// Value of all the deposits of `supplier`, whether they are used as collateral or not.
+	fn total_deposit_value(supplier: &T::AccountId) -> Result<FixedU128, DispatchError> {
+		let mut total_deposit_value: FixedU128 = FixedU128::zero();
+		for (asset_id, _) in Self::active_markets() {
+			let voucher_balance = AccountDeposits::<T>::get(asset_id, supplier).voucher_balance;
+			if voucher_balance.is_zero() {
+				continue;
+			}
+			let exchange_rate = Self::exchange_rate_stored(asset_id)?;
+			let underlying_amount = Self::calc_underlying_amount(voucher_balance, exchange_rate)?;
+			total_deposit_value = Self::get_asset_value(asset_id, underlying_amount)?
+				.checked_add(&total_deposit_value)
+				.ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Ok(total_deposit_value)
+	}
+
+	/// Moves the deposits of an insolvent `borrower` in `asset_id` to the market reserves.
+	/// The exchange rate of the market is unchanged. Returns the seized underlying amount.
+	#[require_transactional]
+	fn seize_deposits_to_reserves(
+		borrower: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let voucher_amount = AccountDeposits::<T>::get(asset_id, borrower).voucher_balance;
+		if voucher_amount.is_zero() {
+			return Ok(Zero::zero());
+		}
+		Self::update_reward_supply_index(asset_id)?;
+		Self::distribute_supplier_reward(asset_id, borrower)?;
+
+		let exchange_rate = Self::exchange_rate_stored(asset_id)?;
+		let amount = Self::calc_underlying_amount(voucher_amount, exchange_rate)?;
+		AccountDeposits::<T>::remove(asset_id, borrower);
+		TotalSupply::<T>::try_mutate(asset_id, |total_supply| -> DispatchResult {
+			*total_supply =
+				total_supply.checked_sub(voucher_amount).ok_or(ArithmeticError::Underflow)?;
+			Ok(())
+		})?;
+		TotalReserves::<T>::try_mutate(asset_id, |total_reserves| -> DispatchResult {
+			*total_reserves =
+				total_reserves.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T>::BadDebtDepositsSeized {
+			borrower: borrower.clone(),
+			asset_id,
+			voucher_amount,
+			amount,
+		});
+		Ok(amount)
+	}
+
 	// Ensures a given `asset_id` is an active market.
 	fn ensure_active_market(asset_id: AssetIdOf<T>) -> Result<Market<BalanceOf<T>>, DispatchError> {
 		Self::active_markets()
@@ -1850,6 +2706,7 @@ impl<T: Config> Pallet<T> {
 	fn ensure_enough_cash(asset_id: AssetIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
 		let reducible_cash = Self::get_total_cash(asset_id)
 			.checked_sub(TotalReserves::<T>::get(asset_id))
+			.and_then(|r| r.checked_sub(FlashLoans::<T>::get(asset_id)))
 			.ok_or(ArithmeticError::Underflow)?;
 		if reducible_cash < amount {
 			return Err(Error::<T>::InsufficientCash.into());
@@ -1878,12 +2735,14 @@ impl<T: Config> Pallet<T> {
 	// `reduce_amount`: values that will have an impact on liquidity
 	// `lf_enable`: check in liquidation free mode which means borrowing dot or redeeming assets in
 	// `LiquidationFreeCollaterals`.
+	// `emode`: efficiency mode category the account is in after the operation.
 	fn ensure_liquidity(
 		account: &T::AccountId,
 		reduce_amount: FixedU128,
 		lf_enable: bool,
+		emode: Option<&EModeCategory>,
 	) -> DispatchResult {
-		let (total_liquidity, _, lf_liquidity, _) = Self::get_account_liquidity(account)?;
+		let (total_liquidity, _, lf_liquidity, _) = Self::account_liquidity(account, emode)?;
 
 		if lf_enable && max(total_liquidity, lf_liquidity) >= reduce_amount {
 			return Ok(());
@@ -1900,6 +2759,7 @@ impl<T: Config> Pallet<T> {
 		borrow_asset_id: AssetIdOf<T>,
 		account: &T::AccountId,
 		reduce_amount: FixedU128,
+		emode: Option<&EModeCategory>,
 	) -> DispatchResult {
 		let collateral_asset_ids = MarketBond::<T>::try_get(borrow_asset_id)
 			.map_err(|_err| Error::<T>::MarketBondDoesNotExist)?;
@@ -1910,7 +2770,7 @@ impl<T: Config> Pallet<T> {
 		let mut total_collateral_value: FixedU128 = FixedU128::zero();
 		for asset_id in collateral_asset_ids {
 			total_collateral_value = total_collateral_value
-				.checked_add(&Self::collateral_asset_value(account, asset_id)?)
+				.checked_add(&Self::collateral_asset_value(account, asset_id, emode)?)
 				.ok_or(ArithmeticError::Overflow)?;
 		}
 
@@ -1942,6 +2802,8 @@ impl<T: Config> Pallet<T> {
 			.ok_or(ArithmeticError::Underflow)?)
 	}
 
+	// Cash lent out by flash loans is still counted, so the exchange rate and the
+	// utilization ratio can't be manipulated during a flash loan.
 	fn get_total_cash(asset_id: AssetIdOf<T>) -> BalanceOf<T> {
 		T::Assets::reducible_balance(
 			asset_id,
@@ -1949,6 +2811,7 @@ impl<T: Config> Pallet<T> {
 			Preservation::Expendable,
 			Fortitude::Polite,
 		)
+		.saturating_add(FlashLoans::<T>::get(asset_id))
 	}
 
 	// Returns the uniform format price.
@@ -2161,8 +3024,10 @@ impl<T: Config> LendMarketTrait<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for
 			return Ok(());
 		}
 		// turn off the collateral button after checking the liquidity
-		let total_collateral_value = Self::total_collateral_value(supplier)?;
-		let collateral_asset_value = Self::collateral_asset_value(supplier, asset_id)?;
+		let emode = Self::account_emode(supplier)?;
+		let total_collateral_value = Self::total_collateral_value(supplier, emode.as_ref())?;
+		let collateral_asset_value =
+			Self::collateral_asset_value(supplier, asset_id, emode.as_ref())?;
 		let total_borrowed_value = Self::total_borrowed_value(supplier)?;
 		log::trace!(
 			target: "lend-market::collateral_asset",
@@ -2254,6 +3119,7 @@ impl<T: Config> LendMarketMarketDataProvider<AssetIdOf<T>, BalanceOf<T>> for Pal
 		if let Ok(market) = Self::market(asset_id) {
 			let rate = match market.rate_model {
 				InterestRateModel::Jump(jump) => Some(jump.full_rate),
+				InterestRateModel::Adaptive(adaptive) => adaptive.get_borrow_rate(Ratio::one()),
 				_ => None,
 			};
 			return rate;
@@ -2277,6 +3143,73 @@ impl<T: Config> LendMarketPositionDataProvider<AssetIdOf<T>, AccountIdOf<T>, Bal
 		supplier: &AccountIdOf<T>,
 		asset_id: AssetIdOf<T>,
 	) -> Result<BalanceOf<T>, DispatchError> {
-		Self::current_collateral_balance(supplier, asset_id)
+		Self::current_collateral_balance(
+			supplier,
+			asset_id,
+			Self::account_emode(supplier)?.as_ref(),
+		)
+	}
+}
+
+impl<T: Config> FlashLoan<AssetIdOf<T>, AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
+	fn flash_loan_fee(
+		asset_id: AssetIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::ensure_active_market(asset_id)?;
+		Ok(T::FlashLoanFee::get().mul_ceil(amount))
+	}
+
+	#[transactional]
+	fn flash_loan(
+		borrower: &AccountIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		amount: BalanceOf<T>,
+		f: impl FnOnce() -> DispatchResult,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		let fee = Self::flash_loan_fee(asset_id, amount)?;
+		Self::accrue_interest(asset_id)?;
+		Self::ensure_enough_cash(asset_id, amount)?;
+
+		T::Assets::transfer(
+			asset_id,
+			&Self::account_id(),
+			borrower,
+			amount,
+			Preservation::Expendable,
+		)?;
+		FlashLoans::<T>::try_mutate(asset_id, |loans| -> DispatchResult {
+			*loans = loans.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+
+		f()?;
+
+		FlashLoans::<T>::try_mutate(asset_id, |loans| -> DispatchResult {
+			*loans = loans.checked_sub(amount).ok_or(ArithmeticError::Underflow)?;
+			Ok(())
+		})?;
+		let repay_amount = amount.checked_add(fee).ok_or(ArithmeticError::Overflow)?;
+		T::Assets::transfer(
+			asset_id,
+			borrower,
+			&Self::account_id(),
+			repay_amount,
+			Preservation::Expendable,
+		)
+		.map_err(|_| Error::<T>::FlashLoanNotRepaid)?;
+		TotalReserves::<T>::try_mutate(asset_id, |total_reserves| -> DispatchResult {
+			*total_reserves = total_reserves.checked_add(fee).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::<T>::FlashLoaned {
+			borrower: borrower.clone(),
+			asset_id,
+			amount,
+			fee,
+		});
+		Ok(fee)
 	}
 }
@@ -311,11 +311,14 @@ impl pallet_assets::Config for Test {
 impl pallet_prices::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = MockDataProvider;
+	type Twap = ();
+	type Time = TimestampPallet;
 	type FeederOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type RelayCurrency = RelayCurrency;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type VtokenMinting = ();
 	type WeightInfo = ();
 }
 
@@ -323,6 +326,7 @@ parameter_types! {
 	pub const LendMarketPalletId: PalletId = PalletId(*b"bf/ldmkt");
 	pub const RewardAssetId: CurrencyId = BNC;
 	pub const LiquidationFreeAssetId: CurrencyId = DOT;
+	pub const FlashLoanFee: Ratio = Ratio::from_parts(1_000);
 }
 
 impl Config for Test {
@@ -336,6 +340,8 @@ impl Config for Test {
 	type Assets = Currencies;
 	type RewardAssetId = RewardAssetId;
 	type LiquidationFreeAssetId = LiquidationFreeAssetId;
+	type RuntimeCall = RuntimeCall;
+	type FlashLoanFee = FlashLoanFee;
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use bifrost_primitives::{Rate, Ratio};
+use bifrost_primitives::{Rate, Ratio, Timestamp, SECONDS_PER_YEAR};
 use scale_info::TypeInfo;
 use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedSub, Saturating};
 
@@ -24,6 +24,7 @@ use crate::*;
 pub enum InterestRateModel {
 	Jump(JumpModel),
 	Curve(CurveModel),
+	Adaptive(AdaptiveModel),
 }
 
 impl Default for InterestRateModel {
@@ -51,10 +52,31 @@ impl InterestRateModel {
 		Self::Curve(CurveModel::new_model(base_rate))
 	}
 
+	pub fn new_adaptive_model(
+		target_utilization: Ratio,
+		rate_at_target: Rate,
+		min_rate_at_target: Rate,
+		max_rate_at_target: Rate,
+		curve_steepness: Rate,
+		adjustment_speed: Rate,
+		max_adjustment: Ratio,
+	) -> Self {
+		Self::Adaptive(AdaptiveModel::new_model(
+			target_utilization,
+			rate_at_target,
+			min_rate_at_target,
+			max_rate_at_target,
+			curve_steepness,
+			adjustment_speed,
+			max_adjustment,
+		))
+	}
+
 	pub fn check_model(&self) -> bool {
 		match self {
 			Self::Jump(jump) => jump.check_model(),
 			Self::Curve(curve) => curve.check_model(),
+			Self::Adaptive(adaptive) => adaptive.check_model(),
 		}
 	}
 
@@ -63,6 +85,7 @@ impl InterestRateModel {
 		match self {
 			Self::Jump(jump) => jump.get_borrow_rate(utilization),
 			Self::Curve(curve) => curve.get_borrow_rate(utilization),
+			Self::Adaptive(adaptive) => adaptive.get_borrow_rate(utilization),
 		}
 	}
 
@@ -176,6 +199,143 @@ impl CurveModel {
 	}
 }
 
+/// The adaptive interest rate model
+///
+/// The borrow rate follows a curve around `rate_at_target`, which is adjusted on every
+/// accrual of interest toward the rate that brings the utilization to `target_utilization`.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+pub struct AdaptiveModel {
+	/// The utilization the model steers the market toward
+	pub target_utilization: Ratio,
+	/// The borrow rate at the target utilization, adapted over time
+	pub rate_at_target: Rate,
+	/// Lower bound of `rate_at_target`
+	pub min_rate_at_target: Rate,
+	/// Upper bound of `rate_at_target`
+	pub max_rate_at_target: Rate,
+	/// The borrow rate at 100% utilization is `rate_at_target * curve_steepness`, the rate at
+	/// 0% utilization is `rate_at_target / curve_steepness`
+	pub curve_steepness: Rate,
+	/// Yearly relative change of `rate_at_target` when the utilization is 0% or 100%
+	pub adjustment_speed: Rate,
+	/// Upper bound of the relative change of `rate_at_target` in a single accrual
+	pub max_adjustment: Ratio,
+}
+
+impl AdaptiveModel {
+	pub const MAX_RATE_AT_TARGET: Rate = Rate::from_inner(Rate::DIV); // 100%
+	pub const MAX_CURVE_STEEPNESS: Rate = Rate::from_inner(Rate::DIV * 10);
+	pub const MAX_ADJUSTMENT_SPEED: Rate = Rate::from_inner(Rate::DIV * 100);
+	pub const MAX_ADJUSTMENT: Ratio = Ratio::from_percent(10);
+
+	/// Create a new adaptive model
+	pub fn new_model(
+		target_utilization: Ratio,
+		rate_at_target: Rate,
+		min_rate_at_target: Rate,
+		max_rate_at_target: Rate,
+		curve_steepness: Rate,
+		adjustment_speed: Rate,
+		max_adjustment: Ratio,
+	) -> AdaptiveModel {
+		Self {
+			target_utilization,
+			rate_at_target,
+			min_rate_at_target,
+			max_rate_at_target,
+			curve_steepness,
+			adjustment_speed,
+			max_adjustment,
+		}
+	}
+
+	/// Check the adaptive model for sanity
+	pub fn check_model(&self) -> bool {
+		if self.target_utilization.is_zero() || self.target_utilization == Ratio::one() {
+			return false;
+		}
+		if self.min_rate_at_target.is_zero() ||
+			self.min_rate_at_target > self.rate_at_target ||
+			self.rate_at_target > self.max_rate_at_target ||
+			self.max_rate_at_target > Self::MAX_RATE_AT_TARGET
+		{
+			return false;
+		}
+		if self.curve_steepness <= Rate::one() || self.curve_steepness > Self::MAX_CURVE_STEEPNESS {
+			return false;
+		}
+		if self.adjustment_speed > Self::MAX_ADJUSTMENT_SPEED ||
+			self.max_adjustment > Self::MAX_ADJUSTMENT
+		{
+			return false;
+		}
+
+		true
+	}
+
+	/// Distance between the utilization and the target utilization, normalized to [0, 1].
+	/// Returns whether the utilization is above the target and the distance.
+	fn utilization_error(&self, utilization: Ratio) -> Option<(bool, Rate)> {
+		if utilization > self.target_utilization {
+			// (utilization - target_utilization) / (1 - target_utilization)
+			let error = Rate::from(utilization.saturating_sub(self.target_utilization))
+				.checked_div(&Ratio::one().saturating_sub(self.target_utilization).into())?;
+			Some((true, error))
+		} else {
+			// (target_utilization - utilization) / target_utilization
+			let error = Rate::from(self.target_utilization.saturating_sub(utilization))
+				.checked_div(&self.target_utilization.into())?;
+			Some((false, error))
+		}
+	}
+
+	/// Calculates the borrow interest rate of adaptive model
+	pub fn get_borrow_rate(&self, utilization: Ratio) -> Option<Rate> {
+		let (above_target, error) = self.utilization_error(utilization)?;
+		let factor = if above_target {
+			// 1 + (curve_steepness - 1) * error
+			self.curve_steepness
+				.checked_sub(&Rate::one())?
+				.checked_mul(&error)?
+				.checked_add(&Rate::one())?
+		} else {
+			// 1 - (1 - 1 / curve_steepness) * error
+			let one_over_steepness = Rate::one().checked_div(&self.curve_steepness)?;
+			Rate::one()
+				.checked_sub(&Rate::one().checked_sub(&one_over_steepness)?.checked_mul(&error)?)?
+		};
+
+		self.rate_at_target.checked_mul(&factor)
+	}
+
+	/// Adapts `rate_at_target` to the utilization of the past `delta_time` seconds.
+	///
+	/// The rate rises while the utilization is above the target and falls while it is below,
+	/// a single call changes it by at most `max_adjustment` and keeps it within
+	/// `[min_rate_at_target, max_rate_at_target]`.
+	pub fn adapt(&mut self, utilization: Ratio, delta_time: Timestamp) -> Option<()> {
+		let (above_target, error) = self.utilization_error(utilization)?;
+		// adjustment_speed * error * delta_time / SECONDS_PER_YEAR
+		let adjustment = self
+			.adjustment_speed
+			.checked_mul(&error)?
+			.checked_mul(&Rate::saturating_from_integer(delta_time))?
+			.checked_div(&Rate::saturating_from_integer(SECONDS_PER_YEAR))?
+			.min(self.max_adjustment.into());
+		let multiplier = Rate::one().checked_add(&adjustment)?;
+		let rate_at_target = if above_target {
+			self.rate_at_target.checked_mul(&multiplier)?
+		} else {
+			self.rate_at_target.checked_div(&multiplier)?
+		};
+
+		self.rate_at_target =
+			rate_at_target.max(self.min_rate_at_target).min(self.max_rate_at_target);
+		Some(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -259,4 +419,136 @@ mod tests {
 			Rate::from_inner(154217728000000000)
 		);
 	}
+
+	// Test adaptive model
+	const DAY: Timestamp = 24 * 60 * 60;
+
+	fn adaptive_model(rate_at_target: Rate) -> AdaptiveModel {
+		AdaptiveModel::new_model(
+			Ratio::from_percent(90),
+			rate_at_target,
+			Rate::saturating_from_rational(1, 1000),
+			Rate::saturating_from_rational(50, 100),
+			Rate::saturating_from_integer(4),
+			Rate::saturating_from_integer(50),
+			Ratio::from_percent(5),
+		)
+	}
+
+	#[test]
+	fn check_adaptive_model_works() {
+		let model = adaptive_model(Rate::saturating_from_rational(4, 100));
+		assert!(model.check_model());
+		assert!(InterestRateModel::Adaptive(model).check_model());
+
+		assert!(!AdaptiveModel { target_utilization: Ratio::zero(), ..model }.check_model());
+		assert!(!AdaptiveModel { target_utilization: Ratio::one(), ..model }.check_model());
+		assert!(!AdaptiveModel { min_rate_at_target: Rate::zero(), ..model }.check_model());
+		assert!(!AdaptiveModel { rate_at_target: Rate::from_inner(1), ..model }.check_model());
+		assert!(!AdaptiveModel { rate_at_target: Rate::one(), ..model }.check_model());
+		assert!(!AdaptiveModel {
+			rate_at_target: Rate::saturating_from_integer(2),
+			max_rate_at_target: Rate::saturating_from_integer(2),
+			..model
+		}
+		.check_model());
+		assert!(!AdaptiveModel { curve_steepness: Rate::one(), ..model }.check_model());
+		assert!(!AdaptiveModel { curve_steepness: Rate::saturating_from_integer(11), ..model }
+			.check_model());
+		assert!(!AdaptiveModel { adjustment_speed: Rate::saturating_from_integer(101), ..model }
+			.check_model());
+		assert!(!AdaptiveModel { max_adjustment: Ratio::from_percent(11), ..model }.check_model());
+	}
+
+	#[test]
+	fn adaptive_model_borrow_rate_follows_the_curve_at_sampled_rates() {
+		// Fixed sweep over a handful of rates at target and utilizations in 1% steps.
+		for percent in [1u128, 4, 10, 25, 50] {
+			let rate_at_target = Rate::saturating_from_rational(percent, 100);
+			let model = adaptive_model(rate_at_target);
+			assert_eq!(model.get_borrow_rate(Ratio::from_percent(90)), Some(rate_at_target));
+			assert_eq!(
+				model.get_borrow_rate(Ratio::one()),
+				Some(rate_at_target * Rate::saturating_from_integer(4))
+			);
+			let rate_at_zero = model.get_borrow_rate(Ratio::zero()).unwrap();
+			let expected = rate_at_target / Rate::saturating_from_integer(4);
+			assert!(rate_at_zero.into_inner().abs_diff(expected.into_inner()) <= 1);
+
+			// The borrow rate rises with the utilization
+			let mut previous = Rate::zero();
+			for parts in (0..=1_000_000u32).step_by(10_000) {
+				let rate = model.get_borrow_rate(Ratio::from_parts(parts)).unwrap();
+				assert!(rate >= previous);
+				previous = rate;
+			}
+		}
+	}
+
+	#[test]
+	fn adaptive_model_adjustment_is_bounded_on_sampled_grid() {
+		// Fixed grid of rates at target, utilizations in 5% steps and elapsed times.
+		let max_multiplier = Rate::saturating_from_rational(105, 100);
+		for percent in [1u128, 4, 10, 25, 50] {
+			for parts in (0..=1_000_000u32).step_by(50_000) {
+				for delta_time in [1, 6, 12, 60 * 60, DAY, 365 * DAY] {
+					let utilization = Ratio::from_parts(parts);
+					let model = adaptive_model(Rate::saturating_from_rational(percent, 100));
+					let mut adapted = model;
+					adapted.adapt(utilization, delta_time).unwrap();
+
+					assert!(adapted.check_model());
+					assert!(adapted.rate_at_target <= model.rate_at_target * max_multiplier);
+					assert!(adapted.rate_at_target >= model.rate_at_target / max_multiplier);
+					if utilization > model.target_utilization {
+						assert!(adapted.rate_at_target >= model.rate_at_target);
+					} else if utilization < model.target_utilization {
+						assert!(adapted.rate_at_target <= model.rate_at_target);
+					} else {
+						assert_eq!(adapted.rate_at_target, model.rate_at_target);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn adaptive_model_converges_to_target_utilization_from_sampled_starts() {
+		// Borrowers demand `1 - 2 * borrow_rate` of the supply and move toward it by 10% of
+		// the gap a day, the target utilization of 90% is reached at a borrow rate of 5%.
+		let demand = |borrow_rate: Rate| {
+			let rate = borrow_rate.saturating_mul(Rate::saturating_from_integer(2));
+			Ratio::from_rational(Rate::one().saturating_sub(rate).into_inner(), Rate::DIV)
+		};
+		let tolerance = Ratio::from_parts(1_000);
+		for (rate_at_target, utilization) in [
+			(Rate::saturating_from_rational(10, 100), Ratio::from_percent(80)),
+			(Rate::saturating_from_rational(1, 100), Ratio::from_percent(95)),
+			(Rate::saturating_from_rational(40, 100), Ratio::from_percent(20)),
+		] {
+			let mut model = adaptive_model(rate_at_target);
+			let mut utilization = utilization;
+			for _ in 0..3_000 {
+				let borrow_rate = model.get_borrow_rate(utilization).unwrap();
+				model.adapt(utilization, DAY).unwrap();
+				let target = demand(borrow_rate);
+				utilization = if target > utilization {
+					utilization.saturating_add((target - utilization) / 10u32)
+				} else {
+					utilization.saturating_sub((utilization - target) / 10u32)
+				};
+			}
+
+			assert!(
+				utilization.max(model.target_utilization) -
+					utilization.min(model.target_utilization) <=
+					tolerance
+			);
+			let expected_rate = Rate::saturating_from_rational(5, 100);
+			assert!(
+				model.rate_at_target.max(expected_rate) - model.rate_at_target.min(expected_rate) <=
+					Rate::saturating_from_rational(1, 1000)
+			);
+		}
+	}
 }
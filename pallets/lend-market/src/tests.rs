@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bad_debt;
 mod edge_cases;
+mod emode;
+mod flash_loan;
 mod interest_rate;
 mod lend_tokens;
 mod liquidate_borrow;
+mod liquidation_auction;
 mod market;
 
 use crate::mock::*;
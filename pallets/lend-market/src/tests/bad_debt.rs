@@ -0,0 +1,256 @@
+use crate::{
+	mock::{
+		new_test_ext, unit, LendMarket, MockPriceFeeder, RuntimeEvent, RuntimeOrigin, System, Test,
+		TimestampPallet, ALICE, BOB, DAVE, DOT, DOT_U, KSM,
+	},
+	AccountBorrows, AccountDeposits, Config, CoveredBadDebt, Error, Event, ExchangeRate,
+	LiquidationAuctionConfig, LiquidationAuctions, TotalBorrows, TotalReserves, TotalSupply,
+};
+use bifrost_primitives::{Rate, Ratio};
+use frame_support::{
+	assert_noop, assert_ok,
+	error::BadOrigin,
+	traits::tokens::{fungibles::Mutate, Preservation},
+};
+use sp_runtime::FixedPointNumber;
+
+// Alice ends up with 50 KSM debt and no collateral
+fn alice_has_bad_debt() {
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT, DOT_U]));
+	// Bob deposits 200 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+	// Alice deposits 220 DOT_U as collateral and borrows 100 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(220)));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+	assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+
+	// Bob seizes all the collateral: 50 * 4 * 1.1 = 220
+	MockPriceFeeder::set_price(KSM, 4.into());
+	assert_ok!(LendMarket::liquidate_borrow(
+		RuntimeOrigin::signed(BOB),
+		ALICE,
+		KSM,
+		unit(50),
+		DOT_U
+	));
+	MockPriceFeeder::reset();
+	assert_eq!(AccountDeposits::<Test>::get(DOT_U, ALICE).voucher_balance, 0);
+	assert_eq!(LendMarket::current_borrow_balance(&ALICE, KSM), Ok(unit(50)));
+}
+
+// Bob transfers `amount` KSM to the market without minting, which raises the exchange rate
+fn donate_ksm(amount: u128) {
+	assert_ok!(<Test as Config>::Assets::transfer(
+		KSM,
+		&BOB,
+		&LendMarket::account_id(),
+		amount,
+		Preservation::Expendable
+	));
+}
+
+#[test]
+fn write_off_bad_debt_fails_with_collateral_or_without_debt() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NoBadDebt
+		);
+
+		assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT_U]));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(200)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::CollateralRemaining
+		);
+	})
+}
+
+#[test]
+fn write_off_bad_debt_requires_update_origin() {
+	new_test_ext().execute_with(|| {
+		alice_has_bad_debt();
+		assert_noop!(LendMarket::write_off_bad_debt(RuntimeOrigin::signed(BOB), ALICE), BadOrigin);
+	})
+}
+
+#[test]
+fn write_off_bad_debt_credits_seized_deposits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT, DOT_U]));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+		assert_ok!(LendMarket::add_reserves(RuntimeOrigin::root(), BOB, KSM, unit(60)));
+		// Alice deposits 220 DOT_U as collateral, 10 DOT without collateral and borrows
+		// 100 KSM
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(220)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(10)));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+		let dot_u_reserves = TotalReserves::<Test>::get(DOT_U);
+		let dot_reserves = TotalReserves::<Test>::get(DOT);
+		let dot_u_supply = TotalSupply::<Test>::get(DOT_U);
+		let dot_u_vouchers = AccountDeposits::<Test>::get(DOT_U, ALICE).voucher_balance;
+		let dot_vouchers = AccountDeposits::<Test>::get(DOT, ALICE).voucher_balance;
+		let dot_u_exchange_rate = LendMarket::exchange_rate_stored(DOT_U).unwrap();
+		let ksm_exchange_rate = LendMarket::exchange_rate_stored(KSM).unwrap();
+
+		// The 230 of deposits are worth less than the 500 of debt, and liquidating the close
+		// factor would seize 50 * 5 * 1.1 = 275 > 220
+		MockPriceFeeder::set_price(KSM, 5.into());
+		assert_noop!(
+			LendMarket::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(50), DOT_U),
+			Error::<Test>::InsufficientCollateral
+		);
+
+		assert_ok!(LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE));
+		MockPriceFeeder::reset();
+		System::assert_has_event(RuntimeEvent::LendMarket(Event::BadDebtDepositsSeized {
+			borrower: ALICE,
+			asset_id: DOT_U,
+			voucher_amount: dot_u_vouchers,
+			amount: unit(220),
+		}));
+		System::assert_has_event(RuntimeEvent::LendMarket(Event::BadDebtDepositsSeized {
+			borrower: ALICE,
+			asset_id: DOT,
+			voucher_amount: dot_vouchers,
+			amount: unit(10),
+		}));
+		// The 230 seized cover 230 / 5 = 46 KSM, the reserves absorb the other 54
+		System::assert_last_event(RuntimeEvent::LendMarket(Event::BadDebtWrittenOff {
+			borrower: ALICE,
+			asset_id: KSM,
+			amount: unit(100),
+			covered: unit(46),
+			reserves_absorbed: unit(54),
+			socialized: 0,
+		}));
+
+		assert!(!AccountDeposits::<Test>::contains_key(DOT_U, ALICE));
+		assert!(!AccountDeposits::<Test>::contains_key(DOT, ALICE));
+		assert_eq!(TotalReserves::<Test>::get(DOT_U), dot_u_reserves + unit(220));
+		assert_eq!(TotalReserves::<Test>::get(DOT), dot_reserves + unit(10));
+		assert_eq!(TotalSupply::<Test>::get(DOT_U), dot_u_supply - dot_u_vouchers);
+		// The suppliers of the seized markets keep their share
+		assert_eq!(LendMarket::exchange_rate_stored(DOT_U), Ok(dot_u_exchange_rate));
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, 0);
+		assert_eq!(TotalBorrows::<Test>::get(KSM), 0);
+		assert_eq!(CoveredBadDebt::<Test>::get(KSM), unit(46));
+		assert_eq!(TotalReserves::<Test>::get(KSM), unit(6));
+		// The suppliers of the borrowed market lose nothing
+		assert_eq!(ExchangeRate::<Test>::get(KSM), ksm_exchange_rate);
+
+		// Added reserves repay the covered bad debt first
+		assert_ok!(LendMarket::add_reserves(RuntimeOrigin::root(), BOB, KSM, unit(50)));
+		assert_eq!(CoveredBadDebt::<Test>::get(KSM), 0);
+		assert_eq!(TotalReserves::<Test>::get(KSM), unit(10));
+		assert_eq!(LendMarket::exchange_rate_stored(KSM), Ok(ksm_exchange_rate));
+	})
+}
+
+#[test]
+fn write_off_bad_debt_is_absorbed_by_reserves_first() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		alice_has_bad_debt();
+		let exchange_rate = ExchangeRate::<Test>::get(KSM);
+
+		// Socializing the other 30 would bring the exchange rate below the minimum
+		assert_ok!(LendMarket::add_reserves(RuntimeOrigin::root(), BOB, KSM, unit(20)));
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::InvalidExchangeRate
+		);
+
+		assert_ok!(LendMarket::add_reserves(RuntimeOrigin::root(), BOB, KSM, unit(40)));
+		assert_ok!(LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(RuntimeEvent::LendMarket(Event::BadDebtWrittenOff {
+			borrower: ALICE,
+			asset_id: KSM,
+			amount: unit(50),
+			covered: 0,
+			reserves_absorbed: unit(50),
+			socialized: 0,
+		}));
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, 0);
+		assert_eq!(TotalBorrows::<Test>::get(KSM), 0);
+		assert_eq!(TotalReserves::<Test>::get(KSM), unit(10));
+		assert_eq!(ExchangeRate::<Test>::get(KSM), exchange_rate);
+
+		assert_noop!(
+			LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE),
+			Error::<Test>::NoBadDebt
+		);
+	})
+}
+
+#[test]
+fn write_off_bad_debt_socializes_the_remaining_loss() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		alice_has_bad_debt();
+		assert_ok!(LendMarket::set_liquidation_auction_config(
+			RuntimeOrigin::root(),
+			KSM,
+			Some(LiquidationAuctionConfig {
+				initial_discount: Ratio::from_percent(20),
+				discount_per_block: Ratio::from_percent(5),
+				max_discount: Ratio::from_percent(50),
+				duration: 20,
+				min_bid: Ratio::from_percent(10),
+			})
+		));
+		assert_ok!(LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+		// Bob's 200 KSM supply is worth 260 = 200 - 100 + 50 + 60 + 50
+		donate_ksm(unit(60));
+		let exchange_rate = LendMarket::exchange_rate_stored(KSM).unwrap();
+		assert_eq!(exchange_rate, Rate::saturating_from_rational(26, 1000));
+
+		assert_ok!(LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE));
+		System::assert_last_event(RuntimeEvent::LendMarket(Event::BadDebtWrittenOff {
+			borrower: ALICE,
+			asset_id: KSM,
+			amount: unit(50),
+			covered: 0,
+			reserves_absorbed: 0,
+			socialized: unit(50),
+		}));
+		assert!(!LiquidationAuctions::<Test>::contains_key(KSM, ALICE));
+
+		// Bob's 200 KSM supply is now worth: cash 210 = 200 - 100 + 50 + 60
+		assert_eq!(ExchangeRate::<Test>::get(KSM), Rate::saturating_from_rational(21, 1000));
+		assert_eq!(
+			ExchangeRate::<Test>::get(KSM)
+				.saturating_mul_int(AccountDeposits::<Test>::get(KSM, BOB).voucher_balance),
+			unit(210),
+		);
+	})
+}
+
+#[test]
+fn interest_accrues_after_write_off() {
+	new_test_ext().execute_with(|| {
+		alice_has_bad_debt();
+		donate_ksm(unit(60));
+		assert_ok!(LendMarket::write_off_bad_debt(RuntimeOrigin::root(), ALICE));
+		assert_eq!(ExchangeRate::<Test>::get(KSM), Rate::saturating_from_rational(21, 1000));
+
+		// Dave deposits 100 DOT as collateral and borrows 10 KSM
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(DAVE), DOT, unit(100)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(DAVE), DOT, true));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(DAVE), KSM, unit(10)));
+
+		TimestampPallet::set_timestamp(6000 + 12000);
+		assert_ok!(LendMarket::accrue_interest(KSM));
+		assert!(TotalBorrows::<Test>::get(KSM) > unit(10));
+		assert!(TotalReserves::<Test>::get(KSM) > 0);
+		assert!(ExchangeRate::<Test>::get(KSM) > Rate::saturating_from_rational(21, 1000));
+		assert!(LendMarket::current_borrow_balance(&DAVE, KSM).unwrap() > unit(10));
+		// Bob can still redeem from the remaining cash
+		assert_ok!(LendMarket::redeem(RuntimeOrigin::signed(BOB), KSM, unit(100)));
+	})
+}
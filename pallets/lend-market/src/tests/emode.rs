@@ -0,0 +1,203 @@
+use crate::{
+	mock::{
+		new_test_ext, unit, LendMarket, MockPriceFeeder, RuntimeOrigin, Test, ALICE, BOB, DOT,
+		DOT_U, KSM, VDOT,
+	},
+	AccountDeposits, AccountEMode, EModeCategories, EModeCategory, Error, ExchangeRate,
+};
+use bifrost_primitives::{Rate, Ratio};
+use frame_support::{assert_err, assert_noop, assert_ok, error::BadOrigin};
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+fn dot_family() -> EModeCategory {
+	EModeCategory {
+		collateral_factor: Ratio::from_percent(90),
+		liquidation_threshold: Ratio::from_percent(93),
+		liquidate_incentive: Rate::saturating_from_rational(103, 100),
+		assets: vec![DOT, KSM],
+	}
+}
+
+fn initial_setup() {
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT, KSM]));
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT_U, vec![DOT, KSM]));
+	assert_ok!(LendMarket::set_emode_category(RuntimeOrigin::root(), 1, Some(dot_family())));
+	// Bob deposits 200 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+	// Alice deposits 200 DOT as collateral
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT, unit(200)));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT, true));
+}
+
+#[test]
+fn set_emode_category_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LendMarket::set_emode_category(RuntimeOrigin::signed(ALICE), 1, Some(dot_family())),
+			BadOrigin
+		);
+		// Liquidation threshold should not be less than collateral factor
+		assert_noop!(
+			LendMarket::set_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory {
+					liquidation_threshold: Ratio::from_percent(80),
+					..dot_family()
+				})
+			),
+			Error::<Test>::InvalidFactor
+		);
+		// Liquidation incentive should not be less than 100%
+		assert_noop!(
+			LendMarket::set_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory {
+					liquidate_incentive: Rate::saturating_from_rational(99, 100),
+					..dot_family()
+				})
+			),
+			Error::<Test>::InvalidFactor
+		);
+		assert_noop!(
+			LendMarket::set_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory { assets: vec![], ..dot_family() })
+			),
+			Error::<Test>::EModeCategoryEmpty
+		);
+		assert_noop!(
+			LendMarket::set_emode_category(
+				RuntimeOrigin::root(),
+				1,
+				Some(EModeCategory { assets: vec![DOT, VDOT], ..dot_family() })
+			),
+			Error::<Test>::MarketDoesNotExist
+		);
+		assert_noop!(
+			LendMarket::set_emode_category(RuntimeOrigin::root(), 1, None),
+			Error::<Test>::EModeCategoryDoesNotExist
+		);
+
+		assert_ok!(LendMarket::set_emode_category(RuntimeOrigin::root(), 1, Some(dot_family())));
+		assert_eq!(EModeCategories::<Test>::get(1), Some(dot_family()));
+		assert_ok!(LendMarket::set_emode_category(RuntimeOrigin::root(), 1, None));
+		assert_eq!(EModeCategories::<Test>::get(1), None);
+	})
+}
+
+#[test]
+fn emode_increases_borrowing_power() {
+	new_test_ext().execute_with(|| {
+		initial_setup();
+		assert_noop!(
+			LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(2)),
+			Error::<Test>::EModeCategoryDoesNotExist
+		);
+		assert_eq!(
+			LendMarket::get_account_liquidity(&ALICE).unwrap().0,
+			FixedU128::from_inner(unit(100))
+		);
+
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+		assert_eq!(AccountEMode::<Test>::get(ALICE), Some(1));
+		assert_eq!(LendMarket::account_emode_category_id(&ALICE), Ok(Some(1)));
+		// DOT collateral: 200 * 90% = 180
+		assert_eq!(
+			LendMarket::get_account_liquidity(&ALICE).unwrap().0,
+			FixedU128::from_inner(unit(180))
+		);
+		assert_eq!(
+			LendMarket::get_account_liquidation_threshold_liquidity(&ALICE).unwrap().0,
+			FixedU128::from_inner(unit(186))
+		);
+
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(150)));
+		assert_eq!(
+			LendMarket::get_account_liquidity(&ALICE).unwrap().0,
+			FixedU128::from_inner(unit(30))
+		);
+		// Borrowing outside of the category would leave the efficiency mode
+		assert_err!(
+			LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT_U, unit(10)),
+			Error::<Test>::InsufficientLiquidity
+		);
+		// Leaving the efficiency mode would generate a shortfall
+		assert_noop!(
+			LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), None),
+			Error::<Test>::InsufficientLiquidity
+		);
+
+		assert_ok!(LendMarket::repay_borrow_all(RuntimeOrigin::signed(ALICE), KSM));
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(LendMarket::account_emode_category_id(&ALICE), Ok(None));
+	})
+}
+
+#[test]
+fn emode_does_not_apply_with_borrows_outside_category() {
+	new_test_ext().execute_with(|| {
+		initial_setup();
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(100)));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT_U, unit(50)));
+
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+		assert_eq!(LendMarket::account_emode_category_id(&ALICE), Ok(None));
+		// DOT collateral: 200 * 50% - 50 = 50
+		assert_eq!(
+			LendMarket::get_account_liquidity(&ALICE).unwrap().0,
+			FixedU128::from_inner(unit(50))
+		);
+
+		assert_ok!(LendMarket::repay_borrow_all(RuntimeOrigin::signed(ALICE), DOT_U));
+		assert_eq!(LendMarket::account_emode_category_id(&ALICE), Ok(Some(1)));
+		assert_eq!(
+			LendMarket::get_account_liquidity(&ALICE).unwrap().0,
+			FixedU128::from_inner(unit(180))
+		);
+
+		// Removing the category disables it for the account
+		assert_ok!(LendMarket::set_emode_category(RuntimeOrigin::root(), 1, None));
+		assert_eq!(LendMarket::account_emode_category_id(&ALICE), Ok(None));
+	})
+}
+
+#[test]
+fn emode_liquidation_uses_category_incentive() {
+	new_test_ext().execute_with(|| {
+		initial_setup();
+		assert_ok!(LendMarket::set_account_emode(RuntimeOrigin::signed(ALICE), Some(1)));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(150)));
+		assert_err!(
+			LendMarket::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(50), DOT),
+			Error::<Test>::InsufficientShortfall
+		);
+
+		// KSM borrow value: 150 * 2 = 300 > DOT liquidation threshold value: 200 * 93% = 186
+		MockPriceFeeder::set_price(KSM, 2.into());
+		assert_ok!(LendMarket::liquidate_borrow(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(50),
+			DOT
+		));
+
+		// incentive = repay KSM value * 1.03 = (50 * 2) * 1.03 = 103
+		// Alice DOT collateral: deposit - incentive = 200 - 103 = 97
+		// Bob DOT collateral: incentive - reserved = 103 - (103 / 1.03 * 0.03) = 100
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT, ALICE).voucher_balance),
+			unit(97),
+		);
+		assert_eq!(
+			ExchangeRate::<Test>::get(DOT)
+				.saturating_mul_int(AccountDeposits::<Test>::get(DOT, BOB).voucher_balance),
+			unit(100),
+		);
+		MockPriceFeeder::reset();
+	})
+}
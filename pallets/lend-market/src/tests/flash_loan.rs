@@ -0,0 +1,129 @@
+use crate::{
+	mock::{
+		new_test_ext, unit, LendMarket, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Test,
+		ALICE, BOB, DOT, EVE,
+	},
+	AccountDeposits, Config, Error, Event, FlashLoans, TotalReserves,
+};
+use frame_support::{assert_noop, assert_ok, traits::fungibles::Inspect};
+use pallet_traits::FlashLoan;
+use sp_runtime::DispatchError;
+
+fn remark() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+}
+
+#[test]
+fn flash_loan_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+		assert_ok!(<Test as Config>::Assets::transfer(
+			RuntimeOrigin::signed(ALICE),
+			EVE,
+			DOT.into(),
+			unit(1)
+		));
+
+		// fee = 100 * 0.1% = 0.1
+		assert_eq!(LendMarket::flash_loan_fee(DOT, unit(100)), Ok(unit(1) / 10));
+		assert_ok!(LendMarket::flash_loan(RuntimeOrigin::signed(EVE), DOT, unit(100), remark()));
+		System::assert_last_event(RuntimeEvent::LendMarket(Event::FlashLoaned {
+			borrower: EVE,
+			asset_id: DOT,
+			amount: unit(100),
+			fee: unit(1) / 10,
+		}));
+
+		assert_eq!(<Test as Config>::Assets::balance(DOT, &EVE), unit(1) - unit(1) / 10);
+		assert_eq!(TotalReserves::<Test>::get(DOT), unit(1) / 10);
+		assert_eq!(
+			<Test as Config>::Assets::balance(DOT, &LendMarket::account_id()),
+			unit(200) + unit(1) / 10
+		);
+		assert_eq!(FlashLoans::<Test>::get(DOT), 0);
+	})
+}
+
+#[test]
+fn flash_loan_fails_if_not_repaid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+
+		// Eve can't pay the fee
+		assert_noop!(
+			LendMarket::flash_loan(RuntimeOrigin::signed(EVE), DOT, unit(100), remark()),
+			Error::<Test>::FlashLoanNotRepaid
+		);
+		// The nested call fails
+		assert_noop!(
+			LendMarket::flash_loan(
+				RuntimeOrigin::signed(EVE),
+				DOT,
+				unit(100),
+				Box::new(RuntimeCall::LendMarket(crate::Call::mint {
+					asset_id: DOT,
+					mint_amount: 0
+				}))
+			),
+			Error::<Test>::InvalidAmount
+		);
+		assert_noop!(
+			LendMarket::flash_loan(RuntimeOrigin::signed(EVE), DOT, unit(201), remark()),
+			Error::<Test>::InsufficientCash
+		);
+		assert_noop!(
+			LendMarket::flash_loan(RuntimeOrigin::signed(EVE), DOT, 0, remark()),
+			Error::<Test>::InvalidAmount
+		);
+	})
+}
+
+#[test]
+fn flash_loan_does_not_change_exchange_rate() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+
+		// Alice supplies the borrowed 100 DOT and repays from her own balance
+		assert_ok!(LendMarket::flash_loan(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			unit(100),
+			Box::new(RuntimeCall::LendMarket(crate::Call::mint {
+				asset_id: DOT,
+				mint_amount: unit(100)
+			}))
+		));
+		// 100 / 0.02, the same as Bob
+		assert_eq!(
+			AccountDeposits::<Test>::get(DOT, ALICE).voucher_balance,
+			AccountDeposits::<Test>::get(DOT, BOB).voucher_balance / 2
+		);
+	})
+}
+
+#[test]
+fn flash_loan_trait_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), DOT, unit(200)));
+		let balance = <Test as Config>::Assets::balance(DOT, &ALICE);
+
+		assert_eq!(
+			<LendMarket as FlashLoan<_, _, _>>::flash_loan(&ALICE, DOT, unit(100), || {
+				assert_eq!(<Test as Config>::Assets::balance(DOT, &ALICE), balance + unit(100));
+				assert_eq!(FlashLoans::<Test>::get(DOT), unit(100));
+				Ok(())
+			}),
+			Ok(unit(1) / 10)
+		);
+		assert_eq!(<Test as Config>::Assets::balance(DOT, &ALICE), balance - unit(1) / 10);
+
+		assert_eq!(
+			<LendMarket as FlashLoan<_, _, _>>::flash_loan(&ALICE, DOT, unit(100), || {
+				Err(DispatchError::Other("failed"))
+			}),
+			Err(DispatchError::Other("failed"))
+		);
+		assert_eq!(<Test as Config>::Assets::balance(DOT, &ALICE), balance - unit(1) / 10);
+	})
+}
@@ -1,6 +1,6 @@
-use crate::{mock::*, tests::LendMarket, Markets};
+use crate::{mock::*, tests::LendMarket, Error, InterestRateModel, Markets, UtilizationRatio};
 use bifrost_primitives::{Rate, Ratio, SECONDS_PER_YEAR};
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use sp_runtime::{
 	traits::{CheckedDiv, One, Saturating},
 	FixedPointNumber,
@@ -446,3 +446,70 @@ fn a_market_can_only_accrue_interest_once_in_a_block() {
 		assert_eq!(BorrowIndex::<Test>::get(DOT), Rate::from_inner(1000000003805175038),);
 	})
 }
+
+#[test]
+fn adaptive_rate_model_adapts_on_accrue_interest() {
+	new_test_ext().execute_with(|| {
+		let rate_at_target = |asset_id| match Markets::<Test>::get(asset_id).unwrap().rate_model {
+			InterestRateModel::Adaptive(model) => model.rate_at_target,
+			_ => unreachable!(),
+		};
+		let adaptive_model = |rate_at_target, max_rate_at_target| {
+			InterestRateModel::new_adaptive_model(
+				Ratio::from_percent(80),
+				rate_at_target,
+				Rate::saturating_from_rational(1, 100),
+				max_rate_at_target,
+				Rate::saturating_from_integer(4),
+				Rate::saturating_from_integer(50),
+				Ratio::from_percent(5),
+			)
+		};
+		assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT, vec![KSM]));
+		assert_ok!(LendMarket::update_rate_model(
+			RuntimeOrigin::root(),
+			DOT,
+			adaptive_model(Rate::saturating_from_rational(4, 100), Rate::one())
+		));
+		// Bob deposits 100 DOT, Alice borrows 90 DOT against 400 KSM
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), DOT, unit(100)));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), KSM, unit(400)));
+		assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), KSM, true));
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), DOT, unit(90)));
+
+		// Utilization 90% is above the target, the rate slowly rises
+		TimestampPallet::set_timestamp(6000 + 12000);
+		assert_ok!(LendMarket::accrue_interest(DOT));
+		assert_eq!(UtilizationRatio::<Test>::get(DOT), Ratio::from_percent(90));
+		let rate = rate_at_target(DOT);
+		assert!(rate > Rate::saturating_from_rational(4, 100));
+		assert!(rate < Rate::saturating_from_rational(41, 1000));
+
+		// A single accrual changes the rate by at most 5%
+		TimestampPallet::set_timestamp(6000 + 12000 + SECONDS_PER_YEAR * 1000);
+		assert_ok!(LendMarket::accrue_interest(DOT));
+		assert_eq!(rate_at_target(DOT), rate * Rate::saturating_from_rational(105, 100));
+
+		// The adapted rate is kept within the bounds of the new model
+		assert_ok!(LendMarket::update_rate_model(
+			RuntimeOrigin::root(),
+			DOT,
+			adaptive_model(
+				Rate::saturating_from_rational(2, 100),
+				Rate::saturating_from_rational(4, 100)
+			)
+		));
+		assert_eq!(rate_at_target(DOT), Rate::saturating_from_rational(4, 100));
+		assert_noop!(
+			LendMarket::update_rate_model(
+				RuntimeOrigin::root(),
+				DOT,
+				adaptive_model(
+					Rate::saturating_from_rational(4, 100),
+					Rate::saturating_from_integer(2)
+				)
+			),
+			Error::<Test>::InvalidRateModelParam
+		);
+	})
+}
@@ -0,0 +1,272 @@
+use crate::{
+	mock::{
+		new_test_ext, unit, AccountId, LendMarket, MockPriceFeeder, RuntimeEvent, RuntimeOrigin,
+		System, Test, ALICE, BOB, DOT_U, KSM,
+	},
+	AccountBorrows, AccountDeposits, Error, Event, ExchangeRate, LiquidationAuctionConfig,
+	LiquidationAuctionConfigs, LiquidationAuctions,
+};
+use bifrost_primitives::{Rate, Ratio};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use sp_runtime::FixedPointNumber;
+
+fn auction_config() -> LiquidationAuctionConfig<u64> {
+	LiquidationAuctionConfig {
+		initial_discount: Ratio::from_percent(20),
+		discount_per_block: Ratio::from_percent(5),
+		max_discount: Ratio::from_percent(50),
+		duration: 20,
+		min_bid: Ratio::from_percent(10),
+	}
+}
+
+fn initial_setup() {
+	System::set_block_number(1);
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), KSM, vec![DOT_U]));
+	assert_ok!(LendMarket::set_liquidation_auction_config(
+		RuntimeOrigin::root(),
+		KSM,
+		Some(auction_config())
+	));
+	// Bob deposits 200 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(BOB), KSM, unit(200)));
+	// Alice deposits 200 DOT_U as collateral and borrows 100 KSM
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(ALICE), DOT_U, unit(200)));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(ALICE), DOT_U, true));
+	assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(ALICE), KSM, unit(100)));
+}
+
+fn underlying_deposit(who: &AccountId) -> u128 {
+	ExchangeRate::<Test>::get(DOT_U)
+		.saturating_mul_int(AccountDeposits::<Test>::get(DOT_U, who).voucher_balance)
+}
+
+#[test]
+fn set_liquidation_auction_config_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LendMarket::set_liquidation_auction_config(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				Some(auction_config())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			LendMarket::set_liquidation_auction_config(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(LiquidationAuctionConfig {
+					initial_discount: Ratio::from_percent(60),
+					..auction_config()
+				})
+			),
+			Error::<Test>::InvalidFactor
+		);
+		assert_noop!(
+			LendMarket::set_liquidation_auction_config(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(LiquidationAuctionConfig { max_discount: Ratio::one(), ..auction_config() })
+			),
+			Error::<Test>::InvalidFactor
+		);
+		assert_noop!(
+			LendMarket::set_liquidation_auction_config(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(LiquidationAuctionConfig { duration: 0, ..auction_config() })
+			),
+			Error::<Test>::InvalidFactor
+		);
+		assert_noop!(
+			LendMarket::set_liquidation_auction_config(
+				RuntimeOrigin::root(),
+				KSM,
+				Some(LiquidationAuctionConfig {
+					min_bid: Ratio::from_percent(0),
+					..auction_config()
+				})
+			),
+			Error::<Test>::InvalidFactor
+		);
+		assert_noop!(
+			LendMarket::set_liquidation_auction_config(RuntimeOrigin::root(), KSM, None),
+			Error::<Test>::MarketNotInAuctionMode
+		);
+
+		assert_ok!(LendMarket::set_liquidation_auction_config(
+			RuntimeOrigin::root(),
+			KSM,
+			Some(auction_config())
+		));
+		assert_eq!(LiquidationAuctionConfigs::<Test>::get(KSM), Some(auction_config()));
+		assert_ok!(LendMarket::set_liquidation_auction_config(RuntimeOrigin::root(), KSM, None));
+		assert_eq!(LiquidationAuctionConfigs::<Test>::get(KSM), None);
+	})
+}
+
+#[test]
+fn start_liquidation_auction_requires_shortfall() {
+	new_test_ext().execute_with(|| {
+		initial_setup();
+		assert_noop!(
+			LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, DOT_U),
+			Error::<Test>::MarketNotInAuctionMode
+		);
+		assert_noop!(
+			LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM),
+			Error::<Test>::InsufficientShortfall
+		);
+		assert_noop!(
+			LendMarket::bid_liquidation_auction(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				KSM,
+				unit(10),
+				DOT_U
+			),
+			Error::<Test>::LiquidationAuctionDoesNotExist
+		);
+
+		MockPriceFeeder::set_price(KSM, 2.into());
+		// The fixed incentive liquidation is disabled for the market
+		assert_noop!(
+			LendMarket::liquidate_borrow(RuntimeOrigin::signed(BOB), ALICE, KSM, unit(10), DOT_U),
+			Error::<Test>::MarketInAuctionMode
+		);
+
+		assert_ok!(LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+		System::assert_last_event(RuntimeEvent::LendMarket(Event::LiquidationAuctionStarted {
+			borrower: ALICE,
+			asset_id: KSM,
+			debt: unit(100),
+		}));
+		assert_eq!(
+			LendMarket::liquidation_auction_status(&ALICE, KSM),
+			Ok(Some((unit(100), Ratio::from_percent(20))))
+		);
+		assert_noop!(
+			LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM),
+			Error::<Test>::LiquidationAuctionAlreadyStarted
+		);
+		MockPriceFeeder::reset();
+	})
+}
+
+#[test]
+fn liquidation_auction_discount_grows_until_taken() {
+	new_test_ext().execute_with(|| {
+		initial_setup();
+		// KSM borrow value: 100 * 2 = 200 > DOT_U liquidation threshold value: 200 * 55% = 110
+		MockPriceFeeder::set_price(KSM, 2.into());
+		assert_ok!(LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+		assert_noop!(
+			LendMarket::bid_liquidation_auction(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				KSM,
+				unit(101),
+				DOT_U
+			),
+			Error::<Test>::TooMuchRepay
+		);
+		// A bid repays at least 10% of the auctioned debt
+		assert_noop!(
+			LendMarket::bid_liquidation_auction(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				KSM,
+				unit(9),
+				DOT_U
+			),
+			Error::<Test>::LiquidationAuctionBidTooLow
+		);
+
+		// collateral = repay KSM value / (1 - 20%) = (20 * 2) / 0.8 = 50
+		assert_ok!(LendMarket::bid_liquidation_auction(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(20),
+			DOT_U
+		));
+		System::assert_last_event(RuntimeEvent::LendMarket(Event::LiquidationAuctionBidPlaced {
+			liquidator: BOB,
+			borrower: ALICE,
+			asset_id: KSM,
+			collateral_asset_id: DOT_U,
+			repay_amount: unit(20),
+			collateral_amount: unit(50),
+			discount: Ratio::from_percent(20),
+		}));
+		assert_eq!(underlying_deposit(&ALICE), unit(150));
+		// Bob DOT_U collateral: 50 - (50 * 0.8 * 0.03) = 48.8
+		assert_eq!(underlying_deposit(&BOB), unit(488) / 10);
+
+		// 20% + 10 * 5% is capped by the max discount of 50%
+		System::set_block_number(11);
+		assert_eq!(
+			LendMarket::liquidation_auction_status(&ALICE, KSM),
+			Ok(Some((unit(80), Ratio::from_percent(50))))
+		);
+		// collateral = (30 * 2) / 0.5 = 120
+		assert_ok!(LendMarket::bid_liquidation_auction(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(30),
+			DOT_U
+		));
+		assert_eq!(underlying_deposit(&ALICE), unit(30));
+		// Bob DOT_U collateral: 48.8 + 120 - (120 * 0.5 * 0.03) = 167
+		assert_eq!(underlying_deposit(&BOB), unit(167));
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, unit(50));
+		assert_eq!(LendMarket::liquidation_auction_bids(&ALICE, KSM), Some((2, unit(50))));
+		MockPriceFeeder::reset();
+	})
+}
+
+#[test]
+fn expired_liquidation_auction_can_be_restarted() {
+	new_test_ext().execute_with(|| {
+		initial_setup();
+		MockPriceFeeder::set_price(KSM, 2.into());
+		assert_ok!(LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+
+		System::set_block_number(21);
+		assert_eq!(LendMarket::liquidation_auction_status(&ALICE, KSM), Ok(None));
+		assert_noop!(
+			LendMarket::bid_liquidation_auction(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				KSM,
+				unit(20),
+				DOT_U
+			),
+			Error::<Test>::LiquidationAuctionExpired
+		);
+
+		// The discount starts over
+		MockPriceFeeder::set_price(KSM, Rate::saturating_from_rational(3, 2));
+		assert_ok!(LendMarket::start_liquidation_auction(RuntimeOrigin::signed(BOB), ALICE, KSM));
+		assert_eq!(
+			LendMarket::liquidation_auction_status(&ALICE, KSM),
+			Ok(Some((unit(100), Ratio::from_percent(20))))
+		);
+
+		// collateral = (100 * 1.5) / 0.8 = 187.5
+		assert_ok!(LendMarket::bid_liquidation_auction(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			KSM,
+			unit(100),
+			DOT_U
+		));
+		assert_eq!(underlying_deposit(&ALICE), unit(125) / 10);
+		assert_eq!(AccountBorrows::<Test>::get(KSM, ALICE).principal, 0);
+		// The auction is over once all the debt is repaid
+		assert_eq!(LiquidationAuctions::<Test>::get(KSM, ALICE), None);
+		MockPriceFeeder::reset();
+	})
+}
@@ -2,6 +2,7 @@ use crate::InterestRateModel;
 use bifrost_primitives::{CurrencyId, Rate, Ratio};
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
+use sp_std::vec::Vec;
 
 /// Container for borrow balance information
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
@@ -95,3 +96,62 @@ pub struct RewardMarketState<BlockNumber, Balance> {
 	/// total amount of staking asset user deposited
 	pub block: BlockNumber,
 }
+
+/// Efficiency mode category.
+///
+/// A group of correlated assets (e.g. DOT, vDOT) that can be borrowed against each other with
+/// more aggressive risk parameters than their markets.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	parity_scale_codec::Decode,
+	parity_scale_codec::Encode,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct EModeCategory {
+	/// The collateral utilization ratio applied to assets of the category
+	pub collateral_factor: Ratio,
+	/// The liquidation threshold applied to assets of the category
+	pub liquidation_threshold: Ratio,
+	/// Liquidation incentive ratio applied to assets of the category
+	pub liquidate_incentive: Rate,
+	/// Assets belonging to the category
+	pub assets: Vec<CurrencyId>,
+}
+
+/// Dutch auction liquidation parameters of a market.
+///
+/// Liquidations of a market with these parameters are auctioned, the collateral discount
+/// offered to liquidators grows every block until the debt is repaid.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+pub struct LiquidationAuctionConfig<BlockNumber> {
+	/// Collateral discount when the auction starts
+	pub initial_discount: Ratio,
+	/// Discount added for each block since the auction started
+	pub discount_per_block: Ratio,
+	/// Upper bound of the discount
+	pub max_discount: Ratio,
+	/// Number of blocks after which the auction expires and has to be restarted
+	pub duration: BlockNumber,
+	/// Minimum share of the auctioned debt a bid has to repay, unless less debt is left
+	pub min_bid: Ratio,
+}
+
+/// Liquidation auction of a borrower's debt in a market
+///
+/// Only the totals of the bids are stored, each bid is recorded by a
+/// `LiquidationAuctionBidPlaced` event.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct LiquidationAuction<BlockNumber, Balance> {
+	/// The block the auction started at
+	pub start_block: BlockNumber,
+	/// Debt left to be repaid by bidders
+	pub debt: Balance,
+	/// Debt repaid by the bids placed so far
+	pub total_repaid: Balance,
+	/// Number of bids placed so far
+	pub bid_count: u32,
+}
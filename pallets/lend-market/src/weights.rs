@@ -69,6 +69,13 @@ pub trait WeightInfo {
 	fn withdraw_missing_reward() -> Weight;
 	fn collateral_asset() -> Weight;
 	fn update_liquidation_free_collateral() -> Weight;
+	fn set_emode_category() -> Weight;
+	fn set_account_emode() -> Weight;
+	fn write_off_bad_debt() -> Weight;
+	fn flash_loan() -> Weight;
+	fn set_liquidation_auction_config() -> Weight;
+	fn start_liquidation_auction() -> Weight;
+	fn bid_liquidation_auction() -> Weight;
 }
 
 /// Weights for loans using the Substrate node and recommended hardware.
@@ -633,14 +640,16 @@ impl<T: frame_system::Config> WeightInfo for BifrostWeight<T> {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Loans TotalReserves (r:1 w:1)
 	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans CoveredBadDebt (r:1 w:1)
+	/// Proof Skipped: Loans CoveredBadDebt (max_values: None, max_size: None, mode: Measured)
 	fn add_reserves() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2768`
-		//  Estimated: `26490`
-		// Minimum execution time: 131_052_000 picoseconds.
-		Weight::from_parts(132_405_000, 26490)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+		//  Estimated: `29966`
+		// Minimum execution time: 134_218_000 picoseconds.
+		Weight::from_parts(135_674_000, 29966)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
@@ -690,6 +699,183 @@ impl<T: frame_system::Config> WeightInfo for BifrostWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans EModeCategories (r:0 w:1)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	fn set_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1692`
+		//  Estimated: `8274`
+		// Minimum execution time: 32_110_000 picoseconds.
+		Weight::from_parts(33_020_000, 8274)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EModeCategories (r:1 w:0)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:1)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:3 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:2 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:2 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	fn set_account_emode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `38412`
+		// Minimum execution time: 98_340_000 picoseconds.
+		Weight::from_parts(99_871_000, 38412)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:11 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:5 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:2 w:2)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalSupply (r:2 w:1)
+	/// Proof Skipped: Loans TotalSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:2 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans CoveredBadDebt (r:2 w:1)
+	/// Proof Skipped: Loans CoveredBadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:0 w:6)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4214`
+		//  Estimated: `65810`
+		// Minimum execution time: 205_362_000 picoseconds.
+		Weight::from_parts(207_918_000, 65810)
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:2 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoans (r:2 w:1)
+	/// Proof Skipped: Loans FlashLoans (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn flash_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2915`
+		//  Estimated: `27311`
+		// Minimum execution time: 138_452_000 picoseconds.
+		Weight::from_parts(140_125_000, 27311)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_liquidation_auction_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1207`
+		//  Estimated: `4672`
+		// Minimum execution time: 24_871_000 picoseconds.
+		Weight::from_parts(25_433_000, 4672)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:5 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:4 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:4 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:4 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:4 w:0)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:4 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	fn start_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3842`
+		//  Estimated: `98354`
+		// Minimum execution time: 201_546_000 picoseconds.
+		Weight::from_parts(204_318_000, 98354)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans LiquidationFreeCollaterals (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationFreeCollaterals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:5 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:2 w:2)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:3)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:4 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:2 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	fn bid_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5418`
+		//  Estimated: `203127`
+		// Minimum execution time: 771_380_000 picoseconds.
+		Weight::from_parts(776_092_000, 203127)
+			.saturating_add(T::DbWeight::get().reads(45_u64))
+			.saturating_add(T::DbWeight::get().writes(22_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1253,14 +1439,16 @@ impl WeightInfo for () {
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Loans TotalReserves (r:1 w:1)
 	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans CoveredBadDebt (r:1 w:1)
+	/// Proof Skipped: Loans CoveredBadDebt (max_values: None, max_size: None, mode: Measured)
 	fn add_reserves() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2768`
-		//  Estimated: `26490`
-		// Minimum execution time: 131_052_000 picoseconds.
-		Weight::from_parts(132_405_000, 26490)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Estimated: `29966`
+		// Minimum execution time: 134_218_000 picoseconds.
+		Weight::from_parts(135_674_000, 29966)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Loans Markets (r:2 w:0)
 	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
@@ -1310,4 +1498,181 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans EModeCategories (r:0 w:1)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	fn set_emode_category() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1692`
+		//  Estimated: `8274`
+		// Minimum execution time: 32_110_000 picoseconds.
+		Weight::from_parts(33_020_000, 8274)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans EModeCategories (r:1 w:0)
+	/// Proof Skipped: Loans EModeCategories (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountEMode (r:1 w:1)
+	/// Proof Skipped: Loans AccountEMode (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:3 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:2 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:2 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:2 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	fn set_account_emode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `38412`
+		// Minimum execution time: 98_340_000 picoseconds.
+		Weight::from_parts(99_871_000, 38412)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:6 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:11 w:1)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:5 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowState (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardBorrowerIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:1 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:2 w:2)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalSupply (r:2 w:1)
+	/// Proof Skipped: Loans TotalSupply (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplyState (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplyState (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplySpeed (r:1 w:0)
+	/// Proof Skipped: Loans RewardSupplySpeed (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:1 w:1)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardAccrued (r:1 w:1)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:2 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans CoveredBadDebt (r:2 w:1)
+	/// Proof Skipped: Loans CoveredBadDebt (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:0 w:6)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:0 w:1)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	fn write_off_bad_debt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4214`
+		//  Estimated: `65810`
+		// Minimum execution time: 205_362_000 picoseconds.
+		Weight::from_parts(207_918_000, 65810)
+			.saturating_add(RocksDbWeight::get().reads(41_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
+	}
+	/// Storage: Loans Markets (r:2 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalReserves (r:2 w:1)
+	/// Proof Skipped: Loans TotalReserves (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans FlashLoans (r:2 w:1)
+	/// Proof Skipped: Loans FlashLoans (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn flash_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2915`
+		//  Estimated: `27311`
+		// Minimum execution time: 138_452_000 picoseconds.
+		Weight::from_parts(140_125_000, 27311)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Loans Markets (r:1 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:0 w:1)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_liquidation_auction_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1207`
+		//  Estimated: `4672`
+		// Minimum execution time: 24_871_000 picoseconds.
+		Weight::from_parts(25_433_000, 4672)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Loans Markets (r:5 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:1 w:1)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:4 w:0)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:4 w:0)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans BorrowIndex (r:4 w:0)
+	/// Proof Skipped: Loans BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans ExchangeRate (r:4 w:0)
+	/// Proof Skipped: Loans ExchangeRate (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Prices EmergencyPrice (r:4 w:0)
+	/// Proof Skipped: Prices EmergencyPrice (max_values: None, max_size: None, mode: Measured)
+	fn start_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3842`
+		//  Estimated: `98354`
+		// Minimum execution time: 201_546_000 picoseconds.
+		Weight::from_parts(204_318_000, 98354)
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Loans LiquidationFreeCollaterals (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationFreeCollaterals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Loans Markets (r:5 w:0)
+	/// Proof Skipped: Loans Markets (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctionConfigs (r:1 w:0)
+	/// Proof Skipped: Loans LiquidationAuctionConfigs (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans LiquidationAuctions (r:1 w:1)
+	/// Proof Skipped: Loans LiquidationAuctions (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Loans LastAccruedInterestTime (r:2 w:2)
+	/// Proof Skipped: Loans LastAccruedInterestTime (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountDeposits (r:6 w:3)
+	/// Proof Skipped: Loans AccountDeposits (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans AccountBorrows (r:4 w:1)
+	/// Proof Skipped: Loans AccountBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans TotalBorrows (r:2 w:1)
+	/// Proof Skipped: Loans TotalBorrows (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	/// Storage: Loans RewardAccrued (r:3 w:3)
+	/// Proof Skipped: Loans RewardAccrued (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Loans RewardSupplierIndex (r:3 w:3)
+	/// Proof Skipped: Loans RewardSupplierIndex (max_values: None, max_size: None, mode: Measured)
+	fn bid_liquidation_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5418`
+		//  Estimated: `203127`
+		// Minimum execution time: 771_380_000 picoseconds.
+		Weight::from_parts(776_092_000, 203127)
+			.saturating_add(RocksDbWeight::get().reads(45_u64))
+			.saturating_add(RocksDbWeight::get().writes(22_u64))
+	}
 }
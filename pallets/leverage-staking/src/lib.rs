@@ -29,17 +29,10 @@ mod benchmarking;
 pub mod weights;
 
 use bifrost_primitives::{CurrencyIdConversion, Rate, VtokenMintingInterface};
-use frame_support::{
-	pallet_prelude::*,
-	traits::{
-		fungibles::Mutate,
-		tokens::{Fortitude, Precision, Preservation},
-	},
-	transactional, BoundedVec,
-};
+use frame_support::{pallet_prelude::*, transactional, BoundedVec};
 use frame_system::{ensure_signed, pallet_prelude::*};
 pub use pallet_traits::{
-	ConvertToBigUint, LendMarket as LendMarketTrait, LendMarketMarketDataProvider,
+	ConvertToBigUint, FlashLoan, LendMarket as LendMarketTrait, LendMarketMarketDataProvider,
 	LendMarketPositionDataProvider, MarketInfo, MarketStatus,
 };
 pub use parity_scale_codec::{Decode, Encode};
@@ -184,33 +177,31 @@ impl<T: Config> Pallet<T> {
 			T::StablePoolHandler::get_pool_id(&vtoken_id, &asset_id)
 				.ok_or(Error::<T>::ArgumentsError)?;
 
-		<T as lend_market::Config>::Assets::mint_into(asset_id, &who, reduce_amount)?;
-
-		T::LendMarket::do_repay_borrow(&who, asset_id, reduce_amount)?;
-		let redeem_amount = T::StablePoolHandler::get_swap_input(
-			pool_id,
-			currency_id_in,
-			currency_id_out,
-			reduce_amount,
-		)?;
-		// Do redeem
-		T::LendMarket::do_redeem(&who, vtoken_id, redeem_amount)?;
-
-		T::StablePoolHandler::swap(
-			&who,
-			pool_id,
-			currency_id_in,
-			currency_id_out,
-			redeem_amount,
-			reduce_amount,
-		)?;
-		<T as lend_market::Config>::Assets::burn_from(
+		// the borrow is repaid with a flash loan which is paid back by the redeemed collateral
+		<lend_market::Pallet<T> as FlashLoan<_, _, _>>::flash_loan(
+			who,
 			asset_id,
-			&who,
 			reduce_amount,
-			Preservation::Protect,
-			Precision::Exact,
-			Fortitude::Force,
+			|| {
+				T::LendMarket::do_repay_borrow(who, asset_id, reduce_amount)?;
+				let redeem_amount = T::StablePoolHandler::get_swap_input(
+					pool_id,
+					currency_id_in,
+					currency_id_out,
+					reduce_amount,
+				)?;
+				// Do redeem
+				T::LendMarket::do_redeem(who, vtoken_id, redeem_amount)?;
+
+				T::StablePoolHandler::swap(
+					who,
+					pool_id,
+					currency_id_in,
+					currency_id_out,
+					redeem_amount,
+					reduce_amount,
+				)
+			},
 		)?;
 		Ok(())
 	}
@@ -221,23 +212,22 @@ impl<T: Config> Pallet<T> {
 		vtoken_id: AssetIdOf<T>,
 		increase_amount: BalanceOf<T>,
 	) -> DispatchResult {
-		<T as lend_market::Config>::Assets::mint_into(asset_id, &who, increase_amount)?;
-		let vtoken_value = T::VtokenMinting::mint(
-			who.clone(),
-			asset_id,
-			increase_amount,
-			BoundedVec::default(),
-			None,
-		)?;
-		T::LendMarket::do_mint(&who, vtoken_id, vtoken_value)?;
-		T::LendMarket::do_borrow(&who, asset_id, increase_amount)?;
-		<T as lend_market::Config>::Assets::burn_from(
+		// the collateral is minted with a flash loan which is paid back by the borrow
+		<lend_market::Pallet<T> as FlashLoan<_, _, _>>::flash_loan(
+			who,
 			asset_id,
-			&who,
 			increase_amount,
-			Preservation::Protect,
-			Precision::Exact,
-			Fortitude::Force,
+			|| {
+				let vtoken_value = T::VtokenMinting::mint(
+					who.clone(),
+					asset_id,
+					increase_amount,
+					BoundedVec::default(),
+					None,
+				)?;
+				T::LendMarket::do_mint(who, vtoken_id, vtoken_value)?;
+				T::LendMarket::do_borrow(who, asset_id, increase_amount)
+			},
 		)?;
 		Ok(())
 	}
@@ -264,6 +264,7 @@ impl bifrost_stable_pool::Config for Test {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Test>;
 	type CurrencyIdRegister = AssetIdMaps<Test>;
+	type Twap = ();
 }
 
 impl leverage_staking::Config for Test {
@@ -446,6 +447,7 @@ parameter_types! {
 	pub const LendMarketPalletId: PalletId = PalletId(*b"bf/ldmkt");
 	pub const RewardAssetId: CurrencyId = BNC;
 	pub const LiquidationFreeAssetId: CurrencyId = DOT;
+	pub const FlashLoanFee: Ratio = Ratio::from_parts(1_000);
 }
 
 impl lend_market::Config for Test {
@@ -459,16 +461,21 @@ impl lend_market::Config for Test {
 	type Assets = Currencies;
 	type RewardAssetId = RewardAssetId;
 	type LiquidationFreeAssetId = LiquidationFreeAssetId;
+	type RuntimeCall = RuntimeCall;
+	type FlashLoanFee = FlashLoanFee;
 }
 
 impl pallet_prices::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = MockDataProvider;
+	type Twap = ();
+	type Time = TimestampPallet;
 	type FeederOrigin = EnsureRoot<u128>;
 	type UpdateOrigin = EnsureRoot<u128>;
 	type RelayCurrency = RelayCurrencyId;
 	type Assets = Currencies;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type VtokenMinting = ();
 	type WeightInfo = ();
 }
 
@@ -150,14 +150,16 @@ fn reduce_leverage_should_work() {
 			Deposits { voucher_balance: 9500000, is_collateral: true },
 		);
 		assert_eq!(Tokens::balance(VDOT, &1), 9999999900000);
-		assert_eq!(Tokens::balance(DOT, &1), 990000000000000);
+		// the flash loan fee of 90_000 * 0.1% = 90
+		assert_eq!(Tokens::balance(DOT, &1), 989999999999910);
 		assert_ok!(LeverageStaking::flash_loan_deposit(
 			RuntimeOrigin::signed(1),
 			DOT,
 			FixedU128::from_inner(unit(800_000)),
 		));
 		assert_eq!(Tokens::balance(VDOT, &1), 9999999900000);
-		assert_eq!(Tokens::balance(DOT, &1), 990000000000098);
+		// 98 left by the swap, minus the flash loan fee of 10
+		assert_eq!(Tokens::balance(DOT, &1), 989999999999998);
 		assert_eq!(
 			AccountBorrows::<Test>::get(DOT, 1),
 			BorrowSnapshot { principal: 80_000, borrow_index: 1.into() },
@@ -172,7 +174,8 @@ fn reduce_leverage_should_work() {
 			FixedU128::from_inner(0),
 		));
 		assert_eq!(Tokens::balance(VDOT, &1), 9999999900000);
-		assert_eq!(Tokens::balance(DOT, &1), 990000000000196);
+		// 98 left by the swap, minus the flash loan fee of 80
+		assert_eq!(Tokens::balance(DOT, &1), 990000000000016);
 		assert_eq!(
 			AccountBorrows::<Test>::get(DOT, 1),
 			BorrowSnapshot { principal: 0, borrow_index: 1.into() },
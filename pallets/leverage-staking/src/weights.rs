@@ -92,7 +92,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: LendMarket TotalSupply (max_values: None, max_size: None, mode: Measured)
 	/// Storage: LendMarket TotalBorrows (r:2 w:1)
 	/// Proof Skipped: LendMarket TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	/// Storage: LendMarket TotalReserves (r:2 w:0)
+	/// Storage: LendMarket TotalReserves (r:2 w:1)
 	/// Proof Skipped: LendMarket TotalReserves (max_values: None, max_size: None, mode: Measured)
 	/// Storage: LendMarket AccountEarned (r:1 w:1)
 	/// Proof Skipped: LendMarket AccountEarned (max_values: None, max_size: None, mode: Measured)
@@ -110,15 +110,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: LendMarket RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
 	/// Storage: LendMarket BorrowIndex (r:1 w:0)
 	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket FlashLoans (r:1 w:1)
+	/// Proof Skipped: LendMarket FlashLoans (max_values: None, max_size: None, mode: Measured)
 	fn flash_loan_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3847`
 		//  Estimated: `13955`
-		// Minimum execution time: 265_916_000 picoseconds.
-		Weight::from_parts(267_741_000, 0)
+		// Minimum execution time: 285_916_000 picoseconds.
+		Weight::from_parts(287_741_000, 0)
 			.saturating_add(Weight::from_parts(0, 13955))
-			.saturating_add(T::DbWeight::get().reads(41))
-			.saturating_add(T::DbWeight::get().writes(21))
+			.saturating_add(T::DbWeight::get().reads(42))
+			.saturating_add(T::DbWeight::get().writes(23))
 	}
 }
 
@@ -160,7 +162,7 @@ impl WeightInfo for () {
 	/// Proof Skipped: LendMarket TotalSupply (max_values: None, max_size: None, mode: Measured)
 	/// Storage: LendMarket TotalBorrows (r:2 w:1)
 	/// Proof Skipped: LendMarket TotalBorrows (max_values: None, max_size: None, mode: Measured)
-	/// Storage: LendMarket TotalReserves (r:2 w:0)
+	/// Storage: LendMarket TotalReserves (r:2 w:1)
 	/// Proof Skipped: LendMarket TotalReserves (max_values: None, max_size: None, mode: Measured)
 	/// Storage: LendMarket AccountEarned (r:1 w:1)
 	/// Proof Skipped: LendMarket AccountEarned (max_values: None, max_size: None, mode: Measured)
@@ -178,14 +180,16 @@ impl WeightInfo for () {
 	/// Proof Skipped: LendMarket RewardBorrowerIndex (max_values: None, max_size: None, mode: Measured)
 	/// Storage: LendMarket BorrowIndex (r:1 w:0)
 	/// Proof Skipped: LendMarket BorrowIndex (max_values: None, max_size: None, mode: Measured)
+	/// Storage: LendMarket FlashLoans (r:1 w:1)
+	/// Proof Skipped: LendMarket FlashLoans (max_values: None, max_size: None, mode: Measured)
 	fn flash_loan_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3847`
 		//  Estimated: `13955`
-		// Minimum execution time: 265_916_000 picoseconds.
-		Weight::from_parts(267_741_000, 0)
+		// Minimum execution time: 285_916_000 picoseconds.
+		Weight::from_parts(287_741_000, 0)
 			.saturating_add(Weight::from_parts(0, 13955))
-			.saturating_add(RocksDbWeight::get().reads(41))
-			.saturating_add(RocksDbWeight::get().writes(21))
+			.saturating_add(RocksDbWeight::get().reads(42))
+			.saturating_add(RocksDbWeight::get().writes(23))
 	}
 }
\ No newline at end of file
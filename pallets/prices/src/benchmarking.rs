@@ -0,0 +1,93 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prices pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Pallet as Prices, *};
+use bifrost_primitives::{DOT, KSM, VDOT};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{assert_ok, traits::EnsureOrigin};
+use sp_runtime::Permill;
+
+fn price_feed_config_mock() -> PriceFeedConfig {
+	PriceFeedConfig {
+		max_price_age: Some(60_000),
+		max_deviation: Some(Permill::from_percent(10)),
+		sources: BoundedVec::truncate_from(vec![
+			PriceSource::Oracle,
+			PriceSource::Twap { quote: KSM, window: 600_000 },
+			PriceSource::Twap { quote: DOT, window: 600_000 },
+			PriceSource::Twap { quote: VDOT, window: 600_000 },
+		]),
+		min_sources: 1,
+		reanchor_after: Some(10),
+	}
+}
+
+benchmarks! {
+	set_price {
+		let origin = T::FeederOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let price = Price::saturating_from_integer(100);
+		assert_ok!(Prices::<T>::set_price_feed_config(
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			DOT,
+			price_feed_config_mock()
+		));
+	}: _<T::RuntimeOrigin>(origin, DOT, price)
+	verify {
+		assert_eq!(EmergencyPrice::<T>::get(DOT), Some(price));
+	}
+
+	reset_price {
+		let origin = T::FeederOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		assert_ok!(Prices::<T>::set_price(
+			T::FeederOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			DOT,
+			Price::saturating_from_integer(100)
+		));
+	}: _<T::RuntimeOrigin>(origin, DOT)
+	verify {
+		assert_eq!(EmergencyPrice::<T>::get(DOT), None);
+	}
+
+	set_foreign_asset {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, KSM, DOT)
+	verify {
+		assert_eq!(ForeignToNativeAsset::<T>::get(KSM), Some(DOT));
+	}
+
+	set_price_feed_config {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, DOT, price_feed_config_mock())
+	verify {
+		assert_eq!(PriceFeedConfigs::<T>::get(DOT), Some(price_feed_config_mock()));
+	}
+
+	remove_price_feed_config {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		assert_ok!(Prices::<T>::set_price_feed_config(
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?,
+			DOT,
+			price_feed_config_mock()
+		));
+	}: _<T::RuntimeOrigin>(origin, DOT)
+	verify {
+		assert_eq!(PriceFeedConfigs::<T>::get(DOT), None);
+	}
+}
+
+impl_benchmark_test_suite!(Prices, crate::mock::new_test_ext(), crate::mock::Test);
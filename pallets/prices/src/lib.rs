@@ -19,31 +19,50 @@
 //! This pallet provides the price from Oracle Module by implementing the
 //! `PriceFeeder` trait. In case of emergency, the price can be set directly
 //! by Oracle Collective.
+//!
+//! The price of a vToken is derived from the price of its underlying token and
+//! the vtoken-minting exchange rate, so vTokens do not need their own feed.
+//!
+//! Each asset can be given a `PriceFeedConfig`. The price is then aggregated as
+//! the median of the configured sources, and rejected if it is older than
+//! `max_price_age` or deviates more than `max_deviation` from the last accepted
+//! price. A price that keeps deviating for `reanchor_after` refreshes becomes the
+//! new reference. An emergency price works as a circuit breaker and overrides the
+//! feed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{
-	Balance, CurrencyId, CurrencyIdMapping, Price, PriceDetail, PriceFeeder, TimeStampedPrice,
-	TokenInfo,
+	Balance, CurrencyId, CurrencyIdMapping, Moment, Price, PriceDetail, PriceFeeder,
+	TimeStampedPrice, TokenInfo, TwapProvider, VtokenMintingInterface,
 };
-use frame_support::{dispatch::DispatchClass, pallet_prelude::*, transactional};
+use frame_support::{dispatch::DispatchClass, pallet_prelude::*, traits::Time, transactional};
 use frame_system::pallet_prelude::*;
 use log;
 use orml_oracle::{DataFeeder, DataProvider, DataProviderExtended};
 pub use pallet::*;
 use pallet_traits::*;
-use sp_runtime::{traits::CheckedDiv, FixedU128};
+use sp_runtime::{
+	traits::{CheckedDiv, CheckedMul, Saturating, Zero},
+	FixedPointNumber, FixedU128, Permill,
+};
 use sp_std::vec::Vec;
 use xcm::v3::MultiLocation;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+mod types;
 pub mod weights;
 
+pub use types::*;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -63,6 +82,12 @@ pub mod pallet {
 			+ DataProviderExtended<CurrencyId, TimeStampedPrice>
 			+ DataFeeder<CurrencyId, TimeStampedPrice, Self::AccountId>;
 
+		/// The on-chain time weighted average prices.
+		type Twap: TwapProvider;
+
+		/// Time used to check the age of prices.
+		type Time: Time<Moment = Moment>;
+
 		/// The origin which may set prices feed to system.
 		type FeederOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -85,6 +110,9 @@ pub mod pallet {
 			AssetMetadata<BalanceOf<Self>>,
 		>;
 
+		/// The interface used to derive vToken prices from the exchange rate.
+		type VtokenMinting: VtokenMintingInterface<Self::AccountId, CurrencyId, Balance>;
+
 		/// Weight information
 		type WeightInfo: WeightInfo;
 	}
@@ -96,6 +124,22 @@ pub mod pallet {
 		SetPrice(CurrencyId, Price),
 		/// Reset emergency price. \[asset_id\]
 		ResetPrice(CurrencyId),
+		/// Set price feed config. \[asset_id, config\]
+		SetPriceFeedConfig(CurrencyId, PriceFeedConfig),
+		/// Remove price feed config. \[asset_id\]
+		RemovePriceFeedConfig(CurrencyId),
+		/// The price feed of an asset is rejected. \[asset_id, reason\]
+		PriceFeedRejected(CurrencyId, FeedRejectReason),
+		/// The price feed of an asset is accepted again. \[asset_id\]
+		PriceFeedRecovered(CurrencyId),
+		/// The deviating price of an asset is accepted as the new reference. \[asset_id, price\]
+		PriceFeedReanchored(CurrencyId, Price),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The price feed config is invalid.
+		InvalidPriceFeedConfig,
 	}
 
 	/// Mapping from currency id to it's emergency price
@@ -103,11 +147,37 @@ pub mod pallet {
 	pub type EmergencyPrice<T: Config> =
 		StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// Mapping from currency id to the time it's emergency price is set
+	#[pallet::storage]
+	pub type EmergencyPriceTimestamp<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Moment, OptionQuery>;
+
 	/// Mapping from foreign vault token to our's vault token
 	#[pallet::storage]
 	pub type ForeignToNativeAsset<T: Config> =
 		StorageMap<_, Twox64Concat, CurrencyId, CurrencyId, OptionQuery>;
 
+	/// Mapping from currency id to it's price feed config
+	#[pallet::storage]
+	pub type PriceFeedConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, PriceFeedConfig, OptionQuery>;
+
+	/// Mapping from currency id to the last accepted price, the reference of deviation checks
+	#[pallet::storage]
+	pub type LastValidPrice<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, TimeStampedPrice, OptionQuery>;
+
+	/// Mapping from currency id to the last price rejected by the deviation check, and the
+	/// number of consecutive rejections consistent with it
+	#[pallet::storage]
+	pub type DeviatingPrices<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, (TimeStampedPrice, u32), OptionQuery>;
+
+	/// Mapping from currency id to the reason it's price feed is rejected
+	#[pallet::storage]
+	pub type RejectedFeeds<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, FeedRejectReason, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -121,6 +191,7 @@ pub mod pallet {
 		fn build(&self) {
 			for (asset_id, price) in self.emergency_price.iter() {
 				EmergencyPrice::<T>::insert(asset_id, price);
+				EmergencyPriceTimestamp::<T>::insert(asset_id, T::Time::now());
 			}
 			for (foreign_asset_id, native) in self.foreign_to_native_asset.iter() {
 				ForeignToNativeAsset::<T>::insert(foreign_asset_id, native);
@@ -132,6 +203,23 @@ pub mod pallet {
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut count: u64 = 0;
+			for (asset_id, config) in PriceFeedConfigs::<T>::iter() {
+				Self::refresh_price_feed(asset_id, &config);
+				count = count.saturating_add(1);
+			}
+			// config, last valid price, deviating price, rejected feed, emergency price and the
+			// sources
+			T::DbWeight::get().reads_writes(
+				count.saturating_mul(5 + MAX_PRICE_SOURCES as u64 * 2),
+				count.saturating_mul(3),
+			)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Set emergency price
@@ -176,13 +264,50 @@ pub mod pallet {
 			ForeignToNativeAsset::<T>::insert(foreign_asset_id, asset_id);
 			Ok(().into())
 		}
+
+		/// Set price feed config, the next aggregated price is accepted as the new reference
+		#[pallet::call_index(3)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_price_feed_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_feed_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+			config: PriceFeedConfig,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(config.is_valid(), Error::<T>::InvalidPriceFeedConfig);
+			PriceFeedConfigs::<T>::insert(asset_id, config.clone());
+			LastValidPrice::<T>::remove(asset_id);
+			DeviatingPrices::<T>::remove(asset_id);
+			RejectedFeeds::<T>::remove(asset_id);
+			Self::deposit_event(Event::SetPriceFeedConfig(asset_id, config));
+			Ok(().into())
+		}
+
+		/// Remove price feed config
+		#[pallet::call_index(4)]
+		#[pallet::weight((<T as Config>::WeightInfo::remove_price_feed_config(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn remove_price_feed_config(
+			origin: OriginFor<T>,
+			asset_id: CurrencyId,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			PriceFeedConfigs::<T>::remove(asset_id);
+			LastValidPrice::<T>::remove(asset_id);
+			DeviatingPrices::<T>::remove(asset_id);
+			RejectedFeeds::<T>::remove(asset_id);
+			Self::deposit_event(Event::RemovePriceFeedConfig(asset_id));
+			Ok(().into())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	// get emergency price, the timestamp is zero
+	// get emergency price, the timestamp is the time it is set, prices set before the time
+	// was recorded are treated as fresh
 	fn get_emergency_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
-		EmergencyPrice::<T>::get(asset_id).and_then(|p| {
+		EmergencyPrice::<T>::get(asset_id).and_then(|value| {
 			let mantissa = Self::get_asset_mantissa(asset_id)?;
 			log::trace!(
 				target: "prices::get_emergency_price",
@@ -190,13 +315,196 @@ impl<T: Config> Pallet<T> {
 				asset_id,
 				mantissa
 			);
-			p.checked_div(&FixedU128::from_inner(mantissa)).map(|price| (price, 0))
+			let timestamp =
+				EmergencyPriceTimestamp::<T>::get(asset_id).unwrap_or_else(T::Time::now);
+			Self::normalize_detail_price(TimeStampedPrice { value, timestamp }, mantissa)
 		})
 	}
 
+	// get emergency price as a source of other prices, it is set by governance and always
+	// fresh, so it is timestamped now
+	fn get_emergency_timestamped_price(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
+		EmergencyPrice::<T>::get(asset_id)
+			.map(|value| TimeStampedPrice { value, timestamp: T::Time::now() })
+	}
+
 	fn get_storage_price(asset_id: &CurrencyId) -> Option<Price> {
 		EmergencyPrice::<T>::get(asset_id)
-			.or_else(|| T::Source::get(asset_id).and_then(|price| Some(price.value)))
+			.or_else(|| Self::get_feed_price(asset_id).and_then(|price| Some(price.value)))
+	}
+
+	// get the price of the asset feed, validated by it's config if exists
+	fn get_feed_price(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
+		match PriceFeedConfigs::<T>::get(asset_id) {
+			Some(config) => Self::aggregate_price(asset_id, &config).ok(),
+			None => Self::get_source_price(asset_id, PriceSource::Oracle),
+		}
+	}
+
+	fn get_source_price(asset_id: &CurrencyId, source: PriceSource) -> Option<TimeStampedPrice> {
+		match source {
+			PriceSource::Oracle =>
+				Self::get_special_asset_price(*asset_id).or_else(|| T::Source::get(asset_id)),
+			PriceSource::Twap { quote, window } => Self::get_twap_price(asset_id, &quote, window),
+		}
+	}
+
+	// value the twap against the quote asset by the oracle price of the quote asset
+	// Formula: `price = twap * quote_price * 10.pow(asset_decimal - quote_decimal)`
+	fn get_twap_price(
+		asset_id: &CurrencyId,
+		quote: &CurrencyId,
+		window: Moment,
+	) -> Option<TimeStampedPrice> {
+		let (twap, timestamp) = T::Twap::twap(asset_id, quote, window)?;
+		let quote_price = Self::get_emergency_timestamped_price(quote)
+			.or_else(|| Self::get_source_price(quote, PriceSource::Oracle))?;
+		let asset_mantissa = Self::get_asset_mantissa(asset_id)?;
+		let quote_mantissa = Self::get_asset_mantissa(quote)?;
+		let value = twap
+			.checked_mul(&quote_price.value)?
+			.checked_mul(&FixedU128::checked_from_rational(asset_mantissa, quote_mantissa)?)?;
+		Some(TimeStampedPrice { value, timestamp: timestamp.min(quote_price.timestamp) })
+	}
+
+	/// Aggregate the median price of the configured sources.
+	/// Prices older than `max_price_age` are dropped, and the result is rejected if it
+	/// deviates more than `max_deviation` from the last accepted price.
+	pub(crate) fn aggregate_price(
+		asset_id: &CurrencyId,
+		config: &PriceFeedConfig,
+	) -> Result<TimeStampedPrice, FeedRejectReason> {
+		let price = Self::aggregate_source_price(asset_id, config)?;
+		if Self::deviates_from_last_valid_price(asset_id, config, &price) {
+			return Err(FeedRejectReason::ExcessiveDeviation);
+		}
+
+		Ok(price)
+	}
+
+	// the median price of the sources that are not older than `max_price_age`
+	fn aggregate_source_price(
+		asset_id: &CurrencyId,
+		config: &PriceFeedConfig,
+	) -> Result<TimeStampedPrice, FeedRejectReason> {
+		let now = T::Time::now();
+		let prices: Vec<TimeStampedPrice> = config
+			.sources
+			.iter()
+			.filter_map(|source| Self::get_source_price(asset_id, *source))
+			.collect();
+		let mut fresh_prices: Vec<TimeStampedPrice> = prices
+			.iter()
+			.filter(|price| {
+				config
+					.max_price_age
+					.map_or(true, |max_age| now.saturating_sub(price.timestamp) <= max_age)
+			})
+			.cloned()
+			.collect();
+
+		if (fresh_prices.len() as u32) < config.min_sources {
+			return Err(if fresh_prices.len() < prices.len() {
+				FeedRejectReason::Stale
+			} else {
+				FeedRejectReason::InsufficientSources
+			});
+		}
+
+		Self::median_price(&mut fresh_prices).ok_or(FeedRejectReason::InsufficientSources)
+	}
+
+	// whether `price` moves more than `max_deviation` from the last accepted price
+	fn deviates_from_last_valid_price(
+		asset_id: &CurrencyId,
+		config: &PriceFeedConfig,
+		price: &TimeStampedPrice,
+	) -> bool {
+		match (config.max_deviation, LastValidPrice::<T>::get(asset_id)) {
+			(Some(max_deviation), Some(last_price)) =>
+				Self::deviates(max_deviation, last_price.value, price.value),
+			_ => false,
+		}
+	}
+
+	// whether `price` moves more than `max_deviation` from `reference`
+	fn deviates(max_deviation: Permill, reference: Price, price: Price) -> bool {
+		let diff = if price > reference {
+			price.saturating_sub(reference)
+		} else {
+			reference.saturating_sub(price)
+		};
+		!reference.is_zero() && diff > reference.saturating_mul(Price::from(max_deviation))
+	}
+
+	// the median of the prices, the timestamp is the oldest of the middle prices
+	fn median_price(prices: &mut Vec<TimeStampedPrice>) -> Option<TimeStampedPrice> {
+		prices.sort_by(|a, b| a.value.cmp(&b.value));
+		let len = prices.len();
+		if len == 0 {
+			return None;
+		}
+		let upper = prices[len / 2].clone();
+		if len % 2 == 1 {
+			return Some(upper);
+		}
+		let lower = prices[len / 2 - 1].clone();
+		Some(TimeStampedPrice {
+			value: lower
+				.value
+				.saturating_add(upper.value)
+				.checked_div(&Price::saturating_from_integer(2))?,
+			timestamp: lower.timestamp.min(upper.timestamp),
+		})
+	}
+
+	// accept the aggregated price as the new reference, or record why the feed is rejected
+	fn refresh_price_feed(asset_id: CurrencyId, config: &PriceFeedConfig) {
+		let result = Self::aggregate_source_price(&asset_id, config).and_then(|price| {
+			if Self::deviates_from_last_valid_price(&asset_id, config, &price) {
+				Self::note_deviating_price(asset_id, config, price)
+			} else {
+				DeviatingPrices::<T>::remove(asset_id);
+				Ok(price)
+			}
+		});
+		match result {
+			Ok(price) => {
+				LastValidPrice::<T>::insert(asset_id, price);
+				if RejectedFeeds::<T>::take(asset_id).is_some() {
+					Self::deposit_event(Event::PriceFeedRecovered(asset_id));
+				}
+			},
+			Err(reason) =>
+				if RejectedFeeds::<T>::get(asset_id) != Some(reason) {
+					RejectedFeeds::<T>::insert(asset_id, reason);
+					Self::deposit_event(Event::PriceFeedRejected(asset_id, reason));
+				},
+		}
+	}
+
+	// count the consecutive deviating prices that are consistent with each other, the price
+	// is accepted once the count reaches `reanchor_after`
+	fn note_deviating_price(
+		asset_id: CurrencyId,
+		config: &PriceFeedConfig,
+		price: TimeStampedPrice,
+	) -> Result<TimeStampedPrice, FeedRejectReason> {
+		let max_deviation = config.max_deviation.unwrap_or_default();
+		let count = match DeviatingPrices::<T>::get(asset_id) {
+			Some((last_price, count))
+				if !Self::deviates(max_deviation, last_price.value, price.value) =>
+				count.saturating_add(1),
+			_ => 1,
+		};
+		if config.reanchor_after.map_or(false, |reanchor_after| count >= reanchor_after) {
+			DeviatingPrices::<T>::remove(asset_id);
+			Self::deposit_event(Event::PriceFeedReanchored(asset_id, price.value));
+			Ok(price)
+		} else {
+			DeviatingPrices::<T>::insert(asset_id, (price, count));
+			Err(FeedRejectReason::ExcessiveDeviation)
+		}
 	}
 
 	fn get_asset_mantissa(asset_id: &CurrencyId) -> Option<u128> {
@@ -211,11 +519,34 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	fn get_special_asset_price(
-		_asset_id: CurrencyId,
-		_base_price: TimeStampedPrice,
-	) -> Option<TimeStampedPrice> {
-		None
+	/// Get the price of a vToken by the price of its underlying token and the exchange rate.
+	/// Formula: `vtoken_price = token_price * token_pool / vtoken_total_issuance`
+	///
+	/// The timestamp follows the underlying token price.
+	fn get_special_asset_price(asset_id: CurrencyId) -> Option<TimeStampedPrice> {
+		let token_id = T::VtokenMinting::token_id(asset_id)?;
+		let base_price = Self::get_emergency_timestamped_price(&token_id)
+			.or_else(|| T::Source::get(&token_id))?;
+		let exchange_rate = Self::get_vtoken_exchange_rate(token_id, asset_id)?;
+		log::trace!(
+			target: "prices::get_special_asset_price",
+			"asset_id: {:?}, token_id: {:?}, exchange_rate: {:?}",
+			asset_id,
+			token_id,
+			exchange_rate
+		);
+		base_price
+			.value
+			.checked_mul(&exchange_rate)
+			.map(|value| TimeStampedPrice { value, timestamp: base_price.timestamp })
+	}
+
+	/// The amount of token that one vToken is worth, zero rate is treated as unavailable.
+	fn get_vtoken_exchange_rate(token_id: CurrencyId, vtoken_id: CurrencyId) -> Option<FixedU128> {
+		T::VtokenMinting::vtoken_to_token(token_id, vtoken_id, FixedU128::DIV)
+			.ok()
+			.filter(|rate| !rate.is_zero())
+			.map(FixedU128::from_inner)
 	}
 
 	fn normalize_detail_price(price: TimeStampedPrice, mantissa: u128) -> Option<PriceDetail> {
@@ -233,14 +564,14 @@ impl<T: Config> PriceFeeder for Pallet<T> {
 	/// This particular price makes it easy to calculate the asset value in other pallets,
 	/// because we don't have to consider decimal for each asset.
 	///
-	/// Timestamp is zero means the price is emergency price
+	/// The timestamp of an emergency price is the time it is set
+	///
+	/// Returns `None` if the price feed of the asset is rejected by it's config
 	fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail> {
 		// if emergency price exists, return it
 		Self::get_emergency_price(asset_id).or_else(|| {
 			let mantissa = Self::get_asset_mantissa(asset_id)?;
-			T::Source::get(&T::RelayCurrency::get())
-				.and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
-				.or_else(|| T::Source::get(asset_id))
+			Self::get_feed_price(asset_id)
 				.and_then(|price| Self::normalize_detail_price(price, mantissa))
 		})
 	}
@@ -250,7 +581,7 @@ impl<T: Config> PriceFeeder for Pallet<T> {
 		EmergencyPrice::<T>::get(asset_id)
 			.and_then(|p| Some(p.into_inner().saturating_div(decimals)))
 			.or_else(|| {
-				T::Source::get(&asset_id)
+				Self::get_feed_price(asset_id)
 					.and_then(|price| Some(price.value.into_inner().saturating_div(decimals)))
 			})
 	}
@@ -305,14 +636,25 @@ impl<T: Config> PriceFeeder for Pallet<T> {
 impl<T: Config> EmergencyPriceFeeder<CurrencyId, Price> for Pallet<T> {
 	/// Set emergency price
 	fn set_emergency_price(asset_id: CurrencyId, price: Price) {
+		let now = T::Time::now();
 		// set price direct
 		EmergencyPrice::<T>::insert(asset_id, price);
+		EmergencyPriceTimestamp::<T>::insert(asset_id, now);
+		// the emergency price is the reference once the feed takes over again
+		if PriceFeedConfigs::<T>::contains_key(asset_id) {
+			LastValidPrice::<T>::insert(
+				asset_id,
+				TimeStampedPrice { value: price, timestamp: now },
+			);
+			DeviatingPrices::<T>::remove(asset_id);
+		}
 		<Pallet<T>>::deposit_event(Event::SetPrice(asset_id, price));
 	}
 
 	/// Reset emergency price
 	fn reset_emergency_price(asset_id: CurrencyId) {
 		EmergencyPrice::<T>::remove(asset_id);
+		EmergencyPriceTimestamp::<T>::remove(asset_id);
 		<Pallet<T>>::deposit_event(Event::ResetPrice(asset_id));
 	}
 }
@@ -320,9 +662,7 @@ impl<T: Config> EmergencyPriceFeeder<CurrencyId, Price> for Pallet<T> {
 impl<T: Config> DataProviderExtended<CurrencyId, TimeStampedPrice> for Pallet<T> {
 	fn get_no_op(asset_id: &CurrencyId) -> Option<TimeStampedPrice> {
 		let _mantissa = Self::get_asset_mantissa(asset_id)?;
-		T::Source::get_no_op(&T::RelayCurrency::get())
-			.and_then(|base_price| Self::get_special_asset_price(*asset_id, base_price))
-			.or_else(|| T::Source::get_no_op(asset_id))
+		Self::get_special_asset_price(*asset_id).or_else(|| T::Source::get_no_op(asset_id))
 	}
 
 	fn get_all_values() -> Vec<(CurrencyId, Option<TimeStampedPrice>)> {
@@ -27,8 +27,8 @@ pub use bifrost_primitives::{
 	currency::{FIL, VFIL},
 	DOT, KSM, MANTA, VDOT,
 };
-use bifrost_primitives::{Moment, ASTR, BNC, DOT_U, GLMR};
-use sp_runtime::BuildStorage;
+use bifrost_primitives::{Moment, RedeemType, ASTR, BNC, DOT_U, GLMR};
+use sp_runtime::{BuildStorage, DispatchError};
 
 pub type AccountId = u128;
 pub type BlockNumber = u64;
@@ -109,6 +109,141 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for MockDataProvider {
 	}
 }
 
+parameter_types! {
+	pub static Now: Moment = 0;
+	pub static TwapRate: Option<(Price, Moment)> = None;
+}
+
+pub struct MockTime;
+impl Time for MockTime {
+	type Moment = Moment;
+
+	fn now() -> Moment {
+		Now::get()
+	}
+}
+
+pub struct MockTwap;
+impl TwapProvider for MockTwap {
+	fn on_swap(_asset_in: &CurrencyId, _asset_out: &CurrencyId) {}
+
+	fn twap(
+		_asset_a: &CurrencyId,
+		_asset_b: &CurrencyId,
+		_window: Moment,
+	) -> Option<(Price, Moment)> {
+		TwapRate::get()
+	}
+}
+
+parameter_types! {
+	pub static VdotTokenPool: Balance = 150 * PRICE_ONE;
+	pub static VdotTotalIssuance: Balance = 100 * PRICE_ONE;
+}
+
+pub struct MockVtokenMinting;
+impl VtokenMintingInterface<AccountId, CurrencyId, Balance> for MockVtokenMinting {
+	fn mint(
+		_exchanger: AccountId,
+		_token_id: CurrencyId,
+		_token_amount: Balance,
+		_remark: BoundedVec<u8, ConstU32<32>>,
+		_channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn mint_with_lock(
+		_minter: AccountId,
+		_token_id: CurrencyId,
+		_token_amount: Balance,
+		_remark: BoundedVec<u8, ConstU32<32>>,
+		_channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn redeem(
+		_exchanger: AccountId,
+		_vtoken_id: CurrencyId,
+		_vtoken_amount: Balance,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn rebond(
+		_exchanger: AccountId,
+		_token_id: CurrencyId,
+		_token_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn slpx_redeem(
+		_exchanger: AccountId,
+		_vtoken_id: CurrencyId,
+		_vtoken_amount: Balance,
+		_redeem_type: RedeemType<AccountId>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn token_to_vtoken(
+		_token_id: CurrencyId,
+		_vtoken_id: CurrencyId,
+		_token_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn vtoken_to_token(
+		_token_id: CurrencyId,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		match vtoken_id {
+			VDOT => vtoken_amount
+				.checked_mul(VdotTokenPool::get())
+				.and_then(|x| x.checked_div(VdotTotalIssuance::get()))
+				.ok_or(DispatchError::Other("CalculationOverflow")),
+			_ => Err(DispatchError::Other("NotSupportTokenType")),
+		}
+	}
+
+	fn vtoken_id(token_id: CurrencyId) -> Option<CurrencyId> {
+		match token_id {
+			DOT => Some(VDOT),
+			_ => None,
+		}
+	}
+
+	fn token_id(vtoken_id: CurrencyId) -> Option<CurrencyId> {
+		match vtoken_id {
+			VDOT => Some(DOT),
+			_ => None,
+		}
+	}
+
+	fn get_token_pool(currency_id: CurrencyId) -> Balance {
+		match currency_id {
+			DOT => VdotTokenPool::get(),
+			_ => 0,
+		}
+	}
+
+	fn get_minimums_mint(_token_id: CurrencyId) -> Balance {
+		0
+	}
+
+	fn get_minimums_redeem(_vtoken_id: CurrencyId) -> Balance {
+		0
+	}
+
+	fn get_moonbeam_parachain_id() -> u32 {
+		0
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
@@ -174,11 +309,14 @@ parameter_types! {
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = MockDataProvider;
+	type Twap = MockTwap;
+	type Time = MockTime;
 	type FeederOrigin = EnsureSignedBy<One, AccountId>;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type RelayCurrency = RelayCurrency;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
 	type Assets = Currencies;
+	type VtokenMinting = MockVtokenMinting;
 	type WeightInfo = ();
 }
 
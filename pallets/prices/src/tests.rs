@@ -18,8 +18,8 @@ use super::*;
 use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{BNC, MANTA, VKSM};
 use frame_support::{assert_noop, assert_ok};
-use mock::{RuntimeEvent, *};
-use sp_runtime::{traits::BadOrigin, FixedPointNumber};
+use mock::{RuntimeEvent, TimeStampedPrice, *};
+use sp_runtime::{traits::BadOrigin, FixedPointNumber, Permill};
 
 #[test]
 fn get_price_from_oracle() {
@@ -293,3 +293,435 @@ fn get_oracle_amount_by_currency_and_amount_in() {
 		);
 	});
 }
+
+#[test]
+fn get_vtoken_price_by_exchange_rate_work() {
+	new_test_ext().execute_with(|| {
+		// 1 VDOT = 1.5 DOT, 100 * 1.5 = 150
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 0))
+		);
+
+		// 1 VDOT = 2 DOT, 100 * 2 = 200
+		VdotTokenPool::set(200 * PRICE_ONE);
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(20_000_000_000 * PRICE_ONE), 0))
+		);
+		assert_eq!(Prices::get_normal_price(&VDOT), Some(20_000_000_000));
+
+		// the emergency price of the underlying token is used
+		EmergencyPrice::<Test>::insert(DOT, Price::saturating_from_integer(50));
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 0))
+		);
+
+		// the emergency price of the vtoken takes precedence
+		EmergencyPrice::<Test>::insert(VDOT, Price::saturating_from_integer(99));
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(9_900_000_000 * PRICE_ONE), 0))
+		);
+	});
+}
+
+#[test]
+fn get_vtoken_price_fallback_to_oracle_work() {
+	new_test_ext().execute_with(|| {
+		// the exchange rate is unavailable, fallback to the oracle price of VDOT
+		VdotTotalIssuance::set(0);
+		VdotTokenPool::set(300 * PRICE_ONE);
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 0))
+		);
+
+		// zero exchange rate is treated as unavailable
+		VdotTotalIssuance::set(100 * PRICE_ONE);
+		VdotTokenPool::set(0);
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 0))
+		);
+	});
+}
+
+#[test]
+fn get_oracle_amount_by_vtoken_work() {
+	new_test_ext().execute_with(|| {
+		VdotTokenPool::set(200 * PRICE_ONE);
+		// 1 VDOT = 2 DOT
+		assert_eq!(
+			Some((
+				2 * 10u128.pow(10),
+				Price::saturating_from_integer(200),
+				Price::saturating_from_integer(100)
+			)),
+			Prices::get_oracle_amount_by_currency_and_amount_in(&VDOT, 10u128.pow(10), &DOT)
+		);
+	});
+}
+
+fn feed_config(
+	max_price_age: Option<Moment>,
+	max_deviation: Option<Permill>,
+	sources: Vec<PriceSource>,
+	min_sources: u32,
+) -> PriceFeedConfig {
+	PriceFeedConfig {
+		max_price_age,
+		max_deviation,
+		sources: BoundedVec::truncate_from(sources),
+		min_sources,
+		reanchor_after: None,
+	}
+}
+
+// the TWAP against KSM, valued by the KSM oracle price
+fn twap_source() -> PriceSource {
+	PriceSource::Twap { quote: KSM, window: 600_000 }
+}
+
+// 1 DOT = `price` / 500 KSM, the rate is in the minimal units of DOT(10) and KSM(12)
+fn set_twap_price(price: u128, timestamp: Moment) {
+	TwapRate::set(Some((Price::checked_from_rational(price, 5).unwrap(), timestamp)));
+}
+
+#[test]
+fn set_price_feed_config_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let config = feed_config(Some(5_000), None, vec![PriceSource::Oracle], 1);
+		assert_noop!(
+			Prices::set_price_feed_config(RuntimeOrigin::signed(CHARLIE), DOT, config.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			Prices::set_price_feed_config(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				feed_config(None, None, vec![PriceSource::Oracle], 0)
+			),
+			Error::<Test>::InvalidPriceFeedConfig
+		);
+		assert_noop!(
+			Prices::set_price_feed_config(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				feed_config(None, None, vec![PriceSource::Oracle, PriceSource::Oracle], 2)
+			),
+			Error::<Test>::InvalidPriceFeedConfig
+		);
+		assert_noop!(
+			Prices::set_price_feed_config(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				feed_config(None, None, vec![PriceSource::Oracle], 2)
+			),
+			Error::<Test>::InvalidPriceFeedConfig
+		);
+
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			config.clone()
+		));
+		assert_eq!(PriceFeedConfigs::<Test>::get(DOT), Some(config.clone()));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::SetPriceFeedConfig(
+			DOT, config,
+		)));
+
+		assert_ok!(Prices::remove_price_feed_config(RuntimeOrigin::signed(ALICE), DOT));
+		assert_eq!(PriceFeedConfigs::<Test>::get(DOT), None);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::RemovePriceFeedConfig(DOT)));
+	});
+}
+
+#[test]
+fn stale_price_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Now::set(10_000);
+		// without config, the stale price is still returned
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 0))
+		);
+
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			feed_config(Some(5_000), None, vec![PriceSource::Oracle], 1)
+		));
+		assert_eq!(Prices::get_price(&DOT), None);
+		assert_eq!(Prices::get_normal_price(&DOT), None);
+
+		Prices::on_initialize(1);
+		assert_eq!(RejectedFeeds::<Test>::get(DOT), Some(FeedRejectReason::Stale));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRejected(
+			DOT,
+			FeedRejectReason::Stale,
+		)));
+
+		// the rejection is only reported once
+		let events = System::events().len();
+		Prices::on_initialize(2);
+		assert_eq!(System::events().len(), events);
+
+		Now::set(5_000);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 0))
+		);
+		Prices::on_initialize(3);
+		assert_eq!(RejectedFeeds::<Test>::get(DOT), None);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRecovered(DOT)));
+	});
+}
+
+#[test]
+fn median_price_aggregation_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			feed_config(None, None, vec![PriceSource::Oracle, twap_source()], 2)
+		));
+		// only the oracle provides a price
+		assert_eq!(Prices::get_price(&DOT), None);
+		Prices::on_initialize(1);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRejected(
+			DOT,
+			FeedRejectReason::InsufficientSources,
+		)));
+
+		// (100 + 120) / 2 = 110
+		set_twap_price(120, 0);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(11_000_000_000 * PRICE_ONE), 0))
+		);
+		assert_eq!(
+			Prices::get_oracle_amount_by_currency_and_amount_in(&DOT, 10u128.pow(10), &DOT),
+			Some((
+				10u128.pow(10),
+				Price::saturating_from_integer(110),
+				Price::saturating_from_integer(110)
+			))
+		);
+
+		// a single source is enough
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			feed_config(Some(5_000), None, vec![PriceSource::Oracle, twap_source()], 1)
+		));
+		Now::set(10_000);
+		set_twap_price(120, 9_000);
+		// the stale oracle price is dropped
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(12_000_000_000 * PRICE_ONE), 9_000))
+		);
+	});
+}
+
+#[test]
+fn excessive_deviation_is_rejected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_twap_price(100, 0);
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			feed_config(None, Some(Permill::from_percent(10)), vec![twap_source()], 1)
+		));
+		Prices::on_initialize(1);
+		assert_eq!(
+			LastValidPrice::<Test>::get(DOT).map(|price| price.value),
+			Some(Price::saturating_from_integer(100))
+		);
+
+		set_twap_price(120, 0);
+		assert_eq!(Prices::get_price(&DOT), None);
+		Prices::on_initialize(2);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRejected(
+			DOT,
+			FeedRejectReason::ExcessiveDeviation,
+		)));
+		assert_eq!(
+			LastValidPrice::<Test>::get(DOT).map(|price| price.value),
+			Some(Price::saturating_from_integer(100))
+		);
+
+		set_twap_price(91, 0);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(9_100_000_000 * PRICE_ONE), 0))
+		);
+		Prices::on_initialize(3);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRecovered(DOT)));
+		assert_eq!(
+			LastValidPrice::<Test>::get(DOT).map(|price| price.value),
+			Some(Price::saturating_from_integer(91))
+		);
+	});
+}
+
+#[test]
+fn emergency_price_overrides_rejected_feed() {
+	new_test_ext().execute_with(|| {
+		Now::set(10_000);
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			feed_config(Some(5_000), Some(Permill::from_percent(10)), vec![twap_source()], 1)
+		));
+		assert_eq!(Prices::get_price(&DOT), None);
+
+		assert_ok!(Prices::set_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(99)
+		));
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(9_900_000_000 * PRICE_ONE), 10_000))
+		);
+		// the emergency price becomes the reference of the deviation check
+		assert_eq!(
+			LastValidPrice::<Test>::get(DOT),
+			Some(TimeStampedPrice { value: Price::saturating_from_integer(99), timestamp: 10_000 })
+		);
+
+		assert_ok!(Prices::reset_price(RuntimeOrigin::signed(ALICE), DOT));
+		set_twap_price(120, 10_000);
+		assert_eq!(Prices::get_price(&DOT), None);
+		set_twap_price(100, 10_000);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 10_000))
+		);
+	});
+}
+
+#[test]
+fn emergency_price_is_timestamped() {
+	new_test_ext().execute_with(|| {
+		Now::set(10_000);
+		assert_ok!(Prices::set_price(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			Price::saturating_from_integer(100)
+		));
+		assert_eq!(EmergencyPriceTimestamp::<Test>::get(DOT), Some(10_000));
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(10_000_000_000 * PRICE_ONE), 10_000))
+		);
+
+		// the VDOT price derived from the emergency DOT price is fresh
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			VDOT,
+			feed_config(Some(5_000), None, vec![PriceSource::Oracle], 1)
+		));
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 10_000))
+		);
+
+		// and stays fresh as long as the emergency price is set
+		Now::set(16_000);
+		assert_eq!(
+			Prices::get_price(&VDOT),
+			Some((Price::from_inner(15_000_000_000 * PRICE_ONE), 16_000))
+		);
+
+		assert_ok!(Prices::reset_price(RuntimeOrigin::signed(ALICE), DOT));
+		assert_eq!(EmergencyPriceTimestamp::<Test>::get(DOT), None);
+
+		// the TWAP valued by the stale KSM oracle price is rejected
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			feed_config(Some(5_000), None, vec![twap_source()], 1)
+		));
+		set_twap_price(120, 16_000);
+		assert_eq!(Prices::get_price(&DOT), None);
+		// but not when it is valued by an emergency KSM price
+		assert_ok!(Prices::set_price(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Price::saturating_from_integer(500)
+		));
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(12_000_000_000 * PRICE_ONE), 16_000))
+		);
+	});
+}
+
+#[test]
+fn deviating_price_is_reanchored() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		set_twap_price(100, 0);
+		assert_ok!(Prices::set_price_feed_config(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			PriceFeedConfig {
+				reanchor_after: Some(3),
+				..feed_config(None, Some(Permill::from_percent(10)), vec![twap_source()], 1)
+			}
+		));
+		Prices::on_initialize(1);
+
+		// the price jumps and keeps moving within 10%
+		set_twap_price(150, 0);
+		Prices::on_initialize(2);
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRejected(
+			DOT,
+			FeedRejectReason::ExcessiveDeviation,
+		)));
+		set_twap_price(155, 0);
+		Prices::on_initialize(3);
+		assert_eq!(DeviatingPrices::<Test>::get(DOT).map(|(_, count)| count), Some(2));
+		assert_eq!(Prices::get_price(&DOT), None);
+
+		// a price inconsistent with the previous one starts the count again
+		set_twap_price(200, 0);
+		Prices::on_initialize(4);
+		assert_eq!(DeviatingPrices::<Test>::get(DOT).map(|(_, count)| count), Some(1));
+		set_twap_price(160, 0);
+		Prices::on_initialize(5);
+		assert_eq!(DeviatingPrices::<Test>::get(DOT).map(|(_, count)| count), Some(1));
+		set_twap_price(158, 0);
+		Prices::on_initialize(6);
+		assert_eq!(
+			LastValidPrice::<Test>::get(DOT).map(|price| price.value),
+			Some(Price::saturating_from_integer(100))
+		);
+
+		set_twap_price(162, 0);
+		Prices::on_initialize(7);
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Prices(crate::Event::PriceFeedReanchored(
+				DOT,
+				Price::saturating_from_integer(162)
+			))));
+		System::assert_last_event(RuntimeEvent::Prices(crate::Event::PriceFeedRecovered(DOT)));
+		assert_eq!(DeviatingPrices::<Test>::get(DOT), None);
+		assert_eq!(
+			LastValidPrice::<Test>::get(DOT).map(|price| price.value),
+			Some(Price::saturating_from_integer(162))
+		);
+		assert_eq!(
+			Prices::get_price(&DOT),
+			Some((Price::from_inner(16_200_000_000 * PRICE_ONE), 0))
+		);
+	});
+}
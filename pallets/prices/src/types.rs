@@ -0,0 +1,75 @@
+// Copyright 2021 Parallel Finance Developer.
+// This file is part of Parallel Finance.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bifrost_primitives::{CurrencyId, Moment};
+use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::Permill;
+
+/// The maximum number of sources a price feed can be aggregated from.
+pub const MAX_PRICE_SOURCES: u32 = 4;
+
+/// The source that a price can be read from.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PriceSource {
+	/// The oracle price, vToken prices are derived from the underlying token price.
+	Oracle,
+	/// The on-chain time weighted average price against `quote` over `window` milliseconds,
+	/// valued by the oracle price of `quote`.
+	Twap { quote: CurrencyId, window: Moment },
+}
+
+/// Validation and aggregation parameters of an asset's price feed.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PriceFeedConfig {
+	/// The maximum age of a price in milliseconds, older prices are rejected.
+	pub max_price_age: Option<Moment>,
+	/// The maximum deviation of a new price from the last accepted price.
+	pub max_deviation: Option<Permill>,
+	/// The sources the median price is aggregated from.
+	pub sources: BoundedVec<PriceSource, ConstU32<MAX_PRICE_SOURCES>>,
+	/// The minimum number of valid sources required to produce a price.
+	pub min_sources: u32,
+	/// The number of consecutive prices rejected by `max_deviation`, each within
+	/// `max_deviation` of the previous one, after which the price is accepted as the new
+	/// reference. Without it, the feed only recovers when the price moves back.
+	pub reanchor_after: Option<u32>,
+}
+
+impl PriceFeedConfig {
+	/// Sources must not be empty or duplicated, `min_sources` and `reanchor_after` must be
+	/// reachable.
+	pub fn is_valid(&self) -> bool {
+		let unique = self
+			.sources
+			.iter()
+			.enumerate()
+			.all(|(i, source)| !self.sources[..i].contains(source));
+		unique &&
+			self.min_sources > 0 &&
+			self.min_sources as usize <= self.sources.len() &&
+			self.reanchor_after != Some(0)
+	}
+}
+
+/// The reason why the price feed of an asset is rejected.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum FeedRejectReason {
+	/// The prices of the sources are older than `max_price_age`.
+	Stale,
+	/// Fewer than `min_sources` sources provide a price.
+	InsufficientSources,
+	/// The price moves more than `max_deviation` from the last accepted price.
+	ExcessiveDeviation,
+}
@@ -29,6 +29,8 @@ pub trait WeightInfo {
 	fn set_price() -> Weight;
 	fn reset_price() -> Weight;
 	fn set_foreign_asset() -> Weight;
+	fn set_price_feed_config() -> Weight;
+	fn remove_price_feed_config() -> Weight;
 }
 
 /// Weights for pallet_prices using the Substrate node and recommended hardware.
@@ -37,18 +39,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_price() -> Weight {
 		Weight::from_parts(22_361_000 as u64, 3979)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn reset_price() -> Weight {
 		Weight::from_parts(21_361_000 as u64, 3979)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	fn set_foreign_asset() -> Weight {
 		Weight::from_parts(23_361_000 as u64, 3979)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn set_price_feed_config() -> Weight {
+		Weight::from_parts(24_361_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn remove_price_feed_config() -> Weight {
+		Weight::from_parts(23_361_000 as u64, 3979)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -56,16 +67,25 @@ impl WeightInfo for () {
 	fn set_price() -> Weight {
 		Weight::from_parts(22_361_000 as u64, 3979)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn reset_price() -> Weight {
 		Weight::from_parts(21_361_000 as u64, 3979)
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
 	fn set_foreign_asset() -> Weight {
 		Weight::from_parts(23_361_000 as u64, 3979)
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn set_price_feed_config() -> Weight {
+		Weight::from_parts(24_361_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn remove_price_feed_config() -> Weight {
+		Weight::from_parts(23_361_000 as u64, 3979)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
 }
@@ -360,6 +360,7 @@ impl bifrost_stable_pool::Config for Test {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Test>;
 	type CurrencyIdRegister = AssetIdMaps<Test>;
+	type Twap = ();
 }
 
 parameter_types! {
@@ -567,6 +568,10 @@ impl bifrost_xcm_interface::Config for Test {
 	type ParachainId = ParaInfo;
 	type CallBackTimeOut = ConstU64<10>;
 	type CurrencyIdConvert = AssetIdMaps<Test>;
+	type DestinationWeigher = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 pub struct ParaInfo;
@@ -23,6 +23,8 @@ use crate::{
 		AstarDappStakingLedger, AstarDappStakingPendingStatus, AstarValidator, DappStaking,
 	},
 	common::types::{Ledger, PendingStatus, StakingProtocol, Validator, XcmFee},
+	moonbeam_parachain_staking::types::{MoonbeamParachainStakingPendingStatus, ParachainStaking},
+	polkadot_staking::types::{PolkadotStaking, PolkadotStakingPendingStatus, RewardDestination},
 	Pallet as SlpV2,
 };
 use frame_benchmarking::v2::*;
@@ -34,13 +36,13 @@ use xcm::v4::MaybeErrorCode;
 
 pub const STAKING_PROTOCOL: StakingProtocol = StakingProtocol::AstarDappStaking;
 
-fn do_set_protocol_configuration<T: Config>()
+fn do_set_protocol_configuration<T: Config>(staking_protocol: StakingProtocol)
 where
 	<T as frame_system::Config>::AccountId: From<sp_runtime::AccountId32>,
 {
 	assert_ok!(SlpV2::<T>::set_protocol_configuration(
 		RawOrigin::Root.into(),
-		STAKING_PROTOCOL,
+		staking_protocol,
 		ProtocolConfiguration {
 			xcm_task_fee: XcmFee { weight: Weight::zero(), fee: 100 },
 			protocol_fee_rate: Permill::from_perthousand(100),
@@ -169,7 +171,7 @@ mod benchmarks {
 				.into(),
 		);
 		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), STAKING_PROTOCOL, None));
-		do_set_protocol_configuration::<T>();
+		do_set_protocol_configuration::<T>(STAKING_PROTOCOL);
 		#[extrinsic_call]
 		_(RawOrigin::Root, STAKING_PROTOCOL, delegator, 1000);
 		Ok(())
@@ -203,7 +205,7 @@ mod benchmarks {
 				.into(),
 		);
 		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), STAKING_PROTOCOL, None));
-		do_set_protocol_configuration::<T>();
+		do_set_protocol_configuration::<T>(STAKING_PROTOCOL);
 		let task = DappStaking::Lock(100);
 		#[extrinsic_call]
 		_(RawOrigin::Root, delegator, task);
@@ -218,7 +220,7 @@ mod benchmarks {
 				.into(),
 		);
 		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), STAKING_PROTOCOL, None));
-		do_set_protocol_configuration::<T>();
+		do_set_protocol_configuration::<T>(STAKING_PROTOCOL);
 
 		PendingStatusByQueryId::<T>::insert(
 			0,
@@ -232,5 +234,99 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn moonbeam_parachain_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("delegator not found"))?;
+		assert_ok!(SlpV2::<T>::add_validator(
+			RawOrigin::Root.into(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::MoonbeamParachainStaking(H160::zero())
+		));
+		let task = ParachainStaking::Delegate(H160::zero(), 100, Default::default(), 0, 0, 0);
+		#[extrinsic_call]
+		_(RawOrigin::Root, delegator, task);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_moonbeam_parachain_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		// The time unit can only be updated freely before the protocol is configured.
+		assert_ok!(SlpV2::<T>::update_ongoing_time_unit(
+			RawOrigin::Root.into(),
+			staking_protocol,
+			Some(TimeUnit::Round(1))
+		));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("delegator not found"))?;
+
+		ConfigurationByStakingProtocol::<T>::mutate(staking_protocol, |configuration| {
+			if let Some(configuration) = configuration {
+				configuration.unlock_period = TimeUnit::Round(28);
+			}
+		});
+
+		PendingStatusByQueryId::<T>::insert(
+			0,
+			PendingStatus::MoonbeamParachainStaking(
+				MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(
+					delegator,
+					H160::zero(),
+					100,
+				),
+			),
+		);
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, xcm::v4::Response::DispatchResult(MaybeErrorCode::Success));
+
+		assert!(!PendingStatusByQueryId::<T>::contains_key(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn polkadot_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("delegator not found"))?;
+		let task = PolkadotStaking::Bond(100, RewardDestination::Staked);
+		#[extrinsic_call]
+		_(RawOrigin::Root, delegator, task);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_polkadot_staking() -> Result<(), BenchmarkError> {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		assert_ok!(SlpV2::<T>::add_delegator(RawOrigin::Root.into(), staking_protocol, None));
+		// The time unit can only be updated freely before the protocol is configured.
+		assert_ok!(SlpV2::<T>::update_ongoing_time_unit(
+			RawOrigin::Root.into(),
+			staking_protocol,
+			Some(TimeUnit::Era(1))
+		));
+		do_set_protocol_configuration::<T>(staking_protocol);
+		let delegator = DelegatorByStakingProtocolAndDelegatorIndex::<T>::get(staking_protocol, 0)
+			.ok_or(BenchmarkError::Stop("delegator not found"))?;
+
+		PendingStatusByQueryId::<T>::insert(
+			0,
+			PendingStatus::PolkadotStaking(PolkadotStakingPendingStatus::Unbond(delegator, 100)),
+		);
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, xcm::v4::Response::DispatchResult(MaybeErrorCode::Success));
+
+		assert!(!PendingStatusByQueryId::<T>::contains_key(0));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(SlpV2, crate::mock::new_test_ext(), crate::mock::Test);
 }
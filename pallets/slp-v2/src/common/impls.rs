@@ -25,7 +25,7 @@ use crate::{
 	DelegatorIndexByStakingProtocolAndDelegator, Error, Event, LedgerByStakingProtocolAndDelegator,
 	NextDelegatorIndexByStakingProtocol, Pallet, ValidatorsByStakingProtocolAndDelegator,
 };
-use bifrost_primitives::{Balance, CurrencyId, VtokenMintingOperator};
+use bifrost_primitives::{Balance, CurrencyId, TimeUnit, VtokenMintingOperator};
 use frame_support::{
 	dispatch::{DispatchResultWithPostInfo, GetDispatchInfo, RawOrigin},
 	ensure,
@@ -34,7 +34,7 @@ use frame_support::{
 use frame_system::pallet_prelude::OriginFor;
 use orml_traits::{MultiCurrency, XcmTransfer};
 use parity_scale_codec::{Decode, Encode};
-use sp_core::blake2_256;
+use sp_core::{blake2_256, H160};
 use sp_runtime::{
 	helpers_128bit::multiply_by_rational_with_rounding, traits::TrailingZeroInput, DispatchError,
 	Rounding, Saturating,
@@ -190,6 +190,14 @@ impl<T: Config> Pallet<T> {
 		Ok(account_id)
 	}
 
+	/// Implemented by Utility pallet to get derived 20-byte account id
+	pub fn derivative_account_id_20(account_id: [u8; 20], delegator_index: DelegatorIndex) -> H160 {
+		let entropy = (b"modlpy/utilisuba", account_id, delegator_index).using_encoded(blake2_256);
+		let account_id: [u8; 20] = Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed");
+		H160::from_slice(account_id.as_slice())
+	}
+
 	/// Wrapping any runtime call with as_derivative.
 	pub fn wrap_utility_as_derivative_call_data(
 		staking_protocol: &StakingProtocol,
@@ -293,6 +301,41 @@ impl<T: Config> Pallet<T> {
 		Ok(xcm_message)
 	}
 
+	/// Wrapping the remote call with as_derivative and building the xcm message.
+	/// If `notify_call` is provided, the xcm message will report the transact status to it.
+	pub fn get_query_id_and_xcm_message(
+		staking_protocol: &StakingProtocol,
+		call: Vec<u8>,
+		delegator_index: DelegatorIndex,
+		notify_call: Option<<T as Config>::RuntimeCall>,
+	) -> Result<(Option<QueryId>, Xcm), Error<T>> {
+		let call =
+			Self::wrap_utility_as_derivative_call_data(staking_protocol, delegator_index, call);
+		let mut query_id = None;
+		let xcm_message = match notify_call {
+			Some(notify_call) => Self::wrap_xcm_message_with_notify(
+				staking_protocol,
+				call,
+				notify_call,
+				&mut query_id,
+			)?,
+			None => Self::wrap_xcm_message(staking_protocol, call)?,
+		};
+		Ok((query_id, xcm_message))
+	}
+
+	/// The time unit at which the tokens unlocked now can be withdrawn.
+	pub fn get_unlock_time(staking_protocol: &StakingProtocol) -> Result<TimeUnit, Error<T>> {
+		let currency_id = staking_protocol.info().currency_id;
+		let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
+			.ok_or(Error::<T>::TimeUnitNotFound)?;
+		let configuration = ConfigurationByStakingProtocol::<T>::get(staking_protocol)
+			.ok_or(Error::<T>::ConfigurationNotFound)?;
+		current_time_unit
+			.add(configuration.unlock_period)
+			.ok_or(Error::<T>::TimeUnitNotFound)
+	}
+
 	pub fn send_xcm_message(
 		staking_protocol: StakingProtocol,
 		xcm_message: Xcm,
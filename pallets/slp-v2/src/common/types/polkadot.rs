@@ -21,6 +21,12 @@ use crate::{
 		AstarDappStakingLedger, AstarDappStakingPendingStatus, AstarValidator, DappStaking,
 	},
 	common::types::{Delegator, DelegatorIndex, StakingProtocolInfo},
+	moonbeam_parachain_staking::types::{
+		MoonbeamParachainStakingLedger, MoonbeamParachainStakingPendingStatus, ParachainStaking,
+	},
+	polkadot_staking::types::{
+		PolkadotStaking, PolkadotStakingLedger, PolkadotStakingPendingStatus,
+	},
 	Config, Error,
 };
 use bifrost_primitives::{
@@ -142,7 +148,20 @@ impl StakingProtocol {
 				)?;
 				Ok(Delegator::Substrate(sub_sibling_account))
 			},
-			_ => Err(Error::<T>::UnsupportedStakingProtocol),
+			StakingProtocol::MoonbeamParachainStaking => {
+				let sibling_account: [u8; 20] =
+					Sibling::from(T::ParachainId::get()).into_account_truncating();
+				let sub_sibling_account =
+					crate::Pallet::<T>::derivative_account_id_20(sibling_account, delegator_index);
+				Ok(Delegator::Ethereum(sub_sibling_account))
+			},
+			StakingProtocol::PolkadotStaking => {
+				let sub_parachain_account = crate::Pallet::<T>::derivative_account_id(
+					T::ParachainId::get().into_account_truncating(),
+					delegator_index,
+				)?;
+				Ok(Delegator::Substrate(sub_parachain_account))
+			},
 		}
 	}
 
@@ -150,7 +169,10 @@ impl StakingProtocol {
 		match self {
 			StakingProtocol::AstarDappStaking =>
 				Ledger::AstarDappStaking(AstarDappStakingLedger::default()),
-			_ => unreachable!(),
+			StakingProtocol::MoonbeamParachainStaking =>
+				Ledger::MoonbeamParachainStaking(MoonbeamParachainStakingLedger::default()),
+			StakingProtocol::PolkadotStaking =>
+				Ledger::PolkadotStaking(PolkadotStakingLedger::default()),
 		}
 	}
 }
@@ -167,15 +189,21 @@ pub enum Validator<AccountId> {
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
 pub enum Ledger {
 	AstarDappStaking(AstarDappStakingLedger),
+	MoonbeamParachainStaking(MoonbeamParachainStakingLedger),
+	PolkadotStaking(PolkadotStakingLedger),
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum XcmTask<AccountId> {
 	AstarDappStaking(DappStaking<AccountId>),
+	MoonbeamParachainStaking(ParachainStaking),
+	PolkadotStaking(PolkadotStaking<AccountId>),
 }
 
 /// PendingStatus in slp protocol.
 #[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
 pub enum PendingStatus<AccountId> {
 	AstarDappStaking(AstarDappStakingPendingStatus<AccountId>),
+	MoonbeamParachainStaking(MoonbeamParachainStakingPendingStatus<AccountId>),
+	PolkadotStaking(PolkadotStakingPendingStatus<AccountId>),
 }
@@ -30,8 +30,12 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
+#[cfg(feature = "polkadot")]
+use moonbeam_parachain_staking::types::ParachainStaking;
 use orml_traits::{MultiCurrency, XcmTransfer};
 use polkadot_parachain_primitives::primitives::Id as ParaId;
+#[cfg(feature = "polkadot")]
+use polkadot_staking::types::PolkadotStaking;
 use sp_runtime::traits::AccountIdConversion;
 pub use weights::WeightInfo;
 use xcm::v4::{Location, SendXcm};
@@ -44,6 +48,10 @@ mod mock;
 #[cfg(feature = "polkadot")]
 mod astar_dapp_staking;
 mod common;
+#[cfg(feature = "polkadot")]
+mod moonbeam_parachain_staking;
+#[cfg(feature = "polkadot")]
+mod polkadot_staking;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -701,6 +709,16 @@ pub mod pallet {
 						astar_dapp_staking_ledger.add_lock_amount(amount);
 						Ok(())
 					},
+					#[cfg(feature = "polkadot")]
+					Some(Ledger::MoonbeamParachainStaking(moonbeam_parachain_staking_ledger)) => {
+						moonbeam_parachain_staking_ledger.add_lock_amount(amount);
+						Ok(())
+					},
+					#[cfg(feature = "polkadot")]
+					Some(Ledger::PolkadotStaking(polkadot_staking_ledger)) => {
+						polkadot_staking_ledger.add_lock_amount(amount);
+						Ok(())
+					},
 					_ => Err(Error::<T>::LedgerNotFound),
 				},
 			)?;
@@ -761,5 +779,85 @@ pub mod pallet {
 			}
 			Ok(().into())
 		}
+
+		/// Manipulate a delegator to perform Moonbeam parachain staking related operations.
+		///
+		/// Can be called by governance or staking protocol operator.
+		///
+		/// Parameters
+		/// - `delegator`: Select the delegator which is existed.
+		/// - `task`: The parachain staking task.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::moonbeam_parachain_staking())]
+		pub fn moonbeam_parachain_staking(
+			origin: OriginFor<T>,
+			delegator: Delegator<T::AccountId>,
+			task: ParachainStaking,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_governance_or_operator(origin, StakingProtocol::MoonbeamParachainStaking)?;
+			Self::do_moonbeam_parachain_staking(delegator, task)
+		}
+
+		/// Processing Xcm message execution results.
+		///
+		/// Can be called by governance or xcm origin.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::notify_moonbeam_parachain_staking())]
+		pub fn notify_moonbeam_parachain_staking(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
+			let responder = Self::ensure_governance_or_xcm_response(origin)?;
+			let pending_status = PendingStatusByQueryId::<T>::get(query_id)
+				.ok_or(Error::<T>::PendingStatusNotFound)?;
+			if Response::DispatchResult(MaybeErrorCode::Success) == response {
+				Self::do_notify_moonbeam_parachain_staking(responder, pending_status)?;
+			}
+			PendingStatusByQueryId::<T>::remove(query_id);
+			Ok(().into())
+		}
+
+		/// Manipulate a delegator to perform relay chain staking related operations.
+		///
+		/// Can be called by governance or staking protocol operator.
+		///
+		/// Parameters
+		/// - `delegator`: Select the delegator which is existed.
+		/// - `task`: The relay chain staking task.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::polkadot_staking())]
+		pub fn polkadot_staking(
+			origin: OriginFor<T>,
+			delegator: Delegator<T::AccountId>,
+			task: PolkadotStaking<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_governance_or_operator(origin, StakingProtocol::PolkadotStaking)?;
+			Self::do_polkadot_staking(delegator, task)
+		}
+
+		/// Processing Xcm message execution results.
+		///
+		/// Can be called by governance or xcm origin.
+		#[cfg(feature = "polkadot")]
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::notify_polkadot_staking())]
+		pub fn notify_polkadot_staking(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
+			let responder = Self::ensure_governance_or_xcm_response(origin)?;
+			let pending_status = PendingStatusByQueryId::<T>::get(query_id)
+				.ok_or(Error::<T>::PendingStatusNotFound)?;
+			if Response::DispatchResult(MaybeErrorCode::Success) == response {
+				Self::do_notify_polkadot_staking(responder, pending_status)?;
+			}
+			PendingStatusByQueryId::<T>::remove(query_id);
+			Ok(().into())
+		}
 	}
 }
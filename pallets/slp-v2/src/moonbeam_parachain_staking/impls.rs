@@ -0,0 +1,249 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	common::types::{Delegator, Ledger, PendingStatus, StakingProtocol, Validator, XcmTask},
+	moonbeam_parachain_staking::types::{
+		MoonbeamCall, MoonbeamDelegation, MoonbeamParachainStakingLedger,
+		MoonbeamParachainStakingPendingStatus, MoonbeamScheduledRequest, ParachainStaking,
+	},
+	Call, Config, Error, Event, LedgerByStakingProtocolAndDelegator, Pallet,
+	PendingStatusByQueryId, ValidatorsByStakingProtocolAndDelegator,
+};
+use bifrost_primitives::Balance;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use parity_scale_codec::Encode;
+use sp_core::H160;
+use sp_runtime::Saturating;
+use xcm::v4::Location;
+
+pub const MOONBEAM_PARACHAIN_STAKING: StakingProtocol = StakingProtocol::MoonbeamParachainStaking;
+
+impl<T: Config> Pallet<T> {
+	pub fn ensure_moonbeam_validator_exist(
+		delegator: Delegator<T::AccountId>,
+		candidate: H160,
+	) -> DispatchResultWithPostInfo {
+		let validators = ValidatorsByStakingProtocolAndDelegator::<T>::get(
+			MOONBEAM_PARACHAIN_STAKING,
+			delegator,
+		);
+		ensure!(
+			validators.contains(&Validator::MoonbeamParachainStaking(candidate)),
+			Error::<T>::ValidatorNotFound
+		);
+		Ok(().into())
+	}
+
+	pub fn do_moonbeam_parachain_staking(
+		delegator: Delegator<T::AccountId>,
+		task: ParachainStaking,
+	) -> DispatchResultWithPostInfo {
+		let delegator_index =
+			Self::ensure_delegator_exist(&MOONBEAM_PARACHAIN_STAKING, &delegator)?;
+		let pending_status = match task {
+			ParachainStaking::Delegate(candidate, amount, ..) => {
+				Self::ensure_moonbeam_validator_exist(delegator.clone(), candidate)?;
+				MoonbeamParachainStakingPendingStatus::Delegate(
+					delegator.clone(),
+					candidate,
+					amount,
+				)
+			},
+			ParachainStaking::DelegatorBondMore(candidate, amount) => {
+				Self::ensure_moonbeam_validator_exist(delegator.clone(), candidate)?;
+				MoonbeamParachainStakingPendingStatus::DelegatorBondMore(
+					delegator.clone(),
+					candidate,
+					amount,
+				)
+			},
+			ParachainStaking::ScheduleDelegatorBondLess(candidate, amount) =>
+				MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(
+					delegator.clone(),
+					candidate,
+					amount,
+				),
+			ParachainStaking::ScheduleRevokeDelegation(candidate) =>
+				MoonbeamParachainStakingPendingStatus::ScheduleRevokeDelegation(
+					delegator.clone(),
+					candidate,
+				),
+			ParachainStaking::ExecuteDelegationRequest(delegator_account, candidate) => {
+				ensure!(
+					Delegator::Ethereum(delegator_account) == delegator,
+					Error::<T>::InvalidParameter
+				);
+				MoonbeamParachainStakingPendingStatus::ExecuteDelegationRequest(
+					delegator.clone(),
+					candidate,
+				)
+			},
+			ParachainStaking::CancelDelegationRequest(candidate) =>
+				MoonbeamParachainStakingPendingStatus::CancelDelegationRequest(
+					delegator.clone(),
+					candidate,
+				),
+		};
+		let pending_status = PendingStatus::MoonbeamParachainStaking(pending_status);
+		let call = MoonbeamCall::ParachainStaking(task).encode();
+		let notify_call =
+			<T as Config>::RuntimeCall::from(Call::<T>::notify_moonbeam_parachain_staking {
+				query_id: 0,
+				response: Default::default(),
+			});
+		let (query_id, xcm_message) = Self::get_query_id_and_xcm_message(
+			&MOONBEAM_PARACHAIN_STAKING,
+			call,
+			delegator_index,
+			Some(notify_call),
+		)?;
+		if let Some(query_id) = query_id {
+			PendingStatusByQueryId::<T>::insert(query_id, pending_status.clone());
+		}
+		Self::send_xcm_message(MOONBEAM_PARACHAIN_STAKING, xcm_message)?;
+		Self::deposit_event(Event::<T>::SendXcmTask {
+			query_id,
+			delegator,
+			task: XcmTask::MoonbeamParachainStaking(task),
+			pending_status: Some(pending_status),
+			dest_location: MOONBEAM_PARACHAIN_STAKING.info().remote_dest_location,
+		});
+		Ok(().into())
+	}
+
+	pub fn do_notify_moonbeam_parachain_staking(
+		responder: Location,
+		pending_status: PendingStatus<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		let status = match pending_status.clone() {
+			PendingStatus::MoonbeamParachainStaking(status) => status,
+			_ => return Err(Error::<T>::PendingStatusNotFound),
+		};
+		let delegator = match status.clone() {
+			MoonbeamParachainStakingPendingStatus::Delegate(delegator, ..) |
+			MoonbeamParachainStakingPendingStatus::DelegatorBondMore(delegator, ..) |
+			MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(delegator, ..) |
+			MoonbeamParachainStakingPendingStatus::ScheduleRevokeDelegation(delegator, _) |
+			MoonbeamParachainStakingPendingStatus::ExecuteDelegationRequest(delegator, _) |
+			MoonbeamParachainStakingPendingStatus::CancelDelegationRequest(delegator, _) => delegator,
+		};
+		LedgerByStakingProtocolAndDelegator::<T>::mutate(
+			MOONBEAM_PARACHAIN_STAKING,
+			delegator,
+			|ledger| -> Result<(), Error<T>> {
+				if let Some(Ledger::MoonbeamParachainStaking(mut pending_ledger)) = ledger.clone() {
+					match status {
+						MoonbeamParachainStakingPendingStatus::Delegate(_, candidate, amount) |
+						MoonbeamParachainStakingPendingStatus::DelegatorBondMore(
+							_,
+							candidate,
+							amount,
+						) => {
+							match pending_ledger
+								.delegations
+								.iter_mut()
+								.find(|delegation| delegation.candidate == candidate)
+							{
+								Some(delegation) => delegation.amount.saturating_accrue(amount),
+								None => pending_ledger
+									.delegations
+									.try_push(MoonbeamDelegation { candidate, amount })
+									.map_err(|_| Error::<T>::ValidatorsOverflow)?,
+							};
+							pending_ledger.add_lock_amount(amount);
+						},
+						MoonbeamParachainStakingPendingStatus::ScheduleDelegatorBondLess(
+							_,
+							candidate,
+							amount,
+						) => {
+							Self::schedule_moonbeam_request(
+								&mut pending_ledger,
+								candidate,
+								amount,
+							)?;
+						},
+						MoonbeamParachainStakingPendingStatus::ScheduleRevokeDelegation(
+							_,
+							candidate,
+						) => {
+							let amount = pending_ledger
+								.delegation_amount(&candidate)
+								.ok_or(Error::<T>::ValidatorNotFound)?;
+							Self::schedule_moonbeam_request(
+								&mut pending_ledger,
+								candidate,
+								amount,
+							)?;
+						},
+						MoonbeamParachainStakingPendingStatus::ExecuteDelegationRequest(
+							_,
+							candidate,
+						) => {
+							let request_index = pending_ledger
+								.requests
+								.iter()
+								.position(|request| request.candidate == candidate)
+								.ok_or(Error::<T>::ValidatorNotFound)?;
+							let request = pending_ledger.requests.remove(request_index);
+							let delegation_index = pending_ledger
+								.delegations
+								.iter()
+								.position(|delegation| delegation.candidate == candidate)
+								.ok_or(Error::<T>::ValidatorNotFound)?;
+							if let Some(delegation) =
+								pending_ledger.delegations.get_mut(delegation_index)
+							{
+								delegation.amount.saturating_reduce(request.amount);
+								if delegation.amount == 0 {
+									pending_ledger.delegations.remove(delegation_index);
+								}
+							}
+							pending_ledger.subtract_lock_amount(request.amount);
+						},
+						MoonbeamParachainStakingPendingStatus::CancelDelegationRequest(
+							_,
+							candidate,
+						) => {
+							pending_ledger
+								.requests
+								.retain(|request| request.candidate != candidate);
+						},
+					};
+					*ledger = Some(Ledger::MoonbeamParachainStaking(pending_ledger));
+				};
+				Ok(())
+			},
+		)?;
+		Self::deposit_event(Event::<T>::NotifyResponseReceived { responder, pending_status });
+		Ok(())
+	}
+
+	fn schedule_moonbeam_request(
+		ledger: &mut MoonbeamParachainStakingLedger,
+		candidate: H160,
+		amount: Balance,
+	) -> Result<(), Error<T>> {
+		let unlock_time = Self::get_unlock_time(&MOONBEAM_PARACHAIN_STAKING)?;
+		ledger
+			.requests
+			.try_push(MoonbeamScheduledRequest { candidate, amount, unlock_time })
+			.map_err(|_| Error::<T>::UnlockRecordOverflow)
+	}
+}
@@ -0,0 +1,105 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::common::types::Delegator;
+use bifrost_primitives::{Balance, TimeUnit};
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
+	BoundedVec,
+};
+use sp_core::{ConstU32, H160};
+use sp_runtime::{Percent, Saturating};
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum ParachainStaking {
+	/// delegate_with_auto_compound(candidate, amount, auto_compound,
+	/// candidate_delegation_count, candidate_auto_compounding_delegation_count,
+	/// delegation_count)
+	#[codec(index = 18)]
+	Delegate(H160, Balance, Percent, u32, u32, u32),
+	#[codec(index = 22)]
+	ScheduleRevokeDelegation(H160),
+	#[codec(index = 23)]
+	DelegatorBondMore(H160, Balance),
+	#[codec(index = 24)]
+	ScheduleDelegatorBondLess(H160, Balance),
+	/// execute_delegation_request(delegator, candidate)
+	#[codec(index = 25)]
+	ExecuteDelegationRequest(H160, H160),
+	#[codec(index = 26)]
+	CancelDelegationRequest(H160),
+}
+
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum MoonbeamCall {
+	#[codec(index = 20)]
+	ParachainStaking(ParachainStaking),
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MoonbeamDelegation {
+	pub candidate: H160,
+	pub amount: Balance,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MoonbeamScheduledRequest {
+	pub candidate: H160,
+	pub amount: Balance,
+	pub unlock_time: TimeUnit,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, Default, PartialEq, Eq, TypeInfo)]
+pub struct MoonbeamParachainStakingLedger {
+	/// Total bonded amount, including the rewards compounded into the delegations.
+	pub total: Balance,
+	/// Bonded amount of each candidate.
+	pub delegations: BoundedVec<MoonbeamDelegation, ConstU32<32>>,
+	/// Scheduled decrease or revoke requests, at most one per candidate.
+	pub requests: BoundedVec<MoonbeamScheduledRequest, ConstU32<32>>,
+}
+
+impl MoonbeamParachainStakingLedger {
+	/// Adds the specified amount to the total bonded amount.
+	pub fn add_lock_amount(&mut self, amount: Balance) {
+		self.total.saturating_accrue(amount);
+	}
+
+	/// Subtracts the specified amount of the total bonded amount.
+	pub fn subtract_lock_amount(&mut self, amount: Balance) {
+		self.total.saturating_reduce(amount);
+	}
+
+	/// Bonded amount of the candidate.
+	pub fn delegation_amount(&self, candidate: &H160) -> Option<Balance> {
+		self.delegations
+			.iter()
+			.find(|delegation| delegation.candidate == *candidate)
+			.map(|delegation| delegation.amount)
+	}
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum MoonbeamParachainStakingPendingStatus<AccountId> {
+	Delegate(Delegator<AccountId>, H160, Balance),
+	DelegatorBondMore(Delegator<AccountId>, H160, Balance),
+	ScheduleDelegatorBondLess(Delegator<AccountId>, H160, Balance),
+	ScheduleRevokeDelegation(Delegator<AccountId>, H160),
+	ExecuteDelegationRequest(Delegator<AccountId>, H160),
+	CancelDelegationRequest(Delegator<AccountId>, H160),
+}
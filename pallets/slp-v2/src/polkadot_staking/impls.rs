@@ -0,0 +1,183 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	common::types::{Delegator, Ledger, PendingStatus, StakingProtocol, Validator, XcmTask},
+	polkadot_staking::types::{
+		PolkadotCall, PolkadotStaking, PolkadotStakingPendingStatus, PolkadotUnlockingRecord,
+		StakingCall,
+	},
+	Call, Config, Error, Event, LedgerByStakingProtocolAndDelegator, Pallet,
+	PendingStatusByQueryId, ValidatorsByStakingProtocolAndDelegator,
+};
+use bifrost_primitives::VtokenMintingOperator;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use parity_scale_codec::Encode;
+use sp_runtime::MultiAddress;
+use sp_std::{cmp::Ordering, vec::Vec};
+use xcm::v4::Location;
+
+pub const POLKADOT_STAKING: StakingProtocol = StakingProtocol::PolkadotStaking;
+
+impl<T: Config> Pallet<T> {
+	pub fn ensure_polkadot_validator_exist(
+		delegator: Delegator<T::AccountId>,
+		validator: T::AccountId,
+	) -> DispatchResultWithPostInfo {
+		let validators =
+			ValidatorsByStakingProtocolAndDelegator::<T>::get(POLKADOT_STAKING, delegator);
+		ensure!(
+			validators.contains(&Validator::PolkadotStaking(validator)),
+			Error::<T>::ValidatorNotFound
+		);
+		Ok(().into())
+	}
+
+	pub fn do_polkadot_staking(
+		delegator: Delegator<T::AccountId>,
+		task: PolkadotStaking<T::AccountId>,
+	) -> DispatchResultWithPostInfo {
+		let delegator_index = Self::ensure_delegator_exist(&POLKADOT_STAKING, &delegator)?;
+		let (call, pending_status) = match task.clone() {
+			PolkadotStaking::Bond(amount, payee) => (
+				StakingCall::<T>::Bond(amount, payee),
+				Some(PolkadotStakingPendingStatus::Bond(delegator.clone(), amount)),
+			),
+			PolkadotStaking::BondExtra(amount) => (
+				StakingCall::<T>::BondExtra(amount),
+				Some(PolkadotStakingPendingStatus::BondExtra(delegator.clone(), amount)),
+			),
+			PolkadotStaking::Unbond(amount) => (
+				StakingCall::<T>::Unbond(amount),
+				Some(PolkadotStakingPendingStatus::Unbond(delegator.clone(), amount)),
+			),
+			PolkadotStaking::Rebond(amount) => (
+				StakingCall::<T>::Rebond(amount),
+				Some(PolkadotStakingPendingStatus::Rebond(delegator.clone(), amount)),
+			),
+			PolkadotStaking::WithdrawUnbonded(num_slashing_spans) => (
+				StakingCall::<T>::WithdrawUnbonded(num_slashing_spans),
+				Some(PolkadotStakingPendingStatus::WithdrawUnbonded(delegator.clone())),
+			),
+			PolkadotStaking::Nominate => {
+				let targets: Vec<MultiAddress<T::AccountId, ()>> =
+					ValidatorsByStakingProtocolAndDelegator::<T>::get(
+						POLKADOT_STAKING,
+						delegator.clone(),
+					)
+					.into_iter()
+					.filter_map(|validator| match validator {
+						Validator::PolkadotStaking(account_id) =>
+							Some(MultiAddress::Id(account_id)),
+						_ => None,
+					})
+					.collect();
+				ensure!(!targets.is_empty(), Error::<T>::ValidatorNotFound);
+				(StakingCall::<T>::Nominate(targets), None)
+			},
+			PolkadotStaking::Chill => (StakingCall::<T>::Chill, None),
+			PolkadotStaking::PayoutStakers(validator, era) => {
+				Self::ensure_polkadot_validator_exist(delegator.clone(), validator.clone())?;
+				(StakingCall::<T>::PayoutStakers(validator, era), None)
+			},
+		};
+		let pending_status = pending_status.map(PendingStatus::PolkadotStaking);
+		let call = PolkadotCall::<T>::Staking(call).encode();
+		let notify_call = pending_status.as_ref().map(|_| {
+			<T as Config>::RuntimeCall::from(Call::<T>::notify_polkadot_staking {
+				query_id: 0,
+				response: Default::default(),
+			})
+		});
+		let (query_id, xcm_message) = Self::get_query_id_and_xcm_message(
+			&POLKADOT_STAKING,
+			call,
+			delegator_index,
+			notify_call,
+		)?;
+		if let Some(query_id) = query_id {
+			let pending_status = pending_status.clone().ok_or(Error::<T>::PendingStatusNotFound)?;
+			PendingStatusByQueryId::<T>::insert(query_id, pending_status.clone());
+		}
+		Self::send_xcm_message(POLKADOT_STAKING, xcm_message)?;
+		Self::deposit_event(Event::<T>::SendXcmTask {
+			query_id,
+			delegator,
+			task: XcmTask::PolkadotStaking(task),
+			pending_status,
+			dest_location: POLKADOT_STAKING.info().remote_dest_location,
+		});
+		Ok(().into())
+	}
+
+	pub fn do_notify_polkadot_staking(
+		responder: Location,
+		pending_status: PendingStatus<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		let status = match pending_status.clone() {
+			PendingStatus::PolkadotStaking(status) => status,
+			_ => return Err(Error::<T>::PendingStatusNotFound),
+		};
+		let delegator = match status.clone() {
+			PolkadotStakingPendingStatus::Bond(delegator, _) |
+			PolkadotStakingPendingStatus::BondExtra(delegator, _) |
+			PolkadotStakingPendingStatus::Unbond(delegator, _) |
+			PolkadotStakingPendingStatus::Rebond(delegator, _) |
+			PolkadotStakingPendingStatus::WithdrawUnbonded(delegator) => delegator,
+		};
+		LedgerByStakingProtocolAndDelegator::<T>::mutate(
+			POLKADOT_STAKING,
+			delegator,
+			|ledger| -> Result<(), Error<T>> {
+				if let Some(Ledger::PolkadotStaking(mut pending_ledger)) = ledger.clone() {
+					match status {
+						PolkadotStakingPendingStatus::Bond(_, amount) |
+						PolkadotStakingPendingStatus::BondExtra(_, amount) => {
+							pending_ledger.add_lock_amount(amount);
+						},
+						PolkadotStakingPendingStatus::Unbond(_, amount) => {
+							pending_ledger.subtract_lock_amount(amount);
+							let unlock_time = Self::get_unlock_time(&POLKADOT_STAKING)?;
+							pending_ledger
+								.unlocking
+								.try_push(PolkadotUnlockingRecord { amount, unlock_time })
+								.map_err(|_| Error::<T>::UnlockRecordOverflow)?;
+						},
+						PolkadotStakingPendingStatus::Rebond(_, amount) => {
+							pending_ledger.rebond(amount);
+						},
+						PolkadotStakingPendingStatus::WithdrawUnbonded(_) => {
+							let currency_id = POLKADOT_STAKING.info().currency_id;
+							let current_time_unit =
+								T::VtokenMinting::get_ongoing_time_unit(currency_id)
+									.ok_or(Error::<T>::TimeUnitNotFound)?;
+							// The relay chain releases the chunks whose era has been reached.
+							pending_ledger.unlocking.retain(|record| {
+								current_time_unit.cmp(&record.unlock_time) == Ordering::Less
+							});
+						},
+					};
+					*ledger = Some(Ledger::PolkadotStaking(pending_ledger));
+				};
+				Ok(())
+			},
+		)?;
+		Self::deposit_event(Event::<T>::NotifyResponseReceived { responder, pending_status });
+		Ok(())
+	}
+}
@@ -0,0 +1,140 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{common::types::Delegator, Config};
+use bifrost_primitives::{Balance, TimeUnit};
+use frame_support::{
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
+	BoundedVec,
+};
+use sp_core::ConstU32;
+use sp_runtime::{MultiAddress, Saturating};
+use sp_std::vec::Vec;
+
+/// A destination account for payment.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum RewardDestination<AccountId> {
+	/// Pay into the stash account, increasing the amount at stake accordingly.
+	Staked,
+	/// Pay into the stash account, not increasing the amount at stake.
+	Stash,
+	/// Pay into the controller account.
+	Controller,
+	/// Pay into a specified account.
+	Account(AccountId),
+	/// Receive no reward.
+	None,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum PolkadotStaking<AccountId> {
+	Bond(Balance, RewardDestination<AccountId>),
+	BondExtra(Balance),
+	Unbond(Balance),
+	Rebond(Balance),
+	/// withdraw_unbonded(num_slashing_spans)
+	WithdrawUnbonded(u32),
+	/// Nominate all the validators of the delegator.
+	Nominate,
+	Chill,
+	/// payout_stakers(validator_stash, era)
+	PayoutStakers(AccountId, u32),
+}
+
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum StakingCall<T: Config> {
+	#[codec(index = 0)]
+	Bond(#[codec(compact)] Balance, RewardDestination<T::AccountId>),
+	#[codec(index = 1)]
+	BondExtra(#[codec(compact)] Balance),
+	#[codec(index = 2)]
+	Unbond(#[codec(compact)] Balance),
+	#[codec(index = 3)]
+	WithdrawUnbonded(u32),
+	#[codec(index = 5)]
+	Nominate(Vec<MultiAddress<T::AccountId, ()>>),
+	#[codec(index = 6)]
+	Chill,
+	#[codec(index = 18)]
+	PayoutStakers(T::AccountId, u32),
+	#[codec(index = 19)]
+	Rebond(#[codec(compact)] Balance),
+}
+
+#[derive(Encode, Decode, Debug, Clone)]
+pub enum PolkadotCall<T: Config> {
+	#[codec(index = 7)]
+	Staking(StakingCall<T>),
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct PolkadotUnlockingRecord {
+	pub amount: Balance,
+	pub unlock_time: TimeUnit,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Debug, Default, PartialEq, Eq, TypeInfo)]
+pub struct PolkadotStakingLedger {
+	/// The bonded amount that is actively staking.
+	#[codec(compact)]
+	pub active: Balance,
+	/// Unbonding chunks, the relay chain allows at most 32 of them.
+	pub unlocking: BoundedVec<PolkadotUnlockingRecord, ConstU32<32>>,
+}
+
+impl PolkadotStakingLedger {
+	/// Adds the specified amount to the active bonded amount.
+	pub fn add_lock_amount(&mut self, amount: Balance) {
+		self.active.saturating_accrue(amount);
+	}
+
+	/// Subtracts the specified amount of the active bonded amount.
+	pub fn subtract_lock_amount(&mut self, amount: Balance) {
+		self.active.saturating_reduce(amount);
+	}
+
+	/// Moves up to `amount` from the latest unlocking chunks back to active, returns the amount
+	/// actually rebonded.
+	pub fn rebond(&mut self, amount: Balance) -> Balance {
+		let mut remaining = amount;
+		while let Some(last) = self.unlocking.iter_mut().next_back() {
+			if remaining == 0 {
+				break;
+			}
+			if last.amount <= remaining {
+				remaining.saturating_reduce(last.amount);
+				self.unlocking.pop();
+			} else {
+				last.amount.saturating_reduce(remaining);
+				remaining = 0;
+			}
+		}
+		let rebonded = amount.saturating_sub(remaining);
+		self.add_lock_amount(rebonded);
+		rebonded
+	}
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum PolkadotStakingPendingStatus<AccountId> {
+	Bond(Delegator<AccountId>, Balance),
+	BondExtra(Delegator<AccountId>, Balance),
+	Unbond(Delegator<AccountId>, Balance),
+	Rebond(Delegator<AccountId>, Balance),
+	WithdrawUnbonded(Delegator<AccountId>),
+}
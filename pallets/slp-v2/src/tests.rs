@@ -26,17 +26,25 @@ use crate::{
 		XcmFee, XcmTask,
 	},
 	mock::*,
+	moonbeam_parachain_staking::types::{
+		MoonbeamDelegation, MoonbeamParachainStakingLedger, MoonbeamParachainStakingPendingStatus,
+		MoonbeamScheduledRequest, ParachainStaking,
+	},
+	polkadot_staking::types::{
+		PolkadotStaking, PolkadotStakingLedger, PolkadotStakingPendingStatus,
+		PolkadotUnlockingRecord, RewardDestination,
+	},
 	DelegatorByStakingProtocolAndDelegatorIndex, DelegatorIndexByStakingProtocolAndDelegator,
 	Error as SlpV2Error, Event as SlpV2Event, LastUpdateOngoingTimeUnitBlockNumber,
 	LedgerByStakingProtocolAndDelegator, NextDelegatorIndexByStakingProtocol,
-	ValidatorsByStakingProtocolAndDelegator,
+	PendingStatusByQueryId, ValidatorsByStakingProtocolAndDelegator,
 };
 use bifrost_primitives::{TimeUnit, VtokenMintingOperator, VASTR};
 use cumulus_primitives_core::Weight;
 use frame_support::{assert_noop, assert_ok, traits::fungibles::Mutate};
 use orml_traits::MultiCurrency;
 use pallet_xcm::Origin as XcmOrigin;
-use polkadot_parachain_primitives::primitives::Sibling;
+use polkadot_parachain_primitives::primitives::{Id as ParaId, Sibling};
 use sp_core::{bytes::to_hex, crypto::Ss58Codec, H160};
 use sp_runtime::{
 	helpers_128bit::multiply_by_rational_with_rounding, traits::AccountIdConversion, BoundedVec,
@@ -936,3 +944,286 @@ fn update_token_exchange_rate_limt_error() {
 		);
 	})
 }
+
+#[test]
+fn moonbeam_parachain_staking_delegate_and_bond_less() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::MoonbeamParachainStaking;
+		let sibling_account: [u8; 20] = Sibling::from(2030).into_account_truncating();
+		let delegator = Delegator::Ethereum(SlpV2::derivative_account_id_20(sibling_account, 0));
+		let candidate = H160::from_low_u64_be(1);
+		let dest_location = staking_protocol.info().remote_dest_location;
+
+		assert_ok!(SlpV2::set_protocol_configuration(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			ProtocolConfiguration { unlock_period: TimeUnit::Round(28), ..CONFIGURATION }
+		));
+		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), staking_protocol, None));
+		assert_eq!(
+			DelegatorByStakingProtocolAndDelegatorIndex::<Test>::get(staking_protocol, 0),
+			Some(delegator.clone())
+		);
+
+		let task = ParachainStaking::Delegate(candidate, 100, Default::default(), 0, 0, 0);
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(RuntimeOrigin::root(), delegator.clone(), task),
+			SlpV2Error::<Test>::ValidatorNotFound
+		);
+		assert_ok!(SlpV2::add_validator(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::MoonbeamParachainStaking(candidate)
+		));
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			task
+		));
+		expect_event(SlpV2Event::SendXcmTask {
+			query_id: Some(0),
+			delegator: delegator.clone(),
+			task: XcmTask::MoonbeamParachainStaking(task),
+			pending_status: Some(PendingStatus::MoonbeamParachainStaking(
+				MoonbeamParachainStakingPendingStatus::Delegate(delegator.clone(), candidate, 100),
+			)),
+			dest_location,
+		});
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			0,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		// A handled response can not be applied twice.
+		assert_eq!(PendingStatusByQueryId::<Test>::get(0), None);
+		assert_noop!(
+			SlpV2::notify_moonbeam_parachain_staking(
+				XcmOrigin::Response(Parent.into()).into(),
+				0,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			),
+			SlpV2Error::<Test>::PendingStatusNotFound
+		);
+
+		RelaychainBlockNumber::set(100);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Round(1))
+		));
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::ScheduleDelegatorBondLess(candidate, 40)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator.clone()),
+			Some(Ledger::MoonbeamParachainStaking(MoonbeamParachainStakingLedger {
+				total: 100,
+				delegations: BoundedVec::try_from(vec![MoonbeamDelegation {
+					candidate,
+					amount: 100
+				}])
+				.unwrap(),
+				requests: BoundedVec::try_from(vec![MoonbeamScheduledRequest {
+					candidate,
+					amount: 40,
+					unlock_time: TimeUnit::Round(29)
+				}])
+				.unwrap(),
+			}))
+		);
+
+		let delegator_account = match delegator.clone() {
+			Delegator::Ethereum(account) => account,
+			_ => unreachable!(),
+		};
+		assert_noop!(
+			SlpV2::moonbeam_parachain_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				ParachainStaking::ExecuteDelegationRequest(H160::zero(), candidate)
+			),
+			SlpV2Error::<Test>::InvalidParameter
+		);
+		assert_ok!(SlpV2::moonbeam_parachain_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			ParachainStaking::ExecuteDelegationRequest(delegator_account, candidate)
+		));
+		assert_ok!(SlpV2::notify_moonbeam_parachain_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			2,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator),
+			Some(Ledger::MoonbeamParachainStaking(MoonbeamParachainStakingLedger {
+				total: 60,
+				delegations: BoundedVec::try_from(vec![MoonbeamDelegation {
+					candidate,
+					amount: 60
+				}])
+				.unwrap(),
+				requests: Default::default(),
+			}))
+		);
+	})
+}
+
+#[test]
+fn polkadot_staking_bond_unbond_and_withdraw() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		let parachain_account: AccountId = ParaId::from(2030).into_account_truncating();
+		let delegator =
+			Delegator::Substrate(SlpV2::derivative_account_id(parachain_account, 0).unwrap());
+		let task = PolkadotStaking::Bond(100, RewardDestination::Staked);
+		let dest_location = staking_protocol.info().remote_dest_location;
+
+		assert_ok!(SlpV2::set_protocol_configuration(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			CONFIGURATION
+		));
+		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), staking_protocol, None));
+		assert_ok!(SlpV2::polkadot_staking(RuntimeOrigin::root(), delegator.clone(), task.clone()));
+		expect_event(SlpV2Event::SendXcmTask {
+			query_id: Some(0),
+			delegator: delegator.clone(),
+			task: XcmTask::PolkadotStaking(task),
+			pending_status: Some(PendingStatus::PolkadotStaking(
+				PolkadotStakingPendingStatus::Bond(delegator.clone(), 100),
+			)),
+			dest_location,
+		});
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			0,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		// A handled response can not be applied twice.
+		assert_eq!(PendingStatusByQueryId::<Test>::get(0), None);
+		assert_noop!(
+			SlpV2::notify_polkadot_staking(
+				XcmOrigin::Response(Parent.into()).into(),
+				0,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			),
+			SlpV2Error::<Test>::PendingStatusNotFound
+		);
+
+		RelaychainBlockNumber::set(100);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Era(1))
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Unbond(60)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			1,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Rebond(20)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			2,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator.clone()),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				active: 60,
+				unlocking: BoundedVec::try_from(vec![PolkadotUnlockingRecord {
+					amount: 40,
+					unlock_time: TimeUnit::Era(10)
+				}])
+				.unwrap(),
+			}))
+		);
+
+		RelaychainBlockNumber::set(200);
+		assert_ok!(SlpV2::update_ongoing_time_unit(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			Some(TimeUnit::Era(10))
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::WithdrawUnbonded(0)
+		));
+		assert_ok!(SlpV2::notify_polkadot_staking(
+			XcmOrigin::Response(Parent.into()).into(),
+			3,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		assert_eq!(
+			LedgerByStakingProtocolAndDelegator::<Test>::get(staking_protocol, delegator),
+			Some(Ledger::PolkadotStaking(PolkadotStakingLedger {
+				active: 60,
+				unlocking: Default::default(),
+			}))
+		);
+	})
+}
+
+#[test]
+fn polkadot_staking_nominate() {
+	new_test_ext().execute_with(|| {
+		let staking_protocol = StakingProtocol::PolkadotStaking;
+		let parachain_account: AccountId = ParaId::from(2030).into_account_truncating();
+		let delegator =
+			Delegator::Substrate(SlpV2::derivative_account_id(parachain_account, 0).unwrap());
+		let validator = AccountId::new([1u8; 32]);
+
+		assert_ok!(SlpV2::set_protocol_configuration(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			CONFIGURATION
+		));
+		assert_ok!(SlpV2::add_delegator(RuntimeOrigin::root(), staking_protocol, None));
+		assert_noop!(
+			SlpV2::polkadot_staking(
+				RuntimeOrigin::root(),
+				delegator.clone(),
+				PolkadotStaking::Nominate
+			),
+			SlpV2Error::<Test>::ValidatorNotFound
+		);
+
+		assert_ok!(SlpV2::add_validator(
+			RuntimeOrigin::root(),
+			staking_protocol,
+			delegator.clone(),
+			Validator::PolkadotStaking(validator)
+		));
+		assert_ok!(SlpV2::polkadot_staking(
+			RuntimeOrigin::root(),
+			delegator.clone(),
+			PolkadotStaking::Nominate
+		));
+		expect_event(SlpV2Event::SendXcmTask {
+			query_id: None,
+			delegator,
+			task: XcmTask::PolkadotStaking(PolkadotStaking::Nominate),
+			pending_status: None,
+			dest_location: staking_protocol.info().remote_dest_location,
+		});
+	})
+}
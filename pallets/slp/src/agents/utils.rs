@@ -16,17 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 use crate::{
-	blake2_256, pallet::Error, AccountIdOf, Config, Decode, DelegatorLedgerXcmUpdateQueue,
-	LedgerUpdateEntry, MinimumsAndMaximums, Pallet, TrailingZeroInput, Validators,
-	ValidatorsByDelegatorUpdateEntry, ValidatorsByDelegatorXcmUpdateQueue, ASTR, DOT, GLMR, H160,
-	KSM, MANTA, MOVR, PHA,
+	blake2_256, pallet::Error, primitives::RelayStakingLedger, AccountIdOf, BalanceOf, Config,
+	Decode, DelegatorLedgerXcmUpdateQueue, LedgerUpdateEntry, MinimumsAndMaximums, Pallet,
+	TrailingZeroInput, Validators, ValidatorsByDelegatorUpdateEntry,
+	ValidatorsByDelegatorXcmUpdateQueue, ASTR, DOT, GLMR, H160, KSM, MANTA, MOVR, PHA,
 };
 use bifrost_primitives::CurrencyId;
 use bifrost_xcm_interface::traits::parachains;
-use frame_support::ensure;
+use frame_support::{ensure, storage::storage_prefix, Blake2_128Concat, StorageHasher};
 use parity_scale_codec::Encode;
 use sp_core::Get;
+use sp_runtime::traits::BlakeTwo256;
 use sp_std::prelude::*;
+use sp_trie::{read_trie_value, LayoutV1, StorageProof};
 use xcm::v3::{prelude::*, MultiLocation};
 
 // Some untilities.
@@ -96,6 +98,29 @@ impl<T: Config> Pallet<T> {
 		Ok(validator_set)
 	}
 
+	/// Read the `Staking::Ledger` entry of `account` from a relay chain storage proof, verified
+	/// against the relay parent state root.
+	pub fn read_relay_staking_ledger(
+		account: &[u8; 32],
+		proof: Vec<Vec<u8>>,
+	) -> Result<Option<RelayStakingLedger<BalanceOf<T>>>, Error<T>> {
+		let root = T::RelayStateRoot::get().ok_or(Error::<T>::RelayStateRootNotFound)?;
+
+		let mut key = storage_prefix(b"Staking", b"Ledger").to_vec();
+		key.extend(Blake2_128Concat::hash(&account[..]));
+
+		let db = StorageProof::new(proof).into_memory_db::<BlakeTwo256>();
+		let value = read_trie_value::<LayoutV1<BlakeTwo256>, _>(&db, &root, &key, None, None)
+			.map_err(|_| Error::<T>::InvalidStorageProof)?;
+
+		match value {
+			Some(value) => RelayStakingLedger::decode(&mut &value[..])
+				.map(Some)
+				.map_err(|_| Error::<T>::DecodingError),
+			None => Ok(None),
+		}
+	}
+
 	pub fn multilocation_to_account(who: &MultiLocation) -> Result<AccountIdOf<T>, Error<T>> {
 		// Get the delegator account id in Kusama/Polkadot network
 		let account_32 = Self::multilocation_to_account_32(who)?;
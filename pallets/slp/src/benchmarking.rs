@@ -19,12 +19,18 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::*;
+use crate::{
+	primitives::{RelayStakingLedger, RelayUnlockChunk},
+	*,
+};
 use bifrost_primitives::{DOT, VDOT};
 use frame_benchmarking::v2::*;
-use frame_support::{assert_ok, PalletId};
+use frame_support::{
+	assert_ok, storage::storage_prefix, Blake2_128Concat, PalletId, StorageHasher,
+};
 use frame_system::RawOrigin as SystemOrigin;
-use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedFrom};
+use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, StaticLookup, UniqueSaturatedFrom};
+use sp_trie::{LayoutV1, MemoryDB, TrieDBMutBuilder, TrieMut};
 
 const DELEGATOR1: MultiLocation =
 	MultiLocation { parents: 1, interior: X1(AccountId32 { network: None, id: [1u8; 32] }) };
@@ -727,6 +733,7 @@ mod benchmarks {
 			(1000u32, Permill::from_parts(100_0000u32)),
 		);
 		HostingFees::<T>::insert(KSM, (Permill::from_parts(100_0000u32), DELEGATOR1));
+		DelegatorTuneAllowance::<T>::insert(KSM, DELEGATOR1, BalanceOf::<T>::from(10u32));
 
 		orml_tokens::Pallet::<T>::deposit(
 			VKSM,
@@ -1207,6 +1214,56 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_delegator_ledger_by_proof(n: Linear<1, MAX_PROOF_NODES>) -> Result<(), BenchmarkError> {
+		let caller: AccountIdOf<T> = whitelisted_caller();
+		DelegatorsMultilocation2Index::<T>::insert(KSM, DELEGATOR1, 0);
+		DelegatorLedgers::<T>::insert(
+			KSM,
+			DELEGATOR1,
+			Ledger::Substrate(SubstrateLedger {
+				account: DELEGATOR1,
+				total: 1000u32.into(),
+				active: 1000u32.into(),
+				unlocking: vec![],
+			}),
+		);
+
+		// A relay chain state holding only the ledger of the delegator.
+		let mut key = storage_prefix(b"Staking", b"Ledger").to_vec();
+		key.extend(Blake2_128Concat::hash(&[1u8; 32]));
+		let ledger = RelayStakingLedger::<BalanceOf<T>> {
+			stash: [1u8; 32],
+			total: 1150u32.into(),
+			active: 1100u32.into(),
+			unlocking: vec![RelayUnlockChunk { value: 50u32.into(), era: 10 }],
+		};
+		let mut value = ledger.encode();
+		value.extend(Vec::<u32>::new().encode());
+
+		let mut db = MemoryDB::<BlakeTwo256>::default();
+		let mut root = Default::default();
+		{
+			let mut trie =
+				TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+			trie.insert(&key, &value)
+				.map_err(|_| BenchmarkError::Stop("trie insertion failed"))?;
+		}
+		T::BenchmarkHelper::set_relay_state_root(root);
+
+		// Pad the proof with unused nodes up to `n` nodes.
+		let mut proof: Vec<Vec<u8>> = db.drain().into_values().map(|(node, _)| node).collect();
+		for i in proof.len() as u32..n {
+			proof.push(vec![i as u8; 512]);
+		}
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), KSM, Box::new(DELEGATOR1), proof);
+
+		assert!(DelegatorTuneAllowance::<T>::contains_key(KSM, DELEGATOR1));
+		Ok(())
+	}
+
 	//   `cargo test -p bifrost-slp --all-features`
 	impl_benchmark_test_suite!(
 		Pallet,
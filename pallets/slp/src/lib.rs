@@ -21,7 +21,11 @@
 
 extern crate core;
 
-use crate::{agents::PolkadotAgent, Junction::GeneralIndex, Junctions::X2};
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::traits::BenchmarkHelper;
+use crate::{
+	agents::PolkadotAgent, primitives::UnlockChunk, Junction::GeneralIndex, Junctions::X2,
+};
 pub use crate::{
 	primitives::{
 		Delays, LedgerUpdateEntry, MinimumsMaximums, QueryId, SubstrateLedger,
@@ -51,7 +55,7 @@ use frame_system::{
 use orml_traits::MultiCurrency;
 pub use primitives::Ledger;
 use sp_arithmetic::{per_things::Permill, traits::Zero};
-use sp_core::{bounded::BoundedVec, H160};
+use sp_core::{bounded::BoundedVec, H160, H256};
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::{CheckedAdd, CheckedSub, Convert, TrailingZeroInput, UniqueSaturatedFrom};
 use sp_std::{boxed::Box, vec, vec::Vec};
@@ -89,6 +93,8 @@ pub type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<
 >>::CurrencyId;
 const SIX_MONTHS: u32 = 5 * 60 * 24 * 180;
 const ITERATE_LENGTH: usize = 100;
+/// The maximum number of trie nodes in a relay chain storage proof.
+const MAX_PROOF_NODES: u32 = 64;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -175,6 +181,14 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The state root of the relay parent, which the relay chain storage proofs are verified
+		/// against.
+		type RelayStateRoot: Get<Option<H256>>;
+
+		/// Sets up the relay chain state root in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
 	}
 
 	#[pallet::error]
@@ -262,6 +276,14 @@ pub mod pallet {
 		ExceedLimit,
 		InvalidPageNumber,
 		NoMoreValidatorBoostListForCurrency,
+		RelayStateRootNotFound,
+		InvalidStorageProof,
+		/// The tuning exceeds the rewards proven by the relay chain.
+		ExceedTuneAllowance,
+		/// The ledger of the delegator is proven, only the control origin can overwrite it.
+		DelegatorLedgerProven,
+		/// The ledger of the delegator has not been proven by a storage proof.
+		DelegatorLedgerNotProven,
 	}
 
 	#[pallet::event]
@@ -528,6 +550,12 @@ pub mod pallet {
 			currency_id: CurrencyId,
 			amount: BalanceOf<T>,
 		},
+		DelegatorLedgerProven {
+			currency_id: CurrencyId,
+			delegator: MultiLocation,
+			ledger: Ledger<BalanceOf<T>>,
+			tune_allowance: BalanceOf<T>,
+		},
 	}
 
 	/// The current storage version, we set to 3 our new version(after migrate stroage from vec t
@@ -684,6 +712,21 @@ pub mod pallet {
 	pub type SupplementFeeAccountWhitelist<T> =
 		StorageMap<_, Blake2_128Concat, CurrencyId, Vec<(MultiLocation, Hash<T>)>>;
 
+	/// The rewards of a delegator proven by a relay chain storage proof but not yet tuned into the
+	/// exchange rate. Once a delegator's ledger is proven, its tuning is bounded by this amount
+	/// and only the control origin can overwrite its ledger.
+	/// Currency Id + Delegator Id => tune allowance
+	#[pallet::storage]
+	pub type DelegatorTuneAllowance<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CurrencyId,
+		Blake2_128Concat,
+		MultiLocation,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -1237,6 +1280,9 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::charge_host_fee_and_tune_vtoken_exchange_rate())]
 		/// Charge staking host fee, tune vtoken/token exchange rate, and update delegator ledger
 		/// for single delegator.
+		///
+		/// KSM and DOT delegators must have a ledger proven by `set_delegator_ledger_by_proof`,
+		/// and are tuned up to the proven rewards.
 		pub fn charge_host_fee_and_tune_vtoken_exchange_rate(
 			origin: OriginFor<T>,
 			currency_id: CurrencyId,
@@ -1258,6 +1304,17 @@ pub mod pallet {
 			let max_to_increase = max_permill.mul_floor(pool_token);
 			ensure!(value <= max_to_increase, Error::<T>::GreaterThanMaximum);
 
+			// Relay chain delegators can only be tuned up to the rewards proven by
+			// `set_delegator_ledger_by_proof`.
+			if currency_id == KSM || currency_id == DOT {
+				let delegator = who.as_ref().ok_or(Error::<T>::DelegatorLedgerNotProven)?;
+				let allowance = DelegatorTuneAllowance::<T>::get(currency_id, delegator)
+					.ok_or(Error::<T>::DelegatorLedgerNotProven)?;
+				let allowance =
+					allowance.checked_sub(&value).ok_or(Error::<T>::ExceedTuneAllowance)?;
+				DelegatorTuneAllowance::<T>::insert(currency_id, delegator, allowance);
+			}
+
 			// Ensure this tune is within limit.
 			// Get current TimeUnit.
 			let current_time_unit = T::VtokenMinting::get_ongoing_time_unit(currency_id)
@@ -1522,7 +1579,7 @@ pub mod pallet {
 			ledger: Box<Option<Ledger<BalanceOf<T>>>>,
 		) -> DispatchResult {
 			// Check the validity of origin
-			Self::ensure_authorized(origin, currency_id)?;
+			Self::ensure_ledger_authorized(origin, currency_id, &who)?;
 
 			// Update the ledger.
 			DelegatorLedgers::<T>::mutate_exists(currency_id, &*who, |old_ledger| {
@@ -1764,7 +1821,14 @@ pub mod pallet {
 			#[pallet::compact] query_id: QueryId,
 		) -> DispatchResult {
 			// Ensure origin
-			Self::ensure_authorized(origin, currency_id)?;
+			let (entry, _) = DelegatorLedgerXcmUpdateQueue::<T>::get(query_id)
+				.ok_or(Error::<T>::QueryNotExist)?;
+			let delegator = match entry {
+				LedgerUpdateEntry::Substrate(entry) => entry.delegator_id,
+				LedgerUpdateEntry::Moonbeam(entry) | LedgerUpdateEntry::ParachainStaking(entry) =>
+					entry.delegator_id,
+			};
+			Self::ensure_ledger_authorized(origin, currency_id, &delegator)?;
 			Self::get_ledger_update_agent_then_process(query_id, true)?;
 			Ok(())
 		}
@@ -2195,6 +2259,65 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Update the ledger of a relay chain delegator from a storage proof of its
+		/// `Staking::Ledger` entry, verified against the relay parent state root.
+		///
+		/// The active amount is never raised above the recorded one. The proven surplus is the
+		/// delegator's untuned rewards and bounds `charge_host_fee_and_tune_vtoken_exchange_rate`.
+		#[pallet::call_index(49)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_delegator_ledger_by_proof(proof.len() as u32))]
+		pub fn set_delegator_ledger_by_proof(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: Box<MultiLocation>,
+			proof: Vec<Vec<u8>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(currency_id == KSM || currency_id == DOT, Error::<T>::NotSupportedCurrencyId);
+			ensure!(
+				DelegatorsMultilocation2Index::<T>::contains_key(currency_id, &*who),
+				Error::<T>::DelegatorNotExist
+			);
+			ensure!(proof.len() <= MAX_PROOF_NODES as usize, Error::<T>::InvalidStorageProof);
+
+			let account_32 = Self::multilocation_to_account_32(&who)?;
+			let proven_ledger = Self::read_relay_staking_ledger(&account_32, proof)?
+				.ok_or(Error::<T>::DelegatorNotBonded)?;
+
+			let old_active = match DelegatorLedgers::<T>::get(currency_id, &*who) {
+				Some(Ledger::Substrate(old_sub_ledger)) => old_sub_ledger.active,
+				_ => Zero::zero(),
+			};
+			let tune_allowance =
+				proven_ledger.active.checked_sub(&old_active).unwrap_or_else(Zero::zero);
+			let active = proven_ledger.active.min(old_active);
+
+			let mut total = active;
+			let mut unlocking = Vec::with_capacity(proven_ledger.unlocking.len());
+			for chunk in proven_ledger.unlocking {
+				total = total.checked_add(&chunk.value).ok_or(Error::<T>::OverFlow)?;
+				unlocking.push(UnlockChunk {
+					value: chunk.value,
+					unlock_time: TimeUnit::Era(chunk.era),
+				});
+			}
+			let ledger =
+				Ledger::Substrate(SubstrateLedger { account: *who, total, active, unlocking });
+
+			DelegatorLedgers::<T>::insert(currency_id, &*who, ledger.clone());
+			DelegatorTuneAllowance::<T>::insert(currency_id, &*who, tune_allowance);
+
+			Pallet::<T>::deposit_event(Event::DelegatorLedgerProven {
+				currency_id,
+				delegator: *who,
+				ledger,
+				tune_allowance,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -2215,6 +2338,22 @@ pub mod pallet {
 			}
 		}
 
+		/// Ensure the origin can overwrite the ledger of `delegator`. Once the ledger is proven,
+		/// the operator can no longer overwrite it.
+		fn ensure_ledger_authorized(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			delegator: &MultiLocation,
+		) -> Result<(), Error<T>> {
+			if DelegatorTuneAllowance::<T>::contains_key(currency_id, delegator) {
+				T::ControlOrigin::ensure_origin(origin)
+					.map_err(|_| Error::<T>::DelegatorLedgerProven)?;
+				Ok(())
+			} else {
+				Self::ensure_authorized(origin, currency_id)
+			}
+		}
+
 		pub(crate) fn get_currency_staking_agent(
 			currency_id: CurrencyId,
 		) -> Result<StakingAgentBoxType<T>, Error<T>> {
@@ -40,7 +40,7 @@ use frame_system::{EnsureRoot, EnsureSignedBy};
 use hex_literal::hex;
 use orml_traits::{location::RelativeReserveProvider, parameter_type_with_key};
 use parity_scale_codec::{Decode, Encode};
-use sp_core::{bounded::BoundedVec, hashing::blake2_256};
+use sp_core::{bounded::BoundedVec, hashing::blake2_256, H256};
 pub use sp_runtime::Perbill;
 use sp_runtime::{
 	traits::{AccountIdConversion, Convert, TrailingZeroInput},
@@ -85,6 +85,7 @@ impl bifrost_stable_pool::Config for Runtime {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Runtime>;
 	type CurrencyIdRegister = AssetIdMaps<Runtime>;
+	type Twap = ();
 }
 
 pub struct EnsurePoolAssetId;
@@ -522,6 +523,7 @@ impl QueryResponseManager<QueryId, xcm::v4::Location, u64, RuntimeCall>
 
 parameter_types! {
 	pub BifrostTreasuryAccount: AccountId = PalletId(*b"bf/trsry").into_account_truncating();
+	pub static RelayStateRoot: Option<H256> = None;
 }
 
 impl Config for Runtime {
@@ -545,6 +547,18 @@ impl Config for Runtime {
 	type StablePoolHandler = StablePool;
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = BifrostTreasuryAccount;
+	type RelayStateRoot = RelayStateRoot;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SlpBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SlpBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper for SlpBenchmarkHelper {
+	fn set_relay_state_root(root: H256) {
+		RelayStateRoot::set(Some(root));
+	}
 }
 
 pub struct XcmDestWeightAndFee;
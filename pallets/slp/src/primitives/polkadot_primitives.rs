@@ -50,6 +50,26 @@ pub struct UnlockChunk<Balance> {
 	pub unlock_time: TimeUnit,
 }
 
+/// The `Staking::Ledger` entry of a relay chain account, decoded from a storage proof. The
+/// trailing fields are not decoded.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RelayStakingLedger<Balance> {
+	pub stash: [u8; 32],
+	#[codec(compact)]
+	pub total: Balance,
+	#[codec(compact)]
+	pub active: Balance,
+	pub unlocking: Vec<RelayUnlockChunk<Balance>>,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RelayUnlockChunk<Balance> {
+	#[codec(compact)]
+	pub value: Balance,
+	#[codec(compact)]
+	pub era: u32,
+}
+
 /// A type for substrate ledger updating entries
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct SubstrateLedgerUpdateEntry<Balance> {
@@ -18,12 +18,25 @@
 
 #![cfg(test)]
 
-use crate::{mocks::mock_kusama::*, *};
+use crate::{
+	mocks::mock_kusama::*,
+	primitives::{
+		RelayStakingLedger, RelayUnlockChunk, SubstrateLedgerUpdateEntry,
+		SubstrateLedgerUpdateOperation, UnlockChunk,
+	},
+	*,
+};
 use bifrost_primitives::currency::{KSM, VKSM};
 use bifrost_vtoken_minting::{OngoingTimeUnit, TokenPool};
-use frame_support::{assert_ok, PalletId};
+use frame_support::{
+	assert_noop, assert_ok, storage::storage_prefix, Blake2_128Concat, PalletId, StorageHasher,
+};
 use orml_traits::MultiCurrency;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256},
+	StateVersion,
+};
+use sp_state_machine::{prove_read, InMemoryBackend};
 use xcm::v3::prelude::*;
 
 const SUBACCOUNT_0_32: [u8; 32] =
@@ -240,6 +253,27 @@ fn charge_host_fee_and_tune_vtoken_exchange_rate_works() {
 		assert_ok!(Currencies::deposit(VKSM, &ALICE, 100));
 		assert_ok!(Slp::increase_token_pool(RuntimeOrigin::signed(ALICE), KSM, 100));
 
+		// The delegator ledger is not proven.
+		assert_noop!(
+			Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				100,
+				Some(subaccount_0_location)
+			),
+			Error::<Runtime>::DelegatorLedgerNotProven
+		);
+		DelegatorTuneAllowance::<Runtime>::insert(KSM, subaccount_0_location, 100);
+		assert_noop!(
+			Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				100,
+				None
+			),
+			Error::<Runtime>::DelegatorLedgerNotProven
+		);
+
 		// call the charge_host_fee_and_tune_vtoken_exchange_rate
 		assert_ok!(Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
 			RuntimeOrigin::signed(ALICE),
@@ -480,3 +514,233 @@ fn test_construct_xcm() {
 		assert_eq!(fee, BalanceOf::<Runtime>::from(100u32));
 	});
 }
+
+fn relay_staking_ledger_proof(
+	account: [u8; 32],
+	ledger: RelayStakingLedger<u128>,
+) -> (H256, Vec<Vec<u8>>) {
+	let mut key = storage_prefix(b"Staking", b"Ledger").to_vec();
+	key.extend(Blake2_128Concat::hash(&account[..]));
+	// The relay chain ledger ends with the legacy claimed rewards.
+	let mut value = ledger.encode();
+	value.extend(Vec::<u32>::new().encode());
+
+	let backend = InMemoryBackend::<BlakeTwo256>::from((
+		vec![(None, vec![(key.clone(), Some(value)), (vec![1u8; 32], Some(vec![1u8]))])],
+		StateVersion::V1,
+	));
+	let root = *backend.root();
+	let proof = prove_read(backend, &[&key[..]]).unwrap();
+	(root, proof.into_iter_nodes().collect())
+}
+
+#[test]
+fn set_delegator_ledger_by_proof_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		OngoingTimeUnit::<Runtime>::insert(KSM, TimeUnit::Era(1));
+		DelegatorsIndex2Multilocation::<Runtime>::insert(KSM, 0, SUBACCOUNT_0_LOCATION);
+		DelegatorsMultilocation2Index::<Runtime>::insert(KSM, SUBACCOUNT_0_LOCATION, 0);
+		DelegatorLedgers::<Runtime>::insert(
+			KSM,
+			SUBACCOUNT_0_LOCATION,
+			Ledger::Substrate(SubstrateLedger {
+				account: SUBACCOUNT_0_LOCATION,
+				total: 1000,
+				active: 1000,
+				unlocking: vec![],
+			}),
+		);
+
+		// The delegator earned 100 of rewards and is unlocking 50.
+		let (root, proof) = relay_staking_ledger_proof(
+			SUBACCOUNT_0_32,
+			RelayStakingLedger {
+				stash: SUBACCOUNT_0_32,
+				total: 1150,
+				active: 1100,
+				unlocking: vec![RelayUnlockChunk { value: 50, era: 10 }],
+			},
+		);
+
+		assert_noop!(
+			Slp::set_delegator_ledger_by_proof(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				Box::new(SUBACCOUNT_0_LOCATION),
+				proof.clone()
+			),
+			Error::<Runtime>::RelayStateRootNotFound
+		);
+
+		RelayStateRoot::set(Some(H256::repeat_byte(1)));
+		assert_noop!(
+			Slp::set_delegator_ledger_by_proof(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				Box::new(SUBACCOUNT_0_LOCATION),
+				proof.clone()
+			),
+			Error::<Runtime>::InvalidStorageProof
+		);
+
+		RelayStateRoot::set(Some(root));
+		assert_noop!(
+			Slp::set_delegator_ledger_by_proof(
+				RuntimeOrigin::signed(BOB),
+				MOVR,
+				Box::new(SUBACCOUNT_0_LOCATION),
+				proof.clone()
+			),
+			Error::<Runtime>::NotSupportedCurrencyId
+		);
+		assert_ok!(Slp::set_delegator_ledger_by_proof(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			Box::new(SUBACCOUNT_0_LOCATION),
+			proof
+		));
+
+		// The rewards are left to be tuned.
+		assert_eq!(
+			DelegatorLedgers::<Runtime>::get(KSM, SUBACCOUNT_0_LOCATION),
+			Some(Ledger::Substrate(SubstrateLedger {
+				account: SUBACCOUNT_0_LOCATION,
+				total: 1050,
+				active: 1000,
+				unlocking: vec![UnlockChunk { value: 50, unlock_time: TimeUnit::Era(10) }],
+			}))
+		);
+		assert_eq!(DelegatorTuneAllowance::<Runtime>::get(KSM, SUBACCOUNT_0_LOCATION), Some(100));
+
+		let treasury_id: AccountId = PalletId(*b"bf/trsry").into_account_truncating();
+		let treasury_location = MultiLocation {
+			parents: 0,
+			interior: X1(AccountId32 { network: None, id: treasury_id.into() }),
+		};
+		assert_ok!(Slp::set_hosting_fees(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some((Permill::from_percent(20), treasury_location))
+		));
+		assert_ok!(Slp::set_currency_tune_exchange_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some((2, Permill::from_percent(100)))
+		));
+		assert_ok!(Currencies::deposit(VKSM, &ALICE, 1000));
+		assert_ok!(Slp::increase_token_pool(RuntimeOrigin::signed(ALICE), KSM, 1000));
+
+		assert_noop!(
+			Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				101,
+				Some(SUBACCOUNT_0_LOCATION)
+			),
+			Error::<Runtime>::ExceedTuneAllowance
+		);
+		assert_ok!(Slp::charge_host_fee_and_tune_vtoken_exchange_rate(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			100,
+			Some(SUBACCOUNT_0_LOCATION)
+		));
+		assert_eq!(DelegatorTuneAllowance::<Runtime>::get(KSM, SUBACCOUNT_0_LOCATION), Some(0));
+		assert_eq!(
+			DelegatorLedgers::<Runtime>::get(KSM, SUBACCOUNT_0_LOCATION),
+			Some(Ledger::Substrate(SubstrateLedger {
+				account: SUBACCOUNT_0_LOCATION,
+				total: 1150,
+				active: 1100,
+				unlocking: vec![UnlockChunk { value: 50, unlock_time: TimeUnit::Era(10) }],
+			}))
+		);
+	});
+}
+
+#[test]
+fn proven_ledger_cannot_be_overwritten_by_operator() {
+	ExtBuilder::default().build().execute_with(|| {
+		DelegatorsIndex2Multilocation::<Runtime>::insert(KSM, 0, SUBACCOUNT_0_LOCATION);
+		DelegatorsMultilocation2Index::<Runtime>::insert(KSM, SUBACCOUNT_0_LOCATION, 0);
+		assert_ok!(Slp::set_operate_origin(RuntimeOrigin::signed(ALICE), KSM, Some(BOB)));
+
+		let ledger = Ledger::Substrate(SubstrateLedger {
+			account: SUBACCOUNT_0_LOCATION,
+			total: 1000,
+			active: 1000,
+			unlocking: vec![],
+		});
+		// The operator can set the ledger before it is proven.
+		assert_ok!(Slp::set_delegator_ledger(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			Box::new(SUBACCOUNT_0_LOCATION),
+			Box::new(Some(ledger.clone()))
+		));
+
+		let (root, proof) = relay_staking_ledger_proof(
+			SUBACCOUNT_0_32,
+			RelayStakingLedger {
+				stash: SUBACCOUNT_0_32,
+				total: 1100,
+				active: 1100,
+				unlocking: vec![],
+			},
+		);
+		RelayStateRoot::set(Some(root));
+		assert_ok!(Slp::set_delegator_ledger_by_proof(
+			RuntimeOrigin::signed(BOB),
+			KSM,
+			Box::new(SUBACCOUNT_0_LOCATION),
+			proof
+		));
+
+		// Lowering the active amount would inflate the tune allowance of the next proof.
+		let lowered_ledger = Ledger::Substrate(SubstrateLedger {
+			account: SUBACCOUNT_0_LOCATION,
+			total: 500,
+			active: 500,
+			unlocking: vec![],
+		});
+		assert_noop!(
+			Slp::set_delegator_ledger(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				Box::new(SUBACCOUNT_0_LOCATION),
+				Box::new(Some(lowered_ledger.clone()))
+			),
+			Error::<Runtime>::DelegatorLedgerProven
+		);
+
+		DelegatorLedgerXcmUpdateQueue::<Runtime>::insert(
+			0,
+			(
+				LedgerUpdateEntry::Substrate(SubstrateLedgerUpdateEntry {
+					currency_id: KSM,
+					delegator_id: SUBACCOUNT_0_LOCATION,
+					update_operation: SubstrateLedgerUpdateOperation::Unlock,
+					amount: 500,
+					unlock_time: Some(TimeUnit::Era(10)),
+				}),
+				1000,
+			),
+		);
+		assert_noop!(
+			Slp::confirm_delegator_ledger_query_response(RuntimeOrigin::signed(BOB), KSM, 0),
+			Error::<Runtime>::DelegatorLedgerProven
+		);
+
+		// The control origin can still overwrite it.
+		assert_ok!(Slp::set_delegator_ledger(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Box::new(SUBACCOUNT_0_LOCATION),
+			Box::new(Some(lowered_ledger.clone()))
+		));
+		assert_eq!(
+			DelegatorLedgers::<Runtime>::get(KSM, SUBACCOUNT_0_LOCATION),
+			Some(lowered_ledger)
+		);
+	});
+}
@@ -242,3 +242,15 @@ impl<AccountId, CurrencyId, Balance> OnRefund<AccountId, CurrencyId, Balance> fo
 		0
 	}
 }
+
+/// Sets up the state the benchmarks of the pallet depend on.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Make `root` the relay chain state root that storage proofs are verified against.
+	fn set_relay_state_root(root: sp_core::H256);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn set_relay_state_root(_root: sp_core::H256) {}
+}
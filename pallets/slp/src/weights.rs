@@ -99,6 +99,7 @@ pub trait WeightInfo {
 	fn remove_from_validator_boot_list() -> Weight;
 	fn convert_treasury_vtoken() -> Weight;
 	fn clean_outdated_validator_boost_list() -> Weight;
+	fn set_delegator_ledger_by_proof(n: u32, ) -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -847,4 +848,24 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Slp DelegatorsMultilocation2Index (r:1 w:0)
+	/// Proof Skipped: Slp DelegatorsMultilocation2Index (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ParachainSystem ValidationData (r:1 w:0)
+	/// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Slp DelegatorLedgers (r:1 w:1)
+	/// Proof Skipped: Slp DelegatorLedgers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Slp DelegatorTuneAllowance (r:0 w:1)
+	/// Proof Skipped: Slp DelegatorTuneAllowance (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 64]`.
+	fn set_delegator_ledger_by_proof(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3863`
+		// Minimum execution time: 41_263_000 picoseconds.
+		Weight::from_parts(43_918_512, 3863)
+			// Standard Error: 2_614
+			.saturating_add(Weight::from_parts(1_127_384, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -437,6 +437,9 @@ impl bifrost_slp::Config for Test {
 	type StablePoolHandler = ();
 	type AssetIdMaps = AssetIdMaps<Test>;
 	type TreasuryAccount = BifrostFeeAccount;
+	type RelayStateRoot = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -505,6 +508,7 @@ impl bifrost_stable_pool::Config for Test {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Test>;
 	type CurrencyIdRegister = AssetIdMaps<Test>;
+	type Twap = ();
 }
 
 // Pallet slpx configuration
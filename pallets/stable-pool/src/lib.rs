@@ -31,7 +31,7 @@ pub use weights::*;
 pub mod traits;
 
 use bifrost_primitives::{
-	CurrencyId, CurrencyIdConversion, CurrencyIdExt, CurrencyIdRegister, TimeUnit,
+	CurrencyId, CurrencyIdConversion, CurrencyIdExt, CurrencyIdRegister, TimeUnit, TwapProvider,
 	VtokenMintingOperator,
 };
 pub use bifrost_stable_asset::{
@@ -102,6 +102,9 @@ pub mod pallet {
 		type CurrencyIdConversion: CurrencyIdConversion<AssetIdOf<Self>>;
 
 		type CurrencyIdRegister: CurrencyIdRegister<AssetIdOf<Self>>;
+
+		/// Notified after every swap to accumulate the prices of the pool.
+		type Twap: TwapProvider;
 	}
 
 	#[pallet::error]
@@ -843,6 +846,7 @@ impl<T: Config> Pallet<T> {
 				)
 			}
 		}
+		T::Twap::on_swap(&token_in.into(), &token_out.into());
 		Ok(())
 	}
 
@@ -15,7 +15,7 @@
 use bifrost_primitives::{Rate, Ratio};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::{DispatchError, FixedU128, RuntimeDebug};
+use sp_runtime::{DispatchError, DispatchResult, FixedU128, RuntimeDebug};
 use sp_std::prelude::*;
 
 pub trait LendMarket<CurrencyId, AccountId, Balance> {
@@ -46,6 +46,23 @@ pub trait LendMarket<CurrencyId, AccountId, Balance> {
 	) -> Result<(), DispatchError>;
 }
 
+/// Lends the cash of a market for the duration of a single call.
+pub trait FlashLoan<CurrencyId, AccountId, Balance> {
+	/// The fee charged for flash borrowing `amount` of `asset_id`.
+	fn flash_loan_fee(asset_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError>;
+
+	/// Transfers `amount` of `asset_id` to `borrower` and runs `f`. Once `f` returns, `amount`
+	/// plus the fee is taken back from `borrower`, otherwise everything fails.
+	///
+	/// Returns the fee paid.
+	fn flash_loan(
+		borrower: &AccountId,
+		asset_id: CurrencyId,
+		amount: Balance,
+		f: impl FnOnce() -> DispatchResult,
+	) -> Result<Balance, DispatchError>;
+}
+
 pub trait LendMarketPositionDataProvider<CurrencyId, AccountId, Balance> {
 	fn get_current_borrow_balance(
 		borrower: &AccountId,
@@ -0,0 +1,36 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use bifrost_primitives::{CurrencyId, Moment, Price};
+use sp_api::decl_runtime_apis;
+
+decl_runtime_apis! {
+	pub trait TwapRuntimeApi
+	{
+		/// The time weighted average amount of `asset_b` per unit of `asset_a` over the last
+		/// `window` milliseconds, and the timestamp of the last update. Windows shorter than
+		/// the minimum window of the pallet return `None`.
+		fn twap(
+			asset_a: CurrencyId,
+			asset_b: CurrencyId,
+			window: Moment,
+		) -> Option<(Price, Moment)>;
+	}
+}
@@ -0,0 +1,79 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+use bifrost_primitives::{DOT, VDOT};
+use frame_benchmarking::v2::*;
+use sp_runtime::traits::One;
+
+fn insert_pair<T: Config>(asset_a: CurrencyId, asset_b: CurrencyId) {
+	let pair = (asset_a, asset_b);
+	Pairs::<T>::insert(pair, PairInfo { source: PairSource::Zenlink, probe_amount: 1_000_000 });
+	Accumulators::<T>::insert(
+		pair,
+		Accumulator { last_price: Price::one(), observation_count: 1, ..Default::default() },
+	);
+	Observations::<T>::insert(pair, 0, Observation::default());
+}
+
+#[benchmarks(where T: Config)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn on_initialize(n: Linear<0, 20>) -> Result<(), BenchmarkError> {
+		for i in 0..n {
+			insert_pair::<T>(CurrencyId::Token2(i as u8), CurrencyId::VToken2(i as u8));
+		}
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(1u32.into());
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_pair() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let info = PairInfo { source: PairSource::StablePool(0), probe_amount: 1_000_000 };
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, VDOT, DOT, info);
+
+		assert!(Pairs::<T>::contains_key((VDOT, DOT)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_pair() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		insert_pair::<T>(VDOT, DOT);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, VDOT, DOT);
+
+		assert!(!Pairs::<T>::contains_key((VDOT, DOT)));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext_benchmark(), crate::mock::Runtime);
+}
@@ -0,0 +1,424 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # TWAP pallet
+//!
+//! Accumulates the prices of registered asset pairs over time, so that a time weighted
+//! average price can be read that is not movable by a swap within a single block.
+//!
+//! The accumulator is updated after every swap of a pair and at the beginning of every block,
+//! and records an observation of the cumulative price every `ObservationPeriod`. The TWAP over
+//! a window is the difference of the current cumulative price and the observation at the start
+//! of the window, divided by the elapsed time.
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use bifrost_primitives::{Balance, CurrencyId, Moment, Price, TryConvertFrom, TwapProvider};
+use bifrost_stable_pool::{traits::StablePoolHandler, StableAssetPoolId};
+use cumulus_primitives_core::ParaId;
+use frame_support::{pallet_prelude::*, traits::Time};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	FixedPointNumber,
+};
+use sp_std::{marker::PhantomData, vec};
+pub use weights::WeightInfo;
+use zenlink_protocol::{AssetId, ExportZenlink};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// The DEX that the spot price of a pair is read from.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PairSource {
+	/// A pool of stable-pool.
+	StablePool(StableAssetPoolId),
+	/// The zenlink pair of the assets.
+	Zenlink,
+}
+
+/// The registration of a pair.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PairInfo {
+	/// The DEX that the spot price is read from.
+	pub source: PairSource,
+	/// The amount of `asset_a` swapped to quote the spot price, small enough to keep the
+	/// slippage negligible.
+	pub probe_amount: Balance,
+}
+
+/// The cumulative price of a pair.
+#[derive(
+	Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+)]
+pub struct Accumulator {
+	/// The sum of `price * elapsed milliseconds`, wrapping on overflow.
+	pub price_cumulative: u128,
+	/// The spot price after the last update.
+	pub last_price: Price,
+	/// The timestamp of the last update.
+	pub last_timestamp: Moment,
+	/// The slot of the latest observation.
+	pub observation_index: u32,
+	/// The number of recorded observations.
+	pub observation_count: u32,
+}
+
+/// A snapshot of the cumulative price.
+#[derive(
+	Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo, MaxEncodedLen,
+)]
+pub struct Observation {
+	pub timestamp: Moment,
+	pub price_cumulative: u128,
+}
+
+/// Spot prices of asset pairs.
+pub trait SpotPriceProvider {
+	/// The amount of `asset_b` per unit of `asset_a` quoted by swapping `probe_amount`.
+	fn spot_price(
+		asset_a: CurrencyId,
+		asset_b: CurrencyId,
+		source: PairSource,
+		probe_amount: Balance,
+	) -> Option<Price>;
+}
+
+/// Read spot prices from stable-pool and zenlink.
+pub struct DexSpotPrice<StablePool, DexOperator, AccountId, ParachainId>(
+	PhantomData<(StablePool, DexOperator, AccountId, ParachainId)>,
+);
+impl<StablePool, DexOperator, AccountId, ParachainId> SpotPriceProvider
+	for DexSpotPrice<StablePool, DexOperator, AccountId, ParachainId>
+where
+	StablePool: StablePoolHandler<Balance = Balance, CurrencyId = CurrencyId>,
+	DexOperator: ExportZenlink<AccountId, AssetId>,
+	ParachainId: Get<ParaId>,
+{
+	fn spot_price(
+		asset_a: CurrencyId,
+		asset_b: CurrencyId,
+		source: PairSource,
+		probe_amount: Balance,
+	) -> Option<Price> {
+		let amount_out = match source {
+			PairSource::StablePool(pool_id) => {
+				let index_a = StablePool::get_pool_token_index(pool_id, asset_a)?;
+				let index_b = StablePool::get_pool_token_index(pool_id, asset_b)?;
+				StablePool::get_swap_output(pool_id, index_a, index_b, probe_amount).ok()?
+			},
+			PairSource::Zenlink => {
+				let para_id: u32 = ParachainId::get().into();
+				let path = vec![
+					AssetId::try_convert_from(asset_a, para_id).ok()?,
+					AssetId::try_convert_from(asset_b, para_id).ok()?,
+				];
+				*DexOperator::get_amount_out_by_path(probe_amount, &path).ok()?.last()?
+			},
+		};
+		Price::checked_from_rational(amount_out, probe_amount)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which can register and remove pairs.
+		type ControlOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The spot prices of the pairs.
+		type SpotPrice: SpotPriceProvider;
+
+		/// Time used to weight the prices.
+		type Time: Time<Moment = Moment>;
+
+		/// The maximum number of registered pairs.
+		#[pallet::constant]
+		type MaxPairs: Get<u32>;
+
+		/// The number of observations kept for each pair.
+		#[pallet::constant]
+		type MaxObservations: Get<u32>;
+
+		/// The minimum milliseconds between two observations.
+		#[pallet::constant]
+		type ObservationPeriod: Get<Moment>;
+
+		/// The shortest window in milliseconds a price can be averaged over, shorter windows
+		/// would follow the manipulable spot price.
+		#[pallet::constant]
+		type MinWindow: Get<Moment>;
+
+		/// Weight information for extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pair is already registered.
+		PairAlreadyExists,
+		/// The pair is not registered.
+		PairNotExists,
+		/// The number of pairs exceeds `MaxPairs`.
+		TooManyPairs,
+		/// The spot price of the pair is unavailable.
+		SpotPriceUnavailable,
+		/// The probe amount is zero.
+		ZeroProbeAmount,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pair is registered.
+		PairRegistered { asset_a: CurrencyId, asset_b: CurrencyId, info: PairInfo },
+		/// A pair is removed.
+		PairRemoved { asset_a: CurrencyId, asset_b: CurrencyId },
+	}
+
+	/// The registered pairs.
+	#[pallet::storage]
+	pub type Pairs<T: Config> =
+		CountedStorageMap<_, Twox64Concat, (CurrencyId, CurrencyId), PairInfo, OptionQuery>;
+
+	/// The cumulative price of each pair.
+	#[pallet::storage]
+	pub type Accumulators<T: Config> =
+		StorageMap<_, Twox64Concat, (CurrencyId, CurrencyId), Accumulator, OptionQuery>;
+
+	/// The ring buffer of observations of each pair.
+	#[pallet::storage]
+	pub type Observations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(CurrencyId, CurrencyId),
+		Twox64Concat,
+		u32,
+		Observation,
+		OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			let mut count: u32 = 0;
+			for (pair, info) in Pairs::<T>::iter() {
+				Self::update(pair, info);
+				count = count.saturating_add(1);
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a pair, the TWAP of `asset_b` per unit of `asset_a` is accumulated from the
+		/// next update.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_pair())]
+		pub fn register_pair(
+			origin: OriginFor<T>,
+			asset_a: CurrencyId,
+			asset_b: CurrencyId,
+			info: PairInfo,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			let pair = (asset_a, asset_b);
+			ensure!(!Pairs::<T>::contains_key(pair), Error::<T>::PairAlreadyExists);
+			ensure!(Pairs::<T>::count() < T::MaxPairs::get(), Error::<T>::TooManyPairs);
+			ensure!(!info.probe_amount.is_zero(), Error::<T>::ZeroProbeAmount);
+			let price = T::SpotPrice::spot_price(asset_a, asset_b, info.source, info.probe_amount)
+				.ok_or(Error::<T>::SpotPriceUnavailable)?;
+
+			let now = T::Time::now();
+			Pairs::<T>::insert(pair, info);
+			Accumulators::<T>::insert(
+				pair,
+				Accumulator {
+					price_cumulative: Zero::zero(),
+					last_price: price,
+					last_timestamp: now,
+					observation_index: 0,
+					observation_count: 1,
+				},
+			);
+			Observations::<T>::insert(
+				pair,
+				0,
+				Observation { timestamp: now, price_cumulative: Zero::zero() },
+			);
+
+			Self::deposit_event(Event::PairRegistered { asset_a, asset_b, info });
+			Ok(())
+		}
+
+		/// Remove a pair and it's accumulated prices.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_pair())]
+		pub fn remove_pair(
+			origin: OriginFor<T>,
+			asset_a: CurrencyId,
+			asset_b: CurrencyId,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			let pair = (asset_a, asset_b);
+			ensure!(Pairs::<T>::contains_key(pair), Error::<T>::PairNotExists);
+			Pairs::<T>::remove(pair);
+			Accumulators::<T>::remove(pair);
+			let _ = Observations::<T>::clear_prefix(pair, T::MaxObservations::get(), None);
+
+			Self::deposit_event(Event::PairRemoved { asset_a, asset_b });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Accumulate the last price over the elapsed time and take the current spot price.
+		/// The spot price is kept if it is unavailable.
+		pub(crate) fn update(pair: (CurrencyId, CurrencyId), info: PairInfo) {
+			let Some(mut accumulator) = Accumulators::<T>::get(pair) else {
+				return;
+			};
+			let now = T::Time::now();
+			let elapsed = now.saturating_sub(accumulator.last_timestamp);
+			accumulator.price_cumulative = accumulator
+				.price_cumulative
+				.wrapping_add(accumulator.last_price.into_inner().wrapping_mul(elapsed.into()));
+			accumulator.last_timestamp = now;
+			if let Some(price) =
+				T::SpotPrice::spot_price(pair.0, pair.1, info.source, info.probe_amount)
+			{
+				accumulator.last_price = price;
+			}
+
+			let last_observation = Observations::<T>::get(pair, accumulator.observation_index);
+			if last_observation.map_or(true, |observation| {
+				now.saturating_sub(observation.timestamp) >= T::ObservationPeriod::get()
+			}) {
+				let max_observations = T::MaxObservations::get().max(1);
+				accumulator.observation_index =
+					accumulator.observation_index.saturating_add(1) % max_observations;
+				accumulator.observation_count =
+					accumulator.observation_count.saturating_add(1).min(max_observations);
+				Observations::<T>::insert(
+					pair,
+					accumulator.observation_index,
+					Observation { timestamp: now, price_cumulative: accumulator.price_cumulative },
+				);
+			}
+
+			Accumulators::<T>::insert(pair, accumulator);
+		}
+
+		/// The time weighted average price of the pair over the last `window` milliseconds.
+		/// Returns `None` if the window is shorter than `MinWindow` or no observation is as old
+		/// as the window.
+		pub fn get_twap(
+			asset_a: CurrencyId,
+			asset_b: CurrencyId,
+			window: Moment,
+		) -> Option<(Price, Moment)> {
+			if window < T::MinWindow::get() {
+				return None;
+			}
+			let pair = (asset_a, asset_b);
+			let accumulator = Accumulators::<T>::get(pair)?;
+
+			let now = T::Time::now();
+			let price_cumulative = accumulator.price_cumulative.wrapping_add(
+				accumulator
+					.last_price
+					.into_inner()
+					.wrapping_mul(now.saturating_sub(accumulator.last_timestamp).into()),
+			);
+			let observation =
+				Self::find_observation(pair, &accumulator, now.saturating_sub(window))?;
+			let elapsed = now.saturating_sub(observation.timestamp);
+			if elapsed.is_zero() {
+				return None;
+			}
+			let average = price_cumulative
+				.wrapping_sub(observation.price_cumulative)
+				.checked_div(elapsed.into())?;
+
+			Some((Price::from_inner(average), accumulator.last_timestamp))
+		}
+
+		// binary search the latest observation not later than `target`
+		fn find_observation(
+			pair: (CurrencyId, CurrencyId),
+			accumulator: &Accumulator,
+			target: Moment,
+		) -> Option<Observation> {
+			let max_observations = T::MaxObservations::get().max(1);
+			let count = accumulator.observation_count.min(max_observations);
+			// the slot of the oldest observation
+			let oldest =
+				(accumulator.observation_index + max_observations + 1 - count) % max_observations;
+			let at = |position: u32| {
+				Observations::<T>::get(pair, (oldest + position) % max_observations)
+			};
+
+			let (mut low, mut high) = (0u32, count);
+			let mut found = None;
+			while low < high {
+				let mid = low + (high - low) / 2;
+				match at(mid) {
+					Some(observation) if observation.timestamp <= target => {
+						found = Some(observation);
+						low = mid + 1;
+					},
+					_ => high = mid,
+				}
+			}
+			found
+		}
+	}
+}
+
+impl<T: Config> TwapProvider for Pallet<T> {
+	fn on_swap(asset_in: &CurrencyId, asset_out: &CurrencyId) {
+		for pair in [(*asset_in, *asset_out), (*asset_out, *asset_in)] {
+			if let Some(info) = Pairs::<T>::get(pair) {
+				Self::update(pair, info);
+			}
+		}
+	}
+
+	fn twap(asset_a: &CurrencyId, asset_b: &CurrencyId, window: Moment) -> Option<(Price, Moment)> {
+		Self::get_twap(*asset_a, *asset_b, window)
+	}
+}
@@ -0,0 +1,120 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate as bifrost_twap;
+use crate::*;
+use bifrost_primitives::{DOT, VDOT};
+use frame_support::{derive_impl, ord_parameter_types, parameter_types};
+use frame_system::EnsureSignedBy;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+pub type AccountId = u64;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Twap: bifrost_twap,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId;
+	type Block = Block;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+parameter_types! {
+	pub static Now: Moment = 0;
+	pub static SpotPrices: Vec<((CurrencyId, CurrencyId), Price)> = vec![];
+}
+
+pub struct MockTime;
+impl Time for MockTime {
+	type Moment = Moment;
+
+	fn now() -> Moment {
+		Now::get()
+	}
+}
+
+pub struct MockSpotPrice;
+impl SpotPriceProvider for MockSpotPrice {
+	fn spot_price(
+		asset_a: CurrencyId,
+		asset_b: CurrencyId,
+		_source: PairSource,
+		_probe_amount: Balance,
+	) -> Option<Price> {
+		SpotPrices::get()
+			.into_iter()
+			.find(|(pair, _)| *pair == (asset_a, asset_b))
+			.map(|(_, price)| price)
+	}
+}
+
+pub fn set_spot_price(asset_a: CurrencyId, asset_b: CurrencyId, price: Price) {
+	let mut prices = SpotPrices::get();
+	prices.retain(|(pair, _)| *pair != (asset_a, asset_b));
+	prices.push(((asset_a, asset_b), price));
+	SpotPrices::set(prices);
+}
+
+ord_parameter_types! {
+	pub const One: AccountId = ALICE;
+}
+
+parameter_types! {
+	pub const MaxPairs: u32 = 2;
+	pub const MaxObservations: u32 = 4;
+	pub const ObservationPeriod: Moment = 60_000;
+	pub const MinWindow: Moment = 60_000;
+}
+
+impl bifrost_twap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ControlOrigin = EnsureSignedBy<One, AccountId>;
+	type SpotPrice = MockSpotPrice;
+	type Time = MockTime;
+	type MaxPairs = MaxPairs;
+	type MaxObservations = MaxObservations;
+	type ObservationPeriod = ObservationPeriod;
+	type MinWindow = MinWindow;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		set_spot_price(VDOT, DOT, Price::saturating_from_rational(12, 10));
+	});
+	ext
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_test_ext_benchmark() -> sp_io::TestExternalities {
+	new_test_ext()
+}
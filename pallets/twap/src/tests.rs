@@ -0,0 +1,216 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use bifrost_primitives::{BNC, DOT, KSM, VDOT};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+const START: Moment = 1_000_000;
+
+fn info() -> PairInfo {
+	PairInfo { source: PairSource::StablePool(0), probe_amount: 1_000_000 }
+}
+
+fn price(n: u128, d: u128) -> Price {
+	Price::saturating_from_rational(n, d)
+}
+
+// move to the next block after `ms` milliseconds
+fn next_block(ms: Moment) {
+	Now::set(Now::get() + ms);
+	System::set_block_number(System::block_number() + 1);
+	Twap::on_initialize(System::block_number());
+}
+
+fn register_vdot_dot() {
+	Now::set(START);
+	assert_ok!(Twap::register_pair(RuntimeOrigin::signed(ALICE), VDOT, DOT, info()));
+}
+
+#[test]
+fn register_pair_should_work() {
+	new_test_ext().execute_with(|| {
+		Now::set(START);
+		assert_noop!(Twap::register_pair(RuntimeOrigin::signed(BOB), VDOT, DOT, info()), BadOrigin);
+		assert_noop!(
+			Twap::register_pair(
+				RuntimeOrigin::signed(ALICE),
+				VDOT,
+				DOT,
+				PairInfo { source: PairSource::Zenlink, probe_amount: 0 }
+			),
+			Error::<Runtime>::ZeroProbeAmount
+		);
+		assert_noop!(
+			Twap::register_pair(RuntimeOrigin::signed(ALICE), DOT, VDOT, info()),
+			Error::<Runtime>::SpotPriceUnavailable
+		);
+
+		assert_ok!(Twap::register_pair(RuntimeOrigin::signed(ALICE), VDOT, DOT, info()));
+		System::assert_last_event(RuntimeEvent::Twap(Event::PairRegistered {
+			asset_a: VDOT,
+			asset_b: DOT,
+			info: info(),
+		}));
+		assert_eq!(
+			Accumulators::<Runtime>::get((VDOT, DOT)),
+			Some(Accumulator {
+				price_cumulative: 0,
+				last_price: price(12, 10),
+				last_timestamp: START,
+				observation_index: 0,
+				observation_count: 1,
+			})
+		);
+		assert_eq!(
+			Observations::<Runtime>::get((VDOT, DOT), 0),
+			Some(Observation { timestamp: START, price_cumulative: 0 })
+		);
+		assert_noop!(
+			Twap::register_pair(RuntimeOrigin::signed(ALICE), VDOT, DOT, info()),
+			Error::<Runtime>::PairAlreadyExists
+		);
+
+		set_spot_price(KSM, BNC, price(1, 1));
+		set_spot_price(DOT, BNC, price(1, 1));
+		assert_ok!(Twap::register_pair(RuntimeOrigin::signed(ALICE), KSM, BNC, info()));
+		assert_noop!(
+			Twap::register_pair(RuntimeOrigin::signed(ALICE), DOT, BNC, info()),
+			Error::<Runtime>::TooManyPairs
+		);
+	});
+}
+
+#[test]
+fn remove_pair_should_work() {
+	new_test_ext().execute_with(|| {
+		register_vdot_dot();
+		next_block(60_000);
+		assert_noop!(Twap::remove_pair(RuntimeOrigin::signed(BOB), VDOT, DOT), BadOrigin);
+		assert_noop!(
+			Twap::remove_pair(RuntimeOrigin::signed(ALICE), DOT, VDOT),
+			Error::<Runtime>::PairNotExists
+		);
+
+		assert_ok!(Twap::remove_pair(RuntimeOrigin::signed(ALICE), VDOT, DOT));
+		System::assert_last_event(RuntimeEvent::Twap(Event::PairRemoved {
+			asset_a: VDOT,
+			asset_b: DOT,
+		}));
+		assert_eq!(Pairs::<Runtime>::count(), 0);
+		assert_eq!(Accumulators::<Runtime>::get((VDOT, DOT)), None);
+		assert_eq!(Observations::<Runtime>::iter_prefix((VDOT, DOT)).count(), 0);
+		assert_eq!(Twap::get_twap(VDOT, DOT, 0), None);
+	});
+}
+
+#[test]
+fn twap_should_work() {
+	new_test_ext().execute_with(|| {
+		register_vdot_dot();
+		// 1.2 over the first minute
+		next_block(60_000);
+		// the price is 2 after a swap in the second minute
+		next_block(60_000);
+		set_spot_price(VDOT, DOT, price(2, 1));
+		Twap::on_swap(&DOT, &VDOT);
+		next_block(60_000);
+
+		// windows shorter than the minimum would follow the spot price
+		assert_eq!(Twap::get_twap(VDOT, DOT, 0), None);
+		assert_eq!(Twap::get_twap(VDOT, DOT, 59_999), None);
+		// 2 over the last minute
+		assert_eq!(Twap::get_twap(VDOT, DOT, 60_000), Some((price(2, 1), START + 180_000)));
+		// (1.2 * 60_000 + 2 * 60_000) / 120_000 = 1.6
+		assert_eq!(Twap::get_twap(VDOT, DOT, 120_000), Some((price(16, 10), START + 180_000)));
+		// (1.2 * 120_000 + 2 * 60_000) / 180_000 = 1.4666...
+		assert_eq!(
+			Twap::get_twap(VDOT, DOT, 180_000),
+			Some((Price::from_inner(1_466_666_666_666_666_666), START + 180_000))
+		);
+		// no observation is as old as the window
+		assert_eq!(Twap::get_twap(VDOT, DOT, 240_000), None);
+		// the reverse pair is not registered
+		assert_eq!(Twap::get_twap(DOT, VDOT, 60_000), None);
+
+		// the price after the last update is accumulated up to now
+		Now::set(START + 240_000);
+		// (2 * 60_000 + 2 * 60_000) / 120_000 = 2
+		assert_eq!(Twap::get_twap(VDOT, DOT, 120_000), Some((price(2, 1), START + 180_000)));
+	});
+}
+
+#[test]
+fn price_within_a_block_should_not_move_twap() {
+	new_test_ext().execute_with(|| {
+		register_vdot_dot();
+		next_block(60_000);
+		next_block(60_000);
+
+		// the price is pushed up and restored in the same block
+		set_spot_price(VDOT, DOT, price(100, 1));
+		Twap::on_swap(&VDOT, &DOT);
+		set_spot_price(VDOT, DOT, price(12, 10));
+		Twap::on_swap(&VDOT, &DOT);
+
+		next_block(60_000);
+		assert_eq!(Twap::get_twap(VDOT, DOT, 180_000), Some((price(12, 10), START + 180_000)));
+	});
+}
+
+#[test]
+fn observations_should_be_recorded_by_period() {
+	new_test_ext().execute_with(|| {
+		register_vdot_dot();
+		// updates within the observation period are accumulated only
+		next_block(12_000);
+		next_block(12_000);
+		let accumulator = Accumulators::<Runtime>::get((VDOT, DOT)).unwrap();
+		assert_eq!(accumulator.observation_count, 1);
+		assert_eq!(accumulator.price_cumulative, price(12, 10).into_inner() * 24_000);
+
+		next_block(36_000);
+		let accumulator = Accumulators::<Runtime>::get((VDOT, DOT)).unwrap();
+		assert_eq!(accumulator.observation_index, 1);
+		assert_eq!(accumulator.observation_count, 2);
+	});
+}
+
+#[test]
+fn observations_should_wrap_around() {
+	new_test_ext().execute_with(|| {
+		register_vdot_dot();
+		for i in 1..=5 {
+			set_spot_price(VDOT, DOT, price(i, 1));
+			next_block(60_000);
+		}
+		let accumulator = Accumulators::<Runtime>::get((VDOT, DOT)).unwrap();
+		assert_eq!(accumulator.observation_index, 1);
+		assert_eq!(accumulator.observation_count, 4);
+		// the observations of START and START + 60_000 are overwritten
+		assert_eq!(Twap::get_twap(VDOT, DOT, 300_000), None);
+		assert_eq!(Twap::get_twap(VDOT, DOT, 240_000), None);
+		// prices 2, 3, 4 are accumulated over the last three minutes
+		assert_eq!(Twap::get_twap(VDOT, DOT, 180_000), Some((price(3, 1), START + 300_000)));
+		// a window between observations uses the older one
+		assert_eq!(Twap::get_twap(VDOT, DOT, 90_000), Some((price(35, 10), START + 300_000)));
+	});
+}
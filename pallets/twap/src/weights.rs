@@ -0,0 +1,93 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for bifrost_twap.
+pub trait WeightInfo {
+	fn on_initialize(n: u32, ) -> Weight;
+	fn register_pair() -> Weight;
+	fn remove_pair() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Twap::Pairs` (r:11 w:0)
+	/// Proof: `Twap::Pairs` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::Accumulators` (r:10 w:10)
+	/// Proof: `Twap::Accumulators` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::Observations` (r:10 w:10)
+	/// Proof: `Twap::Observations` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	/// Storage: `ZenlinkProtocol::PairStatuses` (r:10 w:0)
+	/// Proof: `ZenlinkProtocol::PairStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 10]`.
+	fn on_initialize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `147 + n * (241 ±0)`
+		//  Estimated: `3528 + n * (2557 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(4_000_000, 3528)
+			// Standard Error: 25_000
+			.saturating_add(Weight::from_parts(26_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2557).saturating_mul(n.into()))
+	}
+	/// Storage: `Twap::Pairs` (r:1 w:1)
+	/// Proof: `Twap::Pairs` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::CounterForPairs` (r:1 w:1)
+	/// Proof: `Twap::CounterForPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ZenlinkProtocol::PairStatuses` (r:1 w:0)
+	/// Proof: `ZenlinkProtocol::PairStatuses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Twap::Accumulators` (r:0 w:1)
+	/// Proof: `Twap::Accumulators` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::Observations` (r:0 w:1)
+	/// Proof: `Twap::Observations` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	fn register_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Twap::Pairs` (r:1 w:1)
+	/// Proof: `Twap::Pairs` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::CounterForPairs` (r:1 w:1)
+	/// Proof: `Twap::CounterForPairs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::Accumulators` (r:0 w:1)
+	/// Proof: `Twap::Accumulators` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Twap::Observations` (r:600 w:600)
+	/// Proof: `Twap::Observations` (`max_values`: None, `max_size`: Some(62), added: 2537, mode: `MaxEncodedLen`)
+	fn remove_pair() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `29063`
+		//  Estimated: `1523690`
+		// Minimum execution time: 612_000_000 picoseconds.
+		Weight::from_parts(615_000_000, 1523690)
+			.saturating_add(RocksDbWeight::get().reads(602_u64))
+			.saturating_add(RocksDbWeight::get().writes(603_u64))
+	}
+}
@@ -75,7 +75,7 @@ benchmarks! {
 			Some(100u32.into()),
 			"Vesting schedule not added",
 		);
-	}: vest(RawOrigin::Signed(caller.clone()))
+	}: vest(RawOrigin::Signed(caller.clone()), T::NativeCurrencyId::get())
 	verify {
 		// Nothing happened since everything is still vested.
 		assert_eq!(
@@ -99,7 +99,7 @@ benchmarks! {
 			Some(BalanceOf::<T>::zero()),
 			"Vesting schedule still active",
 		);
-	}: vest(RawOrigin::Signed(caller.clone()))
+	}: vest(RawOrigin::Signed(caller.clone()), T::NativeCurrencyId::get())
 	verify {
 		// Vesting schedule is removed!
 		assert_eq!(
@@ -126,7 +126,7 @@ benchmarks! {
 		);
 
 		let caller: T::AccountId = whitelisted_caller();
-	}: vest_other(RawOrigin::Signed(caller.clone()), other_lookup)
+	}: vest_other(RawOrigin::Signed(caller.clone()), other_lookup, T::NativeCurrencyId::get())
 	verify {
 		// Nothing happened since everything is still vested.
 		assert_eq!(
@@ -153,7 +153,7 @@ benchmarks! {
 		);
 
 		let caller: T::AccountId = whitelisted_caller();
-	}: vest_other(RawOrigin::Signed(caller.clone()), other_lookup)
+	}: vest_other(RawOrigin::Signed(caller.clone()), other_lookup, T::NativeCurrencyId::get())
 	verify {
 		// Vesting schedule is removed!
 		assert_eq!(
@@ -178,7 +178,7 @@ benchmarks! {
 			per_block: 10u32.into(),
 			starting_block: 1u32.into(),
 		};
-	}: _(RawOrigin::Signed(caller), target_lookup, vesting_schedule)
+	}: _(RawOrigin::Signed(caller), target_lookup, T::NativeCurrencyId::get(), vesting_schedule)
 	verify {
 		assert_eq!(
 			T::MinVestedTransfer::get(),
@@ -208,7 +208,7 @@ benchmarks! {
 			per_block: 10u32.into(),
 			starting_block: 1u32.into(),
 		};
-	}: _(RawOrigin::Root, source_lookup, target_lookup, vesting_schedule)
+	}: _(RawOrigin::Root, source_lookup, target_lookup, T::NativeCurrencyId::get(), vesting_schedule)
 	verify {
 		assert_eq!(
 			T::MinVestedTransfer::get(),
@@ -222,6 +222,80 @@ benchmarks! {
 		);
 	}
 
+	create_grant {
+		let l in 0 .. MaxLocksOf::<T>::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		let beneficiary_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(caller.clone());
+
+		let transfer_amount = T::MinVestedTransfer::get();
+
+		let vesting_schedule = VestingInfo {
+			locked: transfer_amount,
+			per_block: 10u32.into(),
+			starting_block: 1u32.into(),
+		};
+	}: _(
+		RawOrigin::Signed(caller),
+		target_lookup,
+		T::NativeCurrencyId::get(),
+		vesting_schedule,
+		Some(10u32.into()),
+		Some(100u32.into()),
+		beneficiary_lookup
+	)
+	verify {
+		assert_eq!(
+			T::MinVestedTransfer::get(),
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&target),
+			Some(T::MinVestedTransfer::get()),
+			"Lock not created",
+		);
+	}
+
+	revoke_grant {
+		let l in 0 .. MaxLocksOf::<T>::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(beneficiary.clone());
+
+		let transfer_amount = T::MinVestedTransfer::get();
+
+		let vesting_schedule = VestingInfo {
+			locked: transfer_amount,
+			per_block: 10u32.into(),
+			starting_block: 1u32.into(),
+		};
+		Vesting::<T>::create_grant(
+			RawOrigin::Signed(caller).into(),
+			target_lookup.clone(),
+			T::NativeCurrencyId::get(),
+			vesting_schedule,
+			None,
+			None,
+			beneficiary_lookup,
+		)?;
+	}: _(RawOrigin::Root, target_lookup, T::NativeCurrencyId::get(), 0)
+	verify {
+		assert_eq!(
+			T::MinVestedTransfer::get(),
+			T::Currency::free_balance(&beneficiary),
+			"Grant not revoked",
+		);
+		assert_eq!(Vesting::<T>::vesting_balance(&target), None, "Grant not removed");
+	}
+
 	impl_benchmark_test_suite!(
 		Vesting,
 		crate::mock::ExtBuilder::default().existential_deposit(256).build(),
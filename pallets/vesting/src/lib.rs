@@ -43,6 +43,11 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `create_grant` - Create a revocable vesting grant with an optional cliff and ending block.
+//! - `revoke_grant` - Reclaim the unvested remainder of a grant to its beneficiary.
+//!
+//! Schedules are kept per currency. The native currency is locked through `Currency`, while
+//! other currencies are locked through `MultiCurrency`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -57,6 +62,7 @@ mod vesting_info;
 pub mod migrations;
 pub mod weights;
 
+use bifrost_primitives::CurrencyId;
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -68,6 +74,7 @@ use frame_support::{
 	weights::Weight,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
+use orml_traits::{MultiCurrency, MultiLockableCurrency};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	codec::{Decode, Encode, MaxEncodedLen},
@@ -88,6 +95,8 @@ type BalanceOf<T> =
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type VestingGrantOf<T> =
+	VestingGrant<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -97,6 +106,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -163,6 +173,17 @@ pub mod pallet {
 		/// The currency trait.
 		type Currency: LockableCurrency<Self::AccountId>;
 
+		/// The multi-currency used to transfer and lock non-native currencies.
+		type MultiCurrency: MultiLockableCurrency<
+			Self::AccountId,
+			CurrencyId = CurrencyId,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// The native currency, whose schedules are locked through `Currency`.
+		#[pallet::constant]
+		type NativeCurrencyId: Get<CurrencyId>;
+
 		/// Convert the block number into a balance.
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
 
@@ -204,19 +225,39 @@ pub mod pallet {
 	/// Cliff vesting
 	#[pallet::storage]
 	#[pallet::getter(fn cliffs)]
-	pub(super) type Cliff<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+	pub(super) type Cliff<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		CurrencyId,
+		BlockNumberFor<T>,
+	>;
 
-	/// Information regarding the vesting of a given account.
+	/// Information regarding the vesting of a given account in a given currency.
 	#[pallet::storage]
 	#[pallet::getter(fn vesting)]
-	pub type Vesting<T: Config> = StorageMap<
+	pub type Vesting<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
+		Blake2_128Concat,
+		CurrencyId,
 		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
 	>;
 
+	/// Revocable vesting grants of a given account in a given currency.
+	#[pallet::storage]
+	#[pallet::getter(fn grants)]
+	pub type Grants<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		CurrencyId,
+		BoundedVec<VestingGrantOf<T>, MaxVestingSchedulesGet<T>>,
+	>;
+
 	/// Storage version of the pallet.
 	///
 	/// New networks start with latest version, as determined by the genesis build.
@@ -238,7 +279,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -257,7 +298,7 @@ pub mod pallet {
 					panic!("Invalid VestingInfo params at genesis")
 				};
 
-				Vesting::<T>::try_append(who, vesting_info)
+				Vesting::<T>::try_append(who, T::NativeCurrencyId::get(), vesting_info)
 					.expect("Too many vesting schedules at genesis.");
 
 				let reasons =
@@ -273,9 +314,23 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// The amount vested has been updated. This could indicate a change in funds available.
 		/// The balance given is the amount which is left unvested (and thus locked).
-		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
+		VestingUpdated { account: T::AccountId, currency_id: CurrencyId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
-		VestingCompleted { account: T::AccountId },
+		VestingCompleted { account: T::AccountId, currency_id: CurrencyId },
+		/// A revocable grant has been created.
+		GrantCreated {
+			creator: T::AccountId,
+			target: T::AccountId,
+			currency_id: CurrencyId,
+			locked: BalanceOf<T>,
+		},
+		/// A grant has been revoked, the unvested remainder is moved to the beneficiary.
+		GrantRevoked {
+			target: T::AccountId,
+			currency_id: CurrencyId,
+			beneficiary: T::AccountId,
+			reclaimed: BalanceOf<T>,
+		},
 	}
 
 	/// Error for the vesting pallet.
@@ -300,6 +355,8 @@ pub mod pallet {
 		WrongLockedAmount,
 		/// Wrong vesting during cliff period
 		WrongCliffVesting,
+		/// Only the creator of a grant or root can revoke it
+		NotGrantCreator,
 	}
 
 	#[pallet::call]
@@ -309,6 +366,8 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_ and the sender must have funds still
 		/// locked under this pallet.
 		///
+		/// - `currency_id`: The currency whose vested funds should be unlocked.
+		///
 		/// Emits either `VestingCompleted` or `VestingUpdated`.
 		///
 		/// ## Complexity
@@ -317,10 +376,10 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::vest_locked(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
 			.max(T::WeightInfo::vest_unlocked(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES))
 		)]
-		pub fn vest(origin: OriginFor<T>) -> DispatchResult {
+		pub fn vest(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::check_cliff(who.clone())?;
-			Self::do_vest(who)
+			Self::check_cliff(who.clone(), currency_id)?;
+			Self::do_vest(who, currency_id)
 		}
 
 		/// Unlock any vested funds of a `target` account.
@@ -329,6 +388,7 @@ pub mod pallet {
 		///
 		/// - `target`: The account whose vested funds should be unlocked. Must have funds still
 		/// locked under this pallet.
+		/// - `currency_id`: The currency whose vested funds should be unlocked.
 		///
 		/// Emits either `VestingCompleted` or `VestingUpdated`.
 		///
@@ -338,11 +398,15 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::vest_other_locked(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
 			.max(T::WeightInfo::vest_other_unlocked(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES))
 		)]
-		pub fn vest_other(origin: OriginFor<T>, target: AccountIdLookupOf<T>) -> DispatchResult {
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
 			ensure_signed(origin)?;
 			let who = T::Lookup::lookup(target)?;
-			Self::check_cliff(who.clone())?;
-			Self::do_vest(who)
+			Self::check_cliff(who.clone(), currency_id)?;
+			Self::do_vest(who, currency_id)
 		}
 
 		/// Create a vested transfer.
@@ -350,6 +414,7 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: The account receiving the vested funds.
+		/// - `currency_id`: The currency of the vested funds.
 		/// - `schedule`: The vesting schedule attached to the transfer.
 		///
 		/// Emits `VestingCreated`.
@@ -365,11 +430,12 @@ pub mod pallet {
 		pub fn vested_transfer(
 			origin: OriginFor<T>,
 			target: AccountIdLookupOf<T>,
+			currency_id: CurrencyId,
 			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
 			let transactor = <T::Lookup as StaticLookup>::unlookup(transactor);
-			Self::do_vested_transfer(transactor, target, schedule)
+			Self::do_vested_transfer(transactor, target, currency_id, schedule)
 		}
 
 		/// Force a vested transfer.
@@ -378,6 +444,7 @@ pub mod pallet {
 		///
 		/// - `source`: The account whose funds should be transferred.
 		/// - `target`: The account that should be transferred the vested funds.
+		/// - `currency_id`: The currency of the vested funds.
 		/// - `schedule`: The vesting schedule attached to the transfer.
 		///
 		/// Emits `VestingCreated`.
@@ -394,10 +461,11 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			source: AccountIdLookupOf<T>,
 			target: AccountIdLookupOf<T>,
+			currency_id: CurrencyId,
 			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			Self::do_vested_transfer(source, target, schedule)
+			Self::do_vested_transfer(source, target, currency_id, schedule)
 		}
 
 		#[pallet::call_index(5)]
@@ -418,14 +486,15 @@ pub mod pallet {
 		pub fn set_vesting_per_block(
 			origin: OriginFor<T>,
 			target: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
 			index: u32,
 			per_block: BalanceOf<T>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 			let target = T::Lookup::lookup(target)?;
 			let index = index as usize;
-			Self::do_vest(target.clone())?;
-			let schedules = Self::vesting(&target).ok_or(Error::<T>::NotVesting)?;
+			Self::do_vest(target.clone(), currency_id)?;
+			let schedules = Self::vesting(&target, currency_id).ok_or(Error::<T>::NotVesting)?;
 			ensure!(schedules.len() > index, Error::<T>::ScheduleIndexOutOfBounds);
 			ensure!(schedules[index].per_block() != per_block, Error::<T>::SamePerBlock);
 
@@ -444,7 +513,7 @@ pub mod pallet {
 				new_start_offset = now - absolute_start;
 			}
 
-			Vesting::<T>::mutate_exists(&target, |info| {
+			Vesting::<T>::mutate_exists(&target, currency_id, |info| {
 				if let Some(ref mut vesting_info) = info {
 					vesting_info[index].locked = remained_vesting;
 					vesting_info[index].per_block = per_block;
@@ -452,7 +521,7 @@ pub mod pallet {
 				}
 			});
 
-			Self::do_vest(target.clone())?;
+			Self::do_vest(target.clone(), currency_id)?;
 			Ok(())
 		}
 
@@ -461,12 +530,13 @@ pub mod pallet {
 		pub fn force_set_cliff(
 			origin: OriginFor<T>,
 			target: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
 			cliff_block: BlockNumberFor<T>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
 			let target = T::Lookup::lookup(target)?;
-			Cliff::<T>::insert(target, cliff_block);
+			Cliff::<T>::insert(target, currency_id, cliff_block);
 
 			Ok(())
 		}
@@ -481,6 +551,7 @@ pub mod pallet {
 		/// NOTE: This will unlock all schedules through the current block prior to merging.
 		/// NOTE: If both schedules have ended by the current block, no new schedule will be created
 		/// and both will be removed.
+		/// NOTE: Revocable grants are kept apart from the schedules and are never merged.
 		///
 		/// Merged schedule attributes:
 		/// - `starting_block`: `MAX(schedule1.starting_block, scheduled2.starting_block,
@@ -490,6 +561,7 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
+		/// - `currency_id`: the currency of the schedules to merge.
 		/// - `schedule1_index`: index of the first schedule to merge.
 		/// - `schedule2_index`: index of the second schedule to merge.
 		#[pallet::call_index(4)]
@@ -499,6 +571,7 @@ pub mod pallet {
 		)]
 		pub fn merge_schedules(
 			origin: OriginFor<T>,
+			currency_id: CurrencyId,
 			schedule1_index: u32,
 			schedule2_index: u32,
 		) -> DispatchResult {
@@ -509,14 +582,130 @@ pub mod pallet {
 			let schedule1_index = schedule1_index as usize;
 			let schedule2_index = schedule2_index as usize;
 
-			let schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+			let schedules = Self::vesting(&who, currency_id).ok_or(Error::<T>::NotVesting)?;
 			let merge_action =
 				VestingAction::Merge { index1: schedule1_index, index2: schedule2_index };
 
 			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), merge_action)?;
 
-			Self::write_vesting(&who, schedules)?;
-			Self::write_lock(&who, locked_now);
+			Self::write_vesting(&who, currency_id, schedules)?;
+			Self::write_lock(&who, currency_id, locked_now)?;
+
+			Ok(())
+		}
+
+		/// Create a revocable vesting grant, transferring `schedule.locked` to `target`.
+		///
+		/// The dispatch origin for this call must be _Signed_, and becomes the creator of the
+		/// grant.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `currency_id`: The currency of the vested funds.
+		/// - `schedule`: The linear unlocking of the grant.
+		/// - `cliff_block`: Nothing is unlocked before this block.
+		/// - `ending_block`: Everything is unlocked at this block.
+		/// - `beneficiary`: The account receiving the unvested remainder if the grant is revoked.
+		///
+		/// Emits `GrantCreated`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::create_grant(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn create_grant(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			currency_id: CurrencyId,
+			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+			cliff_block: Option<BlockNumberFor<T>>,
+			ending_block: Option<BlockNumberFor<T>>,
+			beneficiary: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			ensure!(schedule.locked() >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+			let grant = VestingGrant {
+				schedule,
+				cliff_block,
+				ending_block,
+				creator: creator.clone(),
+				beneficiary,
+			};
+			ensure!(grant.is_valid(), Error::<T>::InvalidScheduleParams);
+
+			let mut grants = Self::grants(&target, currency_id).unwrap_or_default();
+			ensure!(grants.try_push(grant).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+			Self::do_transfer(currency_id, &creator, &target, schedule.locked())?;
+			Grants::<T>::insert(&target, currency_id, grants);
+			Self::deposit_event(Event::<T>::GrantCreated {
+				creator,
+				target: target.clone(),
+				currency_id,
+				locked: schedule.locked(),
+			});
+
+			Self::update_vesting(&target, currency_id)
+		}
+
+		/// Revoke a grant, moving its unvested remainder to the beneficiary. Already vested funds
+		/// stay with the grantee.
+		///
+		/// The dispatch origin for this call must be _Root_ or _Signed_ by the grant creator.
+		///
+		/// - `target`: The account holding the grant.
+		/// - `currency_id`: The currency of the grant.
+		/// - `index`: The index of the grant in `Grants`.
+		///
+		/// Emits `GrantRevoked`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_grant(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn revoke_grant(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			currency_id: CurrencyId,
+			index: u32,
+		) -> DispatchResult {
+			let maybe_revoker = ensure_signed_or_root(origin)?;
+			let target = T::Lookup::lookup(target)?;
+
+			let mut grants = Self::grants(&target, currency_id).ok_or(Error::<T>::NotVesting)?;
+			let index = index as usize;
+			ensure!(index < grants.len(), Error::<T>::ScheduleIndexOutOfBounds);
+			let grant = grants.remove(index);
+			if let Some(revoker) = maybe_revoker {
+				ensure!(revoker == grant.creator, Error::<T>::NotGrantCreator);
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let start_at = Self::vesting_start_at()
+				.map(|st| st.saturating_add(grant.schedule.starting_block()));
+			let unvested = grant.locked_at::<T::BlockNumberToBalance>(now, start_at);
+
+			if grants.is_empty() {
+				Grants::<T>::remove(&target, currency_id);
+			} else {
+				Grants::<T>::insert(&target, currency_id, grants);
+			}
+			// Release the lock of the revoked grant before moving the unvested remainder.
+			Self::update_vesting(&target, currency_id)?;
+
+			// Only what the remaining schedules and grants leave usable is reclaimed, fees may
+			// have been paid out of the locked funds.
+			let usable = Self::free_balance(currency_id, &target)
+				.saturating_sub(Self::total_locked_at(&target, currency_id, now));
+			let reclaimed = unvested.min(usable);
+			Self::do_transfer(currency_id, &target, &grant.beneficiary, reclaimed)?;
+
+			Self::deposit_event(Event::<T>::GrantRevoked {
+				target,
+				currency_id,
+				beneficiary: grant.beneficiary,
+				reclaimed,
+			});
 
 			Ok(())
 		}
@@ -524,11 +713,11 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
-	fn check_cliff(who: T::AccountId) -> DispatchResult {
-		if let Some(cliff_block) = Cliff::<T>::get(who.clone()) {
+	fn check_cliff(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		if let Some(cliff_block) = Cliff::<T>::get(who.clone(), currency_id) {
 			let now = <frame_system::Pallet<T>>::block_number();
 			ensure!(cliff_block < now, Error::<T>::WrongCliffVesting);
-			Cliff::<T>::remove(who);
+			Cliff::<T>::remove(who, currency_id);
 		};
 
 		Ok(())
@@ -593,6 +782,7 @@ impl<T: Config> Pallet<T> {
 	fn do_vested_transfer(
 		source: AccountIdLookupOf<T>,
 		target: AccountIdLookupOf<T>,
+		currency_id: CurrencyId,
 		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
 	) -> DispatchResult {
 		// Validate user inputs.
@@ -604,23 +794,20 @@ impl<T: Config> Pallet<T> {
 		let source = T::Lookup::lookup(source)?;
 
 		// Check we can add to this account prior to any storage writes.
-		Self::can_add_vesting_schedule(
+		Self::ensure_can_add_schedule(
 			&target,
+			currency_id,
 			schedule.locked(),
 			schedule.per_block(),
 			schedule.starting_block(),
 		)?;
 
-		T::Currency::transfer(
-			&source,
-			&target,
-			schedule.locked(),
-			ExistenceRequirement::AllowDeath,
-		)?;
+		Self::do_transfer(currency_id, &source, &target, schedule.locked())?;
 
 		// We can't let this fail because the currency transfer has already happened.
-		let res = Self::add_vesting_schedule(
+		let res = Self::do_add_schedule(
 			&target,
+			currency_id,
 			schedule.locked(),
 			schedule.per_block(),
 			schedule.starting_block(),
@@ -630,6 +817,88 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Transfer `amount` of `currency_id` from `source` to `target`.
+	fn do_transfer(
+		currency_id: CurrencyId,
+		source: &T::AccountId,
+		target: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if currency_id == T::NativeCurrencyId::get() {
+			T::Currency::transfer(source, target, amount, ExistenceRequirement::AllowDeath)
+		} else {
+			T::MultiCurrency::transfer(currency_id, source, target, amount)
+		}
+	}
+
+	/// The free balance of `who` in `currency_id`.
+	fn free_balance(currency_id: CurrencyId, who: &T::AccountId) -> BalanceOf<T> {
+		if currency_id == T::NativeCurrencyId::get() {
+			T::Currency::free_balance(who)
+		} else {
+			T::MultiCurrency::free_balance(currency_id, who)
+		}
+	}
+
+	/// Amount locked at block `now` by the grants of `who` in `currency_id`.
+	fn grants_locked_at(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		now: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		Self::grants(who, currency_id).unwrap_or_default().iter().fold(
+			Zero::zero(),
+			|total: BalanceOf<T>, grant| {
+				let start_at = Self::vesting_start_at()
+					.map(|st| st.saturating_add(grant.schedule.starting_block()));
+				total.saturating_add(grant.locked_at::<T::BlockNumberToBalance>(now, start_at))
+			},
+		)
+	}
+
+	/// Amount locked at block `now` by both the schedules and the grants of `who` in
+	/// `currency_id`.
+	fn total_locked_at(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		now: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		let grants_locked_now = Self::grants_locked_at(who, currency_id, now);
+		Self::vesting(who, currency_id).unwrap_or_default().iter().fold(
+			grants_locked_now,
+			|total, schedule| {
+				let start_at =
+					Self::vesting_start_at().map(|st| st.saturating_add(schedule.starting_block()));
+				schedule
+					.locked_at::<T::BlockNumberToBalance>(now, start_at)
+					.saturating_add(total)
+			},
+		)
+	}
+
+	/// Remove the fully vested grants of `who` in `currency_id`, returning the amount still
+	/// locked by the remaining grants.
+	fn update_grants(who: &T::AccountId, currency_id: CurrencyId) -> BalanceOf<T> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut total_locked_now: BalanceOf<T> = Zero::zero();
+		Grants::<T>::mutate_exists(who, currency_id, |maybe_grants| {
+			if let Some(grants) = maybe_grants {
+				grants.retain(|grant| {
+					let start_at = Self::vesting_start_at()
+						.map(|st| st.saturating_add(grant.schedule.starting_block()));
+					let locked_now = grant.locked_at::<T::BlockNumberToBalance>(now, start_at);
+					total_locked_now = total_locked_now.saturating_add(locked_now);
+					!locked_now.is_zero()
+				});
+				if grants.is_empty() {
+					*maybe_grants = None;
+				}
+			}
+		});
+
+		total_locked_now
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -664,24 +933,45 @@ impl<T: Config> Pallet<T> {
 		(filtered_schedules, total_locked_now)
 	}
 
-	/// Write an accounts updated vesting lock to storage.
-	fn write_lock(who: &T::AccountId, total_locked_now: BalanceOf<T>) {
+	/// Write an accounts updated vesting lock to storage. The lock also covers the grants, fully
+	/// vested grants are removed.
+	fn write_lock(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		schedules_locked_now: BalanceOf<T>,
+	) -> DispatchResult {
+		let total_locked_now =
+			schedules_locked_now.saturating_add(Self::update_grants(who, currency_id));
+		let is_native = currency_id == T::NativeCurrencyId::get();
 		if total_locked_now.is_zero() {
-			T::Currency::remove_lock(VESTING_ID, who);
-			Self::deposit_event(Event::<T>::VestingCompleted { account: who.clone() });
+			if is_native {
+				T::Currency::remove_lock(VESTING_ID, who);
+			} else {
+				T::MultiCurrency::remove_lock(VESTING_ID, currency_id, who)?;
+			}
+			Self::deposit_event(Event::<T>::VestingCompleted { account: who.clone(), currency_id });
 		} else {
-			let reasons = WithdrawReasons::except(T::UnvestedFundsAllowedWithdrawReasons::get());
-			T::Currency::set_lock(VESTING_ID, who, total_locked_now, reasons);
+			if is_native {
+				let reasons =
+					WithdrawReasons::except(T::UnvestedFundsAllowedWithdrawReasons::get());
+				T::Currency::set_lock(VESTING_ID, who, total_locked_now, reasons);
+			} else {
+				T::MultiCurrency::set_lock(VESTING_ID, currency_id, who, total_locked_now)?;
+			}
 			Self::deposit_event(Event::<T>::VestingUpdated {
 				account: who.clone(),
+				currency_id,
 				unvested: total_locked_now,
 			});
 		};
+
+		Ok(())
 	}
 
 	/// Write an accounts updated vesting schedules to storage.
 	fn write_vesting(
 		who: &T::AccountId,
+		currency_id: CurrencyId,
 		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
 	) -> Result<(), DispatchError> {
 		let schedules: BoundedVec<
@@ -690,23 +980,91 @@ impl<T: Config> Pallet<T> {
 		> = schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
 
 		if schedules.len() == 0 {
-			Vesting::<T>::remove(&who);
+			Vesting::<T>::remove(&who, currency_id);
 		} else {
-			Vesting::<T>::insert(who, schedules)
+			Vesting::<T>::insert(who, currency_id, schedules)
 		}
 
 		Ok(())
 	}
 
-	/// Unlock any vested funds of `who`.
-	fn do_vest(who: T::AccountId) -> DispatchResult {
-		let schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+	/// Unlock any vested funds of `who` in `currency_id`.
+	fn do_vest(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		ensure!(
+			Vesting::<T>::contains_key(&who, currency_id) ||
+				Grants::<T>::contains_key(&who, currency_id),
+			Error::<T>::NotVesting
+		);
+
+		Self::update_vesting(&who, currency_id)
+	}
+
+	/// Update the schedules, grants and lock of `who` in `currency_id` through the current block.
+	fn update_vesting(who: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		let schedules = Self::vesting(who, currency_id).unwrap_or_default();
 
 		let (schedules, locked_now) =
 			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
 
-		Self::write_vesting(&who, schedules)?;
-		Self::write_lock(&who, locked_now);
+		Self::write_vesting(who, currency_id, schedules)?;
+		Self::write_lock(who, currency_id, locked_now)?;
+
+		Ok(())
+	}
+
+	/// Add a vesting schedule of `currency_id` to `who`, see
+	/// [`VestingSchedule::add_vesting_schedule`].
+	fn do_add_schedule(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		locked: BalanceOf<T>,
+		per_block: BalanceOf<T>,
+		starting_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		if locked.is_zero() {
+			return Ok(());
+		}
+
+		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
+		// Check for `per_block` or `locked` of 0.
+		if !vesting_schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into());
+		};
+
+		let mut schedules = Self::vesting(who, currency_id).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, currency_id, schedules)?;
+		Self::write_lock(who, currency_id, locked_now)?;
+
+		Ok(())
+	}
+
+	/// Ensure a vesting schedule of `currency_id` can be added to `who`, see
+	/// [`VestingSchedule::can_add_vesting_schedule`].
+	fn ensure_can_add_schedule(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		locked: BalanceOf<T>,
+		per_block: BalanceOf<T>,
+		starting_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// Check for `per_block` or `locked` of 0.
+		if !VestingInfo::new(locked, per_block, starting_block).is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into());
+		}
+
+		ensure!(
+			(Vesting::<T>::decode_len(who, currency_id).unwrap_or_default() as u32) <
+				T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
 
 		Ok(())
 	}
@@ -767,19 +1125,16 @@ where
 
 	/// Get the amount that is currently being vested and cannot be transferred out of this account.
 	fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
-		if let Some(v) = Self::vesting(who) {
-			let now = <frame_system::Pallet<T>>::block_number();
-			let total_locked_now = v.iter().fold(Zero::zero(), |total, schedule| {
-				let start_at =
-					Self::vesting_start_at().map(|st| st.saturating_add(schedule.starting_block()));
-				schedule
-					.locked_at::<T::BlockNumberToBalance>(now, start_at)
-					.saturating_add(total)
-			});
-			Some(T::Currency::free_balance(who).min(total_locked_now))
-		} else {
-			None
+		let currency_id = T::NativeCurrencyId::get();
+		if !Vesting::<T>::contains_key(who, currency_id) &&
+			!Grants::<T>::contains_key(who, currency_id)
+		{
+			return None;
 		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let total_locked_now = Self::total_locked_at(who, currency_id, now);
+		Some(T::Currency::free_balance(who).min(total_locked_now))
 	}
 
 	/// Adds a vesting schedule to a given account.
@@ -800,29 +1155,7 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		if locked.is_zero() {
-			return Ok(());
-		}
-
-		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
-		// Check for `per_block` or `locked` of 0.
-		if !vesting_schedule.is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into());
-		};
-
-		let mut schedules = Self::vesting(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::do_add_schedule(who, T::NativeCurrencyId::get(), locked, per_block, starting_block)
 	}
 
 	// Ensure we can call `add_vesting_schedule` without error. This should always
@@ -833,28 +1166,25 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		// Check for `per_block` or `locked` of 0.
-		if !VestingInfo::new(locked, per_block, starting_block).is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into());
-		}
-
-		ensure!(
-			(Vesting::<T>::decode_len(who).unwrap_or_default() as u32) < T::MAX_VESTING_SCHEDULES,
-			Error::<T>::AtMaxVestingSchedules
-		);
-
-		Ok(())
+		Self::ensure_can_add_schedule(
+			who,
+			T::NativeCurrencyId::get(),
+			locked,
+			per_block,
+			starting_block,
+		)
 	}
 
 	/// Remove a vesting schedule for a given account.
 	fn remove_vesting_schedule(who: &T::AccountId, schedule_index: u32) -> DispatchResult {
-		let schedules = Self::vesting(who).ok_or(Error::<T>::NotVesting)?;
+		let currency_id = T::NativeCurrencyId::get();
+		let schedules = Self::vesting(who, currency_id).ok_or(Error::<T>::NotVesting)?;
 		let remove_action = VestingAction::Remove { index: schedule_index as usize };
 
 		let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
 
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
+		Self::write_vesting(who, currency_id, schedules)?;
+		Self::write_lock(who, currency_id, locked_now)?;
 		Ok(())
 	}
 }
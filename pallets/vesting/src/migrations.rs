@@ -23,6 +23,15 @@ use super::*;
 // Migration from single schedule to multiple schedules.
 pub(crate) mod v1 {
 	use super::*;
+	use frame_support::{storage_alias, Blake2_128Concat};
+
+	#[storage_alias]
+	type Vesting<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+	>;
 
 	#[allow(dead_code)]
 	#[cfg(feature = "try-runtime")]
@@ -100,3 +109,90 @@ pub(crate) mod v1 {
 		Ok(())
 	}
 }
+
+// Migration from single currency schedules to schedules keyed by currency.
+pub mod v2 {
+	use super::*;
+	use frame_support::{storage_alias, traits::OnRuntimeUpgrade, Blake2_128Concat};
+
+	#[storage_alias]
+	pub(crate) type Vesting<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+	>;
+
+	#[storage_alias]
+	pub(crate) type Cliff<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+	>;
+
+	/// Key the existing `Vesting` and `Cliff` entries by `T::NativeCurrencyId`.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if super::pallet::StorageVersion::<T>::get() == Releases::V1 {
+				let weight_consumed = migrate::<T>();
+				log::info!(target: "runtime::vesting", "Migrating vesting storage to v2");
+				super::pallet::StorageVersion::<T>::put(Releases::V2);
+				weight_consumed.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+			} else {
+				log::warn!(target: "runtime::vesting", "vesting migration should be removed.");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			let count = Vesting::<T>::iter().count() as u32;
+			log::info!(target: "runtime::vesting", "before migration: Vesting v1 count: {}", count);
+
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			ensure!(
+				super::pallet::StorageVersion::<T>::get() == Releases::V2,
+				"Storage version should be V2"
+			);
+			let count = super::pallet::Vesting::<T>::iter().count() as u32;
+			log::info!(target: "runtime::vesting", "after migration: Vesting v2 count: {}", count);
+			ensure!(
+				u32::decode(&mut &state[..]).map_err(|_| "Invalid pre_upgrade state")? == count,
+				"Vesting count should not change during the migration"
+			);
+			ensure!(
+				super::pallet::Vesting::<T>::iter_keys()
+					.all(|(_, currency_id)| currency_id == T::NativeCurrencyId::get()),
+				"All migrated schedules should be in the native currency"
+			);
+
+			Ok(())
+		}
+	}
+
+	pub(crate) fn migrate<T: Config>() -> Weight {
+		let native = T::NativeCurrencyId::get();
+		let mut weight = Weight::zero();
+
+		// The old and the new maps share the storage prefix, so drain all the old entries first.
+		let schedules = Vesting::<T>::drain().collect::<Vec<_>>();
+		for (who, schedule) in schedules {
+			super::pallet::Vesting::<T>::insert(who, native, schedule);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+		}
+
+		let cliffs = Cliff::<T>::drain().collect::<Vec<_>>();
+		for (who, cliff_block) in cliffs {
+			super::pallet::Cliff::<T>::insert(who, native, cliff_block);
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+		}
+
+		weight
+	}
+}
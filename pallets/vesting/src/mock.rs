@@ -16,10 +16,12 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use bifrost_primitives::{CurrencyId, BNC};
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU32, WithdrawReasons},
+	traits::{ConstU32, Nothing, WithdrawReasons},
 };
+use orml_traits::parameter_type_with_key;
 use sp_runtime::{
 	traits::{Identity, IdentityLookup},
 	BuildStorage,
@@ -35,6 +37,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Vesting: pallet_vesting::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
 );
@@ -65,7 +68,28 @@ impl pallet_balances::Config for Test {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> u64 {
+		1
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type Amount = i64;
+	type Balance = u64;
+	type CurrencyId = CurrencyId;
+	type DustRemovalWhitelist = Nothing;
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<10>;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+	type CurrencyHooks = ();
+}
+
 parameter_types! {
+	pub const NativeCurrencyId: CurrencyId = BNC;
 	pub const MinVestedTransfer: u64 = 256 * 2;
 	pub static ExistentialDeposit: u64 = 1;
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
@@ -74,6 +98,8 @@ parameter_types! {
 impl Config for Test {
 	type BlockNumberToBalance = Identity;
 	type Currency = Balances;
+	type MultiCurrency = Tokens;
+	type NativeCurrencyId = NativeCurrencyId;
 	type RuntimeEvent = RuntimeEvent;
 	const MAX_VESTING_SCHEDULES: u32 = 3;
 	type MinVestedTransfer = MinVestedTransfer;
@@ -84,11 +110,12 @@ impl Config for Test {
 pub struct ExtBuilder {
 	existential_deposit: u64,
 	vesting_genesis_config: Option<Vec<(u64, u64, u64, u64)>>,
+	tokens_balances: Vec<(u64, CurrencyId, u64)>,
 }
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
-		Self { existential_deposit: 1, vesting_genesis_config: None }
+		Self { existential_deposit: 1, vesting_genesis_config: None, tokens_balances: vec![] }
 	}
 }
 
@@ -103,6 +130,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn tokens_balances(mut self, balances: Vec<(u64, CurrencyId, u64)>) -> Self {
+		self.tokens_balances = balances;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = self.existential_deposit);
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -119,6 +151,10 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		orml_tokens::GenesisConfig::<Test> { balances: self.tokens_balances }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
 		let vesting = if let Some(vesting_config) = self.vesting_genesis_config {
 			vesting_config
 		} else {
@@ -16,12 +16,13 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{assert_noop, assert_ok, assert_storage_noop};
+use bifrost_primitives::{BNC, VDOT};
+use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::OnRuntimeUpgrade};
 use frame_system::RawOrigin;
 use sp_runtime::{traits::Identity, TokenError};
 
 use super::{Vesting as VestingStorage, *};
-use crate::mock::{Balances, ExtBuilder, System, Test, Vesting};
+use crate::mock::{Balances, ExtBuilder, System, Test, Tokens, Vesting};
 
 const ED: u64 = 1000;
 
@@ -71,9 +72,9 @@ fn check_vesting_status() {
 			CHAR_PER_BLOCK,   // Vesting over 20 blocks
 			10u64,
 		);
-		assert_eq!(Vesting::vesting(&ALICE).unwrap().to_vec(), vec![user1_vesting_schedule]);
-		assert_eq!(Vesting::vesting(&BOB).unwrap().to_vec(), vec![user2_vesting_schedule]);
-		assert_eq!(Vesting::vesting(&CHAR).unwrap().to_vec(), vec![user3_vesting_schedule]);
+		assert_eq!(Vesting::vesting(&ALICE, BNC).unwrap().to_vec(), vec![user1_vesting_schedule]);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![user2_vesting_schedule]);
+		assert_eq!(Vesting::vesting(&CHAR, BNC).unwrap().to_vec(), vec![user3_vesting_schedule]);
 	});
 }
 
@@ -157,10 +158,11 @@ fn vested_transfer_should_work() {
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			ALICE,
+			BNC,
 			user1_vesting_schedule_2
 		));
 		assert_eq!(
-			Vesting::vesting(&ALICE).unwrap().to_vec(),
+			Vesting::vesting(&ALICE, BNC).unwrap().to_vec(),
 			vec![user1_vesting_schedule_1, user1_vesting_schedule_2]
 		);
 	})
@@ -178,7 +180,7 @@ fn do_vest_should_work() {
 
 		//check alice
 		let alice_locked_1 = Balances::locks(ALICE).to_vec()[0].amount;
-		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into()));
+		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into(), BNC));
 		let alice_locked_2 = Balances::locks(ALICE).to_vec()[0].amount;
 
 		assert_eq!(alice_locked_1 - 5 * ALICE_PER_BLOCK, alice_locked_2);
@@ -187,6 +189,7 @@ fn do_vest_should_work() {
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			ALICE,
+			BNC,
 			VestingInfo::new(10000, 1000, 2,)
 		));
 		//check start block is 2 , add locked = 10000 - 3 * 1000
@@ -196,7 +199,7 @@ fn do_vest_should_work() {
 		);
 		let alice_locked_3 = Balances::locks(ALICE).to_vec()[0].amount;
 		assert_eq!(alice_locked_2 + 10000 - 3 * 1000, alice_locked_3);
-		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into()));
+		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into(), BNC));
 		let alice_locked_4 = Balances::locks(ALICE).to_vec()[0].amount;
 		assert_eq!(alice_locked_3, alice_locked_4);
 	})
@@ -207,15 +210,15 @@ fn do_vest_with_cliff_should_work() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
 		//set start_at to 0
 		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
-		assert_ok!(Vesting::force_set_cliff(RawOrigin::Root.into(), ALICE, 4));
-		assert_eq!(Vesting::cliffs(ALICE), Some(4));
+		assert_ok!(Vesting::force_set_cliff(RawOrigin::Root.into(), ALICE, BNC, 4));
+		assert_eq!(Vesting::cliffs(ALICE, BNC), Some(4));
 
 		//set block to 4
 		System::set_block_number(4);
 		assert_eq!(System::block_number(), 4);
 
 		assert_noop!(
-			Vesting::vest(RawOrigin::Signed(ALICE).into()),
+			Vesting::vest(RawOrigin::Signed(ALICE).into(), BNC),
 			Error::<Test>::WrongCliffVesting
 		);
 
@@ -225,7 +228,7 @@ fn do_vest_with_cliff_should_work() {
 
 		//check alice
 		let alice_locked_1 = Balances::locks(ALICE).to_vec()[0].amount;
-		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into()));
+		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into(), BNC));
 		let alice_locked_2 = Balances::locks(ALICE).to_vec()[0].amount;
 		assert_eq!(alice_locked_1 - 5 * ALICE_PER_BLOCK, alice_locked_2);
 	})
@@ -243,7 +246,7 @@ fn do_vest_with_start_at_should_work() {
 
 		//check alice
 		let alice_locked_1 = Balances::locks(ALICE).to_vec()[0].amount;
-		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into()));
+		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into(), BNC));
 		let alice_locked_2 = Balances::locks(ALICE).to_vec()[0].amount;
 		assert_eq!(alice_locked_1 - 2 * ALICE_PER_BLOCK, alice_locked_2);
 
@@ -255,7 +258,7 @@ fn do_vest_with_start_at_should_work() {
 		assert_eq!(System::block_number(), 10);
 		//check alice
 		let alice_locked_3 = Balances::locks(ALICE).to_vec()[0].amount;
-		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into()));
+		assert_ok!(Vesting::vest(RawOrigin::Signed(ALICE).into(), BNC));
 		let alice_locked_4 = Balances::locks(ALICE).to_vec()[0].amount;
 
 		//check current locked = 5 * alice_pre_block , before vest 2 * alice_pre_block , now vest 3
@@ -279,11 +282,11 @@ fn set_vesting_per_block_should_work() {
 		assert_eq!(alice_locked_1, BOB_INIT_LOCKED);
 
 		//set vesting_per_block to 100
-		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 0, 100));
+		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 0, 100));
 
 		//check result start_at 10 > now 5 => 10 start_at
 		let user_vesting_schedule_1 = VestingInfo::new(20000, 100, 10);
-		assert_eq!(Vesting::vesting(&BOB).unwrap().to_vec(), vec![user_vesting_schedule_1]);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![user_vesting_schedule_1]);
 
 		//set block to 15
 		System::set_block_number(15);
@@ -292,13 +295,13 @@ fn set_vesting_per_block_should_work() {
 		let bob_locked_1 = Balances::locks(BOB).to_vec()[0].amount;
 
 		//set vesting_per_block to 10
-		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 0, 10));
+		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 0, 10));
 
 		//check result start_at 10 < now 15 => now 15 - absolute_start 0
 		//old_start_at = old_start_block 10 + absolute_start 0
 		//remained_vesting = 20000 - 5 * 100
 		let user_vesting_schedule_2 = VestingInfo::new(20000 - 5 * 100, 10, 15);
-		assert_eq!(Vesting::vesting(&BOB).unwrap().to_vec(), vec![user_vesting_schedule_2]);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![user_vesting_schedule_2]);
 		let bob_locked_2 = Balances::locks(BOB).to_vec()[0].amount;
 		assert_eq!(bob_locked_1 - 5 * 100, bob_locked_2);
 	})
@@ -319,11 +322,11 @@ fn set_vesting_per_block_with_start_at_should_work() {
 		assert_eq!(alice_locked_1, BOB_INIT_LOCKED);
 
 		//set vesting_per_block to 100
-		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 0, 100));
+		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 0, 100));
 
 		//check result old_start_at 12 > now 5 => 10 start_at
 		let user_vesting_schedule_1 = VestingInfo::new(20000, 100, 10);
-		assert_eq!(Vesting::vesting(&BOB).unwrap().to_vec(), vec![user_vesting_schedule_1]);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![user_vesting_schedule_1]);
 
 		//set block to 15
 		System::set_block_number(15);
@@ -332,13 +335,13 @@ fn set_vesting_per_block_with_start_at_should_work() {
 		let bob_locked_1 = Balances::locks(BOB).to_vec()[0].amount;
 
 		//set vesting_per_block to 10
-		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 0, 10));
+		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 0, 10));
 
 		//old_start_at = old_start_block 10 + absolute_start 2
 		//old_start_at 12 < now 15 => now 15 - absolute_start 2 = 13
 		//remained_vesting = 20000 - 3 * 100
 		let user_vesting_schedule_2 = VestingInfo::new(20000 - 3 * 100, 10, 13);
-		assert_eq!(Vesting::vesting(&BOB).unwrap().to_vec(), vec![user_vesting_schedule_2]);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![user_vesting_schedule_2]);
 		let bob_locked_2 = Balances::locks(BOB).to_vec()[0].amount;
 		assert_eq!(bob_locked_1 - 3 * 100, bob_locked_2);
 	})
@@ -357,15 +360,17 @@ fn repeatedly_set_vesting_per_block_should_work() {
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			BOB,
+			BNC,
 			user_vesting_schedule_2
 		));
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			BOB,
+			BNC,
 			user_vesting_schedule_3
 		));
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![user_vesting_schedule_1, user_vesting_schedule_2, user_vesting_schedule_3]
 		);
 
@@ -379,16 +384,16 @@ fn repeatedly_set_vesting_per_block_should_work() {
 
 		//error OutOfBounds
 		assert_noop!(
-			Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 3, 100),
+			Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 3, 100),
 			Error::<Test>::ScheduleIndexOutOfBounds
 		);
 		//set vesting_per_block to 100
-		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 1, 100));
+		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 1, 100));
 
 		//check result old_start_at 12 > now 5 => 12 start_at
 		let new_user_vesting_schedule_1 = VestingInfo::new(10000, 100, 12);
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![user_vesting_schedule_1, new_user_vesting_schedule_1, user_vesting_schedule_3]
 		);
 
@@ -399,14 +404,14 @@ fn repeatedly_set_vesting_per_block_should_work() {
 		let bob_locked_1 = Balances::locks(BOB).to_vec()[0].amount;
 
 		//set vesting_per_block to 10
-		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, 0, 10));
+		assert_ok!(Vesting::set_vesting_per_block(RawOrigin::Root.into(), BOB, BNC, 0, 10));
 
 		//old_start_at = old_start_block 10 + absolute_start 2
 		//old_start_block 12 < now 15 => now 15 - absolute_start 2 = 13
 		//remained_vesting = 20000 - 3 * 1000
 		let new_user_vesting_schedule_2 = VestingInfo::new(20000 - 3 * 1000, 10, 13);
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![new_user_vesting_schedule_2, new_user_vesting_schedule_1, user_vesting_schedule_3]
 		);
 
@@ -442,10 +447,11 @@ fn merge_schedules_has_not_started_should_work() {
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			BOB,
+			BNC,
 			user_vesting_schedule_2
 		));
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![user_vesting_schedule_1, user_vesting_schedule_2]
 		);
 
@@ -457,10 +463,10 @@ fn merge_schedules_has_not_started_should_work() {
 		// starting_block = 12
 		// locked = 40000
 		// per_block = 40000 / 20 = 2000
-		assert_ok!(Vesting::merge_schedules(RawOrigin::Signed(BOB).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(RawOrigin::Signed(BOB).into(), BNC, 0, 1));
 
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![VestingInfo::new(BOB_INIT_LOCKED * 2, 2000, 12)]
 		);
 		assert_eq!(40000, Balances::locks(BOB).to_vec()[0].amount);
@@ -479,10 +485,11 @@ fn merge_ongoing_schedules_should_work() {
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			BOB,
+			BNC,
 			user_vesting_schedule_2
 		));
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![user_vesting_schedule_1, user_vesting_schedule_2]
 		);
 
@@ -494,10 +501,10 @@ fn merge_ongoing_schedules_should_work() {
 		// starting_block = 40
 		// locked = 40000
 		// per_block = 40000 / 20 = 2000
-		assert_ok!(Vesting::merge_schedules(RawOrigin::Signed(BOB).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(RawOrigin::Signed(BOB).into(), BNC, 0, 1));
 
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![VestingInfo::new(BOB_INIT_LOCKED, 1000, 40)]
 		);
 		assert_eq!(12000, Balances::locks(BOB).to_vec()[0].amount);
@@ -516,10 +523,11 @@ fn merge_finished_schedules_should_work() {
 		assert_ok!(Vesting::vested_transfer(
 			RawOrigin::Signed(DAVE).into(),
 			BOB,
+			BNC,
 			user_vesting_schedule_2
 		));
 		assert_eq!(
-			Vesting::vesting(&BOB).unwrap().to_vec(),
+			Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
 			vec![user_vesting_schedule_1, user_vesting_schedule_2]
 		);
 
@@ -528,9 +536,9 @@ fn merge_finished_schedules_should_work() {
 		assert_eq!(System::block_number(), 60);
 
 		//None
-		assert_ok!(Vesting::merge_schedules(RawOrigin::Signed(BOB).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(RawOrigin::Signed(BOB).into(), BNC, 0, 1));
 
-		assert_eq!(Vesting::vesting(&BOB), None);
+		assert_eq!(Vesting::vesting(&BOB, BNC), None);
 		assert_eq!(0, Balances::locks(BOB).to_vec().len());
 	})
 }
@@ -544,14 +552,14 @@ fn merge_schedules_that_have_not_started() {
 			ED, // Vest over 20 blocks.
 			10,
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 		assert_eq!(Balances::usable_balance(&2), 0);
 
 		// Add a schedule that is identical to the one that already exists.
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, sched0));
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched0]);
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, BNC, sched0));
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched0]);
 		assert_eq!(Balances::usable_balance(&2), 0);
-		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
 
 		// Since we merged identical schedules, the new schedule finishes at the same
 		// time as the original, just with double the amount.
@@ -560,7 +568,7 @@ fn merge_schedules_that_have_not_started() {
 			sched0.per_block() * 2,
 			10, // Starts at the block the schedules are merged/
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched1]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched1]);
 
 		assert_eq!(Balances::usable_balance(&2), 0);
 	});
@@ -577,18 +585,18 @@ fn merge_ongoing_schedules() {
 			ED, // Vest over 20 blocks.
 			10,
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 
 		let sched1 = VestingInfo::new(
 			ED * 10,
 			ED,                          // Vest over 10 blocks.
 			sched0.starting_block() + 5, // Start at block 15.
 		);
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, sched1));
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched1]);
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, BNC, sched1));
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched1]);
 
-		// assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
-		// assert_eq!(Vesting::vesting(&2).unwrap(), vec![]);
+		// assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
+		// assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![]);
 
 		// Got to half way through the second schedule where both schedules are actively vesting.
 		let cur_block = 20;
@@ -598,7 +606,7 @@ fn merge_ongoing_schedules() {
 		// with `vest` yet.
 		assert_eq!(Balances::usable_balance(&2), 0);
 
-		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
 
 		// Merging schedules un-vests all pre-existing schedules prior to merging, which is
 		// reflected in account 2's updated usable balance.
@@ -620,7 +628,7 @@ fn merge_ongoing_schedules() {
 		let sched2_per_block = sched2_locked / sched2_duration;
 
 		let sched2 = VestingInfo::new(sched2_locked, sched2_per_block, cur_block);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched2]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched2]);
 
 		// And just to double check, we assert the new merged schedule we be cleaned up as expected.
 		System::set_block_number(30);
@@ -636,8 +644,8 @@ where
 	T: pallet::Config,
 {
 	// Its ok for this to fail because the user may already have no schedules.
-	let _result = Vesting::vest(Some(account).into());
-	assert!(!<VestingStorage<T>>::contains_key(account));
+	let _result = Vesting::vest(Some(account).into(), BNC);
+	assert!(!<VestingStorage<T>>::contains_key(account, BNC));
 }
 
 #[test]
@@ -663,7 +671,7 @@ fn merging_shifts_other_schedules_index() {
 		);
 
 		// Account 3 starts out with no schedules,
-		assert_eq!(Vesting::vesting(&3), None);
+		assert_eq!(Vesting::vesting(&3, BNC), None);
 		// and some usable balance.
 		let usable_balance = Balances::usable_balance(&3);
 		assert_eq!(usable_balance, 30 * ED);
@@ -672,16 +680,16 @@ fn merging_shifts_other_schedules_index() {
 		assert_eq!(System::block_number(), cur_block);
 
 		// Transfer the above 3 schedules to account 3.
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, sched0));
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, sched1));
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, sched2));
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, BNC, sched0));
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, BNC, sched1));
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 3, BNC, sched2));
 
 		// With no schedules vested or merged they are in the order they are created
-		assert_eq!(Vesting::vesting(&3).unwrap(), vec![sched0, sched1, sched2]);
+		assert_eq!(Vesting::vesting(&3, BNC).unwrap(), vec![sched0, sched1, sched2]);
 		// and the usable balance has not changed.
 		assert_eq!(usable_balance, Balances::usable_balance(&3));
 
-		assert_ok!(Vesting::merge_schedules(Some(3).into(), 0, 2));
+		assert_ok!(Vesting::merge_schedules(Some(3).into(), BNC, 0, 2));
 
 		// Create the merged schedule of sched0 & sched2.
 		// The merged schedule will have the max possible starting block,
@@ -698,7 +706,7 @@ fn merging_shifts_other_schedules_index() {
 		let sched3 = VestingInfo::new(sched3_locked, sched3_per_block, sched3_start);
 
 		// The not touched schedule moves left and the new merged schedule is appended.
-		assert_eq!(Vesting::vesting(&3).unwrap(), vec![sched1, sched3]);
+		assert_eq!(Vesting::vesting(&3, BNC).unwrap(), vec![sched1, sched3]);
 		// The usable balance hasn't changed since none of the schedules have started.
 		assert_eq!(Balances::usable_balance(&3), usable_balance);
 	});
@@ -716,7 +724,7 @@ fn merge_ongoing_and_yet_to_be_started_schedules() {
 			ED, // Vesting over 20 blocks
 			10,
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 
 		// Fast forward to half way through the life of sched1.
 		let mut cur_block =
@@ -728,7 +736,7 @@ fn merge_ongoing_and_yet_to_be_started_schedules() {
 		let mut usable_balance = 0;
 		assert_eq!(Balances::usable_balance(&2), usable_balance);
 		// Vest the current schedules (which is just sched0 now).
-		Vesting::vest(Some(2).into()).unwrap();
+		Vesting::vest(Some(2).into(), BNC).unwrap();
 
 		// After vesting the usable balance increases by the unlocked amount.
 		let sched0_vested_now = sched0.locked() -
@@ -746,10 +754,10 @@ fn merge_ongoing_and_yet_to_be_started_schedules() {
 			1, // Vesting over 256 * 10 (2560) blocks
 			cur_block + 1,
 		);
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, sched1));
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, BNC, sched1));
 
 		// Merge the schedules before sched1 starts.
-		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
 		// After merging, the usable balance only changes by the amount sched0 vested since we
 		// last called `vest` (which is just 1 block). The usable balance is not affected by
 		// sched1 because it has not started yet.
@@ -769,7 +777,7 @@ fn merge_ongoing_and_yet_to_be_started_schedules() {
 		let sched2_per_block = sched2_locked / sched2_duration;
 
 		let sched2 = VestingInfo::new(sched2_locked, sched2_per_block, sched2_start);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched2]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched2]);
 	});
 }
 
@@ -785,14 +793,14 @@ fn merge_finished_and_ongoing_schedules() {
 			ED, // Vesting over 20 blocks.
 			10,
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 
 		let sched1 = VestingInfo::new(
 			ED * 40,
 			ED, // Vesting over 40 blocks.
 			10,
 		);
-		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, sched1));
+		assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, BNC, sched1));
 
 		// Transfer a 3rd schedule, so we can demonstrate how schedule indices change.
 		// (We are not merging this schedule.)
@@ -801,10 +809,10 @@ fn merge_finished_and_ongoing_schedules() {
 			ED, // Vesting over 30 blocks.
 			10,
 		);
-		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched2));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, BNC, sched2));
 
 		// The schedules are in expected order prior to merging.
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched1, sched2]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched1, sched2]);
 
 		// Fast forward to sched0's end block.
 		let cur_block = sched0.ending_block_as_balance::<Identity>();
@@ -814,12 +822,12 @@ fn merge_finished_and_ongoing_schedules() {
 		// Prior to `merge_schedules` and with no vest/vest_other called the user has no usable
 		// balance.
 		assert_eq!(Balances::usable_balance(&2), 0);
-		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
 
 		// sched2 is now the first, since sched0 & sched1 get filtered out while "merging".
 		// sched1 gets treated like the new merged schedule by getting pushed onto back
 		// of the vesting schedules vec. Note: sched0 finished at the current block.
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched2, sched1]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched2, sched1]);
 
 		// sched0 has finished, so its funds are fully unlocked.
 		let sched0_unlocked_now = sched0.locked();
@@ -850,7 +858,7 @@ fn merge_finishing_schedules_does_not_create_a_new_one() {
 			ED, // 20 block duration.
 			10,
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 
 		// Create sched1 and transfer it to account 2.
 		let sched1 = VestingInfo::new(
@@ -858,8 +866,8 @@ fn merge_finishing_schedules_does_not_create_a_new_one() {
 			ED, // 30 block duration.
 			10,
 		);
-		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, sched1));
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched1]);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, BNC, sched1));
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched1]);
 
 		let all_scheds_end = sched0
 			.ending_block_as_balance::<Identity>()
@@ -873,10 +881,10 @@ fn merge_finishing_schedules_does_not_create_a_new_one() {
 		assert_eq!(Balances::usable_balance(&2), 0);
 
 		// Merge schedule 0 and 1.
-		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
 		// The user no longer has any more vesting schedules because they both ended at the
 		// block they where merged,
-		assert!(!<VestingStorage<Test>>::contains_key(&2));
+		assert!(!<VestingStorage<Test>>::contains_key(&2, BNC));
 		// and their usable balance has increased by the total amount locked in the merged
 		// schedules.
 		assert_eq!(Balances::usable_balance(&2), sched0.locked() + sched1.locked());
@@ -893,15 +901,15 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 			ED, // 20 block duration.
 			10, // Ends at block 30
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 
 		let sched1 = VestingInfo::new(
 			ED * 30,
 			ED * 2, // 30 block duration.
 			35,
 		);
-		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched1));
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched1]);
+		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, BNC, sched1));
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched1]);
 
 		let sched2 = VestingInfo::new(
 			ED * 40,
@@ -909,8 +917,8 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 			30,
 		);
 		// Add a 3rd schedule to demonstrate how sched1 shifts.
-		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, sched2));
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched1, sched2]);
+		assert_ok!(Vesting::vested_transfer(Some(13).into(), 2, BNC, sched2));
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched1, sched2]);
 
 		System::set_block_number(30);
 
@@ -921,11 +929,11 @@ fn merge_finished_and_yet_to_be_started_schedules() {
 		assert_eq!(Balances::usable_balance(&2), 0);
 
 		// Merge schedule 0 and 1.
-		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 1));
 
 		// sched0 is removed since it finished, and sched1 is removed and then pushed on the back
 		// because it is treated as the merged schedule
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched2, sched1]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched2, sched1]);
 
 		// The usable balance is updated because merging fully unlocked sched0.
 		assert_eq!(Balances::usable_balance(&2), sched0.locked());
@@ -941,28 +949,31 @@ fn merge_schedules_throws_proper_errors() {
 			ED, // 20 block duration.
 			10,
 		);
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0]);
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0]);
 
 		// Account 2 only has 1 vesting schedule.
 		assert_noop!(
-			Vesting::merge_schedules(Some(2).into(), 0, 1),
+			Vesting::merge_schedules(Some(2).into(), BNC, 0, 1),
 			Error::<Test>::ScheduleIndexOutOfBounds
 		);
 
 		// Account 4 has 0 vesting schedules.
-		assert_eq!(Vesting::vesting(&4), None);
-		assert_noop!(Vesting::merge_schedules(Some(4).into(), 0, 1), Error::<Test>::NotVesting);
+		assert_eq!(Vesting::vesting(&4, BNC), None);
+		assert_noop!(
+			Vesting::merge_schedules(Some(4).into(), BNC, 0, 1),
+			Error::<Test>::NotVesting
+		);
 
 		// There are enough schedules to merge but an index is non-existent.
-		Vesting::vested_transfer(Some(3).into(), 2, sched0).unwrap();
-		assert_eq!(Vesting::vesting(&2).unwrap(), vec![sched0, sched0]);
+		Vesting::vested_transfer(Some(3).into(), 2, BNC, sched0).unwrap();
+		assert_eq!(Vesting::vesting(&2, BNC).unwrap(), vec![sched0, sched0]);
 		assert_noop!(
-			Vesting::merge_schedules(Some(2).into(), 0, 2),
+			Vesting::merge_schedules(Some(2).into(), BNC, 0, 2),
 			Error::<Test>::ScheduleIndexOutOfBounds
 		);
 
 		// It is a storage noop with no errors if the indexes are the same.
-		assert_storage_noop!(Vesting::merge_schedules(Some(2).into(), 0, 0).unwrap());
+		assert_storage_noop!(Vesting::merge_schedules(Some(2).into(), BNC, 0, 0).unwrap());
 	});
 }
 
@@ -1087,11 +1098,268 @@ fn vested_transfer_less_than_existential_deposit_fails() {
 		);
 
 		// vested_transfer fails.
-		assert_noop!(Vesting::vested_transfer(Some(3).into(), 99, sched), TokenError::BelowMinimum,);
+		assert_noop!(
+			Vesting::vested_transfer(Some(3).into(), 99, BNC, sched),
+			TokenError::BelowMinimum,
+		);
 		// force_vested_transfer fails.
 		assert_noop!(
-			Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, 99, sched),
+			Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, 99, BNC, sched),
 			TokenError::BelowMinimum,
 		);
 	});
 }
+
+#[test]
+fn vested_transfer_non_native_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.tokens_balances(vec![(3, VDOT, 10 * ED)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
+			let sched = VestingInfo::new(5 * ED, 500, 0u64);
+
+			assert_ok!(Vesting::vested_transfer(Some(3).into(), DAVE, VDOT, sched));
+			assert_eq!(Vesting::vesting(&DAVE, VDOT).unwrap().to_vec(), vec![sched]);
+			assert_eq!(Vesting::vesting(&DAVE, BNC), None);
+			assert_eq!(Tokens::free_balance(VDOT, &DAVE), 5 * ED);
+			// Block 1 has already vested 500.
+			assert_eq!(orml_tokens::Locks::<Test>::get(DAVE, VDOT)[0].amount, 5 * ED - 500);
+			assert_noop!(
+				<Tokens as MultiCurrency<u64>>::transfer(VDOT, &DAVE, &3, ED),
+				orml_tokens::Error::<Test>::LiquidityRestrictions
+			);
+
+			System::set_block_number(4);
+			assert_ok!(Vesting::vest(Some(DAVE).into(), VDOT));
+			assert_eq!(orml_tokens::Locks::<Test>::get(DAVE, VDOT)[0].amount, 5 * ED - 2000);
+
+			System::set_block_number(10);
+			assert_ok!(Vesting::vest_other(Some(3).into(), DAVE, VDOT));
+			assert_eq!(Vesting::vesting(&DAVE, VDOT), None);
+			assert!(orml_tokens::Locks::<Test>::get(DAVE, VDOT).is_empty());
+			assert_ok!(<Tokens as MultiCurrency<u64>>::transfer(VDOT, &DAVE, &3, 5 * ED));
+		});
+}
+
+#[test]
+fn cliff_and_merge_are_per_currency() {
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.tokens_balances(vec![(3, VDOT, 10 * ED)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
+			let sched0 = VestingInfo::new(2 * ED, 100, 5u64);
+			let sched1 = VestingInfo::new(2 * ED, 100, 10u64);
+			assert_ok!(Vesting::vested_transfer(Some(3).into(), BOB, VDOT, sched0));
+			assert_ok!(Vesting::vested_transfer(Some(3).into(), BOB, VDOT, sched1));
+
+			assert_ok!(Vesting::force_set_cliff(RawOrigin::Root.into(), BOB, VDOT, 3));
+			assert_eq!(Vesting::cliffs(BOB, VDOT), Some(3));
+			assert_eq!(Vesting::cliffs(BOB, BNC), None);
+			assert_noop!(Vesting::vest(Some(BOB).into(), VDOT), Error::<Test>::WrongCliffVesting);
+			// The cliff of vDOT does not apply to the BNC schedules.
+			assert_ok!(Vesting::vest(Some(BOB).into(), BNC));
+
+			assert_ok!(Vesting::merge_schedules(Some(BOB).into(), VDOT, 0, 1));
+			let merged = VestingInfo::new(4 * ED, 200, 10u64);
+			assert_eq!(Vesting::vesting(&BOB, VDOT).unwrap().to_vec(), vec![merged]);
+			assert_eq!(
+				Vesting::vesting(&BOB, BNC).unwrap().to_vec(),
+				vec![VestingInfo::new(BOB_INIT_LOCKED, BOB_PER_BLOCK, 10u64)]
+			);
+			assert_eq!(orml_tokens::Locks::<Test>::get(BOB, VDOT)[0].amount, 4 * ED);
+		});
+}
+
+#[test]
+fn migrate_to_v2_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(ED)
+		.vesting_genesis_config(vec![])
+		.build()
+		.execute_with(|| {
+			StorageVersion::<Test>::put(Releases::V1);
+			let sched = VestingInfo::new(ALICE_INIT_LOCKED, ALICE_PER_BLOCK, 0u64);
+			migrations::v2::Vesting::<Test>::insert(
+				ALICE,
+				BoundedVec::try_from(vec![sched]).unwrap(),
+			);
+			migrations::v2::Cliff::<Test>::insert(ALICE, 4);
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+			assert_eq!(Vesting::vesting(&ALICE, BNC).unwrap().to_vec(), vec![sched]);
+			assert_eq!(Vesting::cliffs(ALICE, BNC), Some(4));
+			assert_eq!(VestingStorage::<Test>::iter().count(), 1);
+			assert_eq!(Cliff::<Test>::iter().count(), 1);
+		});
+}
+
+#[test]
+fn grant_with_cliff_and_ending_block_should_work() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
+		let sched = VestingInfo::new(10 * ED, 500, 0u64);
+		assert_noop!(
+			Vesting::create_grant(Some(3).into(), DAVE, BNC, sched, Some(11), Some(10), 3),
+			Error::<Test>::InvalidScheduleParams
+		);
+
+		assert_ok!(Vesting::create_grant(Some(3).into(), DAVE, BNC, sched, Some(5), Some(10), 3));
+		assert_eq!(Vesting::grants(&DAVE, BNC).unwrap().len(), 1);
+		assert_eq!(Vesting::vesting(&DAVE, BNC), None);
+		// Nothing is unlocked before the cliff.
+		assert_eq!(Vesting::vesting_balance(&DAVE), Some(10 * ED));
+		assert_eq!(Balances::locks(DAVE).to_vec()[0].amount, 10 * ED);
+
+		System::set_block_number(4);
+		assert_ok!(Vesting::vest(Some(DAVE).into(), BNC));
+		assert_eq!(Balances::locks(DAVE).to_vec()[0].amount, 10 * ED);
+
+		System::set_block_number(5);
+		assert_ok!(Vesting::vest(Some(DAVE).into(), BNC));
+		assert_eq!(Balances::locks(DAVE).to_vec()[0].amount, 10 * ED - 5 * 500);
+
+		// Everything is unlocked at the ending block.
+		System::set_block_number(10);
+		assert_ok!(Vesting::vest(Some(DAVE).into(), BNC));
+		assert_eq!(Vesting::grants(&DAVE, BNC), None);
+		assert!(Balances::locks(DAVE).is_empty());
+		assert_noop!(Vesting::vest(Some(DAVE).into(), BNC), Error::<Test>::NotVesting);
+	});
+}
+
+#[test]
+fn revoke_grant_should_work() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
+		let sched = VestingInfo::new(10 * ED, 1000, 0u64);
+		assert_ok!(Vesting::create_grant(Some(3).into(), DAVE, BNC, sched, Some(4), None, 13));
+		assert_ok!(Vesting::create_grant(Some(3).into(), DAVE, BNC, sched, None, None, 13));
+
+		assert_noop!(
+			Vesting::revoke_grant(Some(BOB).into(), DAVE, BNC, 0),
+			Error::<Test>::NotGrantCreator
+		);
+		assert_noop!(
+			Vesting::revoke_grant(Some(3).into(), DAVE, BNC, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		// The creator revokes after the cliff, the vested part stays with the grantee.
+		System::set_block_number(6);
+		assert_ok!(Vesting::revoke_grant(Some(3).into(), DAVE, BNC, 0));
+		System::assert_last_event(
+			Event::<Test>::GrantRevoked {
+				target: DAVE,
+				currency_id: BNC,
+				beneficiary: 13,
+				reclaimed: 4 * ED,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(&13), 9999 * ED + 4 * ED);
+		assert_eq!(Balances::free_balance(&DAVE), 40 * ED + 16 * ED);
+		assert_eq!(Vesting::grants(&DAVE, BNC).unwrap().len(), 1);
+		assert_eq!(Balances::locks(DAVE).to_vec()[0].amount, 4 * ED);
+
+		// Root revokes the remaining grant.
+		assert_ok!(Vesting::revoke_grant(RawOrigin::Root.into(), DAVE, BNC, 0));
+		assert_eq!(Balances::free_balance(&13), 9999 * ED + 8 * ED);
+		assert_eq!(Balances::free_balance(&DAVE), 40 * ED + 12 * ED);
+		assert_eq!(Vesting::grants(&DAVE, BNC), None);
+		assert!(Balances::locks(DAVE).is_empty());
+		assert_noop!(
+			Vesting::revoke_grant(RawOrigin::Root.into(), DAVE, BNC, 0),
+			Error::<Test>::NotVesting
+		);
+	});
+}
+
+#[test]
+fn revoke_grant_should_not_reclaim_funds_of_other_grants() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
+		let sched0 = VestingInfo::new(10 * ED, 1000, 0u64);
+		let sched1 = VestingInfo::new(10 * ED, 500, 0u64);
+		assert_ok!(Vesting::create_grant(Some(3).into(), DAVE, BNC, sched0, None, None, 13));
+		assert_ok!(Vesting::create_grant(Some(3).into(), DAVE, BNC, sched1, None, None, 13));
+
+		// DAVE spends everything usable, 4 * ED of the first grant and 7 * ED of the second
+		// are still locked.
+		System::set_block_number(6);
+		assert_ok!(Vesting::vest(Some(DAVE).into(), BNC));
+		assert_eq!(Balances::locks(DAVE).to_vec()[0].amount, 11 * ED);
+		assert_ok!(Balances::transfer_allow_death(Some(DAVE).into(), BOB, 49 * ED));
+		// Fees were paid out of the locked funds.
+		Balances::make_free_balance_be(&DAVE, 9 * ED);
+
+		// Only what the second grant leaves usable is reclaimed.
+		assert_ok!(Vesting::revoke_grant(Some(3).into(), DAVE, BNC, 0));
+		System::assert_last_event(
+			Event::<Test>::GrantRevoked {
+				target: DAVE,
+				currency_id: BNC,
+				beneficiary: 13,
+				reclaimed: 2 * ED,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(&DAVE), 7 * ED);
+		assert_eq!(Balances::locks(DAVE).to_vec()[0].amount, 7 * ED);
+
+		// The second grant is still fully backed.
+		assert_ok!(Vesting::revoke_grant(Some(3).into(), DAVE, BNC, 0));
+		System::assert_last_event(
+			Event::<Test>::GrantRevoked {
+				target: DAVE,
+				currency_id: BNC,
+				beneficiary: 13,
+				reclaimed: 7 * ED,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(&13), 9999 * ED + 9 * ED);
+		assert_eq!(Vesting::grants(&DAVE, BNC), None);
+		assert!(Balances::locks(DAVE).is_empty());
+	});
+}
+
+#[test]
+fn revoke_grant_after_merge_schedules_should_work() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_ok!(Vesting::init_vesting_start_at(RawOrigin::Root.into(), 0));
+		let sched0 = VestingInfo::new(BOB_INIT_LOCKED, BOB_PER_BLOCK, 10u64);
+		let sched1 = VestingInfo::new(10 * ED, 1000, 12u64);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), BOB, BNC, sched1));
+		let grant = VestingInfo::new(10 * ED, 500, 0u64);
+		assert_ok!(Vesting::create_grant(Some(13).into(), BOB, BNC, grant, Some(5), None, 13));
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![sched0, sched1]);
+		assert_eq!(Balances::locks(BOB).to_vec()[0].amount, 40 * ED);
+
+		// Merging only touches the schedules, the grant is kept apart.
+		assert_ok!(Vesting::merge_schedules(Some(BOB).into(), BNC, 0, 1));
+		let merged = VestingInfo::new(30 * ED, 1666, 12u64);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![merged]);
+		assert_eq!(Vesting::grants(&BOB, BNC).unwrap().len(), 1);
+		assert_eq!(Balances::locks(BOB).to_vec()[0].amount, 40 * ED);
+
+		System::set_block_number(6);
+		assert_ok!(Vesting::revoke_grant(Some(13).into(), BOB, BNC, 0));
+		assert_eq!(Balances::free_balance(&BOB), 40 * ED - 7 * ED);
+		assert_eq!(Vesting::vesting(&BOB, BNC).unwrap().to_vec(), vec![merged]);
+		assert_eq!(Vesting::grants(&BOB, BNC), None);
+		assert_eq!(Balances::locks(BOB).to_vec()[0].amount, 30 * ED);
+
+		// A grant can't be merged.
+		assert_ok!(Vesting::create_grant(Some(13).into(), DAVE, BNC, grant, None, None, 13));
+		assert_noop!(
+			Vesting::merge_schedules(Some(DAVE).into(), BNC, 0, 1),
+			Error::<Test>::NotVesting
+		);
+	});
+}
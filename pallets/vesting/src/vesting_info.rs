@@ -114,3 +114,49 @@ where
 		starting_block.saturating_add(duration)
 	}
 }
+
+/// A revocable vesting grant. The `schedule` unlocks linearly, but nothing is unlocked before
+/// `cliff_block` and everything is unlocked at `ending_block`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingGrant<AccountId, Balance, BlockNumber> {
+	/// The linear unlocking of the grant.
+	pub schedule: VestingInfo<Balance, BlockNumber>,
+	/// Nothing is unlocked before this block.
+	pub cliff_block: Option<BlockNumber>,
+	/// Everything is unlocked at this block.
+	pub ending_block: Option<BlockNumber>,
+	/// The account which created the grant and may revoke it.
+	pub creator: AccountId,
+	/// The account receiving the unvested remainder when the grant is revoked.
+	pub beneficiary: AccountId,
+}
+
+impl<AccountId, Balance, BlockNumber> VestingGrant<AccountId, Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded,
+{
+	/// Validate parameters for `VestingGrant`, the cliff must not come after the ending block.
+	pub fn is_valid(&self) -> bool {
+		let cliff_before_end = match (self.cliff_block, self.ending_block) {
+			(Some(cliff_block), Some(ending_block)) => cliff_block <= ending_block,
+			_ => true,
+		};
+		self.schedule.is_valid() && cliff_before_end
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		n: BlockNumber,
+		start_at: Option<BlockNumber>,
+	) -> Balance {
+		if self.ending_block.map_or(false, |ending_block| n >= ending_block) {
+			return Zero::zero();
+		}
+		if self.cliff_block.map_or(false, |cliff_block| n < cliff_block) {
+			return self.schedule.locked();
+		}
+		self.schedule.locked_at::<BlockNumberToBalance>(n, start_at)
+	}
+}
@@ -57,6 +57,8 @@ pub trait WeightInfo {
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight;
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
+	fn create_grant(l: u32, s: u32, ) -> Weight;
+	fn revoke_grant(l: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_vesting using the Substrate node and recommended hardware.
@@ -242,9 +244,62 @@ impl<T: frame_system::Config> WeightInfo for BifrostWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: Vesting Grants (r:1 w:1)
+	/// Proof: Vesting Grants (max_values: None, max_size: Some(3021), added: 5496, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:1 w:0)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Storage: Vesting VestingStartAt (r:1 w:0)
+	/// Proof: Vesting VestingStartAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn create_grant(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `658 + l * (25 ±0) + s * (116 ±0)`
+		//  Estimated: `6486`
+		// Minimum execution time: 81_420_000 picoseconds.
+		Weight::from_parts(82_913_562, 6486)
+			// Standard Error: 2_611
+			.saturating_add(Weight::from_parts(71_204, 0).saturating_mul(l.into()))
+			// Standard Error: 4_646
+			.saturating_add(Weight::from_parts(131_507, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Vesting Grants (r:1 w:1)
+	/// Proof: Vesting Grants (max_values: None, max_size: Some(3021), added: 5496, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:1 w:0)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Storage: Vesting VestingStartAt (r:1 w:0)
+	/// Proof: Vesting VestingStartAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_grant(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `774 + l * (25 ±0) + s * (116 ±0)`
+		//  Estimated: `6486`
+		// Minimum execution time: 84_136_000 picoseconds.
+		Weight::from_parts(85_302_118, 6486)
+			// Standard Error: 2_702
+			.saturating_add(Weight::from_parts(70_418, 0).saturating_mul(l.into()))
+			// Standard Error: 4_811
+			.saturating_add(Weight::from_parts(128_930, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
-// For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Vesting Vesting (r:1 w:1)
 	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
@@ -426,4 +481,58 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: Vesting Grants (r:1 w:1)
+	/// Proof: Vesting Grants (max_values: None, max_size: Some(3021), added: 5496, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:1 w:0)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Storage: Vesting VestingStartAt (r:1 w:0)
+	/// Proof: Vesting VestingStartAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn create_grant(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `658 + l * (25 ±0) + s * (116 ±0)`
+		//  Estimated: `6486`
+		// Minimum execution time: 81_420_000 picoseconds.
+		Weight::from_parts(82_913_562, 6486)
+			// Standard Error: 2_611
+			.saturating_add(Weight::from_parts(71_204, 0).saturating_mul(l.into()))
+			// Standard Error: 4_646
+			.saturating_add(Weight::from_parts(131_507, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Vesting Grants (r:1 w:1)
+	/// Proof: Vesting Grants (max_values: None, max_size: Some(3021), added: 5496, mode: MaxEncodedLen)
+	/// Storage: Vesting Vesting (r:1 w:0)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Storage: Vesting VestingStartAt (r:1 w:0)
+	/// Proof: Vesting VestingStartAt (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: Balances Freezes (r:1 w:0)
+	/// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_grant(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `774 + l * (25 ±0) + s * (116 ±0)`
+		//  Estimated: `6486`
+		// Minimum execution time: 84_136_000 picoseconds.
+		Weight::from_parts(85_302_118, 6486)
+			// Standard Error: 2_702
+			.saturating_add(Weight::from_parts(70_418, 0).saturating_mul(l.into()))
+			// Standard Error: 4_811
+			.saturating_add(Weight::from_parts(128_930, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
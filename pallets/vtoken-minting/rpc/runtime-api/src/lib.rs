@@ -18,6 +18,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use bifrost_primitives::{Balance, Ratio, TimeUnit};
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
 use sp_core::U256;
@@ -27,5 +28,11 @@ decl_runtime_apis! {
 	pub trait VtokenMintingRuntimeApi<CurrencyId> where CurrencyId: Codec
 	{
 		fn get_exchange_rate(token_id: Option<CurrencyId>) -> Vec<(CurrencyId, U256)>;
+
+		fn get_instant_redeem_quote(vtoken_id: CurrencyId, vtoken_amount: Balance) -> Option<(Balance, Balance)>;
+
+		fn get_exchange_rate_history(token_id: CurrencyId) -> Vec<(TimeUnit, Balance, Balance)>;
+
+		fn get_apy(token_id: CurrencyId, time_units: u32) -> Option<Ratio>;
 	}
 }
@@ -18,6 +18,7 @@
 
 use std::{marker::PhantomData, sync::Arc};
 
+use bifrost_primitives::{Balance, Ratio, TimeUnit};
 pub use bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
@@ -40,6 +41,33 @@ pub trait VtokenMintingRpcApi<CurrencyId, BlockHash> {
 		asset_id: Option<CurrencyId>,
 		at: Option<BlockHash>,
 	) -> RpcResult<Vec<(CurrencyId, NumberOrHex)>>;
+
+	/// rpc method for quoting an instant redemption, returns the token amount and the fee
+	#[method(name = "vtoken_minting_getInstantRedeemQuote")]
+	fn get_instant_redeem_quote(
+		&self,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<(NumberOrHex, NumberOrHex)>>;
+
+	/// rpc method for getting the recorded exchange rates of a token, returns the time unit,
+	/// the token pool and the vtoken issuance of each snapshot
+	#[method(name = "vtoken_minting_getExchangeRateHistory")]
+	fn get_exchange_rate_history(
+		&self,
+		token_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(TimeUnit, NumberOrHex, NumberOrHex)>>;
+
+	/// rpc method for getting the annualized yield of a token over the last time units
+	#[method(name = "vtoken_minting_getApy")]
+	fn get_apy(
+		&self,
+		token_id: CurrencyId,
+		time_units: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Ratio>>;
 }
 
 #[derive(Clone, Debug)]
@@ -85,4 +113,76 @@ where
 			)),
 		}
 	}
+
+	fn get_instant_redeem_quote(
+		&self,
+		vtoken_id: CurrencyId,
+		vtoken_amount: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<(NumberOrHex, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Option<(Balance, Balance)>, _> =
+			api.get_instant_redeem_quote(at, vtoken_id, vtoken_amount);
+
+		match rs {
+			Ok(quote) => Ok(quote.map(|(token_amount, fee)| {
+				(NumberOrHex::Hex(token_amount.into()), NumberOrHex::Hex(fee.into()))
+			})),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get instant redeem quote.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+
+	fn get_exchange_rate_history(
+		&self,
+		token_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(TimeUnit, NumberOrHex, NumberOrHex)>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let rs: Result<Vec<(TimeUnit, Balance, Balance)>, _> =
+			api.get_exchange_rate_history(at, token_id);
+
+		match rs {
+			Ok(data) => Ok(data
+				.into_iter()
+				.map(|(time_unit, token_pool, vtoken_issuance)| {
+					(
+						time_unit,
+						NumberOrHex::Hex(token_pool.into()),
+						NumberOrHex::Hex(vtoken_issuance.into()),
+					)
+				})
+				.collect()),
+			Err(e) => Err(ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get exchange rate history.",
+				Some(format!("{:?}", e)),
+			)),
+		}
+	}
+
+	fn get_apy(
+		&self,
+		token_id: CurrencyId,
+		time_units: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Ratio>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_apy(at, token_id, time_units).map_err(|e| {
+			ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Failed to get apy.",
+				Some(format!("{:?}", e)),
+			)
+		})
+	}
 }
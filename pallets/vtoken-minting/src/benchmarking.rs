@@ -21,7 +21,7 @@
 
 use crate::{Pallet as VtokenMinting, *};
 use bifrost_primitives::{CurrencyId, TokenSymbol, VKSM};
-use frame_benchmarking::v1::{benchmarks, whitelisted_caller, BenchmarkError};
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller, BenchmarkError};
 use frame_support::{assert_ok, sp_runtime::traits::UniqueSaturatedFrom};
 use frame_system::RawOrigin;
 
@@ -222,6 +222,65 @@ benchmarks! {
 		let blocks = Some(BlockNumberFor::<T>::from(1000u32));
 	}: _<T::RuntimeOrigin>(origin, VKSM, blocks)
 
+	set_instant_redeem_buffer {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let buffer = InstantRedeemBuffer {
+			target: BalanceOf::<T>::unique_saturated_from(10000000000u128),
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(10),
+		};
+		assert_ok!(VtokenMinting::<T>::set_instant_redeem_buffer(origin.clone(), KSM, Some(buffer)));
+		T::MultiCurrency::deposit(KSM, &VtokenMinting::<T>::instant_redeem_buffer_account(), BalanceOf::<T>::unique_saturated_from(10000000000u128))?;
+	}: _<T::RuntimeOrigin>(origin, KSM, None)
+
+	instant_redeem {
+		let origin = T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let token_amount = BalanceOf::<T>::unique_saturated_from(10000000000u128);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000u128);
+		let buffer = InstantRedeemBuffer {
+			target: token_amount,
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(10),
+		};
+		assert_ok!(VtokenMinting::<T>::set_instant_redeem_buffer(origin, KSM, Some(buffer)));
+		T::MultiCurrency::deposit(KSM, &caller, token_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, token_amount, BoundedVec::default(), None));
+	}: _(RawOrigin::Signed(caller), VKSM, redeem_amount)
+
+	transfer_unlock {
+		let caller: T::AccountId = whitelisted_caller();
+		let dest: T::AccountId = account("dest", 0, 0);
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &caller, mint_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, mint_amount, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), VKSM, redeem_amount));
+		let unlock_id: UnlockId = 0;
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id, dest)
+
+	split_unlock {
+		let caller: T::AccountId = whitelisted_caller();
+		const VKSM: CurrencyId = CurrencyId::VToken(TokenSymbol::KSM);
+		const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+		let redeem_amount = BalanceOf::<T>::unique_saturated_from(1000000000000u128);
+		let mint_amount = BalanceOf::<T>::unique_saturated_from(2000000000000u128);
+		let split_amount = BalanceOf::<T>::unique_saturated_from(100000000000u128);
+		assert_ok!(VtokenMinting::<T>::set_unlock_duration(T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?, KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::<T>::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		T::MultiCurrency::deposit(KSM, &caller, mint_amount)?;
+		assert_ok!(VtokenMinting::<T>::mint(RawOrigin::Signed(caller.clone()).into(), KSM, mint_amount, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::<T>::redeem(RawOrigin::Signed(caller.clone()).into(), VKSM, redeem_amount));
+		let unlock_id: UnlockId = 0;
+	}: _(RawOrigin::Signed(caller), KSM, unlock_id, split_amount)
+
 	impl_benchmark_test_suite!(
 	VtokenMinting,
 	crate::mock::ExtBuilder::default().one_hundred_for_alice_n_bob().build(),
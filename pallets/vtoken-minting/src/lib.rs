@@ -70,6 +70,33 @@ pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountId
 
 pub type UnlockId = u32;
 
+/// The instant redemption buffer of a currency.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InstantRedeemBuffer<Balance> {
+	/// The amount of token the buffer is refilled to from new mints.
+	pub target: Balance,
+	/// The fee charged while the buffer stays at its target.
+	pub min_fee: Permill,
+	/// The fee charged when the buffer is drained.
+	pub max_fee: Permill,
+}
+
+/// The exchange rate of a token recorded when it is tuned in a time unit.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ExchangeRateSnapshot<Balance, BlockNumber> {
+	pub time_unit: TimeUnit,
+	pub block_number: BlockNumber,
+	pub token_pool: Balance,
+	pub vtoken_issuance: Balance,
+}
+
+/// The maximum number of exchange rate snapshots kept for each token.
+pub const MAX_EXCHANGE_RATE_HISTORY: u32 = 100;
+
+const SECONDS_PER_YEAR: u32 = 31557600;
+const SECONDS_PER_BLOCK: u32 = 12;
+pub const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
+
 // incentive lock id for vtoken minted by user
 const INCENTIVE_LOCK_ID: LockIdentifier = *b"vmincntv";
 
@@ -265,6 +292,34 @@ pub mod pallet {
 			vtoken_id: CurrencyIdOf<T>,
 			blocks: Option<BlockNumberFor<T>>,
 		},
+		InstantRedeemBufferSet {
+			token_id: CurrencyIdOf<T>,
+			buffer: Option<InstantRedeemBuffer<BalanceOf<T>>>,
+		},
+		InstantRedeemBufferRefilled {
+			token_id: CurrencyIdOf<T>,
+			token_amount: BalanceOf<T>,
+		},
+		InstantRedeemed {
+			address: AccountIdOf<T>,
+			token_id: CurrencyIdOf<T>,
+			token_amount: BalanceOf<T>,
+			vtoken_amount: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		UnlockTransferred {
+			token_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+			token_amount: BalanceOf<T>,
+		},
+		UnlockSplit {
+			token_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			new_unlock_id: UnlockId,
+			token_amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -297,6 +352,16 @@ pub mod pallet {
 		FailToRemoveLock,
 		BalanceZero,
 		IncentiveLockBlocksNotSet,
+		InstantRedeemBufferNotSet,
+		InvalidInstantRedeemFee,
+		NotEnoughInstantRedeemBuffer,
+		/// The caller does not hold the unlock entry.
+		NotUnlockOwner,
+		/// Only unlock entries redeemed to the native chain can be transferred or split.
+		UnlockNotTransferable,
+		InvalidUnlockAmount,
+		/// Only the redeemer of an unlock entry can split it, while holding it.
+		CanNotSplitUnlock,
 	}
 
 	#[pallet::storage]
@@ -394,6 +459,42 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The instant redemption buffer of each token.
+	#[pallet::storage]
+	pub type InstantRedeemBuffers<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		InstantRedeemBuffer<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The exchange rate history of each token, one snapshot per time unit, oldest first.
+	#[pallet::storage]
+	pub type ExchangeRateHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		BoundedVec<
+			ExchangeRateSnapshot<BalanceOf<T>, BlockNumberFor<T>>,
+			ConstU32<MAX_EXCHANGE_RATE_HISTORY>,
+		>,
+		ValueQuery,
+	>;
+
+	/// The holder of a transferred unlock entry, who is paid out in place of the redeemer. The
+	/// entry stays listed in the unlock ledger of the redeemer.
+	#[pallet::storage]
+	pub type UnlockHolders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		Twox64Concat,
+		UnlockId,
+		AccountIdOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
@@ -448,163 +549,7 @@ pub mod pallet {
 			token_amount: BalanceOf<T>,
 		) -> DispatchResult {
 			let exchanger = ensure_signed(origin)?;
-			let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(token_id)
-				.map_err(|_| Error::<T>::NotSupportTokenType)?;
-			let _token_amount_to_rebond =
-				TokenToRebond::<T>::get(token_id).ok_or(Error::<T>::InvalidRebondToken)?;
-			if let Some((user_unlock_amount, mut ledger_list)) =
-				UserUnlockLedger::<T>::get(&exchanger, token_id)
-			{
-				ensure!(user_unlock_amount >= token_amount, Error::<T>::NotEnoughBalanceToUnlock);
-				let mut tmp_amount = token_amount;
-				let ledger_list_rev: Vec<UnlockId> = ledger_list.into_iter().rev().collect();
-				ledger_list =
-					BoundedVec::<UnlockId, T::MaximumUnlockIdOfUser>::try_from(ledger_list_rev)
-						.map_err(|_| Error::<T>::ExceedMaximumUnlockId)?;
-				let mut tmp = ledger_list
-					.iter()
-					.map(|&index| -> Result<(UnlockId, bool), Error<T>> {
-						if let Some((_, unlock_amount, time_unit, _)) =
-							TokenUnlockLedger::<T>::get(token_id, index)
-						{
-							if tmp_amount >= unlock_amount {
-								if let Some((_, _, time_unit, _)) =
-									TokenUnlockLedger::<T>::take(&token_id, &index)
-								{
-									TimeUnitUnlockLedger::<T>::mutate_exists(
-										&time_unit,
-										&token_id,
-										|value| -> Result<(), Error<T>> {
-											if let Some((
-												total_locked_origin,
-												ledger_list_origin,
-												_,
-											)) = value
-											{
-												if total_locked_origin == &unlock_amount {
-													*value = None;
-													return Ok(());
-												}
-												*total_locked_origin = total_locked_origin
-													.checked_sub(&unlock_amount)
-													.ok_or(Error::<T>::CalculationOverflow)?;
-												ledger_list_origin.retain(|&x| x != index);
-											} else {
-												return Err(
-													Error::<T>::TimeUnitUnlockLedgerNotFound,
-												);
-											}
-											Ok(())
-										},
-									)?;
-									tmp_amount = tmp_amount.saturating_sub(unlock_amount);
-								} else {
-									return Err(Error::<T>::TokenUnlockLedgerNotFound.into());
-								}
-								Ok((index, false))
-							} else {
-								TokenUnlockLedger::<T>::mutate_exists(
-									&token_id,
-									&index,
-									|value| -> Result<(), Error<T>> {
-										if let Some((_, total_locked_origin, _, _)) = value {
-											if total_locked_origin == &tmp_amount {
-												*value = None;
-												return Ok(());
-											}
-											*total_locked_origin = total_locked_origin
-												.checked_sub(&tmp_amount)
-												.ok_or(Error::<T>::CalculationOverflow)?;
-										} else {
-											return Err(Error::<T>::TokenUnlockLedgerNotFound);
-										}
-										Ok(())
-									},
-								)?;
-								TimeUnitUnlockLedger::<T>::mutate_exists(
-									&time_unit,
-									&token_id,
-									|value| -> Result<(), Error<T>> {
-										if let Some((total_locked_origin, _, _)) = value {
-											if total_locked_origin == &tmp_amount {
-												*value = None;
-												return Ok(());
-											}
-											*total_locked_origin = total_locked_origin
-												.checked_sub(&tmp_amount)
-												.ok_or(Error::<T>::CalculationOverflow)?;
-										} else {
-											return Err(Error::<T>::TimeUnitUnlockLedgerNotFound);
-										}
-										Ok(())
-									},
-								)?;
-								Ok((index, true))
-							}
-						} else {
-							Ok((index, true))
-						}
-					})
-					.collect::<Result<Vec<(UnlockId, bool)>, Error<T>>>()?;
-				tmp.retain(|(_index, result)| *result);
-
-				let ledger_list_tmp: Vec<UnlockId> =
-					tmp.into_iter().map(|(index, _)| index).rev().collect();
-
-				ledger_list =
-					BoundedVec::<UnlockId, T::MaximumUnlockIdOfUser>::try_from(ledger_list_tmp)
-						.map_err(|_| Error::<T>::ExceedMaximumUnlockId)?;
-
-				UnlockingTotal::<T>::mutate(&token_id, |pool| -> Result<(), Error<T>> {
-					*pool =
-						pool.checked_sub(&token_amount).ok_or(Error::<T>::CalculationOverflow)?;
-					Ok(())
-				})?;
-				UserUnlockLedger::<T>::mutate_exists(
-					&exchanger,
-					&token_id,
-					|value| -> Result<(), Error<T>> {
-						if let Some((total_locked_origin, ledger_list_origin)) = value {
-							if total_locked_origin == &token_amount {
-								*value = None;
-								return Ok(());
-							}
-							*ledger_list_origin = ledger_list;
-							*total_locked_origin = total_locked_origin
-								.checked_sub(&token_amount)
-								.ok_or(Error::<T>::CalculationOverflow)?;
-						} else {
-							return Err(Error::<T>::UserUnlockLedgerNotFound);
-						}
-						Ok(())
-					},
-				)?;
-			} else {
-				return Err(Error::<T>::UserUnlockLedgerNotFound.into());
-			}
-
-			let (_, vtoken_amount, fee) =
-				Self::mint_without_tranfer(&exchanger, vtoken_id, token_id, token_amount)?;
-
-			TokenToRebond::<T>::mutate(&token_id, |value| -> Result<(), Error<T>> {
-				if let Some(value_info) = value {
-					*value_info = value_info
-						.checked_add(&token_amount)
-						.ok_or(Error::<T>::CalculationOverflow)?;
-				} else {
-					return Err(Error::<T>::InvalidRebondToken);
-				}
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::Rebonded {
-				address: exchanger,
-				token_id,
-				token_amount,
-				vtoken_amount,
-				fee,
-			});
-			Ok(())
+			Self::rebond_inner(exchanger, token_id, token_amount).map(|_| ())
 		}
 
 		#[pallet::call_index(3)]
@@ -623,7 +568,9 @@ pub mod pallet {
 
 			let unlock_amount = match TokenUnlockLedger::<T>::get(token_id, unlock_id) {
 				Some((who, unlock_amount, time_unit, _)) => {
-					ensure!(who == exchanger, Error::<T>::CanNotRebond);
+					let holder =
+						UnlockHolders::<T>::get(token_id, unlock_id).unwrap_or_else(|| who.clone());
+					ensure!(holder == exchanger, Error::<T>::CanNotRebond);
 					TimeUnitUnlockLedger::<T>::mutate_exists(
 						&time_unit,
 						&token_id,
@@ -671,6 +618,7 @@ pub mod pallet {
 					})?;
 
 					TokenUnlockLedger::<T>::remove(&token_id, &unlock_id);
+					UnlockHolders::<T>::remove(&token_id, &unlock_id);
 					unlock_amount
 				},
 				_ => return Err(Error::<T>::TokenUnlockLedgerNotFound.into()),
@@ -897,95 +845,37 @@ pub mod pallet {
 		) -> DispatchResult {
 			// Check origin
 			let minter = ensure_signed(origin)?;
+			Self::mint_with_lock_inner(minter, token_id, token_amount, remark, channel_id)
+				.map(|_| ())
+		}
 
-			// check if the minter has at least token_amount of token_id which is transferable
-			T::MultiCurrency::ensure_can_withdraw(token_id, &minter, token_amount)
-				.map_err(|_| Error::<T>::NotEnoughBalance)?;
-
-			// check whether the token_id is supported
-			ensure!(MinimumMint::<T>::contains_key(token_id), Error::<T>::NotSupportTokenType);
-
-			// check whether the user has veBNC
-			let vebnc_balance =
-				T::BbBNC::balance_of(&minter, None).map_err(|_| Error::<T>::VeBNCCheckingError)?;
-			ensure!(vebnc_balance > BalanceOf::<T>::zero(), Error::<T>::NotEnoughBalance);
-
-			// check whether the vtoken coefficient is set
-			let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(token_id)
-				.map_err(|_| Error::<T>::NotSupportTokenType)?;
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::unlock_incentive_minted_vtoken())]
+		pub fn unlock_incentive_minted_vtoken(
+			origin: OriginFor<T>,
+			vtoken_id: CurrencyIdOf<T>,
+		) -> DispatchResult {
+			let unlocker = ensure_signed(origin)?;
 
+			// get the user's VtokenLockLedger
 			ensure!(
-				VtokenIncentiveCoef::<T>::contains_key(vtoken_id),
-				Error::<T>::IncentiveCoefNotFound
+				VtokenLockLedger::<T>::contains_key(&unlocker, vtoken_id),
+				Error::<T>::UserUnlockLedgerNotFound
 			);
 
-			// check whether the pool has balance of vtoken_id
-			let incentive_pool_account = &Self::incentive_pool_account();
-			let vtoken_pool_balance =
-				T::MultiCurrency::free_balance(vtoken_id, &incentive_pool_account);
-
-			ensure!(vtoken_pool_balance > BalanceOf::<T>::zero(), Error::<T>::NotEnoughBalance);
-
-			// mint vtoken
-			let vtoken_minted =
-				Self::mint_inner(minter.clone(), token_id, token_amount, remark, channel_id)?;
+			VtokenLockLedger::<T>::mutate_exists(
+				&unlocker,
+				vtoken_id,
+				|maybe_ledger| -> Result<(), Error<T>> {
+					let current_block = frame_system::Pallet::<T>::block_number();
 
-			// lock vtoken and record the lock
-			Self::lock_vtoken_for_incentive_minting(minter.clone(), vtoken_id, vtoken_minted)?;
+					if let Some(ref mut ledger) = maybe_ledger {
+						// check the total locked amount
+						let (total_locked, mut lock_records) = ledger.clone();
 
-			// calculate the incentive amount
-			let incentive_amount =
-				Self::calculate_incentive_vtoken_amount(&minter, vtoken_id, vtoken_minted)?;
-
-			// Since the user has already locked the vtoken, we can directly transfer the incentive
-			// vtoken. It won't fail. transfer the incentive amount to the minter
-			T::MultiCurrency::transfer(
-				vtoken_id,
-				incentive_pool_account,
-				&minter,
-				incentive_amount,
-			)
-			.map_err(|_| Error::<T>::NotEnoughBalance)?;
-
-			// deposit event
-			Self::deposit_event(Event::IncentivizedMinting {
-				address: minter,
-				token_id,
-				token_amount,
-				locked_vtoken_amount: vtoken_minted,
-				incentive_vtoken_amount: incentive_amount,
-			});
-
-			Ok(())
-		}
-
-		#[pallet::call_index(15)]
-		#[pallet::weight(T::WeightInfo::unlock_incentive_minted_vtoken())]
-		pub fn unlock_incentive_minted_vtoken(
-			origin: OriginFor<T>,
-			vtoken_id: CurrencyIdOf<T>,
-		) -> DispatchResult {
-			let unlocker = ensure_signed(origin)?;
-
-			// get the user's VtokenLockLedger
-			ensure!(
-				VtokenLockLedger::<T>::contains_key(&unlocker, vtoken_id),
-				Error::<T>::UserUnlockLedgerNotFound
-			);
-
-			VtokenLockLedger::<T>::mutate_exists(
-				&unlocker,
-				vtoken_id,
-				|maybe_ledger| -> Result<(), Error<T>> {
-					let current_block = frame_system::Pallet::<T>::block_number();
-
-					if let Some(ref mut ledger) = maybe_ledger {
-						// check the total locked amount
-						let (total_locked, mut lock_records) = ledger.clone();
-
-						// unlock the vtoken
-						let mut unlock_amount = BalanceOf::<T>::zero();
-						let mut remove_index = 0;
+						// unlock the vtoken
+						let mut unlock_amount = BalanceOf::<T>::zero();
+						let mut remove_index = 0;
 
 						// enumerate lock_records
 						for (index, (locked_amount, due_block_num)) in
@@ -1086,6 +976,152 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set or remove the instant redemption buffer of `token_id`. The tokens left in a
+		/// removed buffer are returned to the entrance account to be staked.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_instant_redeem_buffer())]
+		pub fn set_instant_redeem_buffer(
+			origin: OriginFor<T>,
+			token_id: CurrencyIdOf<T>,
+			buffer: Option<InstantRedeemBuffer<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			if let Some(ref buffer) = buffer {
+				ensure!(buffer.min_fee <= buffer.max_fee, Error::<T>::InvalidInstantRedeemFee);
+				InstantRedeemBuffers::<T>::insert(token_id, buffer);
+			} else {
+				InstantRedeemBuffers::<T>::remove(token_id);
+				let buffer_account = Self::instant_redeem_buffer_account();
+				let buffer_balance = T::MultiCurrency::free_balance(token_id, &buffer_account);
+				T::MultiCurrency::transfer(
+					token_id,
+					&buffer_account,
+					&T::EntranceAccount::get().into_account_truncating(),
+					buffer_balance,
+				)?;
+			}
+
+			Self::deposit_event(Event::InstantRedeemBufferSet { token_id, buffer });
+
+			Ok(())
+		}
+
+		/// Redeem `vtoken_amount` immediately from the instant redemption buffer, charging a
+		/// fee which rises as the buffer drains.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::instant_redeem())]
+		pub fn instant_redeem(
+			origin: OriginFor<T>,
+			vtoken_id: CurrencyIdOf<T>,
+			vtoken_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let exchanger = ensure_signed(origin)?;
+			Self::instant_redeem_inner(exchanger, vtoken_id, vtoken_amount).map(|_| ())
+		}
+
+		/// Transfer the pending unlock entry `unlock_id` to `dest`, who receives the tokens when
+		/// the entry is paid out and can transfer or rebond it in turn. The entry stays listed
+		/// in the unlock ledger of its redeemer, so any account can receive it.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::transfer_unlock())]
+		pub fn transfer_unlock(
+			origin: OriginFor<T>,
+			token_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			dest: AccountIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (redeemer, unlock_amount, _) =
+				Self::ensure_unlock_transferable(&who, token_id, unlock_id)?;
+
+			if dest == redeemer {
+				UnlockHolders::<T>::remove(token_id, unlock_id);
+			} else {
+				UnlockHolders::<T>::insert(token_id, unlock_id, &dest);
+			}
+
+			Self::deposit_event(Event::UnlockTransferred {
+				token_id,
+				unlock_id,
+				from: who,
+				to: dest,
+				token_amount: unlock_amount,
+			});
+			Ok(())
+		}
+
+		/// Split `token_amount` off the pending unlock entry `unlock_id` into a new entry with
+		/// the same unlock time, so that it can be transferred on its own. Both entries must be
+		/// worth at least the minimum redemption, and only the redeemer can split an entry it
+		/// holds.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::split_unlock())]
+		pub fn split_unlock(
+			origin: OriginFor<T>,
+			token_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+			token_amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (redeemer, unlock_amount, time_unit) =
+				Self::ensure_unlock_transferable(&who, token_id, unlock_id)?;
+			// The new entry is listed in the unlock ledger of the redeemer.
+			ensure!(redeemer == who, Error::<T>::CanNotSplitUnlock);
+			ensure!(
+				!token_amount.is_zero() && token_amount < unlock_amount,
+				Error::<T>::InvalidUnlockAmount
+			);
+			let minimum_unlock_amount = Self::minimum_unlock_amount(token_id)?;
+			ensure!(
+				token_amount >= minimum_unlock_amount &&
+					unlock_amount - token_amount >= minimum_unlock_amount,
+				Error::<T>::BelowMinimumRedeem
+			);
+
+			let new_unlock_id = TokenUnlockNextId::<T>::get(token_id);
+			TokenUnlockLedger::<T>::insert(
+				&token_id,
+				&unlock_id,
+				(&who, unlock_amount - token_amount, &time_unit, RedeemType::Native),
+			);
+			TokenUnlockLedger::<T>::insert(
+				&token_id,
+				&new_unlock_id,
+				(&who, token_amount, &time_unit, RedeemType::Native),
+			);
+
+			// The totals stay the same, only the new entry is listed.
+			UserUnlockLedger::<T>::mutate(&who, &token_id, |value| -> Result<(), Error<T>> {
+				let (_, ledger_list) =
+					value.as_mut().ok_or(Error::<T>::UserUnlockLedgerNotFound)?;
+				ledger_list.try_push(new_unlock_id).map_err(|_| Error::<T>::TooManyRedeems)
+			})?;
+			TimeUnitUnlockLedger::<T>::mutate(
+				&time_unit,
+				&token_id,
+				|value| -> Result<(), Error<T>> {
+					let (_, ledger_list, _) =
+						value.as_mut().ok_or(Error::<T>::TimeUnitUnlockLedgerNotFound)?;
+					ledger_list.try_push(new_unlock_id).map_err(|_| Error::<T>::TooManyRedeems)
+				},
+			)?;
+			TokenUnlockNextId::<T>::mutate(&token_id, |unlock_id| -> Result<(), Error<T>> {
+				*unlock_id = unlock_id.checked_add(1).ok_or(Error::<T>::CalculationOverflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::UnlockSplit {
+				token_id,
+				unlock_id,
+				new_unlock_id,
+				token_amount,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1178,11 +1214,14 @@ pub mod pallet {
 			redeem_type: RedeemType<AccountIdOf<T>>,
 		) -> DispatchResult {
 			let ed = T::MultiCurrency::minimum_balance(token_id);
-			let mut account_to_send = account.clone();
+			// A transferred entry is paid out to its holder.
+			let holder =
+				UnlockHolders::<T>::get(token_id, index).unwrap_or_else(|| account.clone());
+			let mut account_to_send = holder.clone();
 			let mut redeem_to = RedeemTo::Native(account_to_send.clone());
 
 			if unlock_amount < ed {
-				let receiver_balance = T::MultiCurrency::total_balance(token_id, &account);
+				let receiver_balance = T::MultiCurrency::total_balance(token_id, &holder);
 
 				let receiver_balance_after = receiver_balance
 					.checked_add(&unlock_amount)
@@ -1200,6 +1239,7 @@ pub mod pallet {
 					unlock_amount,
 				)?;
 				TokenUnlockLedger::<T>::remove(&token_id, &index);
+				UnlockHolders::<T>::remove(&token_id, &index);
 
 				TimeUnitUnlockLedger::<T>::mutate_exists(
 					&time_unit,
@@ -1441,7 +1481,7 @@ pub mod pallet {
 				Ok(())
 			})?;
 
-			T::OnRedeemSuccess::on_redeem_success(token_id, account.clone(), unlock_amount);
+			T::OnRedeemSuccess::on_redeem_success(token_id, holder, unlock_amount);
 
 			Self::deposit_event(Event::RedeemSuccess {
 				unlock_id: *index,
@@ -1553,12 +1593,20 @@ pub mod pallet {
 				.map_err(|_| Error::<T>::NotSupportTokenType)?;
 			let (token_amount_excluding_fee, vtoken_amount, fee) =
 				Self::mint_without_tranfer(&exchanger, vtoken_id, token_id, token_amount)?;
+			// Refill the instant redemption buffer before the rest is staked.
+			let refill_amount = Self::refill_instant_redeem_buffer(
+				&exchanger,
+				token_id,
+				token_amount_excluding_fee,
+			)?;
 			// Transfer the user's token to EntranceAccount.
 			T::MultiCurrency::transfer(
 				token_id,
 				&exchanger,
 				&T::EntranceAccount::get().into_account_truncating(),
-				token_amount_excluding_fee,
+				token_amount_excluding_fee
+					.checked_sub(&refill_amount)
+					.ok_or(Error::<T>::CalculationOverflow)?,
 			)?;
 
 			// record the minting information for ChannelCommission module
@@ -1737,6 +1785,448 @@ pub mod pallet {
 			Ok(Some(T::WeightInfo::redeem() + extra_weight).into())
 		}
 
+		/// Redeem `vtoken_amount` from the instant redemption buffer, returns the token amount
+		/// received by the exchanger.
+		#[transactional]
+		pub fn instant_redeem_inner(
+			exchanger: AccountIdOf<T>,
+			vtoken_id: CurrencyIdOf<T>,
+			vtoken_amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let token_id = T::CurrencyIdConversion::convert_to_token(vtoken_id)
+				.map_err(|_| Error::<T>::NotSupportTokenType)?;
+			ensure!(
+				vtoken_amount >= MinimumRedeem::<T>::get(vtoken_id),
+				Error::<T>::BelowMinimumRedeem
+			);
+
+			let (token_amount, fee) = Self::quote_instant_redeem(vtoken_id, vtoken_amount)?;
+			let token_amount_excluding_fee =
+				token_amount.checked_sub(&fee).ok_or(Error::<T>::CalculationOverflow)?;
+
+			// The redeemed tokens leave the pool, so the exchange rate is kept.
+			T::MultiCurrency::withdraw(vtoken_id, &exchanger, vtoken_amount)?;
+			TokenPool::<T>::mutate(&token_id, |pool| -> Result<(), Error<T>> {
+				*pool = pool.checked_sub(&token_amount).ok_or(Error::<T>::CalculationOverflow)?;
+				Ok(())
+			})?;
+
+			let buffer_account = Self::instant_redeem_buffer_account();
+			T::MultiCurrency::transfer(token_id, &buffer_account, &T::FeeAccount::get(), fee)?;
+			T::MultiCurrency::transfer(
+				token_id,
+				&buffer_account,
+				&exchanger,
+				token_amount_excluding_fee,
+			)?;
+
+			T::ChannelCommission::record_redeem_amount(vtoken_id, vtoken_amount)?;
+
+			Self::deposit_event(Event::InstantRedeemed {
+				address: exchanger,
+				token_id,
+				token_amount: token_amount_excluding_fee,
+				vtoken_amount,
+				fee,
+			});
+			Ok(token_amount_excluding_fee)
+		}
+
+		/// Record the current exchange rate of `token_id` for its ongoing time unit, replacing
+		/// the snapshot of the same time unit and dropping the oldest one when full.
+		pub(crate) fn record_exchange_rate(token_id: CurrencyIdOf<T>) {
+			let time_unit = match OngoingTimeUnit::<T>::get(token_id) {
+				Some(time_unit) => time_unit,
+				None => return,
+			};
+			let vtoken_id = match T::CurrencyIdConversion::convert_to_vtoken(token_id) {
+				Ok(vtoken_id) => vtoken_id,
+				Err(_) => return,
+			};
+			let snapshot = ExchangeRateSnapshot {
+				time_unit: time_unit.clone(),
+				block_number: frame_system::Pallet::<T>::block_number(),
+				token_pool: TokenPool::<T>::get(token_id),
+				vtoken_issuance: T::MultiCurrency::total_issuance(vtoken_id),
+			};
+
+			ExchangeRateHistory::<T>::mutate(token_id, |history| {
+				if history.last().map_or(false, |last| last.time_unit == time_unit) {
+					history.pop();
+				} else if history.len() as u32 >= MAX_EXCHANGE_RATE_HISTORY {
+					history.remove(0);
+				}
+				let _ = history.try_push(snapshot);
+			});
+		}
+
+		/// The annualized yield of `token_id` over the last `time_units` recorded time units,
+		/// from the growth of the exchange rate without compounding. Returns `None` if there
+		/// are not enough snapshots.
+		pub fn get_apy(token_id: CurrencyIdOf<T>, time_units: u32) -> Option<Permill> {
+			let history = ExchangeRateHistory::<T>::get(token_id);
+			if time_units == 0 || history.len() <= time_units as usize {
+				return None;
+			}
+			let latest = history.last()?;
+			let base = history.get(history.len() - 1 - time_units as usize)?;
+
+			let elapsed: u32 =
+				latest.block_number.saturating_sub(base.block_number).unique_saturated_into();
+			let to_u256 = |balance: BalanceOf<T>| U256::from(balance.saturated_into::<u128>());
+			let latest_value =
+				to_u256(latest.token_pool).saturating_mul(to_u256(base.vtoken_issuance));
+			let base_value =
+				to_u256(base.token_pool).saturating_mul(to_u256(latest.vtoken_issuance));
+			if elapsed == 0 || base_value.is_zero() {
+				return None;
+			}
+
+			let parts = latest_value
+				.saturating_sub(base_value)
+				.saturating_mul(U256::from(BLOCKS_PER_YEAR))
+				.saturating_mul(U256::from(1_000_000u32))
+				.checked_div(base_value.saturating_mul(U256::from(elapsed)))?;
+			Some(Permill::from_parts(parts.min(U256::from(1_000_000u32)).as_u32()))
+		}
+
+		/// Quote an instant redemption of `vtoken_amount`, returns the token amount taken from
+		/// the buffer and the fee charged on it.
+		pub fn quote_instant_redeem(
+			vtoken_id: CurrencyIdOf<T>,
+			vtoken_amount: BalanceOf<T>,
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let token_id = T::CurrencyIdConversion::convert_to_token(vtoken_id)
+				.map_err(|_| Error::<T>::NotSupportTokenType)?;
+			let buffer = InstantRedeemBuffers::<T>::get(token_id)
+				.ok_or(Error::<T>::InstantRedeemBufferNotSet)?;
+
+			let token_amount = Self::vtoken_to_token_inner(token_id, vtoken_id, vtoken_amount)?;
+			let buffer_balance =
+				T::MultiCurrency::free_balance(token_id, &Self::instant_redeem_buffer_account());
+			let remaining = buffer_balance
+				.checked_sub(&token_amount)
+				.ok_or(Error::<T>::NotEnoughInstantRedeemBuffer)?;
+
+			// The fee grows linearly from `min_fee` to `max_fee` as the buffer drains below its
+			// target.
+			let drained = if buffer.target.is_zero() {
+				Permill::one()
+			} else {
+				Permill::one().saturating_sub(Permill::from_rational(
+					remaining.min(buffer.target),
+					buffer.target,
+				))
+			};
+			let fee_rate = buffer
+				.min_fee
+				.saturating_add(drained * buffer.max_fee.saturating_sub(buffer.min_fee));
+
+			Ok((token_amount, fee_rate.mul_ceil(token_amount)))
+		}
+
+		/// Move up to `token_amount` of the exchanger's token into the instant redemption buffer
+		/// until it reaches its target, returns the refilled amount.
+		fn refill_instant_redeem_buffer(
+			exchanger: &AccountIdOf<T>,
+			token_id: CurrencyIdOf<T>,
+			token_amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let buffer = match InstantRedeemBuffers::<T>::get(token_id) {
+				Some(buffer) => buffer,
+				None => return Ok(Zero::zero()),
+			};
+			let buffer_account = Self::instant_redeem_buffer_account();
+			let buffer_balance = T::MultiCurrency::free_balance(token_id, &buffer_account);
+			let refill_amount = buffer.target.saturating_sub(buffer_balance).min(token_amount);
+			if refill_amount.is_zero() {
+				return Ok(Zero::zero());
+			}
+
+			T::MultiCurrency::transfer(token_id, exchanger, &buffer_account, refill_amount)?;
+			Self::deposit_event(Event::InstantRedeemBufferRefilled {
+				token_id,
+				token_amount: refill_amount,
+			});
+			Ok(refill_amount)
+		}
+
+		/// Ensure `who` holds the unlock entry and that it is paid out on the native chain,
+		/// returns the redeemer, the unlock amount and time.
+		fn ensure_unlock_transferable(
+			who: &AccountIdOf<T>,
+			token_id: CurrencyIdOf<T>,
+			unlock_id: UnlockId,
+		) -> Result<(AccountIdOf<T>, BalanceOf<T>, TimeUnit), DispatchError> {
+			let (redeemer, unlock_amount, time_unit, redeem_type) =
+				TokenUnlockLedger::<T>::get(token_id, unlock_id)
+					.ok_or(Error::<T>::TokenUnlockLedgerNotFound)?;
+			let holder =
+				UnlockHolders::<T>::get(token_id, unlock_id).unwrap_or_else(|| redeemer.clone());
+			ensure!(holder == *who, Error::<T>::NotUnlockOwner);
+			ensure!(redeem_type == RedeemType::Native, Error::<T>::UnlockNotTransferable);
+			Ok((redeemer, unlock_amount, time_unit))
+		}
+
+		/// The minimum redemption of the vtoken of `token_id`, valued in `token_id`.
+		fn minimum_unlock_amount(token_id: CurrencyIdOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+			let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(token_id)
+				.map_err(|_| Error::<T>::NotSupportTokenType)?;
+			let minimum_redeem = MinimumRedeem::<T>::get(vtoken_id);
+			// Without vtoken issuance there is no exchange rate, use the vtoken amount as is.
+			Ok(Self::vtoken_to_token_inner(token_id, vtoken_id, minimum_redeem)
+				.unwrap_or(minimum_redeem))
+		}
+
+		/// Rebond `token_amount` of the exchanger's unlocking tokens, returns the minted vtoken
+		/// amount.
+		pub fn rebond_inner(
+			exchanger: AccountIdOf<T>,
+			token_id: CurrencyIdOf<T>,
+			token_amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(token_id)
+				.map_err(|_| Error::<T>::NotSupportTokenType)?;
+			let _token_amount_to_rebond =
+				TokenToRebond::<T>::get(token_id).ok_or(Error::<T>::InvalidRebondToken)?;
+			if let Some((user_unlock_amount, mut ledger_list)) =
+				UserUnlockLedger::<T>::get(&exchanger, token_id)
+			{
+				// Entries transferred to other holders can not be rebonded by the redeemer.
+				let transferred_amount = ledger_list
+					.iter()
+					.filter(|&&index| UnlockHolders::<T>::contains_key(token_id, index))
+					.filter_map(|&index| TokenUnlockLedger::<T>::get(token_id, index))
+					.fold(BalanceOf::<T>::zero(), |total, (_, unlock_amount, _, _)| {
+						total.saturating_add(unlock_amount)
+					});
+				ensure!(
+					user_unlock_amount.saturating_sub(transferred_amount) >= token_amount,
+					Error::<T>::NotEnoughBalanceToUnlock
+				);
+				let mut tmp_amount = token_amount;
+				let ledger_list_rev: Vec<UnlockId> = ledger_list.into_iter().rev().collect();
+				ledger_list =
+					BoundedVec::<UnlockId, T::MaximumUnlockIdOfUser>::try_from(ledger_list_rev)
+						.map_err(|_| Error::<T>::ExceedMaximumUnlockId)?;
+				let mut tmp = ledger_list
+					.iter()
+					.map(|&index| -> Result<(UnlockId, bool), Error<T>> {
+						if UnlockHolders::<T>::contains_key(token_id, index) {
+							return Ok((index, true));
+						}
+						if let Some((_, unlock_amount, time_unit, _)) =
+							TokenUnlockLedger::<T>::get(token_id, index)
+						{
+							if tmp_amount >= unlock_amount {
+								if let Some((_, _, time_unit, _)) =
+									TokenUnlockLedger::<T>::take(&token_id, &index)
+								{
+									TimeUnitUnlockLedger::<T>::mutate_exists(
+										&time_unit,
+										&token_id,
+										|value| -> Result<(), Error<T>> {
+											if let Some((
+												total_locked_origin,
+												ledger_list_origin,
+												_,
+											)) = value
+											{
+												if total_locked_origin == &unlock_amount {
+													*value = None;
+													return Ok(());
+												}
+												*total_locked_origin = total_locked_origin
+													.checked_sub(&unlock_amount)
+													.ok_or(Error::<T>::CalculationOverflow)?;
+												ledger_list_origin.retain(|&x| x != index);
+											} else {
+												return Err(
+													Error::<T>::TimeUnitUnlockLedgerNotFound,
+												);
+											}
+											Ok(())
+										},
+									)?;
+									tmp_amount = tmp_amount.saturating_sub(unlock_amount);
+								} else {
+									return Err(Error::<T>::TokenUnlockLedgerNotFound.into());
+								}
+								Ok((index, false))
+							} else {
+								TokenUnlockLedger::<T>::mutate_exists(
+									&token_id,
+									&index,
+									|value| -> Result<(), Error<T>> {
+										if let Some((_, total_locked_origin, _, _)) = value {
+											if total_locked_origin == &tmp_amount {
+												*value = None;
+												return Ok(());
+											}
+											*total_locked_origin = total_locked_origin
+												.checked_sub(&tmp_amount)
+												.ok_or(Error::<T>::CalculationOverflow)?;
+										} else {
+											return Err(Error::<T>::TokenUnlockLedgerNotFound);
+										}
+										Ok(())
+									},
+								)?;
+								TimeUnitUnlockLedger::<T>::mutate_exists(
+									&time_unit,
+									&token_id,
+									|value| -> Result<(), Error<T>> {
+										if let Some((total_locked_origin, _, _)) = value {
+											if total_locked_origin == &tmp_amount {
+												*value = None;
+												return Ok(());
+											}
+											*total_locked_origin = total_locked_origin
+												.checked_sub(&tmp_amount)
+												.ok_or(Error::<T>::CalculationOverflow)?;
+										} else {
+											return Err(Error::<T>::TimeUnitUnlockLedgerNotFound);
+										}
+										Ok(())
+									},
+								)?;
+								Ok((index, true))
+							}
+						} else {
+							Ok((index, true))
+						}
+					})
+					.collect::<Result<Vec<(UnlockId, bool)>, Error<T>>>()?;
+				tmp.retain(|(_index, result)| *result);
+
+				let ledger_list_tmp: Vec<UnlockId> =
+					tmp.into_iter().map(|(index, _)| index).rev().collect();
+
+				ledger_list =
+					BoundedVec::<UnlockId, T::MaximumUnlockIdOfUser>::try_from(ledger_list_tmp)
+						.map_err(|_| Error::<T>::ExceedMaximumUnlockId)?;
+
+				UnlockingTotal::<T>::mutate(&token_id, |pool| -> Result<(), Error<T>> {
+					*pool =
+						pool.checked_sub(&token_amount).ok_or(Error::<T>::CalculationOverflow)?;
+					Ok(())
+				})?;
+				UserUnlockLedger::<T>::mutate_exists(
+					&exchanger,
+					&token_id,
+					|value| -> Result<(), Error<T>> {
+						if let Some((total_locked_origin, ledger_list_origin)) = value {
+							if total_locked_origin == &token_amount {
+								*value = None;
+								return Ok(());
+							}
+							*ledger_list_origin = ledger_list;
+							*total_locked_origin = total_locked_origin
+								.checked_sub(&token_amount)
+								.ok_or(Error::<T>::CalculationOverflow)?;
+						} else {
+							return Err(Error::<T>::UserUnlockLedgerNotFound);
+						}
+						Ok(())
+					},
+				)?;
+			} else {
+				return Err(Error::<T>::UserUnlockLedgerNotFound.into());
+			}
+
+			let (_, vtoken_amount, fee) =
+				Self::mint_without_tranfer(&exchanger, vtoken_id, token_id, token_amount)?;
+
+			TokenToRebond::<T>::mutate(&token_id, |value| -> Result<(), Error<T>> {
+				if let Some(value_info) = value {
+					*value_info = value_info
+						.checked_add(&token_amount)
+						.ok_or(Error::<T>::CalculationOverflow)?;
+				} else {
+					return Err(Error::<T>::InvalidRebondToken);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Rebonded {
+				address: exchanger,
+				token_id,
+				token_amount,
+				vtoken_amount,
+				fee,
+			});
+			Ok(vtoken_amount)
+		}
+
+		/// Mint vtoken and lock it for the incentive, returns the locked vtoken amount.
+		pub fn mint_with_lock_inner(
+			minter: AccountIdOf<T>,
+			token_id: CurrencyIdOf<T>,
+			token_amount: BalanceOf<T>,
+			remark: BoundedVec<u8, ConstU32<32>>,
+			channel_id: Option<u32>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			// check if the minter has at least token_amount of token_id which is transferable
+			T::MultiCurrency::ensure_can_withdraw(token_id, &minter, token_amount)
+				.map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+			// check whether the token_id is supported
+			ensure!(MinimumMint::<T>::contains_key(token_id), Error::<T>::NotSupportTokenType);
+
+			// check whether the user has veBNC
+			let vebnc_balance =
+				T::BbBNC::balance_of(&minter, None).map_err(|_| Error::<T>::VeBNCCheckingError)?;
+			ensure!(vebnc_balance > BalanceOf::<T>::zero(), Error::<T>::NotEnoughBalance);
+
+			// check whether the vtoken coefficient is set
+			let vtoken_id = T::CurrencyIdConversion::convert_to_vtoken(token_id)
+				.map_err(|_| Error::<T>::NotSupportTokenType)?;
+
+			ensure!(
+				VtokenIncentiveCoef::<T>::contains_key(vtoken_id),
+				Error::<T>::IncentiveCoefNotFound
+			);
+
+			// check whether the pool has balance of vtoken_id
+			let incentive_pool_account = &Self::incentive_pool_account();
+			let vtoken_pool_balance =
+				T::MultiCurrency::free_balance(vtoken_id, &incentive_pool_account);
+
+			ensure!(vtoken_pool_balance > BalanceOf::<T>::zero(), Error::<T>::NotEnoughBalance);
+
+			// mint vtoken
+			let vtoken_minted =
+				Self::mint_inner(minter.clone(), token_id, token_amount, remark, channel_id)?;
+
+			// lock vtoken and record the lock
+			Self::lock_vtoken_for_incentive_minting(minter.clone(), vtoken_id, vtoken_minted)?;
+
+			// calculate the incentive amount
+			let incentive_amount =
+				Self::calculate_incentive_vtoken_amount(&minter, vtoken_id, vtoken_minted)?;
+
+			// Since the user has already locked the vtoken, we can directly transfer the incentive
+			// vtoken. It won't fail. transfer the incentive amount to the minter
+			T::MultiCurrency::transfer(
+				vtoken_id,
+				incentive_pool_account,
+				&minter,
+				incentive_amount,
+			)
+			.map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+			// deposit event
+			Self::deposit_event(Event::IncentivizedMinting {
+				address: minter,
+				token_id,
+				token_amount,
+				locked_vtoken_amount: vtoken_minted,
+				incentive_vtoken_amount: incentive_amount,
+			});
+
+			Ok(vtoken_minted)
+		}
+
 		pub fn token_to_vtoken_inner(
 			token_id: CurrencyIdOf<T>,
 			vtoken_id: CurrencyIdOf<T>,
@@ -1785,6 +2275,12 @@ pub mod pallet {
 			T::IncentivePoolAccount::get().into_account_truncating()
 		}
 
+		/// The account holding the instant redemption buffers, kept apart from the entrance
+		/// account so that slp does not stake it.
+		pub fn instant_redeem_buffer_account() -> AccountIdOf<T> {
+			T::EntranceAccount::get().into_sub_account_truncating(b"buffer")
+		}
+
 		// to lock user vtoken for incentive minting
 		fn lock_vtoken_for_incentive_minting(
 			minter: AccountIdOf<T>,
@@ -1963,6 +2459,7 @@ impl<T: Config> VtokenMintingOperator<CurrencyId, BalanceOf<T>, AccountIdOf<T>,
 
 			Ok(())
 		})?;
+		Self::record_exchange_rate(currency_id);
 
 		Ok(())
 	}
@@ -2062,6 +2559,7 @@ impl<T: Config> VtokenMintingOperator<CurrencyId, BalanceOf<T>, AccountIdOf<T>,
 
 			if unlock_amount == deduct_amount {
 				TokenUnlockLedger::<T>::remove(&currency_id, &index);
+				UnlockHolders::<T>::remove(&currency_id, &index);
 			} else {
 				TokenUnlockLedger::<T>::mutate_exists(
 					&currency_id,
@@ -2118,6 +2616,16 @@ impl<T: Config> VtokenMintingInterface<AccountIdOf<T>, CurrencyIdOf<T>, BalanceO
 		Self::mint_inner(exchanger, token_id, token_amount, remark, channel_id)
 	}
 
+	fn mint_with_lock(
+		minter: AccountIdOf<T>,
+		token_id: CurrencyIdOf<T>,
+		token_amount: BalanceOf<T>,
+		remark: BoundedVec<u8, ConstU32<32>>,
+		channel_id: Option<u32>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::mint_with_lock_inner(minter, token_id, token_amount, remark, channel_id)
+	}
+
 	fn redeem(
 		exchanger: AccountIdOf<T>,
 		vtoken_id: CurrencyIdOf<T>,
@@ -2126,6 +2634,14 @@ impl<T: Config> VtokenMintingInterface<AccountIdOf<T>, CurrencyIdOf<T>, BalanceO
 		Self::redeem_inner(exchanger, vtoken_id, vtoken_amount, RedeemType::Native)
 	}
 
+	fn rebond(
+		exchanger: AccountIdOf<T>,
+		token_id: CurrencyIdOf<T>,
+		token_amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::rebond_inner(exchanger, token_id, token_amount)
+	}
+
 	fn slpx_redeem(
 		exchanger: AccountIdOf<T>,
 		vtoken_id: CurrencyIdOf<T>,
@@ -2159,6 +2675,10 @@ impl<T: Config> VtokenMintingInterface<AccountIdOf<T>, CurrencyIdOf<T>, BalanceO
 		Self::token_id_inner(vtoken_id)
 	}
 
+	fn get_minimums_mint(token_id: CurrencyIdOf<T>) -> BalanceOf<T> {
+		MinimumMint::<T>::get(token_id)
+	}
+
 	fn get_minimums_redeem(vtoken_id: CurrencyIdOf<T>) -> BalanceOf<T> {
 		MinimumRedeem::<T>::get(vtoken_id)
 	}
@@ -728,3 +728,356 @@ fn set_vtoken_incentive_lock_blocks_should_work() {
 		assert_eq!(MintWithLockBlocks::<Runtime>::get(VKSM), None);
 	})
 }
+
+#[test]
+fn set_instant_redeem_buffer_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		let buffer = InstantRedeemBuffer {
+			target: 40000000000,
+			min_fee: Permill::from_percent(1),
+			max_fee: Permill::from_percent(10),
+		};
+		assert_noop!(
+			VtokenMinting::set_instant_redeem_buffer(
+				RuntimeOrigin::signed(BOB),
+				KSM,
+				Some(buffer.clone())
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			VtokenMinting::set_instant_redeem_buffer(
+				RuntimeOrigin::signed(ALICE),
+				KSM,
+				Some(InstantRedeemBuffer { min_fee: Permill::from_percent(20), ..buffer.clone() })
+			),
+			Error::<Runtime>::InvalidInstantRedeemFee
+		);
+		assert_ok!(VtokenMinting::set_instant_redeem_buffer(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(buffer.clone())
+		));
+		assert_eq!(InstantRedeemBuffers::<Runtime>::get(KSM), Some(buffer));
+
+		// the tokens left in a removed buffer are returned to the entrance account
+		assert_ok!(VtokenMinting::mint(
+			Some(BOB).into(),
+			KSM,
+			100000000000,
+			BoundedVec::default(),
+			None
+		));
+		let buffer_account = VtokenMinting::instant_redeem_buffer_account();
+		let (entrance_account, _exit_account) = VtokenMinting::get_entrance_and_exit_accounts();
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 40000000000);
+		assert_eq!(Tokens::free_balance(KSM, &entrance_account), 60000000000);
+
+		assert_ok!(VtokenMinting::set_instant_redeem_buffer(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			None
+		));
+		assert_eq!(InstantRedeemBuffers::<Runtime>::get(KSM), None);
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 0);
+		assert_eq!(Tokens::free_balance(KSM, &entrance_account), 100000000000);
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 100000000000);
+	})
+}
+
+#[test]
+fn instant_redeem_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 1000),
+			Error::<Runtime>::InstantRedeemBufferNotSet
+		);
+		assert_ok!(VtokenMinting::set_instant_redeem_buffer(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			Some(InstantRedeemBuffer {
+				target: 40000000000,
+				min_fee: Permill::from_percent(1),
+				max_fee: Permill::from_percent(10),
+			})
+		));
+		assert_ok!(VtokenMinting::mint(
+			Some(BOB).into(),
+			KSM,
+			100000000000,
+			BoundedVec::default(),
+			None
+		));
+		let buffer_account = VtokenMinting::instant_redeem_buffer_account();
+		let fee_account: AccountId = <Runtime as Config>::FeeAccount::get();
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 40000000000);
+		assert_eq!(Tokens::total_issuance(VKSM), 100000001000);
+
+		// The buffer drains to 75% of its target, so the fee is 1% + 25% * (10% - 1%).
+		assert_eq!(
+			VtokenMinting::quote_instant_redeem(VKSM, 10000000000),
+			Ok((9999999900, 324999997))
+		);
+		let bob_ksm_balance = Tokens::free_balance(KSM, &BOB);
+		assert_ok!(VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 10000000000));
+		System::assert_last_event(RuntimeEvent::VtokenMinting(Event::InstantRedeemed {
+			address: BOB,
+			token_id: KSM,
+			token_amount: 9674999903,
+			vtoken_amount: 10000000000,
+			fee: 324999997,
+		}));
+		assert_eq!(Tokens::free_balance(KSM, &BOB), bob_ksm_balance + 9674999903);
+		assert_eq!(Tokens::free_balance(KSM, &fee_account), 324999997);
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 30000000100);
+		assert_eq!(Tokens::total_issuance(VKSM), 90000001000);
+		assert_eq!(TokenPool::<Runtime>::get(KSM), 90000000100);
+
+		assert_noop!(
+			VtokenMinting::instant_redeem(Some(BOB).into(), VKSM, 50000000000),
+			Error::<Runtime>::NotEnoughInstantRedeemBuffer
+		);
+
+		// New mints refill the buffer to its target before the rest is staked.
+		let (entrance_account, _exit_account) = VtokenMinting::get_entrance_and_exit_accounts();
+		assert_ok!(VtokenMinting::mint(
+			Some(BOB).into(),
+			KSM,
+			10000000000,
+			BoundedVec::default(),
+			None
+		));
+		System::assert_has_event(RuntimeEvent::VtokenMinting(Event::InstantRedeemBufferRefilled {
+			token_id: KSM,
+			token_amount: 9999999900,
+		}));
+		assert_eq!(Tokens::free_balance(KSM, &buffer_account), 40000000000);
+		assert_eq!(Tokens::free_balance(KSM, &entrance_account), 60000000100);
+	})
+}
+
+#[test]
+fn transfer_and_split_unlock_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(3)));
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::set_hook_iteration_limit(RuntimeOrigin::signed(ALICE), 10));
+		VtokenMinting::on_initialize(100);
+		VtokenMinting::on_initialize(100);
+		VtokenMinting::on_initialize(100);
+		VtokenMinting::on_initialize(100);
+		VtokenMinting::on_initialize(100);
+		assert_eq!(MinTimeUnit::<Runtime>::get(KSM), TimeUnit::Era(4));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 300, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((BOB, 200, TimeUnit::Era(4), RedeemType::Native))
+		);
+
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(ALICE).into(), KSM, 0, 50),
+			Error::<Runtime>::NotUnlockOwner
+		);
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 200),
+			Error::<Runtime>::InvalidUnlockAmount
+		);
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 0),
+			Error::<Runtime>::InvalidUnlockAmount
+		);
+		assert_ok!(VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 50));
+		System::assert_last_event(RuntimeEvent::VtokenMinting(Event::UnlockSplit {
+			token_id: KSM,
+			unlock_id: 0,
+			new_unlock_id: 1,
+			token_amount: 50,
+		}));
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 0),
+			Some((BOB, 150, TimeUnit::Era(4), RedeemType::Native))
+		);
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 1),
+			Some((BOB, 50, TimeUnit::Era(4), RedeemType::Native))
+		);
+		assert_eq!(TokenUnlockNextId::<Runtime>::get(KSM), 2);
+		let ledger_list: BoundedVec<u32, MaximumUnlockIdOfUser> =
+			BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), Some((200, ledger_list)));
+		let ledger_list: BoundedVec<u32, MaximumUnlockIdOfTimeUnit> =
+			BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_eq!(
+			TimeUnitUnlockLedger::<Runtime>::get(TimeUnit::Era(4), KSM),
+			Some((200, ledger_list, KSM))
+		);
+
+		assert_noop!(
+			VtokenMinting::transfer_unlock(Some(ALICE).into(), KSM, 1, CHARLIE),
+			Error::<Runtime>::NotUnlockOwner
+		);
+		assert_ok!(VtokenMinting::transfer_unlock(Some(BOB).into(), KSM, 1, CHARLIE));
+		System::assert_last_event(RuntimeEvent::VtokenMinting(Event::UnlockTransferred {
+			token_id: KSM,
+			unlock_id: 1,
+			from: BOB,
+			to: CHARLIE,
+			token_amount: 50,
+		}));
+		// The entry stays listed by the redeemer, only its holder changes.
+		assert_eq!(
+			TokenUnlockLedger::<Runtime>::get(KSM, 1),
+			Some((BOB, 50, TimeUnit::Era(4), RedeemType::Native))
+		);
+		assert_eq!(UnlockHolders::<Runtime>::get(KSM, 1), Some(CHARLIE));
+		let ledger_list: BoundedVec<u32, MaximumUnlockIdOfUser> =
+			BoundedVec::try_from(vec![0, 1]).unwrap();
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), Some((200, ledger_list)));
+		assert_eq!(UserUnlockLedger::<Runtime>::get(CHARLIE, KSM), None);
+
+		// The unlock is paid out to the current holder.
+		let bob_balance = Tokens::free_balance(KSM, &BOB);
+		VtokenMinting::on_initialize(100);
+		assert_eq!(Tokens::free_balance(KSM, &BOB), bob_balance + 150);
+		assert_eq!(Tokens::free_balance(KSM, &CHARLIE), 50);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 0), None);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 1), None);
+		assert_eq!(UnlockHolders::<Runtime>::get(KSM, 1), None);
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), None);
+	});
+}
+
+#[test]
+fn transferred_unlock_should_be_tradable() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(3)));
+		assert_ok!(VtokenMinting::set_unlock_duration(
+			RuntimeOrigin::signed(ALICE),
+			KSM,
+			TimeUnit::Era(1)
+		));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert_ok!(VtokenMinting::add_support_rebond_token(RuntimeOrigin::signed(ALICE), KSM));
+		assert_ok!(VtokenMinting::mint(Some(BOB).into(), KSM, 300, BoundedVec::default(), None));
+		assert_ok!(VtokenMinting::redeem(Some(BOB).into(), VKSM, 200));
+		assert_ok!(VtokenMinting::set_minimum_redeem(RuntimeOrigin::signed(ALICE), VKSM, 30));
+
+		// Dust entries would fill up the unlock ledger of the redeemer.
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 20),
+			Error::<Runtime>::BelowMinimumRedeem
+		);
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 180),
+			Error::<Runtime>::BelowMinimumRedeem
+		);
+		assert_ok!(VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 30));
+
+		// Any account can receive an unlock entry and sell it on, but not split it.
+		assert_ok!(VtokenMinting::transfer_unlock(Some(BOB).into(), KSM, 1, CHARLIE));
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(CHARLIE).into(), KSM, 1, 15),
+			Error::<Runtime>::CanNotSplitUnlock
+		);
+		assert_ok!(VtokenMinting::transfer_unlock(Some(CHARLIE).into(), KSM, 1, ALICE));
+		assert_eq!(UnlockHolders::<Runtime>::get(KSM, 1), Some(ALICE));
+
+		// The redeemer can no longer move or rebond the transferred entry.
+		assert_noop!(
+			VtokenMinting::transfer_unlock(Some(BOB).into(), KSM, 1, CHARLIE),
+			Error::<Runtime>::NotUnlockOwner
+		);
+		assert_noop!(
+			VtokenMinting::rebond_by_unlock_id(Some(BOB).into(), KSM, 1),
+			Error::<Runtime>::CanNotRebond
+		);
+		assert_noop!(
+			VtokenMinting::rebond(Some(BOB).into(), KSM, 200),
+			Error::<Runtime>::NotEnoughBalanceToUnlock
+		);
+		assert_ok!(VtokenMinting::rebond(Some(BOB).into(), KSM, 170));
+		let ledger_list: BoundedVec<u32, MaximumUnlockIdOfUser> =
+			BoundedVec::try_from(vec![1]).unwrap();
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), Some((30, ledger_list)));
+
+		// The holder rebonds the entry into its own vtokens.
+		let alice_vksm = Tokens::free_balance(VKSM, &ALICE);
+		assert_ok!(VtokenMinting::rebond_by_unlock_id(Some(ALICE).into(), KSM, 1));
+		assert!(Tokens::free_balance(VKSM, &ALICE) > alice_vksm);
+		assert_eq!(TokenUnlockLedger::<Runtime>::get(KSM, 1), None);
+		assert_eq!(UnlockHolders::<Runtime>::get(KSM, 1), None);
+		assert_eq!(UserUnlockLedger::<Runtime>::get(BOB, KSM), None);
+	});
+}
+
+#[test]
+fn transfer_unlock_to_other_chain_should_fail() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		TokenUnlockLedger::<Runtime>::insert(
+			KSM,
+			0,
+			(BOB, 100, TimeUnit::Era(1), RedeemType::Astar(BOB)),
+		);
+		assert_noop!(
+			VtokenMinting::transfer_unlock(Some(BOB).into(), KSM, 0, CHARLIE),
+			Error::<Runtime>::UnlockNotTransferable
+		);
+		assert_noop!(
+			VtokenMinting::split_unlock(Some(BOB).into(), KSM, 0, 50),
+			Error::<Runtime>::UnlockNotTransferable
+		);
+	});
+}
+
+#[test]
+fn exchange_rate_history_should_work() {
+	ExtBuilder::default().one_hundred_for_alice_n_bob().build().execute_with(|| {
+		System::set_block_number(1);
+		// No snapshot is recorded before the time unit is known.
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 1000));
+		assert!(ExchangeRateHistory::<Runtime>::get(KSM).is_empty());
+
+		let vtoken_issuance = Tokens::total_issuance(VKSM);
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(1)));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 100));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 100));
+		assert_eq!(
+			ExchangeRateHistory::<Runtime>::get(KSM).into_inner(),
+			vec![ExchangeRateSnapshot {
+				time_unit: TimeUnit::Era(1),
+				block_number: 1,
+				token_pool: 1200,
+				vtoken_issuance,
+			}]
+		);
+		assert_eq!(VtokenMinting::get_apy(KSM, 1), None);
+
+		// The exchange rate grows by 10% in half a year.
+		System::set_block_number(1 + BLOCKS_PER_YEAR as u64 / 2);
+		assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(2)));
+		assert_ok!(VtokenMinting::increase_token_pool(KSM, 120));
+		assert_eq!(ExchangeRateHistory::<Runtime>::get(KSM).len(), 2);
+		assert_eq!(VtokenMinting::get_apy(KSM, 1), Some(Permill::from_percent(20)));
+		assert_eq!(VtokenMinting::get_apy(KSM, 0), None);
+		assert_eq!(VtokenMinting::get_apy(KSM, 2), None);
+
+		for era in 3..MAX_EXCHANGE_RATE_HISTORY + 5 {
+			assert_ok!(VtokenMinting::update_ongoing_time_unit(KSM, TimeUnit::Era(era)));
+			assert_ok!(VtokenMinting::increase_token_pool(KSM, 1));
+		}
+		let history = ExchangeRateHistory::<Runtime>::get(KSM);
+		assert_eq!(history.len() as u32, MAX_EXCHANGE_RATE_HISTORY);
+		assert_eq!(history[0].time_unit, TimeUnit::Era(5));
+		assert_eq!(history.last().unwrap().time_unit, TimeUnit::Era(MAX_EXCHANGE_RATE_HISTORY + 4));
+	});
+}
@@ -72,6 +72,10 @@ pub trait WeightInfo {
 	fn unlock_incentive_minted_vtoken() -> Weight;
 	fn set_incentive_coef() -> Weight;
 	fn set_vtoken_incentive_lock_blocks() -> Weight;
+	fn set_instant_redeem_buffer() -> Weight;
+	fn instant_redeem() -> Weight;
+	fn transfer_unlock() -> Weight;
+	fn split_unlock() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -284,13 +288,15 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockHolders (r:1 w:0)
+	/// Proof: VtokenMinting UnlockHolders (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn rebond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2620`
 		//  Estimated: `8769`
 		// Minimum execution time: 234_399_000 picoseconds.
 		Weight::from_parts(237_749_000, 8769)
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: VtokenMinting TokenToRebond (r:1 w:1)
@@ -313,14 +319,16 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
 	/// Storage: AssetRegistry CurrencyMetadatas (r:1 w:0)
 	/// Proof Skipped: AssetRegistry CurrencyMetadatas (max_values: None, max_size: None, mode: Measured)
+	/// Storage: VtokenMinting UnlockHolders (r:1 w:1)
+	/// Proof: VtokenMinting UnlockHolders (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn rebond_by_unlock_id() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2620`
 		//  Estimated: `8769`
 		// Minimum execution time: 224_388_000 picoseconds.
 		Weight::from_parts(227_544_000, 8769)
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: VtokenMinting OngoingTimeUnit (r:1 w:0)
 	/// Proof: VtokenMinting OngoingTimeUnit (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
@@ -350,4 +358,77 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: VtokenMinting InstantRedeemBuffers (r:0 w:1)
+	/// Proof: VtokenMinting InstantRedeemBuffers (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	fn set_instant_redeem_buffer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1375`
+		//  Estimated: `6176`
+		// Minimum execution time: 98_271_000 picoseconds.
+		Weight::from_parts(101_033_000, 6176)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: VtokenMinting MinimumRedeem (r:1 w:0)
+	/// Proof: VtokenMinting MinimumRedeem (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting InstantRedeemBuffers (r:1 w:0)
+	/// Proof: VtokenMinting InstantRedeemBuffers (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenPool (r:1 w:1)
+	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn instant_redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2187`
+		//  Estimated: `11362`
+		// Minimum execution time: 231_650_000 picoseconds.
+		Weight::from_parts(238_417_000, 11362)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:0)
+	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting UnlockHolders (r:1 w:1)
+	/// Proof: VtokenMinting UnlockHolders (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn transfer_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1054`
+		//  Estimated: `3601`
+		// Minimum execution time: 36_418_000 picoseconds.
+		Weight::from_parts(37_593_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: VtokenMinting TokenUnlockLedger (r:1 w:2)
+	/// Proof: VtokenMinting TokenUnlockLedger (max_values: None, max_size: Some(136), added: 2611, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting MinimumRedeem (r:1 w:0)
+	/// Proof: VtokenMinting MinimumRedeem (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenPool (r:1 w:0)
+	/// Proof: VtokenMinting TokenPool (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TokenUnlockNextId (r:1 w:1)
+	/// Proof: VtokenMinting TokenUnlockNextId (max_values: None, max_size: Some(26), added: 2501, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting UserUnlockLedger (r:1 w:1)
+	/// Proof: VtokenMinting UserUnlockLedger (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting TimeUnitUnlockLedger (r:1 w:1)
+	/// Proof: VtokenMinting TimeUnitUnlockLedger (max_values: None, max_size: Some(282), added: 2757, mode: MaxEncodedLen)
+	/// Storage: VtokenMinting UnlockHolders (r:1 w:0)
+	/// Proof: VtokenMinting UnlockHolders (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn split_unlock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1376`
+		//  Estimated: `3747`
+		// Minimum execution time: 63_914_000 picoseconds.
+		Weight::from_parts(65_287_000, 3747)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 }
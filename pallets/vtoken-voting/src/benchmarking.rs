@@ -61,6 +61,27 @@ fn init_vote<T: Config>(vtoken: CurrencyIdOf<T>) -> Result<(), BenchmarkError> {
 	Ok(())
 }
 
+fn cast_votes<T: Config>(
+	who: &AccountIdOf<T>,
+	vtoken: CurrencyIdOf<T>,
+	r: u32,
+) -> Result<(), BenchmarkError> {
+	let control_origin =
+		T::ControlOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let vote = account_vote::<T>(100u32.into());
+	let response = Response::DispatchResult(MaybeErrorCode::Success);
+	for poll_index in 0..r {
+		Pallet::<T>::vote(RawOrigin::Signed(who.clone()).into(), vtoken, poll_index, vote)?;
+		Pallet::<T>::notify_vote(
+			control_origin.clone() as <T as frame_system::Config>::RuntimeOrigin,
+			poll_index as QueryId,
+			response.clone(),
+		)?;
+	}
+
+	Ok(())
+}
+
 #[benchmarks(where T::MaxVotes: core::fmt::Debug)]
 mod benchmarks {
 	use super::*;
@@ -87,7 +108,7 @@ mod benchmarks {
 				response.clone(),
 			)?;
 		}
-		let votes = match VotingFor::<T>::get(&caller) {
+		let votes = match VotingFor::<T>::get(&caller, vtoken) {
 			Voting::Casting(Casting { votes, .. }) => votes,
 			_ => return Err("Votes are not direct".into()),
 		};
@@ -97,7 +118,7 @@ mod benchmarks {
 		Pallet::<T>::vote(RawOrigin::Signed(caller.clone()), vtoken, poll_index, vote);
 
 		assert_matches!(
-			VotingFor::<T>::get(&caller),
+			VotingFor::<T>::get(&caller, vtoken),
 			Voting::Casting(Casting { votes, .. }) if votes.len() == (r + 1) as usize
 		);
 
@@ -124,7 +145,7 @@ mod benchmarks {
 				response.clone(),
 			)?;
 		}
-		let votes = match VotingFor::<T>::get(&caller) {
+		let votes = match VotingFor::<T>::get(&caller, vtoken) {
 			Voting::Casting(Casting { votes, .. }) => votes,
 			_ => return Err("Votes are not direct".into()),
 		};
@@ -136,7 +157,7 @@ mod benchmarks {
 		Pallet::<T>::vote(RawOrigin::Signed(caller.clone()), vtoken, poll_index, new_vote);
 
 		assert_matches!(
-			VotingFor::<T>::get(&caller),
+			VotingFor::<T>::get(&caller, vtoken),
 			Voting::Casting(Casting { votes, .. }) if votes.len() == r as usize
 		);
 
@@ -364,6 +385,74 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	pub fn delegate(r: Linear<0, { T::MaxVotes::get() }>) -> Result<(), BenchmarkError> {
+		let vtoken = VKSM;
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let delegate = funded_account::<T>("delegate", 0);
+
+		init_vote::<T>(vtoken)?;
+		cast_votes::<T>(&delegate, vtoken, r)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), vtoken, delegate, Conviction::Locked1x, 100u32.into());
+
+		assert_matches!(VotingFor::<T>::get(&caller, vtoken), Voting::Delegating(_));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	pub fn undelegate(r: Linear<0, { T::MaxVotes::get() }>) -> Result<(), BenchmarkError> {
+		let vtoken = VKSM;
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let delegate = funded_account::<T>("delegate", 0);
+
+		init_vote::<T>(vtoken)?;
+		Pallet::<T>::set_vote_locking_period(RawOrigin::Root.into(), vtoken, 100u32.into())?;
+		cast_votes::<T>(&delegate, vtoken, r)?;
+		Pallet::<T>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			vtoken,
+			delegate,
+			Conviction::Locked1x,
+			100u32.into(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), vtoken);
+
+		assert_matches!(VotingFor::<T>::get(&caller, vtoken), Voting::Casting(_));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	pub fn unlock_delegation() -> Result<(), BenchmarkError> {
+		let vtoken = VKSM;
+		let caller = funded_account::<T>("caller", 0);
+		whitelist_account!(caller);
+		let delegate = funded_account::<T>("delegate", 0);
+
+		init_vote::<T>(vtoken)?;
+		Pallet::<T>::set_vote_locking_period(RawOrigin::Root.into(), vtoken, 0u32.into())?;
+		Pallet::<T>::delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			vtoken,
+			delegate,
+			Conviction::Locked1x,
+			100u32.into(),
+		)?;
+		Pallet::<T>::undelegate(RawOrigin::Signed(caller.clone()).into(), vtoken)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), vtoken);
+
+		Ok(())
+	}
+
 	// This line generates test cases for benchmarking, and could be run by:
 	//   `cargo test -p pallet-example-basic --all-features`, you will see one line per case:
 	//   `test benchmarking::bench_sort_vector ... ok`
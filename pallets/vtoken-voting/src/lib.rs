@@ -30,10 +30,10 @@ mod tests;
 mod call;
 mod vote;
 
-// pub mod migration;
+pub mod migration;
 pub mod weights;
 
-use crate::vote::{Casting, Tally, Voting};
+use crate::vote::{Casting, Delegating, Tally, Voting};
 pub use crate::{
 	call::*,
 	vote::{AccountVote, PollStatus, ReferendumInfo, ReferendumStatus, VoteRole},
@@ -52,7 +52,7 @@ use frame_support::{
 use frame_system::pallet_prelude::{BlockNumberFor, *};
 use orml_traits::{MultiCurrency, MultiLockableCurrency};
 pub use pallet::*;
-use pallet_conviction_voting::{Conviction, UnvoteScope, Vote};
+use pallet_conviction_voting::{Conviction, Delegations, UnvoteScope, Vote};
 use sp_runtime::{
 	traits::{
 		BlockNumberProvider, Bounded, CheckedDiv, CheckedMul, Saturating, UniqueSaturatedInto, Zero,
@@ -87,7 +87,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -187,6 +187,7 @@ pub mod pallet {
 		VoteNotified {
 			vtoken: CurrencyIdOf<T>,
 			poll_index: PollIndex,
+			derivative_index: DerivativeIndex,
 			success: bool,
 		},
 		DelegatorVoteRemovedNotified {
@@ -203,6 +204,21 @@ pub mod pallet {
 			vtoken: CurrencyIdOf<T>,
 			vote_cap_ratio: Perbill,
 		},
+		Delegated {
+			who: AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+			target: AccountIdOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T>,
+		},
+		Undelegated {
+			who: AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+		},
+		DelegationUnlocked {
+			who: AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -247,6 +263,13 @@ pub mod pallet {
 		InvalidConviction,
 		/// The given value is out of range.
 		OutOfRange,
+		/// The account currently has votes attached to it and the operation cannot succeed until
+		/// these are removed.
+		AlreadyVoting,
+		/// The account is not currently delegating.
+		NotDelegating,
+		/// Delegation to oneself makes no sense.
+		Nonsense,
 	}
 
 	/// Information concerning any given referendum.
@@ -263,8 +286,15 @@ pub mod pallet {
 	/// All voting for a particular voter in a particular voting class. We store the balance for the
 	/// number of votes that we have recorded.
 	#[pallet::storage]
-	pub type VotingFor<T: Config> =
-		StorageMap<_, Twox64Concat, AccountIdOf<T>, VotingOf<T>, ValueQuery>;
+	pub type VotingFor<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AccountIdOf<T>,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		VotingOf<T>,
+		ValueQuery,
+	>;
 
 	/// The voting classes which have a non-zero lock requirement and the lock amounts which they
 	/// require. The actual amount locked on behalf of this pallet should always be the maximum of
@@ -290,7 +320,7 @@ pub mod pallet {
 		(
 			CurrencyIdOf<T>,
 			PollIndex,
-			DerivativeIndex,
+			(DerivativeIndex, AccountVote<BalanceOf<T>>),
 			AccountIdOf<T>,
 			Option<(AccountVote<BalanceOf<T>>, BalanceOf<T>)>,
 		),
@@ -332,17 +362,6 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	#[pallet::storage]
-	pub type PendingDelegatorVotes<T: Config> = StorageDoubleMap<
-		_,
-		Twox64Concat,
-		CurrencyIdOf<T>,
-		Twox64Concat,
-		PollIndex,
-		BoundedVec<(DerivativeIndex, AccountVote<BalanceOf<T>>), ConstU32<100>>,
-		ValueQuery,
-	>;
-
 	#[pallet::storage]
 	pub type ReferendumTimeout<T: Config> = StorageMap<
 		_,
@@ -360,7 +379,8 @@ pub mod pallet {
 			NMapKey<Twox64Concat, CurrencyIdOf<T>>,
 			NMapKey<Twox64Concat, PollIndex>,
 		),
-		DerivativeIndex,
+		BoundedVec<DerivativeIndex, ConstU32<100>>,
+		ValueQuery,
 	>;
 
 	#[pallet::genesis_config]
@@ -469,29 +489,22 @@ pub mod pallet {
 				vtoken,
 				maybe_total_vote.ok_or(Error::<T>::NoData)?,
 			)?;
-			let new_delegator_votes =
-				Self::allocate_delegator_votes(vtoken, poll_index, delegator_total_vote)?;
+			let new_delegator_votes: BoundedVec<_, ConstU32<100>> =
+				Self::allocate_delegator_votes(vtoken, poll_index, delegator_total_vote)?
+					.try_into()
+					.map_err(|_| Error::<T>::TooMany)?;
 
-			PendingDelegatorVotes::<T>::try_mutate(vtoken, poll_index, |item| -> DispatchResult {
-				for (derivative_index, vote) in new_delegator_votes.iter() {
-					item.try_push((*derivative_index, *vote)).map_err(|_| Error::<T>::TooMany)?;
-				}
-				Ok(())
-			})?;
-
-			// send XCM message
+			// send XCM message, each sub-vote is cast by its own derivative account and reports
+			// its own outcome, so the sub-votes are confirmed or rolled back independently
 			let vote_calls = new_delegator_votes
 				.iter()
-				.map(|(_derivative_index, vote)| {
-					<RelayCall<T> as ConvictionVotingCall<T>>::vote(poll_index, *vote)
+				.map(|(derivative_index, vote)| {
+					<RelayCall<T> as UtilityCall<RelayCall<T>>>::as_derivative(
+						*derivative_index,
+						<RelayCall<T> as ConvictionVotingCall<T>>::vote(poll_index, *vote),
+					)
 				})
 				.collect::<Vec<_>>();
-			let vote_call = if vote_calls.len() == 1 {
-				vote_calls.into_iter().nth(0).ok_or(Error::<T>::NoData)?
-			} else {
-				ensure!(false, Error::<T>::NoPermissionYet);
-				<RelayCall<T> as UtilityCall<RelayCall<T>>>::batch_all(vote_calls)
-			};
 			let notify_call = Call::<T>::notify_vote { query_id: 0, response: Default::default() };
 			let (weight, extra_fee) = T::XcmDestWeightAndFee::get_operation_weight_and_fee(
 				CurrencyId::to_token(&vtoken).map_err(|_| Error::<T>::NoData)?,
@@ -499,20 +512,18 @@ pub mod pallet {
 			)
 			.ok_or(Error::<T>::NoData)?;
 
-			let derivative_index = new_delegator_votes[0].0;
 			Self::send_xcm_with_notify(
-				derivative_index,
-				vote_call,
+				vote_calls,
 				notify_call,
 				weight,
 				extra_fee,
-				|query_id| {
+				|i, query_id| {
 					if !submitted {
 						PendingReferendumInfo::<T>::insert(query_id, (vtoken, poll_index));
 					}
 					PendingVotingInfo::<T>::insert(
 						query_id,
-						(vtoken, poll_index, derivative_index, who.clone(), maybe_old_vote),
+						(vtoken, poll_index, new_delegator_votes[i], who.clone(), maybe_old_vote),
 					)
 				},
 			)?;
@@ -522,7 +533,7 @@ pub mod pallet {
 				vtoken,
 				poll_index,
 				token_vote,
-				delegator_vote: new_delegator_votes[0].1,
+				delegator_vote: delegator_total_vote,
 			});
 
 			Ok(())
@@ -579,12 +590,14 @@ pub mod pallet {
 			)
 			.ok_or(Error::<T>::NoData)?;
 			Self::send_xcm_with_notify(
-				derivative_index,
-				remove_vote_call,
+				vec![<RelayCall<T> as UtilityCall<RelayCall<T>>>::as_derivative(
+					derivative_index,
+					remove_vote_call,
+				)],
 				notify_call,
 				weight,
 				extra_fee,
-				|query_id| {
+				|_, query_id| {
 					PendingRemoveDelegatorVote::<T>::insert(
 						query_id,
 						(vtoken, poll_index, derivative_index),
@@ -707,30 +720,43 @@ pub mod pallet {
 			let responder = Self::ensure_xcm_response_or_governance(origin)?;
 			let success = Response::DispatchResult(MaybeErrorCode::Success) == response;
 
-			if let Some((vtoken, poll_index, derivative_index, who, maybe_old_vote)) =
+			if let Some((vtoken, poll_index, (derivative_index, vote), who, maybe_old_vote)) =
 				PendingVotingInfo::<T>::get(query_id)
 			{
 				if !success {
-					// rollback vote
-					let _ = PendingDelegatorVotes::<T>::clear(u32::MAX, None);
-					Self::try_remove_vote(&who, vtoken, poll_index, UnvoteScope::Any)?;
-					Self::update_lock(&who, vtoken)?;
-					if let Some((old_vote, vtoken_balance)) = maybe_old_vote {
-						Self::try_vote(&who, vtoken, poll_index, old_vote, vtoken_balance)?;
+					// rollback the vote, each sub-vote reports to its own query and the first
+					// failed one rolls it back, the confirmed sub-votes are kept
+					if Self::vote_of(&who, vtoken, poll_index) != maybe_old_vote {
+						Self::try_remove_vote(&who, vtoken, poll_index, UnvoteScope::Any)?;
+						Self::update_lock(&who, vtoken)?;
+						if let Some((old_vote, vtoken_balance)) = maybe_old_vote {
+							Self::try_vote(&who, vtoken, poll_index, old_vote, vtoken_balance)?;
+						}
 					}
 				} else {
-					if !VoteDelegatorFor::<T>::contains_key((&who, vtoken, poll_index)) {
-						VoteDelegatorFor::<T>::insert((&who, vtoken, poll_index), derivative_index);
-					}
-					DelegatorVotes::<T>::remove(vtoken, poll_index);
+					VoteDelegatorFor::<T>::try_mutate(
+						(&who, vtoken, poll_index),
+						|derivative_indexes| -> DispatchResult {
+							if !vote.balance().is_zero() &&
+								!derivative_indexes.contains(&derivative_index)
+							{
+								derivative_indexes
+									.try_push(derivative_index)
+									.map_err(|_| Error::<T>::TooMany)?;
+							}
+							Ok(())
+						},
+					)?;
 					DelegatorVotes::<T>::try_mutate(
 						vtoken,
 						poll_index,
 						|item| -> DispatchResult {
-							for (derivative_index, vote) in
-								PendingDelegatorVotes::<T>::take(vtoken, poll_index).iter()
+							if let Some((_, v)) =
+								item.iter_mut().find(|(index, _)| *index == derivative_index)
 							{
-								item.try_push((*derivative_index, *vote))
+								*v = vote;
+							} else {
+								item.try_push((derivative_index, vote))
 									.map_err(|_| Error::<T>::TooMany)?;
 							}
 							Ok(())
@@ -738,17 +764,26 @@ pub mod pallet {
 					)?;
 				}
 				PendingVotingInfo::<T>::remove(query_id);
-				Self::deposit_event(Event::<T>::VoteNotified { vtoken, poll_index, success });
+				Self::deposit_event(Event::<T>::VoteNotified {
+					vtoken,
+					poll_index,
+					derivative_index,
+					success,
+				});
 			}
 
-			if let Some((vtoken, poll_index)) = PendingReferendumInfo::<T>::get(query_id) {
+			if let Some((vtoken, poll_index)) = PendingReferendumInfo::<T>::take(query_id) {
 				if success {
 					ReferendumInfoFor::<T>::try_mutate_exists(
 						vtoken,
 						poll_index,
 						|maybe_info| -> DispatchResult {
 							if let Some(info) = maybe_info {
+								// the first confirmed sub-vote submits the referendum
 								if let ReferendumInfo::Ongoing(status) = info {
+									if status.submitted.is_some() {
+										return Ok(());
+									}
 									let relay_current_block_number =
 										T::RelaychainBlockNumberProvider::current_block_number();
 									status.submitted = Some(relay_current_block_number);
@@ -773,10 +808,20 @@ pub mod pallet {
 							Ok(())
 						},
 					)?;
-				} else {
-					ReferendumInfoFor::<T>::remove(vtoken, poll_index);
+				} else if !PendingReferendumInfo::<T>::iter_values()
+					.any(|(v, p)| v == vtoken && p == poll_index)
+				{
+					// the referendum is dropped once none of the sub-votes submitted it
+					ReferendumInfoFor::<T>::mutate_exists(vtoken, poll_index, |maybe_info| {
+						if let Some(ReferendumInfo::Ongoing(ReferendumStatus {
+							submitted: None,
+							..
+						})) = maybe_info
+						{
+							*maybe_info = None;
+						}
+					});
 				}
-				PendingReferendumInfo::<T>::remove(query_id);
 			}
 
 			Self::deposit_event(Event::<T>::ResponseReceived { responder, query_id, response });
@@ -792,12 +837,19 @@ pub mod pallet {
 			response: Response,
 		) -> DispatchResult {
 			let responder = Self::ensure_xcm_response_or_governance(origin)?;
-			if let Some((vtoken, poll_index, _derivative_index)) =
+			if let Some((vtoken, poll_index, derivative_index)) =
 				PendingRemoveDelegatorVote::<T>::get(query_id)
 			{
 				let success = Response::DispatchResult(MaybeErrorCode::Success) == response;
 				if success {
-					DelegatorVotes::<T>::remove(vtoken, poll_index);
+					DelegatorVotes::<T>::mutate_exists(vtoken, poll_index, |maybe_votes| {
+						if let Some(votes) = maybe_votes {
+							votes.retain(|(index, _)| *index != derivative_index);
+							if votes.is_empty() {
+								*maybe_votes = None;
+							}
+						}
+					});
 				}
 				PendingRemoveDelegatorVote::<T>::remove(query_id);
 				Self::deposit_event(Event::<T>::DelegatorVoteRemovedNotified {
@@ -825,6 +877,63 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Delegate the voting power of `balance` vtoken to `to`, with a particular conviction.
+		///
+		/// The delegated votes are added to the tally of every ongoing poll `to` has voted on, and
+		/// are sent to the relay chain with the next vote of `to` on that poll. The balance stays
+		/// locked while delegating, and for the conviction lock period after undelegating.
+		///
+		/// The account must not be voting or delegating with `vtoken` already.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate(T::MaxVotes::get()))]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			vtoken: CurrencyIdOf<T>,
+			to: AccountIdOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_vtoken(&vtoken)?;
+
+			let votes = Self::try_delegate(who, vtoken, to, conviction, balance)?;
+
+			Ok(Some(<T as Config>::WeightInfo::delegate(votes)).into())
+		}
+
+		/// Undelegate the voting power of the sender for `vtoken`.
+		///
+		/// The delegated votes are removed from the tallies of the delegate. The balance remains
+		/// locked for the conviction lock period, after which it can be unlocked with
+		/// `unlock_delegation`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::undelegate(T::MaxVotes::get()))]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			vtoken: CurrencyIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_vtoken(&vtoken)?;
+
+			let votes = Self::try_undelegate(who, vtoken)?;
+
+			Ok(Some(<T as Config>::WeightInfo::undelegate(votes)).into())
+		}
+
+		/// Remove the lock of a former delegation once its lock period has passed.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_delegation())]
+		pub fn unlock_delegation(origin: OriginFor<T>, vtoken: CurrencyIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_vtoken(&vtoken)?;
+
+			Self::update_lock(&who, vtoken)?;
+
+			Self::deposit_event(Event::<T>::DelegationUnlocked { who, vtoken });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -846,7 +955,7 @@ pub mod pallet {
 			let mut total_vote = None;
 			Self::try_access_poll(vtoken, poll_index, |poll_status| {
 				let tally = poll_status.ensure_ongoing().ok_or(Error::<T>::NotOngoing)?;
-				VotingFor::<T>::try_mutate(who, |voting| {
+				VotingFor::<T>::try_mutate(who, vtoken, |voting| {
 					if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
 						match votes.binary_search_by_key(&poll_index, |i| i.0) {
 							Ok(i) => {
@@ -899,7 +1008,7 @@ pub mod pallet {
 			poll_index: PollIndex,
 			scope: UnvoteScope,
 		) -> DispatchResult {
-			VotingFor::<T>::try_mutate(who, |voting| {
+			VotingFor::<T>::try_mutate(who, vtoken, |voting| {
 				if let Voting::Casting(Casting { ref mut votes, delegations, ref mut prior }) =
 					voting
 				{
@@ -949,7 +1058,7 @@ pub mod pallet {
 		/// Rejig the lock on an account. It will never get more stringent (since that would
 		/// indicate a security hole) but may be reduced from what they are currently.
 		pub(crate) fn update_lock(who: &AccountIdOf<T>, vtoken: CurrencyIdOf<T>) -> DispatchResult {
-			let lock_needed = VotingFor::<T>::mutate(who, |voting| {
+			let lock_needed = VotingFor::<T>::mutate(who, vtoken, |voting| {
 				voting.rejig(T::RelaychainBlockNumberProvider::current_block_number());
 				voting.locked_balance()
 			});
@@ -978,6 +1087,177 @@ pub mod pallet {
 			}
 		}
 
+		/// Delegate the voting power of `who` to `target`.
+		///
+		/// Return the number of votes of `target`.
+		fn try_delegate(
+			who: AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+			target: AccountIdOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T>,
+		) -> Result<u32, DispatchError> {
+			ensure!(who != target, Error::<T>::Nonsense);
+			ensure!(
+				balance <= T::MultiCurrency::total_balance(vtoken, &who),
+				Error::<T>::InsufficientFunds
+			);
+			// The tallies are kept in token, so are the delegated votes.
+			let token_balance = Self::compute_token_vote(
+				vtoken,
+				AccountVote::new_standard(Vote { aye: true, conviction }, balance),
+			)?
+			.balance();
+
+			let votes =
+				VotingFor::<T>::try_mutate(&who, vtoken, |voting| -> Result<u32, DispatchError> {
+					let old = sp_std::mem::replace(
+						voting,
+						Voting::Delegating(Delegating {
+							balance,
+							token_balance,
+							target: target.clone(),
+							conviction,
+							delegations: Default::default(),
+							prior: Default::default(),
+						}),
+					);
+					match old {
+						Voting::Delegating(Delegating { .. }) =>
+							return Err(Error::<T>::AlreadyDelegating.into()),
+						Voting::Casting(Casting { votes, delegations, prior }) => {
+							// here we just ensure that we're currently idling with no votes
+							// recorded.
+							ensure!(votes.is_empty(), Error::<T>::AlreadyVoting);
+							voting.set_common(delegations, prior);
+						},
+					}
+					let votes = Self::increase_upstream_delegation(
+						&target,
+						vtoken,
+						conviction.votes(token_balance),
+					)?;
+					Self::set_lock(&who, vtoken, voting.locked_balance())?;
+					Ok(votes)
+				})?;
+
+			Self::deposit_event(Event::<T>::Delegated { who, vtoken, target, conviction, balance });
+
+			Ok(votes)
+		}
+
+		/// Attempt to end the current delegation of `who`.
+		///
+		/// Return the number of votes of the former delegate.
+		fn try_undelegate(
+			who: AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+		) -> Result<u32, DispatchError> {
+			let votes =
+				VotingFor::<T>::try_mutate(&who, vtoken, |voting| -> Result<u32, DispatchError> {
+					match sp_std::mem::replace(voting, Voting::default()) {
+						Voting::Delegating(Delegating {
+							balance,
+							token_balance,
+							target,
+							conviction,
+							delegations,
+							mut prior,
+						}) => {
+							// remove any delegation votes to our current target.
+							let votes = Self::reduce_upstream_delegation(
+								&target,
+								vtoken,
+								conviction.votes(token_balance),
+							)?;
+							let unlock_at =
+								T::RelaychainBlockNumberProvider::current_block_number()
+									.saturating_add(
+										VoteLockingPeriod::<T>::get(vtoken)
+											.ok_or(Error::<T>::NoData)?
+											.saturating_mul(conviction.lock_periods().into()),
+									);
+							prior.accumulate(unlock_at, balance);
+							voting.set_common(delegations, prior);
+
+							Ok(votes)
+						},
+						Voting::Casting(_) => Err(Error::<T>::NotDelegating.into()),
+					}
+				})?;
+			Self::update_lock(&who, vtoken)?;
+
+			Self::deposit_event(Event::<T>::Undelegated { who, vtoken });
+
+			Ok(votes)
+		}
+
+		/// Add the delegated votes to `who` and to the tally of every ongoing poll `who` has
+		/// a standard vote on.
+		///
+		/// Return the number of votes of `who`.
+		fn increase_upstream_delegation(
+			who: &AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+			amount: Delegations<BalanceOf<T>>,
+		) -> Result<u32, DispatchError> {
+			VotingFor::<T>::try_mutate(who, vtoken, |voting| match voting {
+				Voting::Delegating(Delegating { delegations, .. }) => {
+					// We don't support second level delegating, so we don't need to do anything
+					// more.
+					*delegations = delegations.saturating_add(amount);
+					Ok(1)
+				},
+				Voting::Casting(Casting { votes, delegations, .. }) => {
+					*delegations = delegations.saturating_add(amount);
+					for (poll_index, account_vote, _, _) in votes.iter() {
+						if let Some(approve) = account_vote.as_standard() {
+							Self::try_access_poll(vtoken, *poll_index, |poll_status| {
+								if let PollStatus::Ongoing(tally) = poll_status {
+									tally.increase(approve, amount);
+								}
+								Ok(())
+							})?;
+						}
+					}
+					Ok(votes.len() as u32)
+				},
+			})
+		}
+
+		/// Remove the delegated votes from `who` and from the tally of every ongoing poll `who`
+		/// has a standard vote on.
+		///
+		/// Return the number of votes of `who`.
+		fn reduce_upstream_delegation(
+			who: &AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+			amount: Delegations<BalanceOf<T>>,
+		) -> Result<u32, DispatchError> {
+			VotingFor::<T>::try_mutate(who, vtoken, |voting| match voting {
+				Voting::Delegating(Delegating { delegations, .. }) => {
+					// We don't support second level delegating, so we don't need to do anything
+					// more.
+					*delegations = delegations.saturating_sub(amount);
+					Ok(1)
+				},
+				Voting::Casting(Casting { votes, delegations, .. }) => {
+					*delegations = delegations.saturating_sub(amount);
+					for (poll_index, account_vote, _, _) in votes.iter() {
+						if let Some(approve) = account_vote.as_standard() {
+							Self::try_access_poll(vtoken, *poll_index, |poll_status| {
+								if let PollStatus::Ongoing(tally) = poll_status {
+									tally.reduce(approve, amount);
+								}
+								Ok(())
+							})?;
+						}
+					}
+					Ok(votes.len() as u32)
+				},
+			})
+		}
+
 		pub(crate) fn set_lock(
 			who: &AccountIdOf<T>,
 			vtoken: CurrencyIdOf<T>,
@@ -1004,34 +1284,38 @@ pub mod pallet {
 			}
 		}
 
+		/// Send `calls` to the relay chain in one message, each call reports its outcome to its
+		/// own query, which `f` receives along with the position of the call.
 		fn send_xcm_with_notify(
-			derivative_index: DerivativeIndex,
-			call: RelayCall<T>,
+			calls: Vec<RelayCall<T>>,
 			notify_call: Call<T>,
 			transact_weight: XcmWeight,
 			extra_fee: BalanceOf<T>,
-			f: impl FnOnce(QueryId) -> (),
+			mut f: impl FnMut(usize, QueryId) -> (),
 		) -> DispatchResult {
 			let responder = xcm::v4::Location::parent();
 			let now = frame_system::Pallet::<T>::block_number();
 			let timeout = now.saturating_add(T::QueryTimeout::get());
-			let notify_runtime_call = <T as Config>::RuntimeCall::from(notify_call);
-			let notify_call_weight = notify_runtime_call.get_dispatch_info().weight;
-			let query_id = pallet_xcm::Pallet::<T>::new_notify_query(
-				responder,
-				notify_runtime_call,
-				timeout,
-				xcm::v4::Junctions::Here,
-			);
-			f(query_id);
+			let notify_call_weight =
+				<T as Config>::RuntimeCall::from(notify_call.clone()).get_dispatch_info().weight;
+			let call_count = calls.len() as u32;
+			let mut transacts = Vec::with_capacity(calls.len());
+			for (i, call) in calls.into_iter().enumerate() {
+				let query_id = pallet_xcm::Pallet::<T>::new_notify_query(
+					responder.clone(),
+					<T as Config>::RuntimeCall::from(notify_call.clone()),
+					timeout,
+					xcm::v4::Junctions::Here,
+				);
+				f(i, query_id);
+				transacts.push((call.encode(), query_id));
+			}
 
 			let xcm_message = Self::construct_xcm_message(
-				<RelayCall<T> as UtilityCall<RelayCall<T>>>::as_derivative(derivative_index, call)
-					.encode(),
-				extra_fee,
+				transacts,
+				extra_fee.saturating_mul(call_count.into()),
 				transact_weight,
 				notify_call_weight,
-				query_id,
 			)?;
 
 			xcm::v4::send_xcm::<T::XcmRouter>(Parent.into(), xcm_message)
@@ -1041,40 +1325,58 @@ pub mod pallet {
 		}
 
 		fn construct_xcm_message(
-			call: Vec<u8>,
+			transacts: Vec<(Vec<u8>, QueryId)>,
 			extra_fee: BalanceOf<T>,
 			transact_weight: XcmWeight,
 			notify_call_weight: XcmWeight,
-			query_id: QueryId,
 		) -> Result<Xcm<()>, Error<T>> {
 			let para_id = T::ParachainId::get().into();
 			let asset = Asset {
 				id: AssetId(Location::here()),
 				fun: Fungible(UniqueSaturatedInto::<u128>::unique_saturated_into(extra_fee)),
 			};
-			let xcm_message = sp_std::vec![
+			let mut xcm_message = sp_std::vec![
 				WithdrawAsset(asset.clone().into()),
 				BuyExecution { fees: asset, weight_limit: Unlimited },
-				Transact {
+			];
+			// A failed call doesn't stop the program, the status reported after each `Transact`
+			// is the outcome of its own call.
+			for (call, query_id) in transacts {
+				xcm_message.push(Transact {
 					origin_kind: OriginKind::SovereignAccount,
 					require_weight_at_most: transact_weight,
 					call: call.into(),
-				},
-				ReportTransactStatus(QueryResponseInfo {
+				});
+				xcm_message.push(ReportTransactStatus(QueryResponseInfo {
 					destination: Location::from(Parachain(para_id)),
 					query_id,
 					max_weight: notify_call_weight,
-				}),
-				RefundSurplus,
-				DepositAsset {
-					assets: All.into(),
-					beneficiary: Location::new(0, [Parachain(para_id)]),
-				},
-			];
+				}));
+			}
+			xcm_message.push(RefundSurplus);
+			xcm_message.push(DepositAsset {
+				assets: All.into(),
+				beneficiary: Location::new(0, [Parachain(para_id)]),
+			});
 
 			Ok(Xcm(xcm_message))
 		}
 
+		/// The vote of `who` on `poll_index` and the vtoken balance it locks.
+		pub(crate) fn vote_of(
+			who: &AccountIdOf<T>,
+			vtoken: CurrencyIdOf<T>,
+			poll_index: PollIndex,
+		) -> Option<(AccountVote<BalanceOf<T>>, BalanceOf<T>)> {
+			match VotingFor::<T>::get(who, vtoken) {
+				Voting::Casting(Casting { votes, .. }) => votes
+					.binary_search_by_key(&poll_index, |i| i.0)
+					.ok()
+					.map(|i| (votes[i].1, votes[i].3)),
+				_ => None,
+			}
+		}
+
 		fn ensure_vtoken(vtoken: &CurrencyIdOf<T>) -> Result<(), DispatchError> {
 			ensure!([VKSM, VDOT].contains(vtoken), Error::<T>::VTokenNotSupport);
 			Ok(())
@@ -1275,7 +1577,8 @@ pub mod pallet {
 			for (derivative_index, available_vote) in data {
 				if available_vote >= delegator_total_vote.balance() {
 					delegator_votes.push((derivative_index, delegator_total_vote));
-					return Ok(delegator_votes);
+					delegator_total_vote = AccountVote::<BalanceOf<T>>::from(vote_role);
+					break;
 				} else {
 					let account_vote = AccountVote::new_standard(
 						delegator_total_vote.as_standard_vote().ok_or(Error::<T>::NoData)?,
@@ -1291,6 +1594,14 @@ pub mod pallet {
 				return Err(Error::<T>::OutOfRange.into());
 			}
 
+			// overwrite the stale votes of derivatives which are no longer needed
+			for derivative_index in delegator_vote_keys {
+				if !delegator_votes.iter().any(|(index, _)| *index == derivative_index) {
+					delegator_votes
+						.push((derivative_index, AccountVote::<BalanceOf<T>>::from(vote_role)));
+				}
+			}
+
 			Ok(delegator_votes)
 		}
 	}
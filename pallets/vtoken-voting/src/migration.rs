@@ -208,3 +208,113 @@ pub fn migrate_to_v3<T: Config, C: Get<CurrencyIdOf<T>>>() -> Weight {
 
 	weight
 }
+
+pub mod v4 {
+	use super::*;
+	use crate::{Config, CurrencyIdOf, Pallet};
+	use cumulus_primitives_core::Weight;
+	use frame_support::{pallet_prelude::StorageVersion, traits::OnRuntimeUpgrade};
+	use sp_runtime::traits::Get;
+
+	#[storage_alias]
+	pub(super) type VotingFor<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, AccountIdOf<T>, VotingOf<T>, ValueQuery>;
+
+	#[storage_alias]
+	pub(super) type PendingDelegatorVotes<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Twox64Concat,
+		CurrencyIdOf<T>,
+		Twox64Concat,
+		PollIndex,
+		BoundedVec<(DerivativeIndex, AccountVote<BalanceOf<T>>), ConstU32<100>>,
+		ValueQuery,
+	>;
+
+	pub(super) type OldPendingVotingInfo<T> = (
+		CurrencyIdOf<T>,
+		PollIndex,
+		DerivativeIndex,
+		AccountIdOf<T>,
+		Option<(AccountVote<BalanceOf<T>>, BalanceOf<T>)>,
+	);
+
+	pub struct MigrateToV4<T, C>(sp_std::marker::PhantomData<T>, sp_std::marker::PhantomData<C>);
+	impl<T: Config, C: Get<CurrencyIdOf<T>>> OnRuntimeUpgrade for MigrateToV4<T, C> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() == 3 {
+				let weight_consumed = migrate_to_v4::<T, C>();
+				log::info!("Migrating vtoken-voting storage to v4");
+				StorageVersion::new(4).put::<Pallet<T>>();
+				weight_consumed
+			} else {
+				log::warn!("vtoken-voting migration should be removed.");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+			log::info!(
+				"vtoken-voting before migration: version: {:?}",
+				StorageVersion::get::<Pallet<T>>(),
+			);
+			let count = v4::VotingFor::<T>::iter().count() as u32;
+			log::info!("vtoken-voting before migration: VotingFor v3 count: {}", count);
+
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+			log::info!(
+				"vtoken-voting after migration: version: {:?}",
+				StorageVersion::get::<Pallet<T>>(),
+			);
+			let count = crate::VotingFor::<T>::iter().count() as u32;
+			log::info!("vtoken-voting after migration: VotingFor v4 count: {}", count);
+			ensure!(
+				u32::decode(&mut &state[..]).map_err(|_| "Invalid pre_upgrade state")? == count,
+				"VotingFor count should not change during the migration"
+			);
+
+			Ok(())
+		}
+	}
+}
+
+/// Key `VotingFor` by the vtoken as well, all existing votes belong to the vtoken of `C`.
+/// Move the sub-votes of `PendingDelegatorVotes` into `PendingVotingInfo`, and record every
+/// derivative a vote is cast through in `VoteDelegatorFor`.
+pub fn migrate_to_v4<T: Config, C: Get<CurrencyIdOf<T>>>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+
+	let token = C::get();
+	let vtoken = token.to_vtoken().unwrap();
+	// The old and the new map share the storage prefix, so drain all the old entries first.
+	let votings = v4::VotingFor::<T>::drain().collect::<Vec<_>>();
+	for (who, voting) in votings {
+		VotingFor::<T>::insert(who, vtoken, voting);
+		weight += T::DbWeight::get().reads_writes(1, 2);
+	}
+
+	// Pending votes carry their own sub-vote instead of sharing `PendingDelegatorVotes`, a vote
+	// was cast through a single derivative before.
+	PendingVotingInfo::<T>::translate::<v4::OldPendingVotingInfo<T>, _>(
+		|_, (vtoken, poll_index, derivative_index, who, maybe_old_vote)| {
+			weight += T::DbWeight::get().reads_writes(2, 1);
+			let sub_vote = v4::PendingDelegatorVotes::<T>::get(vtoken, poll_index)
+				.into_iter()
+				.find(|(index, _)| *index == derivative_index)?;
+			Some((vtoken, poll_index, sub_vote, who, maybe_old_vote))
+		},
+	);
+	let _ = v4::PendingDelegatorVotes::<T>::clear(u32::MAX, None);
+
+	VoteDelegatorFor::<T>::translate::<DerivativeIndex, _>(|_, derivative_index| {
+		weight += T::DbWeight::get().reads_writes(1, 1);
+		Some(BoundedVec::truncate_from(vec![derivative_index]))
+	});
+
+	weight
+}
@@ -215,7 +215,7 @@ fn ensure_balance_after_unlock() {
 		assert_ok!(VtokenVoting::unlock(RuntimeOrigin::signed(ALICE), vtoken, poll_index));
 		assert_eq!(usable_balance(vtoken, &ALICE), 0);
 		assert_eq!(Tokens::accounts(&ALICE, vtoken).frozen, 10);
-		assert_eq!(VotingFor::<Runtime>::get(&ALICE).locked_balance(), 10);
+		assert_eq!(VotingFor::<Runtime>::get(&ALICE, vtoken).locked_balance(), 10);
 	});
 }
 
@@ -260,7 +260,7 @@ fn ensure_comprehensive_balance_after_unlock() {
 		assert_ok!(VtokenVoting::unlock(RuntimeOrigin::signed(ALICE), vtoken, poll_index));
 		assert_eq!(usable_balance(vtoken, &ALICE), 8);
 		assert_eq!(Tokens::accounts(&ALICE, vtoken).frozen, 2);
-		assert_eq!(VotingFor::<Runtime>::get(&ALICE).locked_balance(), 2);
+		assert_eq!(VotingFor::<Runtime>::get(&ALICE, vtoken).locked_balance(), 2);
 
 		assert_ok!(VtokenVoting::vote(
 			RuntimeOrigin::signed(ALICE),
@@ -272,7 +272,7 @@ fn ensure_comprehensive_balance_after_unlock() {
 
 		assert_eq!(usable_balance(vtoken, &ALICE), 0);
 		assert_eq!(Tokens::accounts(&ALICE, vtoken).frozen, 10);
-		assert_eq!(VotingFor::<Runtime>::get(&ALICE).locked_balance(), 10);
+		assert_eq!(VotingFor::<Runtime>::get(&ALICE, vtoken).locked_balance(), 10);
 	});
 }
 
@@ -385,12 +385,12 @@ fn lock_amalgamation_valid_with_multiple_removed_votes() {
 			VtokenVoting::unlock(RuntimeOrigin::signed(ALICE), vtoken, 0),
 			Error::<Runtime>::NoPermissionYet
 		);
-		assert_eq!(VotingFor::<Runtime>::get(&ALICE).locked_balance(), 10);
+		assert_eq!(VotingFor::<Runtime>::get(&ALICE, vtoken).locked_balance(), 10);
 		assert_eq!(usable_balance(vtoken, &ALICE), 0);
 
 		RelaychainDataProvider::set_block_number(11);
 		assert_ok!(VtokenVoting::unlock(RuntimeOrigin::signed(ALICE), vtoken, 0));
-		assert_eq!(VotingFor::<Runtime>::get(&ALICE).locked_balance(), 10);
+		assert_eq!(VotingFor::<Runtime>::get(&ALICE, vtoken).locked_balance(), 10);
 		assert_eq!(usable_balance(vtoken, &ALICE), 0);
 		assert_eq!(
 			ClassLocksFor::<Runtime>::get(&ALICE),
@@ -717,12 +717,8 @@ fn notify_vote_success_works() {
 			}))
 		);
 		assert_eq!(
-			PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index),
-			BoundedVec::<(DerivativeIndex, AccountVote<Balance>), ConstU32<100>>::try_from(vec![(
-				derivative_index,
-				aye(200, 0)
-			)])
-			.unwrap()
+			PendingVotingInfo::<Runtime>::get(query_id).unwrap().2,
+			(derivative_index, aye(200, 0))
 		);
 		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
 		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(20, 0, 4));
@@ -742,7 +738,7 @@ fn notify_vote_success_works() {
 				tally: TallyOf::<Runtime>::from_parts(20, 0, 4),
 			}))
 		);
-		assert_eq!(PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
+		assert_eq!(PendingVotingInfo::<Runtime>::get(query_id), None);
 		assert_eq!(
 			DelegatorVotes::<Runtime>::get(vtoken, poll_index),
 			BoundedVec::<(DerivativeIndex, AccountVote<Balance>), ConstU32<100>>::try_from(vec![(
@@ -754,6 +750,7 @@ fn notify_vote_success_works() {
 		System::assert_has_event(RuntimeEvent::VtokenVoting(Event::VoteNotified {
 			vtoken,
 			poll_index,
+			derivative_index,
 			success: true,
 		}));
 		System::assert_has_event(RuntimeEvent::VtokenVoting(Event::ReferendumInfoCreated {
@@ -846,12 +843,8 @@ fn notify_vote_fail_works() {
 		);
 		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
 		assert_eq!(
-			PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index),
-			BoundedVec::<(DerivativeIndex, AccountVote<Balance>), ConstU32<100>>::try_from(vec![(
-				derivative_index,
-				aye(200, 0)
-			)])
-			.unwrap()
+			PendingVotingInfo::<Runtime>::get(query_id).unwrap().2,
+			(derivative_index, aye(200, 0))
 		);
 		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(20, 0, 4));
 		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Voted {
@@ -865,7 +858,7 @@ fn notify_vote_fail_works() {
 		assert_ok!(VtokenVoting::notify_vote(origin_response(), query_id, response.clone()));
 		assert_eq!(ReferendumInfoFor::<Runtime>::get(vtoken, poll_index), None);
 		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
-		assert_eq!(PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
+		assert_eq!(PendingVotingInfo::<Runtime>::get(query_id), None);
 		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::ResponseReceived {
 			responder: Parent.into(),
 			query_id,
@@ -902,12 +895,8 @@ fn notify_remove_delegator_vote_success_works() {
 		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, aye(2, 5)));
 		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
 		assert_eq!(
-			PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index),
-			BoundedVec::<(DerivativeIndex, AccountVote<Balance>), ConstU32<100>>::try_from(vec![(
-				derivative_index,
-				aye(200, 0)
-			)])
-			.unwrap()
+			PendingVotingInfo::<Runtime>::get(query_id).unwrap().2,
+			(derivative_index, aye(200, 0))
 		);
 		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(20, 0, 4));
 		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Voted {
@@ -926,7 +915,7 @@ fn notify_remove_delegator_vote_success_works() {
 			)])
 			.unwrap()
 		);
-		assert_eq!(PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
+		assert_eq!(PendingVotingInfo::<Runtime>::get(query_id), None);
 
 		assert_ok!(VtokenVoting::set_referendum_status(
 			RuntimeOrigin::root(),
@@ -979,12 +968,8 @@ fn notify_remove_delegator_vote_fail_works() {
 		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, aye(2, 5)));
 		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
 		assert_eq!(
-			PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index),
-			BoundedVec::<(DerivativeIndex, AccountVote<Balance>), ConstU32<100>>::try_from(vec![(
-				derivative_index,
-				aye(200, 0)
-			)])
-			.unwrap()
+			PendingVotingInfo::<Runtime>::get(query_id).unwrap().2,
+			(derivative_index, aye(200, 0))
 		);
 		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(20, 0, 4));
 		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Voted {
@@ -1003,7 +988,7 @@ fn notify_remove_delegator_vote_fail_works() {
 			)])
 			.unwrap()
 		);
-		assert_eq!(PendingDelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
+		assert_eq!(PendingVotingInfo::<Runtime>::get(query_id), None);
 
 		assert_ok!(VtokenVoting::set_referendum_status(
 			RuntimeOrigin::root(),
@@ -1377,3 +1362,443 @@ fn set_lock_works() {
 		assert_eq!(usable_balance(vtoken, &ALICE), 10);
 	});
 }
+
+#[test]
+fn delegate_should_work() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+
+		assert_ok!(VtokenVoting::delegate(
+			RuntimeOrigin::signed(BOB),
+			vtoken,
+			ALICE,
+			Conviction::Locked1x,
+			10
+		));
+		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Delegated {
+			who: BOB,
+			vtoken,
+			target: ALICE,
+			conviction: Conviction::Locked1x,
+			balance: 10,
+		}));
+		assert_eq!(usable_balance(vtoken, &BOB), 10);
+		assert_eq!(
+			ClassLocksFor::<Runtime>::get(&BOB),
+			BoundedVec::<(CurrencyId, u128), ConstU32<256>>::try_from(vec![(vtoken, 10)]).unwrap()
+		);
+
+		// The delegated votes are counted in the delegate's vote.
+		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, aye(2, 5)));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(40, 0, 24));
+		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Voted {
+			who: ALICE,
+			vtoken,
+			poll_index,
+			token_vote: aye(4, 5),
+			delegator_vote: aye(400, 0),
+		}));
+		assert_eq!(usable_balance(vtoken, &ALICE), 8);
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 0, response_success()));
+
+		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, aye(1, 5)));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(30, 0, 22));
+
+		// The rollback of a failed vote restores the old vote with the delegated votes.
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 1, response_fail()));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(40, 0, 24));
+	});
+}
+
+#[test]
+fn delegate_with_vote_changes_should_work() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+		assert_ok!(VtokenVoting::set_vote_locking_period(RuntimeOrigin::root(), vtoken, 10));
+
+		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, aye(2, 5)));
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 0, response_success()));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(20, 0, 4));
+
+		// Delegating to an account with ongoing votes updates their tallies.
+		assert_ok!(VtokenVoting::delegate(
+			RuntimeOrigin::signed(BOB),
+			vtoken,
+			ALICE,
+			Conviction::Locked1x,
+			10
+		));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(40, 0, 24));
+
+		// Changing the vote moves the delegated votes along.
+		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, nay(2, 5)));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(0, 40, 0));
+		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Voted {
+			who: ALICE,
+			vtoken,
+			poll_index,
+			token_vote: nay(4, 5),
+			delegator_vote: nay(400, 0),
+		}));
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 1, response_success()));
+
+		assert_ok!(VtokenVoting::delegate(
+			RuntimeOrigin::signed(CHARLIE),
+			vtoken,
+			ALICE,
+			Conviction::Locked2x,
+			5
+		));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(0, 60, 0));
+
+		assert_ok!(VtokenVoting::undelegate(RuntimeOrigin::signed(BOB), vtoken));
+		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Undelegated {
+			who: BOB,
+			vtoken,
+		}));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(0, 40, 0));
+
+		// Removing the vote removes the remaining delegated votes.
+		assert_ok!(VtokenVoting::try_remove_vote(&ALICE, vtoken, poll_index, UnvoteScope::Any));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(0, 0, 0));
+
+		assert_ok!(VtokenVoting::undelegate(RuntimeOrigin::signed(CHARLIE), vtoken));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(0, 0, 0));
+	});
+}
+
+#[test]
+fn undelegate_should_respect_lock_period() {
+	new_test_ext().execute_with(|| {
+		let vtoken = VKSM;
+		assert_ok!(VtokenVoting::set_vote_locking_period(RuntimeOrigin::root(), vtoken, 10));
+
+		assert_ok!(VtokenVoting::delegate(
+			RuntimeOrigin::signed(BOB),
+			vtoken,
+			ALICE,
+			Conviction::Locked2x,
+			15
+		));
+		assert_ok!(VtokenVoting::delegate(
+			RuntimeOrigin::signed(CHARLIE),
+			vtoken,
+			ALICE,
+			Conviction::None,
+			30
+		));
+		assert_eq!(usable_balance(vtoken, &BOB), 5);
+		assert_eq!(usable_balance(vtoken, &CHARLIE), 0);
+
+		assert_ok!(VtokenVoting::undelegate(RuntimeOrigin::signed(BOB), vtoken));
+		assert_eq!(usable_balance(vtoken, &BOB), 5);
+		assert_eq!(VotingFor::<Runtime>::get(&BOB, vtoken).locked_balance(), 15);
+
+		// Without conviction, the balance is unlocked right away.
+		assert_ok!(VtokenVoting::undelegate(RuntimeOrigin::signed(CHARLIE), vtoken));
+		assert_eq!(usable_balance(vtoken, &CHARLIE), 30);
+		assert_eq!(ClassLocksFor::<Runtime>::get(&CHARLIE), BoundedVec::default());
+
+		RelaychainDataProvider::set_block_number(20);
+		assert_ok!(VtokenVoting::unlock_delegation(RuntimeOrigin::signed(BOB), vtoken));
+		assert_eq!(usable_balance(vtoken, &BOB), 5);
+		assert_eq!(
+			ClassLocksFor::<Runtime>::get(&BOB),
+			BoundedVec::<(CurrencyId, u128), ConstU32<256>>::try_from(vec![(vtoken, 15)]).unwrap()
+		);
+
+		RelaychainDataProvider::set_block_number(21);
+		assert_ok!(VtokenVoting::unlock_delegation(RuntimeOrigin::signed(BOB), vtoken));
+		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::DelegationUnlocked {
+			who: BOB,
+			vtoken,
+		}));
+		assert_eq!(usable_balance(vtoken, &BOB), 20);
+		assert_eq!(ClassLocksFor::<Runtime>::get(&BOB), BoundedVec::default());
+	});
+}
+
+#[test]
+fn delegate_should_fail() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+
+		assert_noop!(
+			VtokenVoting::delegate(
+				RuntimeOrigin::signed(ALICE),
+				VBNC,
+				BOB,
+				Conviction::Locked1x,
+				10
+			),
+			Error::<Runtime>::VTokenNotSupport
+		);
+		assert_noop!(
+			VtokenVoting::delegate(
+				RuntimeOrigin::signed(ALICE),
+				vtoken,
+				ALICE,
+				Conviction::Locked1x,
+				10
+			),
+			Error::<Runtime>::Nonsense
+		);
+		assert_noop!(
+			VtokenVoting::delegate(
+				RuntimeOrigin::signed(ALICE),
+				vtoken,
+				BOB,
+				Conviction::Locked1x,
+				11
+			),
+			Error::<Runtime>::InsufficientFunds
+		);
+		assert_noop!(
+			VtokenVoting::undelegate(RuntimeOrigin::signed(ALICE), vtoken),
+			Error::<Runtime>::NotDelegating
+		);
+
+		assert_ok!(VtokenVoting::vote(RuntimeOrigin::signed(ALICE), vtoken, poll_index, aye(2, 5)));
+		assert_noop!(
+			VtokenVoting::delegate(
+				RuntimeOrigin::signed(ALICE),
+				vtoken,
+				BOB,
+				Conviction::Locked1x,
+				10
+			),
+			Error::<Runtime>::AlreadyVoting
+		);
+
+		assert_ok!(VtokenVoting::delegate(
+			RuntimeOrigin::signed(BOB),
+			vtoken,
+			ALICE,
+			Conviction::Locked1x,
+			10
+		));
+		assert_noop!(
+			VtokenVoting::delegate(
+				RuntimeOrigin::signed(BOB),
+				vtoken,
+				CHARLIE,
+				Conviction::Locked1x,
+				10
+			),
+			Error::<Runtime>::AlreadyDelegating
+		);
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 0, response_success()));
+		assert_noop!(
+			VtokenVoting::vote(RuntimeOrigin::signed(BOB), vtoken, poll_index, aye(2, 5)),
+			Error::<Runtime>::AlreadyDelegating
+		);
+	});
+}
+
+#[test]
+fn vote_split_across_delegators_works() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+		let max_vote = u32::MAX as Balance;
+		let votes = |v: Vec<(DerivativeIndex, AccountVote<Balance>)>| {
+			BoundedVec::<(DerivativeIndex, AccountVote<Balance>), ConstU32<100>>::try_from(v)
+				.unwrap()
+		};
+
+		assert_ok!(Tokens::deposit(vtoken, &ALICE, 5_000_000_000));
+		assert_ok!(VtokenVoting::set_vote_cap_ratio(
+			RuntimeOrigin::root(),
+			vtoken,
+			Perbill::from_parts(1)
+		));
+
+		// 1e10 token votes exceed the cap without conviction, so 1x is used and split
+		// across the first three delegators.
+		assert_ok!(VtokenVoting::vote(
+			RuntimeOrigin::signed(ALICE),
+			vtoken,
+			poll_index,
+			aye(5_000_000_000, 1)
+		));
+		let sub_votes = votes(vec![
+			(0, aye(max_vote, 1)),
+			(1, aye(max_vote, 1)),
+			(2, aye(10_000_000_000 - 2 * max_vote, 1)),
+		]);
+		// Each sub-vote reports to its own query.
+		for (query_id, sub_vote) in sub_votes.iter().enumerate() {
+			assert_eq!(
+				PendingVotingInfo::<Runtime>::get(query_id as QueryId).unwrap().2,
+				*sub_vote
+			);
+		}
+		System::assert_last_event(RuntimeEvent::VtokenVoting(Event::Voted {
+			who: ALICE,
+			vtoken,
+			poll_index,
+			token_vote: aye(10_000_000_000, 1),
+			delegator_vote: aye(10_000_000_000, 1),
+		}));
+
+		for query_id in 0..3 {
+			assert_ok!(VtokenVoting::notify_vote(origin_response(), query_id, response_success()));
+			assert_eq!(PendingVotingInfo::<Runtime>::get(query_id), None);
+		}
+		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index), sub_votes);
+		assert_eq!(
+			VoteDelegatorFor::<Runtime>::get((&ALICE, vtoken, poll_index)).into_inner(),
+			vec![0, 1, 2]
+		);
+
+		// A smaller vote fits into one delegator, the others are overwritten with zero votes.
+		assert_ok!(VtokenVoting::vote(
+			RuntimeOrigin::signed(ALICE),
+			vtoken,
+			poll_index,
+			aye(1_000_000_000, 1)
+		));
+		assert_eq!(PendingVotingInfo::<Runtime>::get(3).unwrap().2, (0, aye(2_000_000_000, 1)));
+		assert_eq!(PendingVotingInfo::<Runtime>::get(4).unwrap().2, (1, aye(0, 1)));
+		assert_eq!(PendingVotingInfo::<Runtime>::get(5).unwrap().2, (2, aye(0, 1)));
+
+		// The failed sub-votes roll the vote back once, the confirmed one is kept.
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 3, response_fail()));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(10_000_000_000, 0, 10_000_000_000));
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 4, response_fail()));
+		assert_eq!(tally(vtoken, poll_index), Tally::from_parts(10_000_000_000, 0, 10_000_000_000));
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 5, response_success()));
+		System::assert_has_event(RuntimeEvent::VtokenVoting(Event::VoteNotified {
+			vtoken,
+			poll_index,
+			derivative_index: 2,
+			success: true,
+		}));
+		assert_eq!(
+			DelegatorVotes::<Runtime>::get(vtoken, poll_index),
+			votes(vec![(0, aye(max_vote, 1)), (1, aye(max_vote, 1)), (2, aye(0, 1))])
+		);
+		for query_id in 3..6 {
+			assert_eq!(PendingVotingInfo::<Runtime>::get(query_id), None);
+		}
+	});
+}
+
+#[test]
+fn partially_failed_vote_keeps_referendum() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+		let max_vote = u32::MAX as Balance;
+
+		assert_ok!(Tokens::deposit(vtoken, &ALICE, 5_000_000_000));
+		assert_ok!(VtokenVoting::set_vote_cap_ratio(
+			RuntimeOrigin::root(),
+			vtoken,
+			Perbill::from_parts(1)
+		));
+		assert_ok!(VtokenVoting::vote(
+			RuntimeOrigin::signed(ALICE),
+			vtoken,
+			poll_index,
+			aye(5_000_000_000, 1)
+		));
+
+		// The referendum waits for the other sub-votes.
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 0, response_fail()));
+		assert_eq!(VtokenVoting::vote_of(&ALICE, vtoken, poll_index), None);
+		assert_eq!(
+			ReferendumInfoFor::<Runtime>::get(vtoken, poll_index),
+			Some(ReferendumInfo::Ongoing(ReferendumStatus {
+				submitted: None,
+				tally: TallyOf::<Runtime>::from_parts(0, 0, 0),
+			}))
+		);
+
+		// The first confirmed sub-vote submits it.
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 1, response_success()));
+		assert_ok!(VtokenVoting::notify_vote(origin_response(), 2, response_fail()));
+		assert_eq!(
+			ReferendumInfoFor::<Runtime>::get(vtoken, poll_index),
+			Some(ReferendumInfo::Ongoing(ReferendumStatus {
+				submitted: Some(1),
+				tally: TallyOf::<Runtime>::from_parts(0, 0, 0),
+			}))
+		);
+		assert_eq!(
+			DelegatorVotes::<Runtime>::get(vtoken, poll_index).into_inner(),
+			vec![(1, aye(max_vote, 1))]
+		);
+		assert_eq!(
+			VoteDelegatorFor::<Runtime>::get((&ALICE, vtoken, poll_index)).into_inner(),
+			vec![1]
+		);
+	});
+}
+
+#[test]
+fn failed_vote_drops_referendum() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+
+		assert_ok!(Tokens::deposit(vtoken, &ALICE, 5_000_000_000));
+		assert_ok!(VtokenVoting::set_vote_cap_ratio(
+			RuntimeOrigin::root(),
+			vtoken,
+			Perbill::from_parts(1)
+		));
+		assert_ok!(VtokenVoting::vote(
+			RuntimeOrigin::signed(ALICE),
+			vtoken,
+			poll_index,
+			aye(5_000_000_000, 1)
+		));
+
+		for query_id in 0..3 {
+			assert!(ReferendumInfoFor::<Runtime>::get(vtoken, poll_index).is_some());
+			assert_ok!(VtokenVoting::notify_vote(origin_response(), query_id, response_fail()));
+		}
+		assert_eq!(ReferendumInfoFor::<Runtime>::get(vtoken, poll_index), None);
+		assert_eq!(DelegatorVotes::<Runtime>::get(vtoken, poll_index).len(), 0);
+	});
+}
+
+#[test]
+fn vote_exceeding_vote_cap_ratio_fails() {
+	new_test_ext().execute_with(|| {
+		let poll_index = 3;
+		let vtoken = VKSM;
+
+		assert_ok!(Tokens::deposit(vtoken, &ALICE, 5_000_000_000));
+
+		// 1e11 capital without conviction exceeds the stake of all the delegators.
+		assert_noop!(
+			VtokenVoting::vote(
+				RuntimeOrigin::signed(ALICE),
+				vtoken,
+				poll_index,
+				aye(5_000_000_000, 1)
+			),
+			Error::<Runtime>::OutOfRange
+		);
+
+		assert_ok!(VtokenVoting::set_vote_cap_ratio(
+			RuntimeOrigin::root(),
+			vtoken,
+			Perbill::from_percent(0)
+		));
+		assert_noop!(
+			VtokenVoting::vote(
+				RuntimeOrigin::signed(ALICE),
+				vtoken,
+				poll_index,
+				aye(5_000_000_000, 1)
+			),
+			Error::<Runtime>::InsufficientFunds
+		);
+	});
+}
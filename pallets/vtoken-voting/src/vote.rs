@@ -326,6 +326,8 @@ impl<BlockNumber: Ord + Copy + Zero, Balance: Ord + Copy + Zero> PriorLock<Block
 pub struct Delegating<Balance, AccountId, BlockNumber> {
 	/// The amount of balance delegated.
 	pub balance: Balance,
+	/// The delegated balance in token, which is what the tallies of the target account for.
+	pub token_balance: Balance,
 	/// The account to which the voting power is delegated.
 	pub target: AccountId,
 	/// The conviction with which the voting power is delegated. When this gets undelegated, the
@@ -424,7 +426,7 @@ where
 		match self {
 			Voting::Casting(Casting { votes, .. }) =>
 				votes.iter().map(|i| i.3).fold(Zero::zero(), |a, i| a.max(i)),
-			Voting::Delegating(Delegating { .. }) => Zero::zero(),
+			Voting::Delegating(Delegating { balance, .. }) => *balance,
 		}
 	}
 
@@ -0,0 +1,192 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+use crate::{traits::parachains, Pallet as XcmInterface};
+use bifrost_primitives::CurrencyId;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::EnsureOrigin, weights::Weight};
+use frame_system::RawOrigin;
+use sp_core::H160;
+use sp_runtime::traits::{UniqueSaturatedFrom, Zero};
+use sp_std::prelude::*;
+use xcm::{
+	v4::{prelude::*, Location},
+	VersionedLocation,
+};
+
+/// An AssetHub asset no chain spec registers.
+const STATEMINE_ASSET_ID: u32 = u32::MAX;
+
+fn register_asset<T: bifrost_asset_registry::Config>(
+	currency_id: CurrencyId,
+	location: Location,
+) -> Result<(), BenchmarkError> {
+	let metadata = AssetMetadata {
+		name: b"Benchmark".to_vec(),
+		symbol: b"BENCH".to_vec(),
+		decimals: 12,
+		minimal_balance: Zero::zero(),
+	};
+	bifrost_asset_registry::Pallet::<T>::do_register_metadata(currency_id, &metadata)?;
+	bifrost_asset_registry::Pallet::<T>::do_register_location(currency_id, &location)?;
+	Ok(())
+}
+
+fn weight_fee<T: Config>() -> DestinationWeightFee<BalanceOf<T>> {
+	DestinationWeightFee {
+		per_second: BalanceOf::<T>::unique_saturated_from(1_000_000_000u128),
+		per_proof_megabyte: BalanceOf::<T>::unique_saturated_from(1_000_000_000u128),
+	}
+}
+
+/// Weighs and prices the execution on AssetHub, where the transfers are sent to, and opens the
+/// channel to it.
+fn set_up_asset_hub<T: Config>() -> Result<(), BenchmarkError> {
+	let dest = Location::new(1, [Parachain(parachains::Statemine::ID)]);
+	let v3_dest = xcm::v3::Location::try_from(dest.clone())
+		.map_err(|_| BenchmarkError::Stop("invalid location"))?;
+	DestinationWeightFees::<T>::insert(v3_dest, weight_fee::<T>());
+	DestinationInstructionWeights::<T>::insert(v3_dest, Weight::from_parts(1_000_000_000, 1_000));
+	DestinationTransactWeights::<T>::insert(
+		v3_dest,
+		XcmOperationType::EthereumTransfer,
+		Weight::from_parts(1_000_000_000, 10_000),
+	);
+	T::BenchmarkHelper::ensure_xcm_delivery(&dest);
+	Ok(())
+}
+
+#[benchmarks(where T: bifrost_asset_registry::Config, CurrencyIdOf<T>: From<CurrencyId>)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn transfer_statemine_assets() -> Result<(), BenchmarkError> {
+		let currency_id = CurrencyId::Token2(u8::MAX);
+		let asset_location = Location::new(
+			1,
+			[
+				Parachain(parachains::Statemine::ID),
+				PalletInstance(parachains::Statemine::PALLET_ID),
+				GeneralIndex(STATEMINE_ASSET_ID.into()),
+			],
+		);
+		register_asset::<T>(currency_id, asset_location)?;
+		set_up_asset_hub::<T>()?;
+		let caller: AccountIdOf<T> = whitelisted_caller();
+		let amount = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000u128);
+		T::MultiCurrency::deposit(currency_id.into(), &caller, amount)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), amount, STATEMINE_ASSET_ID, None);
+
+		assert!(T::MultiCurrency::free_balance(currency_id.into(), &caller).is_zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_ethereum_assets() -> Result<(), BenchmarkError> {
+		let currency_id = CurrencyId::Token2(u8::MAX - 1);
+		let asset_location = Location::new(
+			2,
+			[
+				GlobalConsensus(Ethereum { chain_id: 1 }),
+				AccountKey20 { network: None, key: [u8::MAX; 20] },
+			],
+		);
+		register_asset::<T>(currency_id, asset_location)?;
+		set_up_asset_hub::<T>()?;
+		let caller: AccountIdOf<T> = whitelisted_caller();
+		let amount = BalanceOf::<T>::unique_saturated_from(1_000_000_000_000u128);
+		T::MultiCurrency::deposit(currency_id.into(), &caller, amount)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), currency_id.into(), amount, H160::repeat_byte(1));
+
+		assert!(T::MultiCurrency::free_balance(currency_id.into(), &caller).is_zero());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_destination_weight_fee() -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let dest = Location::new(1, [Parachain(parachains::Statemine::ID)]);
+
+		#[extrinsic_call]
+		_(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			Box::new(VersionedLocation::V4(dest.clone())),
+			Some(weight_fee::<T>()),
+		);
+
+		assert_eq!(
+			XcmInterface::<T>::weight_to_fee(&dest, Weight::from_parts(1_000_000_000_000, 0)),
+			Some(weight_fee::<T>().per_second)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_destination_instruction_weight() -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let dest = Location::new(1, [Parachain(parachains::Statemine::ID)]);
+		let v3_dest = xcm::v3::Location::try_from(dest.clone())
+			.map_err(|_| BenchmarkError::Stop("invalid location"))?;
+		let weight = Weight::from_parts(1_000_000_000, 1_000);
+
+		#[extrinsic_call]
+		_(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			Box::new(VersionedLocation::V4(dest)),
+			Some(weight),
+		);
+
+		assert_eq!(DestinationInstructionWeights::<T>::get(v3_dest), Some(weight));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_destination_transact_weight() -> Result<(), BenchmarkError> {
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let dest = Location::new(1, [Parachain(parachains::Statemine::ID)]);
+		let v3_dest = xcm::v3::Location::try_from(dest.clone())
+			.map_err(|_| BenchmarkError::Stop("invalid location"))?;
+		let weight = Weight::from_parts(1_000_000_000, 10_000);
+
+		#[extrinsic_call]
+		_(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			Box::new(VersionedLocation::V4(dest)),
+			XcmOperationType::EthereumTransfer,
+			Some(weight),
+		);
+
+		assert_eq!(
+			DestinationTransactWeights::<T>::get(v3_dest, XcmOperationType::EthereumTransfer),
+			Some(weight)
+		);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(XcmInterface, crate::mock::new_test_ext(), crate::mock::Test);
+}
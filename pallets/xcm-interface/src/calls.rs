@@ -33,11 +33,40 @@ pub enum UtilityCall<RelayChainCall> {
 }
 
 #[derive(Encode, Decode, RuntimeDebug)]
-pub enum StakingCall {
+pub enum StakingCall<BalanceOf, AccountIdOf> {
+	#[codec(index = 0)]
+	Bond(#[codec(compact)] BalanceOf, RewardDestination<AccountIdOf>),
+	#[codec(index = 1)]
+	BondExtra(#[codec(compact)] BalanceOf),
+	#[codec(index = 2)]
+	Unbond(#[codec(compact)] BalanceOf),
 	#[codec(index = 3)]
 	WithdrawUnbonded(u32),
 }
 
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum RewardDestination<AccountIdOf> {
+	Staked,
+	Stash,
+	Controller,
+	Account(AccountIdOf),
+	None,
+}
+
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum ConvictionVotingCall<BalanceOf> {
+	#[codec(index = 0)]
+	Vote(#[codec(compact)] u32, AccountVote<BalanceOf>),
+	#[codec(index = 4)]
+	RemoveVote(Option<u16>, u32),
+}
+
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum AccountVote<BalanceOf> {
+	/// The aye flag in the highest bit, the conviction in the others.
+	Standard { vote: u8, balance: BalanceOf },
+}
+
 #[derive(Encode, Decode, RuntimeDebug, Clone)]
 pub enum PolkadotXcmCall {
 	#[codec(index = 2)]
@@ -77,6 +106,12 @@ pub mod kusama {
 
 	#[derive(Encode, Decode, RuntimeDebug)]
 	pub enum RelaychainCall<BalanceOf, AccountIdOf, BlockNumberOf> {
+		#[codec(index = 6)]
+		Staking(StakingCall<BalanceOf, AccountIdOf>),
+		#[codec(index = 20)]
+		ConvictionVoting(ConvictionVotingCall<BalanceOf>),
+		#[codec(index = 24)]
+		Utility(Box<UtilityCall<Self>>),
 		#[codec(index = 73)]
 		Crowdloan(ContributeCall<BalanceOf, AccountIdOf>),
 		#[codec(index = 30)]
@@ -95,6 +130,12 @@ pub mod polkadot {
 
 	#[derive(Encode, Decode, RuntimeDebug)]
 	pub enum RelaychainCall<BalanceOf, AccountIdOf, BlockNumberOf> {
+		#[codec(index = 7)]
+		Staking(StakingCall<BalanceOf, AccountIdOf>),
+		#[codec(index = 20)]
+		ConvictionVoting(ConvictionVotingCall<BalanceOf>),
+		#[codec(index = 26)]
+		Utility(Box<UtilityCall<Self>>),
 		#[codec(index = 73)]
 		Crowdloan(ContributeCall<BalanceOf, AccountIdOf>),
 		#[codec(index = 29)]
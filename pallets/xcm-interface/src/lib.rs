@@ -19,15 +19,28 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(unused_imports)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 pub mod calls;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 pub mod traits;
+pub mod weights;
 use bifrost_asset_registry::AssetMetadata;
 use bifrost_primitives::{traits::XcmDestWeightAndFeeHandler, CurrencyIdMapping, XcmOperationType};
 pub use calls::*;
+use frame_support::weights::Weight;
 use orml_traits::MultiCurrency;
 pub use pallet::*;
 use sp_runtime::traits::UniqueSaturatedInto;
-pub use traits::{ChainId, MessageId, Nonce, SalpHelper};
+#[cfg(feature = "runtime-benchmarks")]
+pub use traits::BenchmarkHelper;
+pub use traits::{
+	ChainId, DestinationWeightFee, MessageId, Nonce, SalpHelper, XcmDestinationWeigher,
+};
+pub use weights::WeightInfo;
 
 macro_rules! use_relay {
     ({ $( $code:tt )* }) => {
@@ -57,14 +70,20 @@ pub type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<AccountId
 #[frame_support::pallet]
 pub mod pallet {
 	use cumulus_primitives_core::ParaId;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		weights::constants::{WEIGHT_PROOF_SIZE_PER_MB, WEIGHT_REF_TIME_PER_SECOND},
+	};
 	use frame_system::pallet_prelude::*;
 	use orml_traits::{currency::TransferAll, MultiCurrency, MultiReservableCurrency};
-	use sp_runtime::{traits::Convert, DispatchError};
+	use sp_runtime::{
+		traits::{Convert, Zero},
+		DispatchError, FixedPointNumber, FixedU128,
+	};
 	use sp_std::{convert::From, prelude::*, vec, vec::Vec};
 	use xcm::{
 		v4::{prelude::*, Asset, ExecuteXcm, Location},
-		DoubleEncoded, VersionedXcm,
+		DoubleEncoded, VersionedLocation, VersionedXcm,
 	};
 
 	use super::*;
@@ -117,6 +136,16 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type CallBackTimeOut: Get<BlockNumberFor<Self>>;
+
+		/// Weighs the XCM program of an operation as its destination does.
+		type DestinationWeigher: XcmDestinationWeigher;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Opens the channels the benchmarks send messages through.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
 	}
 
 	#[pallet::error]
@@ -136,6 +165,9 @@ pub mod pallet {
 		XcmDestWeightAndFeeUpdated(XcmOperationType, CurrencyIdOf<T>, Weight, BalanceOf<T>),
 		TransferredStatemineMultiAsset(AccountIdOf<T>, BalanceOf<T>),
 		TransferredEthereumAssets(AccountIdOf<T>, sp_core::H160, BalanceOf<T>),
+		DestinationWeightFeeUpdated(Location, Option<DestinationWeightFee<BalanceOf<T>>>),
+		DestinationInstructionWeightUpdated(Location, Option<Weight>),
+		DestinationTransactWeightUpdated(Location, XcmOperationType, Option<Weight>),
 	}
 
 	/// The current storage version, we set to 2 our new version(after migrate stroage
@@ -158,6 +190,43 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The fees of execution weight on a destination, in the asset the destination charges.
+	/// Operations without an `XcmWeightAndFee` override are priced with them.
+	///
+	/// DestinationWeightFees: map: Location => DestinationWeightFee
+	#[pallet::storage]
+	pub type DestinationWeightFees<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		xcm::v3::Location,
+		DestinationWeightFee<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The weight of an XCM instruction on a destination. Governance sets it to the heaviest
+	/// instruction XcmInterface sends there, taken from the `pallet_xcm_benchmarks` weights of
+	/// the destination runtime.
+	///
+	/// DestinationInstructionWeights: map: Location => Weight
+	#[pallet::storage]
+	pub type DestinationInstructionWeights<T> =
+		StorageMap<_, Blake2_128Concat, xcm::v3::Location, Weight, OptionQuery>;
+
+	/// The weight of the call an operation dispatches on a destination. Governance sets it to
+	/// the benchmarked weight of the call in the destination runtime, the surplus is refunded.
+	///
+	/// DestinationTransactWeights: double_map: Location, XcmOperationType => Weight
+	#[pallet::storage]
+	pub type DestinationTransactWeights<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		xcm::v3::Location,
+		Blake2_128Concat,
+		XcmOperationType,
+		Weight,
+		OptionQuery,
+	>;
+
 	// Tracker for the next nonce index
 	#[pallet::storage]
 	pub(super) type CurrentNonce<T: Config> =
@@ -203,7 +272,7 @@ pub mod pallet {
 			Ok(())
 		}
 		#[pallet::call_index(1)]
-		#[pallet::weight({2_000_000_000})]
+		#[pallet::weight(T::WeightInfo::transfer_statemine_assets())]
 		pub fn transfer_statemine_assets(
 			origin: OriginFor<T>,
 			amount: BalanceOf<T>,
@@ -236,7 +305,7 @@ pub mod pallet {
 
 			let dst_location = T::AccountIdToLocation::convert(dest.clone());
 
-			let (dest_weight, xcm_fee) = XcmWeightAndFee::<T>::get(
+			let (dest_weight, xcm_fee) = Self::get_operation_weight_and_fee(
 				T::RelaychainCurrencyId::get(),
 				XcmOperationType::StatemineTransfer,
 			)
@@ -245,26 +314,13 @@ pub mod pallet {
 			let xcm_fee_u128 =
 				TryInto::<u128>::try_into(xcm_fee).map_err(|_| Error::<T>::FeeConvertFailed)?;
 
-			let mut assets = Assets::new();
-			let statemine_asset = Asset {
-				id: AssetId(Location::new(
-					0,
-					[
-						PalletInstance(parachains::Statemine::PALLET_ID),
-						GeneralIndex(asset_id.into()),
-					],
-				)),
-				fun: Fungible(amount_u128),
-			};
-			let fee_asset =
-				Asset { id: AssetId(Location::new(1, Here)), fun: Fungible(xcm_fee_u128) };
-			assets.push(statemine_asset.clone());
-			assets.push(fee_asset.clone());
-			let msg = Xcm(vec![
-				WithdrawAsset(assets),
-				BuyExecution { fees: fee_asset, weight_limit: Limited(dest_weight) },
-				DepositAsset { assets: AllCounted(2).into(), beneficiary: dst_location },
-			]);
+			let msg = Self::build_statemine_transfer(
+				asset_id,
+				amount_u128,
+				xcm_fee_u128,
+				dest_weight,
+				dst_location,
+			);
 
 			pallet_xcm::Pallet::<T>::send_xcm(
 				Here,
@@ -279,7 +335,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight({2_000_000_000})]
+		#[pallet::weight(T::WeightInfo::transfer_ethereum_assets())]
 		pub fn transfer_ethereum_assets(
 			origin: OriginFor<T>,
 			currency_id: CurrencyIdOf<T>,
@@ -296,47 +352,17 @@ pub mod pallet {
 			};
 
 			let (require_weight_at_most, xcm_fee) =
-				XcmWeightAndFee::<T>::get(currency_id, XcmOperationType::EthereumTransfer)
+				Self::get_operation_weight_and_fee(currency_id, XcmOperationType::EthereumTransfer)
 					.ok_or(Error::<T>::OperationWeightAndFeeNotExist)?;
 
-			let fee: Asset = Asset {
-				id: AssetId(Location::parent()),
-				fun: Fungible(UniqueSaturatedInto::<u128>::unique_saturated_into(xcm_fee)),
-			};
-
 			T::MultiCurrency::withdraw(currency_id, &who, amount)?;
 
-			let remote_call: DoubleEncoded<()> = use_relay!({
-				AssetHubCall::PolkadotXcm(PolkadotXcmCall::LimitedReserveTransferAssets(
-					Box::new(Location::new(2, [GlobalConsensus(Ethereum { chain_id: 1 })]).into()),
-					Box::new(
-						Location::new(
-							0,
-							[AccountKey20 { network: None, key: to.to_fixed_bytes() }],
-						)
-						.into(),
-					),
-					Box::new(asset.into()),
-					0,
-					Unlimited,
-				))
-				.encode()
-				.into()
-			});
-
-			let remote_xcm = Xcm(vec![
-				WithdrawAsset(fee.clone().into()),
-				BuyExecution { fees: fee.clone(), weight_limit: Unlimited },
-				Transact {
-					origin_kind: OriginKind::SovereignAccount,
-					require_weight_at_most,
-					call: remote_call,
-				},
-				DepositAsset {
-					assets: All.into(),
-					beneficiary: Location::new(1, [Parachain(T::ParachainId::get().into())]),
-				},
-			]);
+			let remote_xcm = Self::build_ethereum_transfer(
+				asset,
+				to,
+				UniqueSaturatedInto::<u128>::unique_saturated_into(xcm_fee),
+				require_weight_at_most,
+			);
 			let (ticket, _) = <T as pallet_xcm::Config>::XcmRouter::validate(
 				&mut Some(Location::new(1, [Parachain(parachains::Statemine::ID)])),
 				&mut Some(remote_xcm),
@@ -347,6 +373,63 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::TransferredEthereumAssets(who, to, amount));
 			Ok(())
 		}
+
+		/// Sets the fees of execution weight on `dest`, removes them if `None`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_destination_weight_fee())]
+		pub fn set_destination_weight_fee(
+			origin: OriginFor<T>,
+			dest: Box<VersionedLocation>,
+			weight_fee: Option<DestinationWeightFee<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let (dest, v3_dest) = Self::convert_destination(*dest)?;
+			DestinationWeightFees::<T>::set(v3_dest, weight_fee);
+
+			Self::deposit_event(Event::<T>::DestinationWeightFeeUpdated(dest, weight_fee));
+
+			Ok(())
+		}
+
+		/// Sets the weight of an XCM instruction on `dest`, removes it if `None`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_destination_instruction_weight())]
+		pub fn set_destination_instruction_weight(
+			origin: OriginFor<T>,
+			dest: Box<VersionedLocation>,
+			weight: Option<Weight>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let (dest, v3_dest) = Self::convert_destination(*dest)?;
+			DestinationInstructionWeights::<T>::set(v3_dest, weight);
+
+			Self::deposit_event(Event::<T>::DestinationInstructionWeightUpdated(dest, weight));
+
+			Ok(())
+		}
+
+		/// Sets the weight of the call `operation` dispatches on `dest`, removes it if `None`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_destination_transact_weight())]
+		pub fn set_destination_transact_weight(
+			origin: OriginFor<T>,
+			dest: Box<VersionedLocation>,
+			operation: XcmOperationType,
+			weight: Option<Weight>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let (dest, v3_dest) = Self::convert_destination(*dest)?;
+			DestinationTransactWeights::<T>::set(v3_dest, operation, weight);
+
+			Self::deposit_event(Event::<T>::DestinationTransactWeightUpdated(
+				dest, operation, weight,
+			));
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> XcmHelper<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
@@ -395,6 +478,7 @@ pub mod pallet {
 			operation: XcmOperationType,
 		) -> Option<(Weight, BalanceOf<T>)> {
 			XcmWeightAndFee::<T>::get(token, operation)
+				.or_else(|| Self::estimate_operation_weight_and_fee(token, operation))
 		}
 
 		fn set_xcm_dest_weight_and_fee(
@@ -413,6 +497,230 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Estimates the weight and fee of `operation` from the XCM program it sends, weighed by
+		/// `T::DestinationWeigher` and priced with the `DestinationWeightFees` of its
+		/// destination.
+		///
+		/// The weight is the `require_weight_at_most` of the `Transact` in the program, or the
+		/// weight of the whole program if it doesn't dispatch a call. Only the operations whose
+		/// program is known are estimated, the others need an `XcmWeightAndFee`.
+		pub fn estimate_operation_weight_and_fee(
+			currency_id: CurrencyIdOf<T>,
+			operation: XcmOperationType,
+		) -> Option<(Weight, BalanceOf<T>)> {
+			let dest = Self::operation_destination(currency_id, operation)?;
+			let (message, transact_weight) = match operation {
+				XcmOperationType::StatemineTransfer => (
+					Self::build_statemine_transfer(0, 0, 0, Weight::zero(), Location::here()),
+					None,
+				),
+				XcmOperationType::EthereumTransfer => {
+					let transact_weight = T::DestinationWeigher::transact_weight(&dest, operation)?;
+					let asset = Asset { id: AssetId(Location::here()), fun: Fungible(0) };
+					(
+						Self::build_ethereum_transfer(
+							asset,
+							Default::default(),
+							0,
+							transact_weight,
+						),
+						Some(transact_weight),
+					)
+				},
+				XcmOperationType::Bond |
+				XcmOperationType::BondExtra |
+				XcmOperationType::Unbond |
+				XcmOperationType::Vote |
+				XcmOperationType::RemoveVote
+					if dest == Location::parent() =>
+				{
+					let transact_weight = T::DestinationWeigher::transact_weight(&dest, operation)?;
+					let call = Self::build_relay_operation_call(operation)?;
+					(Self::build_relay_transact(call, transact_weight), Some(transact_weight))
+				},
+				_ => return None,
+			};
+			let program_weight = T::DestinationWeigher::weight(&dest, message)?;
+			let fee = Self::weight_to_fee(&dest, program_weight)?;
+
+			Some((transact_weight.unwrap_or(program_weight), fee))
+		}
+
+		/// The fee of both the `ref_time` and the `proof_size` of `weight` on `dest`, `None` if
+		/// its weight fees are unknown.
+		pub fn weight_to_fee(dest: &Location, weight: Weight) -> Option<BalanceOf<T>> {
+			let v3_dest = xcm::v3::Location::try_from(dest.clone()).ok()?;
+			let weight_fee = DestinationWeightFees::<T>::get(v3_dest)?;
+			let ref_time_fee =
+				FixedU128::saturating_from_rational(weight.ref_time(), WEIGHT_REF_TIME_PER_SECOND)
+					.saturating_mul_int(UniqueSaturatedInto::<u128>::unique_saturated_into(
+						weight_fee.per_second,
+					));
+			let proof_size_fee =
+				FixedU128::saturating_from_rational(weight.proof_size(), WEIGHT_PROOF_SIZE_PER_MB)
+					.saturating_mul_int(UniqueSaturatedInto::<u128>::unique_saturated_into(
+						weight_fee.per_proof_megabyte,
+					));
+			Some(UniqueSaturatedInto::<BalanceOf<T>>::unique_saturated_into(
+				ref_time_fee.saturating_add(proof_size_fee),
+			))
+		}
+
+		/// `dest` as both the current and the v3 location the storages are keyed by.
+		fn convert_destination(
+			dest: VersionedLocation,
+		) -> Result<(Location, xcm::v3::Location), DispatchError> {
+			let dest: Location = dest.try_into().map_err(|_| Error::<T>::FailToConvert)?;
+			let v3_dest =
+				xcm::v3::Location::try_from(dest.clone()).map_err(|_| Error::<T>::FailToConvert)?;
+			Ok((dest, v3_dest))
+		}
+
+		/// The chain the XCM program of `operation` is executed on.
+		pub(crate) fn operation_destination(
+			currency_id: CurrencyIdOf<T>,
+			operation: XcmOperationType,
+		) -> Option<Location> {
+			match operation {
+				XcmOperationType::StatemineTransfer | XcmOperationType::EthereumTransfer =>
+					Some(Location::new(1, [Parachain(parachains::Statemine::ID)])),
+				_ => {
+					let location = T::CurrencyIdConvert::get_location(currency_id)?;
+					match location.unpack() {
+						(1, []) => Some(Location::parent()),
+						(1, [Parachain(id), ..]) => Some(Location::new(1, [Parachain(*id)])),
+						_ => None,
+					}
+				},
+			}
+		}
+
+		pub(crate) fn build_statemine_transfer(
+			asset_id: u32,
+			amount: u128,
+			fee: u128,
+			dest_weight: Weight,
+			beneficiary: Location,
+		) -> Xcm<()> {
+			let mut assets = Assets::new();
+			let statemine_asset = Asset {
+				id: AssetId(Location::new(
+					0,
+					[
+						PalletInstance(parachains::Statemine::PALLET_ID),
+						GeneralIndex(asset_id.into()),
+					],
+				)),
+				fun: Fungible(amount),
+			};
+			let fee_asset = Asset { id: AssetId(Location::new(1, Here)), fun: Fungible(fee) };
+			assets.push(statemine_asset.clone());
+			assets.push(fee_asset.clone());
+			Xcm(vec![
+				WithdrawAsset(assets),
+				BuyExecution { fees: fee_asset, weight_limit: Limited(dest_weight) },
+				DepositAsset { assets: AllCounted(2).into(), beneficiary },
+			])
+		}
+
+		pub(crate) fn build_ethereum_transfer(
+			asset: Asset,
+			to: sp_core::H160,
+			fee: u128,
+			require_weight_at_most: Weight,
+		) -> Xcm<()> {
+			let fee: Asset = Asset { id: AssetId(Location::parent()), fun: Fungible(fee) };
+
+			let remote_call: DoubleEncoded<()> = use_relay!({
+				AssetHubCall::PolkadotXcm(PolkadotXcmCall::LimitedReserveTransferAssets(
+					Box::new(Location::new(2, [GlobalConsensus(Ethereum { chain_id: 1 })]).into()),
+					Box::new(
+						Location::new(
+							0,
+							[AccountKey20 { network: None, key: to.to_fixed_bytes() }],
+						)
+						.into(),
+					),
+					Box::new(asset.into()),
+					0,
+					Unlimited,
+				))
+				.encode()
+				.into()
+			});
+
+			Xcm(vec![
+				WithdrawAsset(fee.clone().into()),
+				BuyExecution { fees: fee.clone(), weight_limit: Unlimited },
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most,
+					call: remote_call,
+				},
+				DepositAsset {
+					assets: All.into(),
+					beneficiary: Location::new(1, [Parachain(T::ParachainId::get().into())]),
+				},
+			])
+		}
+
+		/// The call slp and vtoken-voting dispatch on the relay chain for `operation`, from the
+		/// first derivative account of the parachain.
+		pub(crate) fn build_relay_operation_call(
+			operation: XcmOperationType,
+		) -> Option<DoubleEncoded<()>> {
+			let amount = BalanceOf::<T>::zero();
+			use_relay!({
+				let call = match operation {
+					XcmOperationType::Bond => RelaychainCall::Staking(StakingCall::Bond(
+						amount,
+						RewardDestination::Staked,
+					)),
+					XcmOperationType::BondExtra =>
+						RelaychainCall::Staking(StakingCall::BondExtra(amount)),
+					XcmOperationType::Unbond =>
+						RelaychainCall::Staking(StakingCall::Unbond(amount)),
+					XcmOperationType::Vote =>
+						RelaychainCall::ConvictionVoting(ConvictionVotingCall::Vote(
+							0,
+							AccountVote::Standard { vote: 0, balance: amount },
+						)),
+					XcmOperationType::RemoveVote =>
+						RelaychainCall::ConvictionVoting(ConvictionVotingCall::RemoveVote(None, 0)),
+					_ => return None,
+				};
+				let call: RelaychainCall<BalanceOf<T>, AccountIdOf<T>, BlockNumberFor<T>> =
+					RelaychainCall::Utility(Box::new(UtilityCall::AsDerivative(0, call)));
+				Some(call.encode().into())
+			})
+		}
+
+		/// The program slp and vtoken-voting send to the relay chain to dispatch `call`, see
+		/// their `construct_xcm_message`.
+		pub(crate) fn build_relay_transact(
+			call: DoubleEncoded<()>,
+			require_weight_at_most: Weight,
+		) -> Xcm<()> {
+			let asset = Asset { id: AssetId(Location::here()), fun: Fungible(0) };
+			let parachain = Location::new(0, [Parachain(T::ParachainId::get().into())]);
+			Xcm(vec![
+				WithdrawAsset(asset.clone().into()),
+				BuyExecution { fees: asset, weight_limit: Unlimited },
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most,
+					call,
+				},
+				ReportTransactStatus(QueryResponseInfo {
+					destination: parachain.clone(),
+					query_id: 0,
+					max_weight: Weight::zero(),
+				}),
+				RefundSurplus,
+				DepositAsset { assets: AllCounted(8).into(), beneficiary: parachain },
+			])
+		}
+
 		pub(crate) fn transact_id(data: &[u8]) -> MessageId {
 			return sp_io::hashing::blake2_256(data);
 		}
@@ -466,3 +774,39 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Weighs the programs sent to a destination with the `DestinationInstructionWeights` and
+/// `DestinationTransactWeights` governance set from the benchmarks of the destination runtime.
+pub struct ConfiguredDestinationWeigher<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> ConfiguredDestinationWeigher<T> {
+	fn instructions_weight(
+		instructions: &[xcm::v4::Instruction<()>],
+		instruction_weight: Weight,
+	) -> Option<Weight> {
+		instructions.iter().try_fold(Weight::zero(), |weight, instruction| {
+			let inner_weight = match instruction {
+				xcm::v4::Instruction::Transact { require_weight_at_most, .. } =>
+					*require_weight_at_most,
+				xcm::v4::Instruction::SetErrorHandler(inner) |
+				xcm::v4::Instruction::SetAppendix(inner) =>
+					Self::instructions_weight(&inner.0, instruction_weight)?,
+				_ => Weight::zero(),
+			};
+			weight.checked_add(&instruction_weight)?.checked_add(&inner_weight)
+		})
+	}
+}
+
+impl<T: Config> XcmDestinationWeigher for ConfiguredDestinationWeigher<T> {
+	fn weight(dest: &xcm::v4::Location, message: xcm::v4::Xcm<()>) -> Option<Weight> {
+		let v3_dest = xcm::v3::Location::try_from(dest.clone()).ok()?;
+		let instruction_weight = DestinationInstructionWeights::<T>::get(v3_dest)?;
+		Self::instructions_weight(&message.0, instruction_weight)
+	}
+
+	fn transact_weight(dest: &xcm::v4::Location, operation: XcmOperationType) -> Option<Weight> {
+		let v3_dest = xcm::v3::Location::try_from(dest.clone()).ok()?;
+		DestinationTransactWeights::<T>::get(v3_dest, operation)
+	}
+}
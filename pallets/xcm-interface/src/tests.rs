@@ -0,0 +1,349 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{mock::*, traits::parachains, *};
+use bifrost_primitives::{Balance, CurrencyId, TokenSymbol, KSM};
+use frame_support::{assert_noop, assert_ok, weights::Weight};
+use sp_runtime::DispatchError;
+use xcm::{
+	v4::{prelude::*, Location},
+	VersionedLocation,
+};
+
+const FEE_PER_SECOND: Balance = 1_000_000_000_000;
+// A thousand per byte of proof.
+const FEE_PER_PROOF_MEGABYTE: Balance = 1_048_576_000;
+
+fn asset_hub() -> Location {
+	Location::new(1, [Parachain(parachains::Statemine::ID)])
+}
+
+fn weight_fee() -> DestinationWeightFee<Balance> {
+	DestinationWeightFee { per_second: FEE_PER_SECOND, per_proof_megabyte: FEE_PER_PROOF_MEGABYTE }
+}
+
+fn set_destination_weights(dest: Location) {
+	assert_ok!(XcmInterface::set_destination_weight_fee(
+		RuntimeOrigin::root(),
+		Box::new(VersionedLocation::V4(dest.clone())),
+		Some(weight_fee()),
+	));
+	assert_ok!(XcmInterface::set_destination_instruction_weight(
+		RuntimeOrigin::root(),
+		Box::new(VersionedLocation::V4(dest.clone())),
+		Some(UnitWeightCost::get()),
+	));
+	for operation in [
+		XcmOperationType::Bond,
+		XcmOperationType::BondExtra,
+		XcmOperationType::Unbond,
+		XcmOperationType::Vote,
+		XcmOperationType::RemoveVote,
+		XcmOperationType::EthereumTransfer,
+	] {
+		assert_ok!(XcmInterface::set_destination_transact_weight(
+			RuntimeOrigin::root(),
+			Box::new(VersionedLocation::V4(dest.clone())),
+			operation,
+			Some(TransactWeight::get()),
+		));
+	}
+}
+
+#[test]
+fn estimate_relay_operations_should_work() {
+	new_test_ext().execute_with(|| {
+		set_destination_weights(Location::parent());
+
+		// WithdrawAsset, BuyExecution, Transact, ReportTransactStatus, RefundSurplus and
+		// DepositAsset at (1_000_000_000, 1_000) each, plus the (2_000_000_000, 100_000) of the
+		// call.
+		for operation in [
+			XcmOperationType::Bond,
+			XcmOperationType::BondExtra,
+			XcmOperationType::Unbond,
+			XcmOperationType::Vote,
+			XcmOperationType::RemoveVote,
+		] {
+			assert_eq!(
+				XcmInterface::estimate_operation_weight_and_fee(KSM, operation),
+				Some((TransactWeight::get(), 8_000_000_000 + 106_000_000))
+			);
+		}
+	});
+}
+
+#[test]
+fn relay_operation_calls_should_be_dispatched_by_derivative() {
+	new_test_ext().execute_with(|| {
+		// Utility::as_derivative(0, Staking::unbond(0)) on Kusama.
+		let call = XcmInterface::build_relay_operation_call(XcmOperationType::Unbond).unwrap();
+		assert_eq!(call.into_encoded(), vec![24, 1, 0, 0, 6, 2, 0]);
+
+		// Utility::as_derivative(0, ConvictionVoting::remove_vote(None, 0)) on Kusama.
+		let call = XcmInterface::build_relay_operation_call(XcmOperationType::RemoveVote).unwrap();
+		assert_eq!(call.into_encoded(), vec![24, 1, 0, 0, 20, 4, 0, 0, 0, 0, 0]);
+
+		assert!(XcmInterface::build_relay_operation_call(XcmOperationType::Payout).is_none());
+	});
+}
+
+#[test]
+fn estimate_asset_hub_operations_should_work() {
+	new_test_ext().execute_with(|| {
+		set_destination_weights(asset_hub());
+
+		// WithdrawAsset, BuyExecution and DepositAsset, nothing is dispatched.
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(
+				KSM,
+				XcmOperationType::StatemineTransfer
+			),
+			Some((Weight::from_parts(3_000_000_000, 3_000), 3_000_000_000 + 3_000_000))
+		);
+		// WithdrawAsset, BuyExecution, Transact and DepositAsset, plus the call.
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(
+				KSM,
+				XcmOperationType::EthereumTransfer
+			),
+			Some((TransactWeight::get(), 6_000_000_000 + 104_000_000))
+		);
+	});
+}
+
+#[test]
+fn estimate_without_destination_weights_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(KSM, XcmOperationType::Bond),
+			None
+		);
+
+		let dest = Box::new(VersionedLocation::V4(Location::parent()));
+		set_destination_weights(Location::parent());
+		assert_ok!(XcmInterface::set_destination_weight_fee(
+			RuntimeOrigin::root(),
+			dest.clone(),
+			None
+		));
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(KSM, XcmOperationType::Bond),
+			None
+		);
+
+		set_destination_weights(Location::parent());
+		assert_ok!(XcmInterface::set_destination_instruction_weight(
+			RuntimeOrigin::root(),
+			dest.clone(),
+			None
+		));
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(KSM, XcmOperationType::Bond),
+			None
+		);
+
+		set_destination_weights(Location::parent());
+		assert_ok!(XcmInterface::set_destination_transact_weight(
+			RuntimeOrigin::root(),
+			dest,
+			XcmOperationType::Bond,
+			None
+		));
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(KSM, XcmOperationType::Bond),
+			None
+		);
+		assert!(XcmInterface::estimate_operation_weight_and_fee(KSM, XcmOperationType::Unbond)
+			.is_some());
+	});
+}
+
+#[test]
+fn estimate_unknown_programs_should_fail() {
+	new_test_ext().execute_with(|| {
+		set_destination_weights(Location::parent());
+		let sibling = Location::new(1, [Parachain(parachains::karura::ID)]);
+		set_destination_weights(sibling.clone());
+		assert_ok!(AssetRegistry::do_register_location(KAR, &sibling));
+
+		// The programs of these operations aren't built by the estimator.
+		for operation in [
+			XcmOperationType::UmpContributeTransact,
+			XcmOperationType::Payout,
+			XcmOperationType::TransferTo,
+		] {
+			assert_eq!(XcmInterface::estimate_operation_weight_and_fee(KSM, operation), None);
+		}
+		// Staking is only estimated on the relay chain.
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(KAR, XcmOperationType::Bond),
+			None
+		);
+		// The currency has no location.
+		assert_eq!(
+			XcmInterface::estimate_operation_weight_and_fee(
+				CurrencyId::Token(TokenSymbol::DOT),
+				XcmOperationType::Bond
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn xcm_weight_and_fee_should_override_estimate() {
+	new_test_ext().execute_with(|| {
+		set_destination_weights(Location::parent());
+		assert_eq!(
+			XcmInterface::get_operation_weight_and_fee(KSM, XcmOperationType::Bond),
+			Some((TransactWeight::get(), 8_000_000_000 + 106_000_000))
+		);
+
+		let weight = Weight::from_parts(5_000_000_000, 200_000);
+		assert_ok!(XcmInterface::update_xcm_dest_weight_and_fee(
+			RuntimeOrigin::root(),
+			vec![(KSM, XcmOperationType::Bond, weight, 1_000)],
+		));
+		assert_eq!(
+			XcmInterface::get_operation_weight_and_fee(KSM, XcmOperationType::Bond),
+			Some((weight, 1_000))
+		);
+	});
+}
+
+#[test]
+fn set_destination_weight_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmInterface::set_destination_weight_fee(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(VersionedLocation::V4(Location::parent())),
+				Some(weight_fee()),
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(XcmInterface::set_destination_weight_fee(
+			RuntimeOrigin::root(),
+			Box::new(VersionedLocation::V4(Location::parent())),
+			Some(weight_fee()),
+		));
+		System::assert_last_event(RuntimeEvent::XcmInterface(Event::DestinationWeightFeeUpdated(
+			Location::parent(),
+			Some(weight_fee()),
+		)));
+		// Both the ref time and the proof size are charged.
+		assert_eq!(
+			XcmInterface::weight_to_fee(&Location::parent(), Weight::from_parts(500_000_000, 0)),
+			Some(500_000_000)
+		);
+		assert_eq!(
+			XcmInterface::weight_to_fee(&Location::parent(), Weight::from_parts(0, 1_048_576)),
+			Some(FEE_PER_PROOF_MEGABYTE)
+		);
+		assert_eq!(
+			XcmInterface::weight_to_fee(
+				&Location::parent(),
+				Weight::from_parts(500_000_000, 1_048_576)
+			),
+			Some(500_000_000 + FEE_PER_PROOF_MEGABYTE)
+		);
+		assert_eq!(XcmInterface::weight_to_fee(&asset_hub(), Weight::from_parts(1, 0)), None);
+	});
+}
+
+#[test]
+fn set_destination_weights_should_work() {
+	new_test_ext().execute_with(|| {
+		let dest = Box::new(VersionedLocation::V4(Location::parent()));
+		assert_noop!(
+			XcmInterface::set_destination_instruction_weight(
+				RuntimeOrigin::signed(ALICE),
+				dest.clone(),
+				Some(UnitWeightCost::get()),
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			XcmInterface::set_destination_transact_weight(
+				RuntimeOrigin::signed(ALICE),
+				dest.clone(),
+				XcmOperationType::Bond,
+				Some(TransactWeight::get()),
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(XcmInterface::set_destination_instruction_weight(
+			RuntimeOrigin::root(),
+			dest.clone(),
+			Some(UnitWeightCost::get()),
+		));
+		System::assert_last_event(RuntimeEvent::XcmInterface(
+			Event::DestinationInstructionWeightUpdated(
+				Location::parent(),
+				Some(UnitWeightCost::get()),
+			),
+		));
+		assert_ok!(XcmInterface::set_destination_transact_weight(
+			RuntimeOrigin::root(),
+			dest,
+			XcmOperationType::Bond,
+			Some(TransactWeight::get()),
+		));
+		System::assert_last_event(RuntimeEvent::XcmInterface(
+			Event::DestinationTransactWeightUpdated(
+				Location::parent(),
+				XcmOperationType::Bond,
+				Some(TransactWeight::get()),
+			),
+		));
+
+		// Every instruction is weighed, plus the weight a `Transact` dispatches.
+		let message = Xcm(vec![
+			ClearOrigin,
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: TransactWeight::get(),
+				call: vec![].into(),
+			},
+		]);
+		assert_eq!(
+			ConfiguredDestinationWeigher::<Test>::weight(&Location::parent(), message.clone()),
+			Some(Weight::from_parts(4_000_000_000, 102_000))
+		);
+		assert_eq!(ConfiguredDestinationWeigher::<Test>::weight(&asset_hub(), message), None);
+		assert_eq!(
+			ConfiguredDestinationWeigher::<Test>::transact_weight(
+				&Location::parent(),
+				XcmOperationType::Bond
+			),
+			Some(TransactWeight::get())
+		);
+		assert_eq!(
+			ConfiguredDestinationWeigher::<Test>::transact_weight(
+				&Location::parent(),
+				XcmOperationType::Unbond
+			),
+			None
+		);
+	});
+}
@@ -18,11 +18,16 @@
 
 use core::ops::{Add, Mul};
 
+use bifrost_primitives::XcmOperationType;
+use frame_support::weights::Weight;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, RuntimeDebug};
 use sp_std::prelude::*;
-use xcm::v3::QueryId;
+use xcm::{
+	v3::QueryId,
+	v4::{Location, Xcm},
+};
 
 pub type MessageId = [u8; 32];
 
@@ -38,6 +43,15 @@ pub enum TransferOriginType {
 	FromSiblingParaChain = 2,
 }
 
+/// The fees a destination charges for execution weight, in the asset it charges.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DestinationWeightFee<Balance> {
+	/// The fee of a second of `ref_time`.
+	pub per_second: Balance,
+	/// The fee of a megabyte of `proof_size`.
+	pub per_proof_megabyte: Balance,
+}
+
 pub struct XcmBaseWeight(u64);
 
 impl XcmBaseWeight {
@@ -157,3 +171,34 @@ pub trait SalpHelper<AccountId, RuntimeCall, Balance> {
 		amount: Balance,
 	);
 }
+
+/// Weighs the XCM programs sent by XcmInterface as the chains executing them do.
+pub trait XcmDestinationWeigher {
+	/// The weight of executing `message` on `dest`, `None` if `dest` can't be weighed.
+	fn weight(dest: &Location, message: Xcm<()>) -> Option<Weight>;
+
+	/// The `require_weight_at_most` of the call `operation` dispatches on `dest`.
+	fn transact_weight(dest: &Location, operation: XcmOperationType) -> Option<Weight>;
+}
+
+impl XcmDestinationWeigher for () {
+	fn weight(_dest: &Location, _message: Xcm<()>) -> Option<Weight> {
+		None
+	}
+
+	fn transact_weight(_dest: &Location, _operation: XcmOperationType) -> Option<Weight> {
+		None
+	}
+}
+
+/// Sets up the state the benchmarks of the pallet depend on.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Make the XCM router able to deliver messages to `dest`.
+	fn ensure_xcm_delivery(dest: &Location);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn ensure_xcm_delivery(_dest: &Location) {}
+}
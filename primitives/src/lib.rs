@@ -28,6 +28,7 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
 	FixedU128, MultiSignature, OpaqueExtrinsic, Permill,
 };
+use sp_std::vec::Vec;
 
 pub mod currency;
 pub use currency::*;
@@ -212,3 +213,29 @@ pub enum XcmOperationType {
 	EthereumTransfer,
 	TeleportAssets,
 }
+
+// For flexible-fee
+/// A single swap of a currency conversion route.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub enum SwapStep {
+	/// Swap along a zenlink path of currencies.
+	Zenlink(Vec<CurrencyId>),
+	/// Swap between two currencies of a stable-pool pool.
+	StablePool { pool_id: PoolId, currency_in: CurrencyId, currency_out: CurrencyId },
+}
+
+/// The swaps converting one currency to another, executed in order.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct SwapRoute {
+	pub steps: Vec<SwapStep>,
+	/// The quoted amounts, `amounts[0]` is the input of the route and `amounts[i + 1]` the
+	/// output of `steps[i]`.
+	pub amounts: Vec<Balance>,
+}
+
+impl SwapRoute {
+	/// The amount of the input currency the route consumes.
+	pub fn amount_in(&self) -> Balance {
+		self.amounts.first().copied().unwrap_or_default()
+	}
+}
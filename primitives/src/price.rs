@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Balance, CurrencyId, Price, PriceDetail};
+use crate::{Balance, CurrencyId, Moment, Price, PriceDetail};
 
 pub trait PriceFeeder {
 	fn get_price(asset_id: &CurrencyId) -> Option<PriceDetail>;
@@ -34,3 +34,25 @@ pub trait PriceFeeder {
 		currency_out: &CurrencyId,
 	) -> Option<(Balance, Price, Price)>;
 }
+
+/// Time weighted average prices of asset pairs.
+pub trait TwapProvider {
+	/// Accumulate the price of the pair after it is changed by a swap.
+	fn on_swap(asset_in: &CurrencyId, asset_out: &CurrencyId);
+
+	/// Returns the time weighted average amount of `asset_b` per unit of `asset_a` over the
+	/// last `window` milliseconds, and the timestamp of the last update.
+	fn twap(asset_a: &CurrencyId, asset_b: &CurrencyId, window: Moment) -> Option<(Price, Moment)>;
+}
+
+impl TwapProvider for () {
+	fn on_swap(_asset_in: &CurrencyId, _asset_out: &CurrencyId) {}
+
+	fn twap(
+		_asset_a: &CurrencyId,
+		_asset_b: &CurrencyId,
+		_window: Moment,
+	) -> Option<(Price, Moment)> {
+		None
+	}
+}
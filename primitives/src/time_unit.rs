@@ -20,10 +20,13 @@
 
 use parity_scale_codec::MaxEncodedLen;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::{Decode, Encode, RuntimeDebug};
 
 // For vtoken-minting and slp modules
-#[derive(Encode, Decode, Clone, RuntimeDebug, Eq, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Encode, Decode, Clone, RuntimeDebug, Eq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
 pub enum TimeUnit {
 	// Kusama staking time unit
 	Era(#[codec(compact)] u32),
@@ -51,6 +54,12 @@ impl TimeUnit {
 	pub fn add(self, other_time: Self) -> Option<Self> {
 		match (self, other_time) {
 			(TimeUnit::Era(a), TimeUnit::Era(b)) => Some(TimeUnit::Era(a.saturating_add(b))),
+			(TimeUnit::SlashingSpan(a), TimeUnit::SlashingSpan(b)) =>
+				Some(TimeUnit::SlashingSpan(a.saturating_add(b))),
+			(TimeUnit::Round(a), TimeUnit::Round(b)) => Some(TimeUnit::Round(a.saturating_add(b))),
+			(TimeUnit::Kblock(a), TimeUnit::Kblock(b)) =>
+				Some(TimeUnit::Kblock(a.saturating_add(b))),
+			(TimeUnit::Hour(a), TimeUnit::Hour(b)) => Some(TimeUnit::Hour(a.saturating_add(b))),
 			_ => None,
 		}
 	}
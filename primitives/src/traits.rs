@@ -319,11 +319,23 @@ pub trait VtokenMintingInterface<AccountId, CurrencyId, Balance> {
 		remark: BoundedVec<u8, ConstU32<32>>,
 		channel_id: Option<u32>,
 	) -> Result<Balance, DispatchError>;
+	fn mint_with_lock(
+		minter: AccountId,
+		token_id: CurrencyId,
+		token_amount: Balance,
+		remark: BoundedVec<u8, ConstU32<32>>,
+		channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError>;
 	fn redeem(
 		exchanger: AccountId,
 		vtoken_id: CurrencyId,
 		vtoken_amount: Balance,
 	) -> DispatchResultWithPostInfo;
+	fn rebond(
+		exchanger: AccountId,
+		token_id: CurrencyId,
+		token_amount: Balance,
+	) -> Result<Balance, DispatchError>;
 	fn slpx_redeem(
 		exchanger: AccountId,
 		vtoken_id: CurrencyId,
@@ -343,6 +355,7 @@ pub trait VtokenMintingInterface<AccountId, CurrencyId, Balance> {
 	fn vtoken_id(token_id: CurrencyId) -> Option<CurrencyId>;
 	fn token_id(vtoken_id: CurrencyId) -> Option<CurrencyId>;
 	fn get_token_pool(currency_id: CurrencyId) -> Balance;
+	fn get_minimums_mint(token_id: CurrencyId) -> Balance;
 	fn get_minimums_redeem(vtoken_id: CurrencyId) -> Balance;
 	fn get_moonbeam_parachain_id() -> u32;
 }
@@ -360,6 +373,16 @@ impl<AccountId, CurrencyId, Balance: Zero> VtokenMintingInterface<AccountId, Cur
 		Ok(Zero::zero())
 	}
 
+	fn mint_with_lock(
+		_minter: AccountId,
+		_token_id: CurrencyId,
+		_token_amount: Balance,
+		_remark: BoundedVec<u8, ConstU32<32>>,
+		_channel_id: Option<u32>,
+	) -> Result<Balance, DispatchError> {
+		Ok(Zero::zero())
+	}
+
 	fn redeem(
 		_exchanger: AccountId,
 		_vtoken_id: CurrencyId,
@@ -368,6 +391,14 @@ impl<AccountId, CurrencyId, Balance: Zero> VtokenMintingInterface<AccountId, Cur
 		Ok(().into())
 	}
 
+	fn rebond(
+		_exchanger: AccountId,
+		_token_id: CurrencyId,
+		_token_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(Zero::zero())
+	}
+
 	fn slpx_redeem(
 		_exchanger: AccountId,
 		_vtoken_id: CurrencyId,
@@ -405,6 +436,10 @@ impl<AccountId, CurrencyId, Balance: Zero> VtokenMintingInterface<AccountId, Cur
 		Zero::zero()
 	}
 
+	fn get_minimums_mint(_token_id: CurrencyId) -> Balance {
+		Zero::zero()
+	}
+
 	fn get_minimums_redeem(_vtoken_id: CurrencyId) -> Balance {
 		Zero::zero()
 	}
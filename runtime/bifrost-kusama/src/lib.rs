@@ -53,7 +53,8 @@ use sp_core::{ConstBool, OpaqueMetadata, U256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{
-		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, StaticLookup, Zero,
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Dispatchable,
+		StaticLookup, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, DispatchError, DispatchResult, FixedU128, Perbill, Permill, RuntimeDebug,
@@ -79,6 +80,7 @@ pub use bifrost_primitives::{
 	DistributionId, Liquidity, Moment, ParaId, PoolId, Price, Rate, Ratio, RpcContributionStatus,
 	Shortfall, TimeUnit, TokenSymbol,
 };
+use bifrost_runtime_common::xcm_fee::WeightToAssetFee;
 pub use bifrost_runtime_common::{
 	cent, constants::time::*, dollar, micro, milli, millicent, AuraId, CouncilCollective,
 	EnsureRootOrAllTechnicalCommittee, MoreThanHalfCouncil, SlowAdjustingFeeUpdate,
@@ -132,6 +134,10 @@ pub use xcm_config::{
 	Sibling, SiblingParachainConvertsVia, XcmConfig, XcmRouter,
 };
 use xcm_executor::{traits::QueryHandler, XcmExecutor};
+use xcm_runtime_apis::{
+	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
+	fees::Error as XcmPaymentApiError,
+};
 
 impl_opaque_keys! {
 	pub struct SessionKeys {
@@ -1012,6 +1018,8 @@ parameter_types! {
 impl bifrost_vesting::Config for Runtime {
 	type BlockNumberToBalance = ConvertInto;
 	type Currency = Balances;
+	type MultiCurrency = Tokens;
+	type NativeCurrencyId = NativeCurrencyId;
 	type RuntimeEvent = RuntimeEvent;
 	type MinVestedTransfer = ExistentialDeposit;
 	type WeightInfo = weights::bifrost_vesting::BifrostWeight<Runtime>;
@@ -1023,6 +1031,11 @@ impl bifrost_vesting::Config for Runtime {
 
 parameter_types! {
 	pub MaxFeeCurrencyOrderListLen: u32 = 50;
+	pub SwapRouteHubs: Vec<CurrencyId> = vec![
+		NativeCurrencyId::get(),
+		CurrencyId::Token(TokenSymbol::KSM),
+		CurrencyId::VToken(TokenSymbol::KSM),
+	];
 }
 
 impl bifrost_flexible_fee::Config for Runtime {
@@ -1041,6 +1054,10 @@ impl bifrost_flexible_fee::Config for Runtime {
 	type XcmRouter = XcmRouter;
 	type PalletId = FlexibleFeePalletId;
 	type PriceFeeder = Prices;
+	type Twap = Twap;
+	type StablePoolHandler = StablePool;
+	type SwapRouteHubs = SwapRouteHubs;
+	type CurrencyIdConvert = AssetIdMaps<Runtime>;
 }
 
 parameter_types! {
@@ -1251,6 +1268,30 @@ impl QueryResponseManager<QueryId, Location, BlockNumber, RuntimeCall>
 	}
 }
 
+pub struct RelayStateRoot;
+impl Get<Option<Hash>> for RelayStateRoot {
+	fn get() -> Option<Hash> {
+		ParachainSystem::validation_data().map(|data| data.relay_parent_storage_root)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SlpBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl bifrost_slp::BenchmarkHelper for SlpBenchmarkHelper {
+	fn set_relay_state_root(root: Hash) {
+		// The validation data is only set by the inherent, write it as `ParachainSystem` would.
+		let validation_data = cumulus_primitives_core::PersistedValidationData {
+			relay_parent_storage_root: root,
+			..Default::default()
+		};
+		frame_support::storage::unhashed::put(
+			&frame_support::storage::storage_prefix(b"ParachainSystem", b"ValidationData"),
+			&validation_data,
+		);
+	}
+}
+
 impl bifrost_slp::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -1272,6 +1313,9 @@ impl bifrost_slp::Config for Runtime {
 	type StablePoolHandler = StablePool;
 	type AssetIdMaps = AssetIdMaps<Runtime>;
 	type TreasuryAccount = BifrostTreasuryAccount;
+	type RelayStateRoot = RelayStateRoot;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SlpBenchmarkHelper;
 }
 
 impl bifrost_vstoken_conversion::Config for Runtime {
@@ -1554,6 +1598,29 @@ impl bifrost_stable_pool::Config for Runtime {
 	type VtokenMinting = VtokenMinting;
 	type CurrencyIdConversion = AssetIdMaps<Runtime>;
 	type CurrencyIdRegister = AssetIdMaps<Runtime>;
+	type Twap = Twap;
+}
+
+parameter_types! {
+	pub const MaxTwapPairs: u32 = 20;
+	pub const MaxTwapObservations: u32 = 48;
+	// 30 minutes, the observations cover the last 24 hours
+	pub const TwapObservationPeriod: Moment = 30 * 60 * 1000;
+	// Averages over less than an observation period are close to the spot price
+	pub const MinTwapWindow: Moment = 30 * 60 * 1000;
+}
+
+impl bifrost_twap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ControlOrigin = TechAdminOrCouncil;
+	type SpotPrice =
+		bifrost_twap::DexSpotPrice<StablePool, ZenlinkProtocol, AccountId, ParachainInfo>;
+	type Time = Timestamp;
+	type MaxPairs = MaxTwapPairs;
+	type MaxObservations = MaxTwapObservations;
+	type ObservationPeriod = TwapObservationPeriod;
+	type MinWindow = MinTwapWindow;
+	type WeightInfo = weights::bifrost_twap::BifrostWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1615,14 +1682,21 @@ impl DataFeeder<CurrencyId, TimeStampedPrice, AccountId> for AggregatedDataProvi
 impl pallet_prices::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Source = AggregatedDataProvider;
+	type Twap = Twap;
+	type Time = Timestamp;
 	type FeederOrigin = TechAdminOrCouncil;
 	type UpdateOrigin = TechAdminOrCouncil;
 	type RelayCurrency = RelayCurrencyId;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
 	type Assets = Currencies;
+	type VtokenMinting = VtokenMinting;
 	type WeightInfo = pallet_prices::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const FlashLoanFee: Ratio = Ratio::from_parts(900);
+}
+
 impl lend_market::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletId = LendMarketPalletId;
@@ -1634,6 +1708,8 @@ impl lend_market::Config for Runtime {
 	type Assets = Currencies;
 	type RewardAssetId = NativeCurrencyId;
 	type LiquidationFreeAssetId = RelayCurrencyId;
+	type RuntimeCall = RuntimeCall;
+	type FlashLoanFee = FlashLoanFee;
 }
 
 parameter_types! {
@@ -1872,6 +1948,7 @@ construct_runtime! {
 		LeverageStaking: leverage_staking = 135,
 		ChannelCommission: bifrost_channel_commission = 136,
 		VBNCConvert: bifrost_vbnc_convert = 140,
+		Twap: bifrost_twap = 141,
 	}
 }
 
@@ -1940,6 +2017,8 @@ pub mod migrations {
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 		SystemMakerClearPalletId<Runtime>,
 		frame_support::migrations::RemovePallet<SystemMakerName, RocksDbWeight>,
+		bifrost_vtoken_voting::migration::v4::MigrateToV4<Runtime, RelayCurrencyId>,
+		bifrost_vesting::migrations::v2::MigrateToV2<Runtime>,
 	);
 }
 
@@ -1975,8 +2054,10 @@ mod benches {
 		[bifrost_vstoken_conversion, VstokenConversion]
 		[bifrost_vtoken_minting, VtokenMinting]
 		[bifrost_vtoken_voting, VtokenVoting]
+		[bifrost_xcm_interface, XcmInterface]
 		[lend_market, LendMarket]
 		[leverage_staking, LeverageStaking]
+		[pallet_prices, Prices]
 		[bifrost_vbnc_convert, VBNCConvert]
 		// [bifrost_channel_commission, ChannelCommission]
 	);
@@ -2114,6 +2195,12 @@ impl_runtime_apis! {
 				_ => (CurrencyId::Native(TokenSymbol::BNC), Zero::zero()),
 			}
 		}
+
+		fn get_extra_fee_tokens_and_routes(who: AccountId, utx: <Block as BlockT>::Extrinsic) -> Vec<(CurrencyId, Balance, Option<bifrost_primitives::SwapRoute>)> {
+			let call = utx.function;
+
+			FlexibleFee::cal_extra_fee_tokens_and_routes(&who, &call).unwrap_or_default()
+		}
 	}
 
 	// zenlink runtime outer apis
@@ -2273,6 +2360,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bifrost_twap_rpc_runtime_api::TwapRuntimeApi<Block> for Runtime {
+		fn twap(asset_a: CurrencyId, asset_b: CurrencyId, window: Moment) -> Option<(Price, Moment)> {
+			Twap::get_twap(asset_a, asset_b, window)
+		}
+	}
+
 	impl lend_market_rpc_runtime_api::LendMarketApi<Block, AccountId, Balance> for Runtime {
 		fn get_account_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 			LendMarket::get_account_liquidity(&account)
@@ -2285,12 +2378,111 @@ impl_runtime_apis! {
 		fn get_liquidation_threshold_liquidity(account: AccountId) -> Result<(Liquidity, Shortfall, Liquidity, Shortfall), DispatchError> {
 			LendMarket::get_account_liquidation_threshold_liquidity(&account)
 		}
+
+		fn get_account_emode_category(account: AccountId) -> Result<Option<u8>, DispatchError> {
+			LendMarket::account_emode_category_id(&account)
+		}
+
+		fn get_liquidation_auction(borrower: AccountId, asset_id: CurrencyId) -> Result<Option<(Balance, Ratio)>, DispatchError> {
+			LendMarket::liquidation_auction_status(&borrower, asset_id)
+		}
+
+		fn get_liquidation_auction_bids(borrower: AccountId, asset_id: CurrencyId) -> Option<(u32, Balance)> {
+			LendMarket::liquidation_auction_bids(&borrower, asset_id)
+		}
 	}
 
 	impl bifrost_vtoken_minting_rpc_runtime_api::VtokenMintingRuntimeApi<Block, CurrencyId> for Runtime {
 		fn get_exchange_rate(token_id: Option<CurrencyId>) -> Vec<(CurrencyId, U256)> {
 			VtokenMinting::get_exchange_rate(token_id).unwrap_or(Vec::new())
 		}
+
+		fn get_instant_redeem_quote(vtoken_id: CurrencyId, vtoken_amount: Balance) -> Option<(Balance, Balance)> {
+			VtokenMinting::quote_instant_redeem(vtoken_id, vtoken_amount).ok()
+		}
+
+		fn get_exchange_rate_history(token_id: CurrencyId) -> Vec<(TimeUnit, Balance, Balance)> {
+			bifrost_vtoken_minting::ExchangeRateHistory::<Runtime>::get(token_id)
+				.into_iter()
+				.map(|snapshot| (snapshot.time_unit, snapshot.token_pool, snapshot.vtoken_issuance))
+				.collect()
+		}
+
+		fn get_apy(token_id: CurrencyId, time_units: u32) -> Option<Permill> {
+			VtokenMinting::get_apy(token_id, time_units)
+		}
+	}
+
+	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
+		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<xcm::VersionedAssetId>, XcmPaymentApiError> {
+			<xcm_config::Trader as WeightToAssetFee>::acceptable_assets()
+				.into_iter()
+				.map(|asset| xcm::VersionedAssetId::from(asset).into_version(xcm_version))
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|_| XcmPaymentApiError::VersionedConversionFailed)
+		}
+
+		fn query_weight_to_asset_fee(weight: Weight, asset: xcm::VersionedAssetId) -> Result<u128, XcmPaymentApiError> {
+			let asset: AssetId = asset
+				.try_into()
+				.map_err(|_| XcmPaymentApiError::VersionedConversionFailed)?;
+			<xcm_config::Trader as WeightToAssetFee>::weight_to_asset_fee(&weight, &asset)
+				.ok_or(XcmPaymentApiError::AssetNotFound)
+		}
+
+		fn query_xcm_weight(message: xcm::VersionedXcm<()>) -> Result<Weight, XcmPaymentApiError> {
+			PolkadotXcm::query_xcm_weight(message)
+		}
+
+		fn query_delivery_fees(destination: xcm::VersionedLocation, message: xcm::VersionedXcm<()>) -> Result<xcm::VersionedAssets, XcmPaymentApiError> {
+			PolkadotXcm::query_delivery_fees(destination, message)
+		}
+	}
+
+	impl xcm_runtime_apis::dry_run::DryRunApi<Block, RuntimeCall, RuntimeEvent, OriginCaller> for Runtime {
+		fn dry_run_call(origin: OriginCaller, call: RuntimeCall) -> Result<CallDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			use xcm_builder::InspectMessageQueues;
+			use xcm_executor::RecordXcm;
+
+			PolkadotXcm::set_record_xcm(true);
+			// Only record the events emitted by this call.
+			System::reset_events();
+			let result = call.dispatch(origin.into());
+			PolkadotXcm::set_record_xcm(false);
+			let local_xcm = PolkadotXcm::recorded_xcm();
+			let forwarded_xcms = xcm_config::XcmRouter::get_messages();
+			let events: Vec<RuntimeEvent> =
+				System::read_events_no_consensus().map(|record| record.event.clone()).collect();
+			Ok(CallDryRunEffects {
+				local_xcm: local_xcm.map(xcm::VersionedXcm::<()>::V4),
+				forwarded_xcms,
+				emitted_events: events,
+				execution_result: result,
+			})
+		}
+
+		fn dry_run_xcm(origin_location: xcm::VersionedLocation, xcm: xcm::VersionedXcm<RuntimeCall>) -> Result<XcmDryRunEffects<RuntimeEvent>, XcmDryRunApiError> {
+			use xcm_builder::InspectMessageQueues;
+
+			let origin_location: Location = origin_location
+				.try_into()
+				.map_err(|_| XcmDryRunApiError::VersionedConversionFailed)?;
+			let xcm: Xcm<RuntimeCall> =
+				xcm.try_into().map_err(|_| XcmDryRunApiError::VersionedConversionFailed)?;
+			let mut hash = xcm.using_encoded(sp_core::hashing::blake2_256);
+			System::reset_events();
+			let result = XcmExecutor::<xcm_config::XcmConfig>::prepare_and_execute(
+				origin_location,
+				xcm,
+				&mut hash,
+				Weight::MAX,
+				Weight::zero(),
+			);
+			let forwarded_xcms = xcm_config::XcmRouter::get_messages();
+			let events: Vec<RuntimeEvent> =
+				System::read_events_no_consensus().map(|record| record.event.clone()).collect();
+			Ok(XcmDryRunEffects { forwarded_xcms, emitted_events: events, execution_result: result })
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -99,13 +99,15 @@ impl<T: frame_system::Config> bifrost_cross_in_out::WeightInfo for BifrostWeight
 	}
 	// Storage: CrossInOut IssueWhiteList (r:1 w:1)
 	// Proof Skipped: CrossInOut IssueWhiteList (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut AttestationConfigs (r:1 w:0)
+	// Proof Skipped: CrossInOut AttestationConfigs (max_values: None, max_size: None, mode: Measured)
 	fn remove_from_issue_whitelist() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `154`
 		//  Estimated: `3619`
 		// Minimum execution time: 35_748 nanoseconds.
 		Weight::from_parts(36_569_000, 3619)
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: CrossInOut RegisterWhiteList (r:1 w:1)
@@ -206,4 +208,124 @@ impl<T: frame_system::Config> bifrost_cross_in_out::WeightInfo for BifrostWeight
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	// Storage: CrossInOut IssueWhiteList (r:1 w:0)
+	// Proof Skipped: CrossInOut IssueWhiteList (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut AttestationConfigs (r:0 w:1)
+	// Proof Skipped: CrossInOut AttestationConfigs (max_values: None, max_size: None, mode: Measured)
+	fn set_attestation_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `3619`
+		// Minimum execution time: 19_612 nanoseconds.
+		Weight::from_parts(20_512_000, 3619)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut MintLimits (r:0 w:1)
+	// Proof Skipped: CrossInOut MintLimits (max_values: None, max_size: None, mode: Measured)
+	fn set_mint_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 18_974 nanoseconds.
+		Weight::from_parts(19_874_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CrossInOut CrossCurrencyRegistry (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossCurrencyRegistry (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut CrossingMinimumAmount (r:1 w:0)
+	// Proof Skipped: CrossInOut CrossingMinimumAmount (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut AttestationConfigs (r:1 w:0)
+	// Proof Skipped: CrossInOut AttestationConfigs (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut IssueWhiteList (r:1 w:0)
+	// Proof Skipped: CrossInOut IssueWhiteList (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut ProcessedCrossIns (r:1 w:1)
+	// Proof Skipped: CrossInOut ProcessedCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut VetoedCrossIns (r:1 w:0)
+	// Proof Skipped: CrossInOut VetoedCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut PendingCrossIns (r:501 w:501)
+	// Proof Skipped: CrossInOut PendingCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut MintLimits (r:1 w:0)
+	// Proof Skipped: CrossInOut MintLimits (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut MintedInWindow (r:1 w:1)
+	// Proof Skipped: CrossInOut MintedInWindow (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 500]`.
+	fn attest_cross_in(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1049 + n * (183 ±0)`
+		//  Estimated: `4514 + n * (2658 ±0)`
+		// Minimum execution time: 121_504 nanoseconds.
+		Weight::from_parts(118_736_245, 4514)
+			// Standard Error: 6_418
+			.saturating_add(Weight::from_parts(9_472_813, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2658).saturating_mul(n.into()))
+	}
+	// Storage: CrossInOut PendingCrossIns (r:501 w:501)
+	// Proof Skipped: CrossInOut PendingCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut OuterMultilocationToAccount (r:1 w:0)
+	// Proof Skipped: CrossInOut OuterMultilocationToAccount (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut MintLimits (r:1 w:0)
+	// Proof Skipped: CrossInOut MintLimits (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut MintedInWindow (r:1 w:1)
+	// Proof Skipped: CrossInOut MintedInWindow (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut ProcessedCrossIns (r:0 w:1)
+	// Proof Skipped: CrossInOut ProcessedCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 500]`.
+	fn execute_cross_in(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `808 + n * (183 ±0)`
+		//  Estimated: `4273 + n * (2658 ±0)`
+		// Minimum execution time: 97_212 nanoseconds.
+		Weight::from_parts(94_861_307, 4273)
+			// Standard Error: 5_973
+			.saturating_add(Weight::from_parts(9_305_164, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2658).saturating_mul(n.into()))
+	}
+	// Storage: CrossInOut ProcessedCrossIns (r:1 w:0)
+	// Proof Skipped: CrossInOut ProcessedCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut VetoedCrossIns (r:1 w:1)
+	// Proof Skipped: CrossInOut VetoedCrossIns (max_values: None, max_size: None, mode: Measured)
+	// Storage: CrossInOut PendingCrossIns (r:0 w:500)
+	// Proof Skipped: CrossInOut PendingCrossIns (max_values: None, max_size: None, mode: Measured)
+	// The range of component `n` is `[1, 500]`.
+	fn veto_cross_in(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `209 + n * (183 ±0)`
+		//  Estimated: `3674 + n * (2658 ±0)`
+		// Minimum execution time: 34_306 nanoseconds.
+		Weight::from_parts(33_418_552, 3674)
+			// Standard Error: 3_104
+			.saturating_add(Weight::from_parts(1_962_407, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2658).saturating_mul(n.into()))
+	}
+	// Storage: CrossInOut PendingCrossIns (r:1 w:1)
+	// Proof Skipped: CrossInOut PendingCrossIns (max_values: None, max_size: None, mode: Measured)
+	fn remove_expired_cross_in() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `362`
+		//  Estimated: `3827`
+		// Minimum execution time: 30_112 nanoseconds.
+		Weight::from_parts(31_012_000, 3827)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -269,4 +269,77 @@ impl<T: frame_system::Config> bifrost_vtoken_voting::WeightInfo for BifrostWeigh
 		Weight::from_parts(38_955_000, 3501)
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	// Storage: `VtokenVoting::VotingFor` (r:2 w:2)
+	// Proof: `VtokenVoting::VotingFor` (`max_values`: None, `max_size`: Some(13675), added: 16150, mode: `MaxEncodedLen`)
+	// Storage: `VtokenVoting::ReferendumInfoFor` (r:256 w:256)
+	// Proof: `VtokenVoting::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	// Storage: `VtokenVoting::ClassLocksFor` (r:1 w:1)
+	// Proof: `VtokenVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(5162), added: 7637, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Locks` (r:1 w:1)
+	// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1271), added: 3746, mode: `MaxEncodedLen`)
+	// The range of component `r` is `[0, 256]`.
+	fn delegate(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1802 + r * (142 ±0)`
+		//  Estimated: `33290 + r * (2563 ±0)`
+		// Minimum execution time: 62_012_000 picoseconds.
+		Weight::from_parts(63_288_000, 33290)
+			// Standard Error: 4_317
+			.saturating_add(Weight::from_parts(14_208_442, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(r.into()))
+	}
+	// Storage: `VtokenVoting::VotingFor` (r:2 w:2)
+	// Proof: `VtokenVoting::VotingFor` (`max_values`: None, `max_size`: Some(13675), added: 16150, mode: `MaxEncodedLen`)
+	// Storage: `VtokenVoting::ReferendumInfoFor` (r:256 w:256)
+	// Proof: `VtokenVoting::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	// Storage: `VtokenVoting::VoteLockingPeriod` (r:1 w:0)
+	// Proof: `VtokenVoting::VoteLockingPeriod` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `VtokenVoting::ClassLocksFor` (r:1 w:1)
+	// Proof: `VtokenVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(5162), added: 7637, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Locks` (r:1 w:1)
+	// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1271), added: 3746, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	// The range of component `r` is `[0, 256]`.
+	fn undelegate(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1955 + r * (142 ±0)`
+		//  Estimated: `33290 + r * (2563 ±0)`
+		// Minimum execution time: 60_455_000 picoseconds.
+		Weight::from_parts(61_720_000, 33290)
+			// Standard Error: 4_102
+			.saturating_add(Weight::from_parts(14_112_569, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(r.into()))
+	}
+	// Storage: `VtokenVoting::VotingFor` (r:1 w:1)
+	// Proof: `VtokenVoting::VotingFor` (`max_values`: None, `max_size`: Some(13675), added: 16150, mode: `MaxEncodedLen`)
+	// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	// Storage: `VtokenVoting::ClassLocksFor` (r:1 w:1)
+	// Proof: `VtokenVoting::ClassLocksFor` (`max_values`: None, `max_size`: Some(5162), added: 7637, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Locks` (r:1 w:1)
+	// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1271), added: 3746, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn unlock_delegation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `17140`
+		// Minimum execution time: 40_117_000 picoseconds.
+		Weight::from_parts(41_302_000, 17140)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
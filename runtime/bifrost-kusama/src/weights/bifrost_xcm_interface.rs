@@ -0,0 +1,159 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for bifrost_xcm_interface
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-09-14, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `bifrost-jenkins`, CPU: `Intel(R) Xeon(R) CPU E5-26xx v4`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("bifrost-kusama-local"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/bifrost
+// benchmark
+// pallet
+// --chain=bifrost-kusama-local
+// --steps=50
+// --repeat=20
+// --pallet=bifrost_xcm_interface
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/bifrost-kusama/src/weights/bifrost_xcm_interface.rs
+// --template=./weight-template/runtime-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for bifrost_xcm_interface.
+pub struct BifrostWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> bifrost_xcm_interface::WeightInfo for BifrostWeight<T> {
+	// Storage: AssetRegistry LocationToCurrencyIds (r:1 w:0)
+	// Proof Skipped: AssetRegistry LocationToCurrencyIds (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: XcmInterface XcmWeightAndFee (r:1 w:0)
+	// Proof Skipped: XcmInterface XcmWeightAndFee (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmInterface DestinationWeightFees (r:1 w:0)
+	// Proof Skipped: XcmInterface DestinationWeightFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmInterface DestinationInstructionWeights (r:1 w:0)
+	// Proof Skipped: XcmInterface DestinationInstructionWeights (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmpQueue DeliveryFeeFactor (r:1 w:0)
+	// Proof: XcmpQueue DeliveryFeeFactor (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Proof Skipped: XcmpQueue OutboundXcmpStatus (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	// Proof Skipped: XcmpQueue OutboundXcmpMessages (max_values: None, max_size: None, mode: Measured)
+	fn transfer_statemine_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1587`
+		//  Estimated: `5052`
+		// Minimum execution time: 96_105 nanoseconds.
+		Weight::from_parts(98_652_000, 5052)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: AssetRegistry CurrencyIdToLocations (r:1 w:0)
+	// Proof Skipped: AssetRegistry CurrencyIdToLocations (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmInterface XcmWeightAndFee (r:1 w:0)
+	// Proof Skipped: XcmInterface XcmWeightAndFee (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmInterface DestinationTransactWeights (r:1 w:0)
+	// Proof Skipped: XcmInterface DestinationTransactWeights (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmInterface DestinationInstructionWeights (r:1 w:0)
+	// Proof Skipped: XcmInterface DestinationInstructionWeights (max_values: None, max_size: None, mode: Measured)
+	// Storage: XcmInterface DestinationWeightFees (r:1 w:0)
+	// Proof Skipped: XcmInterface DestinationWeightFees (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(38), added: 2513, mode: MaxEncodedLen)
+	// Storage: PolkadotXcm SupportedVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SupportedVersion (max_values: None, max_size: None, mode: Measured)
+	// Storage: PolkadotXcm VersionDiscoveryQueue (r:1 w:1)
+	// Proof Skipped: PolkadotXcm VersionDiscoveryQueue (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: PolkadotXcm SafeXcmVersion (r:1 w:0)
+	// Proof Skipped: PolkadotXcm SafeXcmVersion (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Proof Skipped: ParachainSystem HostConfiguration (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmpQueue DeliveryFeeFactor (r:1 w:0)
+	// Proof: XcmpQueue DeliveryFeeFactor (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: XcmpQueue OutboundXcmpStatus (r:1 w:1)
+	// Proof Skipped: XcmpQueue OutboundXcmpStatus (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: XcmpQueue OutboundXcmpMessages (r:0 w:1)
+	// Proof Skipped: XcmpQueue OutboundXcmpMessages (max_values: None, max_size: None, mode: Measured)
+	fn transfer_ethereum_assets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1498`
+		//  Estimated: `4963`
+		// Minimum execution time: 102_846 nanoseconds.
+		Weight::from_parts(105_318_000, 4963)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: XcmInterface DestinationWeightFees (r:0 w:1)
+	// Proof Skipped: XcmInterface DestinationWeightFees (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_weight_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_986 nanoseconds.
+		Weight::from_parts(14_502_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: XcmInterface DestinationInstructionWeights (r:0 w:1)
+	// Proof Skipped: XcmInterface DestinationInstructionWeights (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_instruction_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_714 nanoseconds.
+		Weight::from_parts(14_231_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: XcmInterface DestinationTransactWeights (r:0 w:1)
+	// Proof Skipped: XcmInterface DestinationTransactWeights (max_values: None, max_size: None, mode: Measured)
+	fn set_destination_transact_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_120 nanoseconds.
+		Weight::from_parts(14_689_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
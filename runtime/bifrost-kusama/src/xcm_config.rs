@@ -427,7 +427,7 @@ impl xcm_executor::Config for XcmConfig {
 	type MessageExporter = ();
 	type Aliasers = Nothing;
 	type TransactionalProcessor = FrameTransactionalProcessor;
-	type XcmRecorder = ();
+	type XcmRecorder = PolkadotXcm;
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
 	type HrmpChannelClosingHandler = ();
@@ -658,7 +658,6 @@ impl orml_xcm::Config for Runtime {
 
 parameter_types! {
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account_truncating();
-
 }
 
 impl bifrost_xcm_interface::Config for Runtime {
@@ -677,4 +676,21 @@ impl bifrost_xcm_interface::Config for Runtime {
 	type ParachainId = ParachainInfo;
 	type CallBackTimeOut = ConstU32<10>;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
+	// The instruction and call weights of the relay chain and AssetHub are set by governance
+	// from the benchmarked weights of those runtimes.
+	type DestinationWeigher = bifrost_xcm_interface::ConfiguredDestinationWeigher<Runtime>;
+	type WeightInfo = weights::bifrost_xcm_interface::BifrostWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = XcmInterfaceBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct XcmInterfaceBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl bifrost_xcm_interface::BenchmarkHelper for XcmInterfaceBenchmarkHelper {
+	fn ensure_xcm_delivery(dest: &Location) {
+		if let (1, [Parachain(para_id)]) = dest.unpack() {
+			ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests((*para_id).into());
+		}
+	}
 }
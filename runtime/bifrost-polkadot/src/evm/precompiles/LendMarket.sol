@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+pragma solidity >=0.8.3;
+
+/// @dev The LendMarket contract's address.
+address constant LEND_MARKET_ADDRESS = 0x0000000000000000000000000000000000000801;
+
+/// @dev The LendMarket contract's instance.
+LendMarket constant LEND_MARKET_CONTRACT = LendMarket(LEND_MARKET_ADDRESS);
+
+/// @title Bifrost lend-market precompile
+/// @notice Supply, borrow and liquidate on the Bifrost lend-market. Assets are identified by
+/// the address of their MultiCurrency ERC-20 precompile, the caller is mapped to its
+/// Substrate account through pallet-evm-accounts. Rates and prices have 18 decimals.
+interface LendMarket {
+    /// @dev Emitted when `account` supplies `amount` of `asset`.
+    event Deposited(address indexed account, address indexed asset, uint256 amount);
+
+    /// @dev Emitted when `account` redeems `amount` of `asset`.
+    event Redeemed(address indexed account, address indexed asset, uint256 amount);
+
+    /// @dev Emitted when `account` borrows `amount` of `asset`.
+    event Borrowed(address indexed account, address indexed asset, uint256 amount);
+
+    /// @dev Emitted when `account` repays `amount` of `asset`.
+    event RepaidBorrow(address indexed account, address indexed asset, uint256 amount);
+
+    /// @dev Emitted when `account` enables `asset` as collateral.
+    event CollateralAssetAdded(address indexed account, address indexed asset);
+
+    /// @dev Emitted when `account` disables `asset` as collateral.
+    event CollateralAssetRemoved(address indexed account, address indexed asset);
+
+    /// @dev Emitted when `liquidator` repays `repayAmount` of the borrower's debt and seizes
+    /// `collateralAmount` of its collateral.
+    event LiquidatedBorrow(
+        address indexed liquidator,
+        bytes32 indexed borrower,
+        address liquidationAsset,
+        address collateralAsset,
+        uint256 repayAmount,
+        uint256 collateralAmount
+    );
+
+    /// @dev Emitted when `borrower` repays the flash loan of `amount` of `asset` with `fee`.
+    event FlashLoaned(address indexed borrower, address indexed asset, uint256 amount, uint256 fee);
+
+    /// @notice Supply `amount` of `asset` to the market.
+    function mint(address asset, uint256 amount) external returns (bool);
+
+    /// @notice Redeem `amount` of the supplied `asset`.
+    function redeem(address asset, uint256 amount) external returns (bool);
+
+    /// @notice Borrow `amount` of `asset` against the collateral of the caller.
+    function borrow(address asset, uint256 amount) external returns (bool);
+
+    /// @notice Repay `amount` of the borrowed `asset`.
+    function repayBorrow(address asset, uint256 amount) external returns (bool);
+
+    /// @notice Enable or disable the supplied `asset` as collateral.
+    function collateralAsset(address asset, bool enable) external returns (bool);
+
+    /// @notice Liquidate the borrower's collateral.
+    /// @param borrower The Substrate account of the borrower.
+    /// @param liquidationAsset The borrowed asset to repay.
+    /// @param repayAmount The amount of the borrowed asset to repay.
+    /// @param collateralAsset The collateral asset to seize.
+    /// @return The amount of the collateral asset seized from the borrower.
+    function liquidateBorrow(
+        bytes32 borrower,
+        address liquidationAsset,
+        uint256 repayAmount,
+        address collateralAsset
+    ) external returns (uint256);
+
+    /// @notice Flash borrow `amount` of `asset`.
+    /// @dev The caller must implement `FlashBorrower`. The amount is transferred to its
+    /// Substrate account before `onFlashLoan` is called, `amount + fee` is taken back once it
+    /// returns and the whole call reverts if it can't be.
+    /// @param data Passed through to `onFlashLoan`.
+    /// @return The fee paid.
+    function flashLoan(address asset, uint256 amount, bytes calldata data) external returns (uint256);
+
+    /// @notice The liquidity of a Substrate account.
+    /// @return liquidity The value that can still be borrowed.
+    /// @return shortfall The value of borrows exceeding the collateral, liquidatable if not zero.
+    /// @return lfLiquidity The liquidity of the liquidation free collateral.
+    /// @return lfShortfall The shortfall of the liquidation free collateral.
+    function getAccountLiquidity(bytes32 account)
+        external
+        view
+        returns (uint256 liquidity, uint256 shortfall, uint256 lfLiquidity, uint256 lfShortfall);
+
+    /// @notice The status of the market of `asset`.
+    /// @return borrowRate The borrow rate per year.
+    /// @return supplyRate The supply rate per year.
+    /// @return exchangeRate The exchange rate between the underlying asset and the vouchers.
+    /// @return utilization The utilization ratio in parts per million.
+    /// @return totalBorrows The total amount borrowed.
+    /// @return totalReserves The total amount of reserves.
+    /// @return borrowIndex The accumulated borrow index.
+    function getMarketStatus(address asset)
+        external
+        view
+        returns (
+            uint256 borrowRate,
+            uint256 supplyRate,
+            uint256 exchangeRate,
+            uint256 utilization,
+            uint256 totalBorrows,
+            uint256 totalReserves,
+            uint256 borrowIndex
+        );
+
+    /// @notice The Substrate account an EVM address is mapped to.
+    function substrateAccount(address account) external view returns (bytes32);
+}
+
+/// @title Bifrost lend-market flash borrower
+/// @notice Implemented by the contracts calling `LendMarket.flashLoan`.
+interface FlashBorrower {
+    /// @notice Called by the lend-market once the flash loan is received.
+    /// @param initiator The caller of `flashLoan`.
+    /// @param asset The borrowed asset.
+    /// @param amount The borrowed amount.
+    /// @param fee The fee to repay on top of `amount`.
+    /// @param data The data passed to `flashLoan`.
+    /// @return keccak256("ERC3156FlashBorrower.onFlashLoan")
+    function onFlashLoan(
+        address initiator,
+        address asset,
+        uint256 amount,
+        uint256 fee,
+        bytes calldata data
+    ) external returns (bytes32);
+}
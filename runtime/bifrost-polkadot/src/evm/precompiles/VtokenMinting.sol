@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+pragma solidity >=0.8.3;
+
+/// @dev The VtokenMinting contract's address.
+address constant VTOKEN_MINTING_ADDRESS = 0x0000000000000000000000000000000000000800;
+
+/// @dev The VtokenMinting contract's instance.
+VtokenMinting constant VTOKEN_MINTING_CONTRACT = VtokenMinting(VTOKEN_MINTING_ADDRESS);
+
+/// @title Bifrost vtoken-minting precompile
+/// @notice Liquid staking of Bifrost for EVM accounts. Tokens and vTokens are identified by
+/// the address of their MultiCurrency ERC-20 precompile, the caller is mapped to its
+/// Substrate account through pallet-evm-accounts.
+interface VtokenMinting {
+    /// @notice Mint vTokens by staking tokens.
+    /// @param token The token to stake.
+    /// @param amount The amount of tokens to stake.
+    /// @param remark A remark of at most 32 bytes, recorded in the minting event.
+    /// @param channelId The channel the minting is attributed to.
+    /// @return The amount of vTokens minted.
+    function mint(address token, uint256 amount, bytes calldata remark, uint32 channelId)
+        external
+        returns (uint256);
+
+    /// @notice Mint vTokens by staking tokens and lock the minted vTokens.
+    /// @param token The token to stake.
+    /// @param amount The amount of tokens to stake.
+    /// @param remark A remark of at most 32 bytes, recorded in the minting event.
+    /// @param channelId The channel the minting is attributed to.
+    /// @return The amount of vTokens minted and locked.
+    function mintWithLock(address token, uint256 amount, bytes calldata remark, uint32 channelId)
+        external
+        returns (uint256);
+
+    /// @notice Redeem vTokens, the tokens are unlocked after the unbonding period.
+    /// @param vtoken The vToken to redeem.
+    /// @param amount The amount of vTokens to redeem.
+    /// @return Whether the redemption is queued.
+    function redeem(address vtoken, uint256 amount) external returns (bool);
+
+    /// @notice Cancel unlocking tokens and mint vTokens again.
+    /// @param token The token being unlocked.
+    /// @param amount The amount of tokens to rebond.
+    /// @return The amount of vTokens minted.
+    function rebond(address token, uint256 amount) external returns (uint256);
+
+    /// @notice The amount of vTokens that `amount` of tokens is worth.
+    function tokenToVtoken(address token, uint256 amount) external view returns (uint256);
+
+    /// @notice The amount of tokens that `amount` of vTokens is worth.
+    function vtokenToToken(address vtoken, uint256 amount) external view returns (uint256);
+
+    /// @notice The minimum amount of tokens per mint.
+    function minimumMint(address token) external view returns (uint256);
+
+    /// @notice The minimum amount of vTokens per redemption.
+    function minimumRedeem(address vtoken) external view returns (uint256);
+
+    /// @notice The unlocking tokens of an account.
+    /// @param account The account address.
+    /// @param token The token being unlocked.
+    /// @return total The total amount of unlocking tokens.
+    /// @return unlockIds The ids of the unlock ledgers, see `tokenUnlockLedger`.
+    function userUnlockLedger(address account, address token)
+        external
+        view
+        returns (uint256 total, uint32[] memory unlockIds);
+
+    /// @notice An unlock ledger of a token.
+    /// @param token The token being unlocked.
+    /// @param unlockId The id of the unlock ledger.
+    /// @return owner The Substrate account the tokens are unlocked to.
+    /// @return amount The amount of unlocking tokens.
+    /// @return timeUnit The era, round or hour at which the tokens are unlocked.
+    function tokenUnlockLedger(address token, uint32 unlockId)
+        external
+        view
+        returns (bytes32 owner, uint256 amount, uint32 timeUnit);
+}
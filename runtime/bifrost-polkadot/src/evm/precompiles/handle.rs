@@ -43,6 +43,11 @@ impl EvmDataWriter {
 		Self { data: vec![], offset_data: vec![], selector: None }
 	}
 
+	/// Creates a new empty input builder with the selector of the called function.
+	pub fn new_with_selector(selector: impl Into<u32>) -> Self {
+		Self { data: vec![], offset_data: vec![], selector: Some(selector.into()) }
+	}
+
 	/// Return the built data.
 	pub fn build(mut self) -> Vec<u8> {
 		Self::bake_offsets(&mut self.data, self.offset_data);
@@ -222,6 +227,59 @@ impl EvmData for Bytes {
 	}
 }
 
+impl<T: EvmData> EvmData for Vec<T> {
+	fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
+		let mut inner_reader = reader.read_pointer()?;
+
+		let array_size: usize = inner_reader
+			.read::<U256>()
+			.map_err(|_| revert("tried to parse array length out of bounds"))?
+			.try_into()
+			.map_err(|_| revert("array length is too large"))?;
+
+		let mut array = vec![];
+
+		// Offsets of the items are relative to the start of the items, after the length.
+		let mut item_reader = EvmDataReader {
+			input: inner_reader
+				.input
+				.get(32..)
+				.ok_or_else(|| revert("tried to parse array items out of bounds"))?,
+			cursor: 0,
+		};
+
+		for _ in 0..array_size {
+			array.push(item_reader.read()?);
+		}
+
+		Ok(array)
+	}
+
+	fn write(writer: &mut EvmDataWriter, value: Self) {
+		let mut inner_writer = EvmDataWriter::new().write(U256::from(value.len()));
+
+		for inner in value {
+			// Offsets in the items are relative to the start of the item, while the pointed data
+			// is appended after all the items. The shift corrects the offsets for the length.
+			let shift = inner_writer.data.len();
+			let item_writer = EvmDataWriter::new().write(inner);
+
+			inner_writer = inner_writer.write_raw_bytes(&item_writer.data);
+			for mut offset_datum in item_writer.offset_data {
+				offset_datum.offset_shift += 32;
+				offset_datum.offset_position += shift;
+				inner_writer.offset_data.push(offset_datum);
+			}
+		}
+
+		writer.write_pointer(inner_writer.build());
+	}
+
+	fn has_static_size() -> bool {
+		false
+	}
+}
+
 impl EvmData for Address {
 	fn read(reader: &mut EvmDataReader) -> EvmResult<Self> {
 		let range = reader.move_cursor(32)?;
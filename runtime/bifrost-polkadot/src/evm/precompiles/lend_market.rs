@@ -0,0 +1,422 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	evm::{
+		precompiles::{
+			erc20_mapping::{BifrostErc20Mapping, Erc20Mapping},
+			handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+			revert,
+			substrate::RuntimeHelper,
+			succeed, Address, Bytes, EvmResult,
+		},
+		ExtendedAddressMapping,
+	},
+	AccountId, LendMarket, Runtime as BifrostRuntime, RuntimeOrigin,
+};
+use bifrost_primitives::{Balance, CurrencyId};
+use lend_market::WeightInfo;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{
+	AddressMapping, Context, ExitReason, Precompile, PrecompileFailure, PrecompileHandle,
+	PrecompileResult,
+};
+use pallet_traits::FlashLoan;
+use primitive_types::{H160, H256, U256};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub const DEPOSITED_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("Deposited(address,address,uint256)");
+pub const REDEEMED_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("Redeemed(address,address,uint256)");
+pub const BORROWED_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("Borrowed(address,address,uint256)");
+pub const REPAID_BORROW_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("RepaidBorrow(address,address,uint256)");
+pub const COLLATERAL_ASSET_ADDED_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("CollateralAssetAdded(address,address)");
+pub const COLLATERAL_ASSET_REMOVED_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("CollateralAssetRemoved(address,address)");
+pub const LIQUIDATED_BORROW_EVENT_SELECTOR: [u8; 32] = *module_evm_utility_macro::keccak256!(
+	"LiquidatedBorrow(address,bytes32,address,address,uint256,uint256)"
+);
+pub const FLASH_LOANED_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("FlashLoaned(address,address,uint256,uint256)");
+/// The value `onFlashLoan` of the borrower must return, as in ERC-3156.
+pub const FLASH_LOAN_CALLBACK_SUCCESS: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("ERC3156FlashBorrower.onFlashLoan");
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Mint = "mint(address,uint256)",
+	Redeem = "redeem(address,uint256)",
+	Borrow = "borrow(address,uint256)",
+	RepayBorrow = "repayBorrow(address,uint256)",
+	CollateralAsset = "collateralAsset(address,bool)",
+	LiquidateBorrow = "liquidateBorrow(bytes32,address,uint256,address)",
+	GetAccountLiquidity = "getAccountLiquidity(bytes32)",
+	GetMarketStatus = "getMarketStatus(address)",
+	SubstrateAccount = "substrateAccount(address)",
+	FlashLoan = "flashLoan(address,uint256,bytes)",
+}
+
+/// The callback of the flash borrower, see `LendMarket.sol`.
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum FlashBorrowerAction {
+	OnFlashLoan = "onFlashLoan(address,address,uint256,uint256,bytes)",
+}
+
+/// The lend-market for EVM accounts, see `LendMarket.sol`.
+/// Assets are identified by their MultiCurrency precompile addresses, the accounts of borrowers
+/// by their 32 bytes Substrate account ids.
+pub struct LendMarketPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for LendMarketPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::GetAccountLiquidity | Action::GetMarketStatus | Action::SubstrateAccount =>
+				FunctionModifier::View,
+			_ => FunctionModifier::NonPayable,
+		})?;
+
+		match selector {
+			Action::Mint => Self::mint(handle),
+			Action::Redeem => Self::redeem(handle),
+			Action::Borrow => Self::borrow(handle),
+			Action::RepayBorrow => Self::repay_borrow(handle),
+			Action::CollateralAsset => Self::collateral_asset(handle),
+			Action::LiquidateBorrow => Self::liquidate_borrow(handle),
+			Action::GetAccountLiquidity => Self::get_account_liquidity(handle),
+			Action::GetMarketStatus => Self::get_market_status(handle),
+			Action::SubstrateAccount => Self::substrate_account(handle),
+			Action::FlashLoan => Self::flash_loan(handle),
+		}
+	}
+}
+
+impl<Runtime> LendMarketPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config,
+{
+	fn mint(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset = input.read::<Address>()?;
+		let asset_id = Self::read_currency_id(asset)?;
+		let mint_amount = input.read::<Balance>()?;
+
+		Self::dispatch(handle, lend_market::Call::mint { asset_id, mint_amount })?;
+		Self::log_amount_event(handle, DEPOSITED_EVENT_SELECTOR, asset, mint_amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn redeem(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset = input.read::<Address>()?;
+		let asset_id = Self::read_currency_id(asset)?;
+		let redeem_amount = input.read::<Balance>()?;
+
+		Self::dispatch(handle, lend_market::Call::redeem { asset_id, redeem_amount })?;
+		Self::log_amount_event(handle, REDEEMED_EVENT_SELECTOR, asset, redeem_amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn borrow(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset = input.read::<Address>()?;
+		let asset_id = Self::read_currency_id(asset)?;
+		let borrow_amount = input.read::<Balance>()?;
+
+		Self::dispatch(handle, lend_market::Call::borrow { asset_id, borrow_amount })?;
+		Self::log_amount_event(handle, BORROWED_EVENT_SELECTOR, asset, borrow_amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn repay_borrow(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset = input.read::<Address>()?;
+		let asset_id = Self::read_currency_id(asset)?;
+		let repay_amount = input.read::<Balance>()?;
+
+		Self::dispatch(handle, lend_market::Call::repay_borrow { asset_id, repay_amount })?;
+		Self::log_amount_event(handle, REPAID_BORROW_EVENT_SELECTOR, asset, repay_amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn collateral_asset(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_log_costs_manual(3, 0)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset = input.read::<Address>()?;
+		let asset_id = Self::read_currency_id(asset)?;
+		let enable = input.read::<bool>()?;
+
+		Self::dispatch(handle, lend_market::Call::collateral_asset { asset_id, enable })?;
+
+		let selector = if enable {
+			COLLATERAL_ASSET_ADDED_EVENT_SELECTOR
+		} else {
+			COLLATERAL_ASSET_REMOVED_EVENT_SELECTOR
+		};
+		let (address, caller) = (handle.code_address(), handle.context().caller);
+		handle
+			.log(
+				address,
+				vec![H256::from(selector), H256::from(caller), H256::from(H160::from(asset))],
+				vec![],
+			)
+			.map_err(|e| PrecompileFailure::Error { exit_status: e })?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn liquidate_borrow(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::weight_to_gas(
+			<BifrostRuntime as lend_market::Config>::WeightInfo::liquidate_borrow(),
+		))?;
+		handle.record_log_costs_manual(3, 128)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let borrower = input.read::<H256>()?;
+		let liquidation_asset = input.read::<Address>()?;
+		let liquidation_asset_id = Self::read_currency_id(liquidation_asset)?;
+		let repay_amount = input.read::<Balance>()?;
+		let collateral_asset = input.read::<Address>()?;
+		let collateral_asset_id = Self::read_currency_id(collateral_asset)?;
+		let liquidator = ExtendedAddressMapping::into_account_id(handle.context().caller);
+
+		let collateral_amount = LendMarket::liquidate_borrow_inner(
+			liquidator,
+			AccountId::from(borrower.0),
+			liquidation_asset_id,
+			repay_amount,
+			collateral_asset_id,
+		)
+		.map_err(|e| revert(Into::<&str>::into(e)))?;
+
+		let (address, caller) = (handle.code_address(), handle.context().caller);
+		handle
+			.log(
+				address,
+				vec![H256::from(LIQUIDATED_BORROW_EVENT_SELECTOR), H256::from(caller), borrower],
+				EvmDataWriter::new()
+					.write(liquidation_asset)
+					.write(collateral_asset)
+					.write(repay_amount)
+					.write(collateral_amount)
+					.build(),
+			)
+			.map_err(|e| PrecompileFailure::Error { exit_status: e })?;
+
+		Ok(succeed(EvmDataWriter::new().write(collateral_amount).build()))
+	}
+
+	fn flash_loan(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::weight_to_gas(
+			<BifrostRuntime as lend_market::Config>::WeightInfo::flash_loan(),
+		))?;
+		handle.record_log_costs_manual(3, 64)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let asset = input.read::<Address>()?;
+		let asset_id = Self::read_currency_id(asset)?;
+		let amount = input.read::<Balance>()?;
+		let data = input.read::<Bytes>()?;
+		let (address, caller) = (handle.code_address(), handle.context().caller);
+		let borrower = ExtendedAddressMapping::into_account_id(caller);
+
+		let fee = <LendMarket as FlashLoan<_, _, _>>::flash_loan_fee(asset_id, amount)
+			.map_err(|e| revert(Into::<&str>::into(e)))?;
+		let callback = EvmDataWriter::new_with_selector(FlashBorrowerAction::OnFlashLoan)
+			.write(Address(caller))
+			.write(asset)
+			.write(amount)
+			.write(fee)
+			.write(data)
+			.build();
+
+		// the caller receives the loan on its mapped account and must approve the repayment
+		// from `onFlashLoan`, everything is reverted if it doesn't
+		<LendMarket as FlashLoan<_, _, _>>::flash_loan(&borrower, asset_id, amount, || {
+			let context =
+				Context { address: caller, caller: address, apparent_value: U256::zero() };
+			match handle.call(caller, None, callback, None, false, &context) {
+				(ExitReason::Succeed(_), output) if output == FLASH_LOAN_CALLBACK_SUCCESS => Ok(()),
+				_ => Err(DispatchError::Other("FlashLoanCallbackFailed")),
+			}
+		})
+		.map_err(|e| revert(Into::<&str>::into(e)))?;
+
+		handle
+			.log(
+				address,
+				vec![
+					H256::from(FLASH_LOANED_EVENT_SELECTOR),
+					H256::from(caller),
+					H256::from(H160::from(asset)),
+				],
+				EvmDataWriter::new().write(amount).write(fee).build(),
+			)
+			.map_err(|e| PrecompileFailure::Error { exit_status: e })?;
+
+		Ok(succeed(EvmDataWriter::new().write(fee).build()))
+	}
+
+	fn get_account_liquidity(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account = AccountId::from(input.read::<H256>()?.0);
+
+		let (liquidity, shortfall, lf_liquidity, lf_shortfall) =
+			LendMarket::get_account_liquidity(&account)
+				.map_err(|e| revert(Into::<&str>::into(e)))?;
+
+		Ok(succeed(
+			EvmDataWriter::new()
+				.write(liquidity.into_inner())
+				.write(shortfall.into_inner())
+				.write(lf_liquidity.into_inner())
+				.write(lf_shortfall.into_inner())
+				.build(),
+		))
+	}
+
+	fn get_market_status(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let asset_id = Self::read_currency_id(input.read::<Address>()?)?;
+
+		let (
+			borrow_rate,
+			supply_rate,
+			exchange_rate,
+			utilization,
+			total_borrows,
+			total_reserves,
+			borrow_index,
+		) = LendMarket::get_market_status(asset_id).map_err(|e| revert(Into::<&str>::into(e)))?;
+
+		Ok(succeed(
+			EvmDataWriter::new()
+				.write(borrow_rate.into_inner())
+				.write(supply_rate.into_inner())
+				.write(exchange_rate.into_inner())
+				.write(utilization.deconstruct())
+				.write(total_borrows)
+				.write(total_reserves)
+				.write(borrow_index.into_inner())
+				.build(),
+		))
+	}
+
+	fn substrate_account(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let account = ExtendedAddressMapping::into_account_id(input.read::<Address>()?.into());
+
+		Ok(succeed(EvmDataWriter::new().write(H256::from(<[u8; 32]>::from(account))).build()))
+	}
+
+	// dispatch a lend-market call on behalf of the caller
+	fn dispatch(
+		handle: &mut impl PrecompileHandle,
+		call: lend_market::Call<BifrostRuntime>,
+	) -> EvmResult {
+		let origin =
+			RuntimeOrigin::signed(ExtendedAddressMapping::into_account_id(handle.context().caller));
+		RuntimeHelper::<BifrostRuntime>::try_dispatch(handle, origin, call)?;
+
+		Ok(())
+	}
+
+	// log a `Deposited`, `Redeemed`, `Borrowed` or `RepaidBorrow` event of the caller
+	fn log_amount_event(
+		handle: &mut impl PrecompileHandle,
+		selector: [u8; 32],
+		asset: Address,
+		amount: Balance,
+	) -> EvmResult {
+		let (address, caller) = (handle.code_address(), handle.context().caller);
+		handle
+			.log(
+				address,
+				vec![H256::from(selector), H256::from(caller), H256::from(H160::from(asset))],
+				EvmDataWriter::new().write(amount).build(),
+			)
+			.map_err(|e| PrecompileFailure::Error { exit_status: e })
+	}
+
+	fn read_currency_id(address: Address) -> EvmResult<CurrencyId> {
+		BifrostErc20Mapping::decode_evm_address(H160::from(address))
+			.ok_or_else(|| revert("invalid currency id"))
+	}
+}
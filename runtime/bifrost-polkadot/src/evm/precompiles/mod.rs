@@ -19,7 +19,8 @@
 use core::marker::PhantomData;
 
 use crate::evm::precompiles::{
-	erc20_mapping::is_asset_address, multicurrency::MultiCurrencyPrecompile,
+	erc20_mapping::is_asset_address, lend_market::LendMarketPrecompile,
+	multicurrency::MultiCurrencyPrecompile, vtoken_minting::VtokenMintingPrecompile,
 };
 use ethabi::Token;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
@@ -40,8 +41,10 @@ use sp_std::{borrow::ToOwned, vec::Vec};
 pub mod costs;
 pub mod erc20_mapping;
 pub mod handle;
+pub mod lend_market;
 pub mod multicurrency;
 pub mod substrate;
+pub mod vtoken_minting;
 
 pub type EvmResult<T = ()> = Result<T, PrecompileFailure>;
 
@@ -82,6 +85,9 @@ impl<R> BifrostPrecompiles<R> {
 // https://docs.moonbeam.network/builders/pallets-precompiles/precompiles/overview/#precompiled-contract-addresses
 const DISPATCH_ADDR: H160 = addr(1025);
 
+pub const VTOKEN_MINTING_ADDR: H160 = addr(2048);
+pub const LEND_MARKET_ADDR: H160 = addr(2049);
+
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
 pub const SHA256: H160 = H160(hex!("0000000000000000000000000000000000000002"));
 pub const RIPEMD: H160 = H160(hex!("0000000000000000000000000000000000000003"));
@@ -104,6 +110,8 @@ where
 	R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + Decode,
 	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
 	MultiCurrencyPrecompile<R>: Precompile,
+	VtokenMintingPrecompile<R>: Precompile,
+	LendMarketPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let context = handle.context();
@@ -137,6 +145,10 @@ where
 			Some(Blake2F::execute(handle))
 		} else if address == DISPATCH_ADDR {
 			Some(pallet_evm_precompile_dispatch::Dispatch::<R>::execute(handle))
+		} else if address == VTOKEN_MINTING_ADDR {
+			Some(VtokenMintingPrecompile::<R>::execute(handle))
+		} else if address == LEND_MARKET_ADDR {
+			Some(LendMarketPrecompile::<R>::execute(handle))
 		} else if is_asset_address(address) {
 			Some(MultiCurrencyPrecompile::<R>::execute(handle))
 		} else {
@@ -146,6 +158,8 @@ where
 
 	fn is_precompile(&self, address: H160, _remaining_gas: u64) -> IsPrecompileResult {
 		let is_precompile = address == DISPATCH_ADDR ||
+			address == VTOKEN_MINTING_ADDR ||
+			address == LEND_MARKET_ADDR ||
 			is_asset_address(address) ||
 			is_standard_precompile(address);
 		IsPrecompileResult::Answer { is_precompile, extra_cost: 0 }
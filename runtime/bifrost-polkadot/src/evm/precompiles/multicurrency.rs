@@ -21,25 +21,73 @@ use crate::{
 		precompiles::{
 			erc20_mapping::{BifrostErc20Mapping, Erc20Mapping},
 			handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+			revert,
 			substrate::RuntimeHelper,
-			succeed, Address, Output,
+			succeed, Address, EvmResult, Output,
 		},
 		ExtendedAddressMapping,
 	},
-	Currencies,
+	Currencies, Timestamp,
 };
 use bifrost_asset_registry::AssetIdMaps;
 use bifrost_primitives::{Balance, CurrencyId, CurrencyIdMapping};
-use frame_support::traits::OriginTrait;
+use frame_support::{
+	storage::types::{Key as NMapKey, StorageDoubleMap, StorageNMap},
+	traits::{Get, OriginTrait},
+	Blake2_128Concat,
+};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::{MultiCurrency as MultiCurrencyT, MultiCurrency};
 use pallet_evm::{
 	AddressMapping, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult,
 };
-use primitive_types::H160;
+use primitive_types::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
 use sp_runtime::{traits::Dispatchable, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
+pub const TRANSFER_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("Transfer(address,address,uint256)");
+pub const APPROVAL_EVENT_SELECTOR: [u8; 32] =
+	*module_evm_utility_macro::keccak256!("Approval(address,address,uint256)");
+pub const EIP712_DOMAIN_TYPEHASH: [u8; 32] = *module_evm_utility_macro::keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+pub const PERMIT_TYPEHASH: [u8; 32] = *module_evm_utility_macro::keccak256!(
+	"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+);
+/// The version of the EIP-712 domain of the permits.
+pub const PERMIT_VERSION: &[u8] = b"1";
+
+/// Cost of recovering the signer of a permit, the same as the `ECRecover` precompile.
+const ECRECOVER_GAS_COST: u64 = 3000;
+
+/// The ERC-20 allowances: (currency, owner, spender) => amount.
+/// `U256::MAX` is an infinite allowance that is not decreased by `transferFrom`.
+#[frame_support::storage_alias]
+pub type Allowances = StorageNMap<
+	MultiCurrencyErc20,
+	(
+		NMapKey<Blake2_128Concat, CurrencyId>,
+		NMapKey<Blake2_128Concat, H160>,
+		NMapKey<Blake2_128Concat, H160>,
+	),
+	U256,
+	ValueQuery,
+>;
+
+/// The EIP-2612 permit nonces: (currency, owner) => nonce.
+#[frame_support::storage_alias]
+pub type PermitNonces = StorageDoubleMap<
+	MultiCurrencyErc20,
+	Blake2_128Concat,
+	CurrencyId,
+	Blake2_128Concat,
+	H160,
+	U256,
+	ValueQuery,
+>;
+
 #[module_evm_utility_macro::generate_function_selector]
 #[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u32)]
@@ -53,6 +101,9 @@ pub enum Action {
 	Transfer = "transfer(address,uint256)",
 	Approve = "approve(address,uint256)",
 	TransferFrom = "transferFrom(address,address,uint256)",
+	Permit = "permit(address,address,uint256,uint256,uint8,bytes32,bytes32)",
+	Nonces = "nonces(address)",
+	DomainSeparator = "DOMAIN_SEPARATOR()",
 }
 pub struct MultiCurrencyPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -80,7 +131,8 @@ where
 			};
 
 			handle.check_function_modifier(match selector {
-				Action::Transfer => FunctionModifier::NonPayable,
+				Action::Transfer | Action::Approve | Action::TransferFrom | Action::Permit =>
+					FunctionModifier::NonPayable,
 				_ => FunctionModifier::View,
 			})?;
 
@@ -91,9 +143,12 @@ where
 				Action::TotalSupply => Self::total_supply(asset_id, handle),
 				Action::BalanceOf => Self::balance_of(asset_id, handle),
 				Action::Transfer => Self::transfer(asset_id, handle),
-				Action::Allowance => Self::not_supported(asset_id, handle),
-				Action::Approve => Self::not_supported(asset_id, handle),
-				Action::TransferFrom => Self::not_supported(asset_id, handle),
+				Action::Allowance => Self::allowance(asset_id, handle),
+				Action::Approve => Self::approve(asset_id, handle),
+				Action::TransferFrom => Self::transfer_from(asset_id, handle),
+				Action::Permit => Self::permit(asset_id, handle),
+				Action::Nonces => Self::nonces(asset_id, handle),
+				Action::DomainSeparator => Self::domain_separator(asset_id, handle),
 			};
 		}
 		Err(PrecompileFailure::Revert {
@@ -209,6 +264,7 @@ where
 
 	fn transfer(currency_id: CurrencyId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
 
 		// Parse input
 		let mut input = handle.read_input()?;
@@ -216,29 +272,252 @@ where
 
 		let to: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
+		let from = handle.context().caller;
+
+		Self::do_transfer(currency_id, from, to, amount)?;
+		Self::log_event(handle, TRANSFER_EVENT_SELECTOR, from, to, amount.into())?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn allowance(currency_id: CurrencyId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+
+		let allowance = Allowances::get((currency_id, owner, spender));
+
+		log::debug!(target: "evm", "multicurrency: allowance: {:?}", allowance);
+
+		Ok(succeed(EvmDataWriter::new().write(allowance).build()))
+	}
+
+	fn approve(currency_id: CurrencyId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
 
-		let origin = ExtendedAddressMapping::into_account_id(handle.context().caller);
+		let spender: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<U256>()?;
+		let owner = handle.context().caller;
+
+		log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+		Allowances::insert((currency_id, owner, spender), amount);
+		Self::log_event(handle, APPROVAL_EVENT_SELECTOR, owner, spender, amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer_from(
+		currency_id: CurrencyId,
+		handle: &mut impl PrecompileHandle,
+	) -> PrecompileResult {
+		handle.record_cost(
+			RuntimeHelper::<Runtime>::db_read_gas_cost()
+				.saturating_mul(2)
+				.saturating_add(RuntimeHelper::<Runtime>::db_write_gas_cost()),
+		)?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let from: H160 = input.read::<Address>()?.into();
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = input.read::<Balance>()?;
+		let spender = handle.context().caller;
+
+		// The owner spends its own balance without an allowance.
+		let remaining = if spender != from {
+			let allowance = Allowances::get((currency_id, from, spender));
+			if allowance == U256::MAX {
+				None
+			} else {
+				Some(
+					allowance
+						.checked_sub(amount.into())
+						.ok_or_else(|| revert("ERC20: insufficient allowance"))?,
+				)
+			}
+		} else {
+			None
+		};
+
+		Self::do_transfer(currency_id, from, to, amount)?;
+		if let Some(remaining) = remaining {
+			Allowances::insert((currency_id, from, spender), remaining);
+		}
+		Self::log_event(handle, TRANSFER_EVENT_SELECTOR, from, to, amount.into())?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn permit(currency_id: CurrencyId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(
+			RuntimeHelper::<Runtime>::db_read_gas_cost()
+				.saturating_mul(3)
+				.saturating_add(RuntimeHelper::<Runtime>::db_write_gas_cost().saturating_mul(2))
+				.saturating_add(ECRECOVER_GAS_COST),
+		)?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(7)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+		let value = input.read::<U256>()?;
+		let deadline = input.read::<U256>()?;
+		let v = input.read::<u8>()?;
+		let r = input.read::<H256>()?;
+		let s = input.read::<H256>()?;
+
+		// The deadline is in seconds.
+		let now = U256::from(Timestamp::get() / 1000);
+		if deadline < now {
+			return Err(revert("ERC20Permit: expired deadline"));
+		}
+
+		let nonce = PermitNonces::get(currency_id, owner);
+		let domain_separator = Self::compute_domain_separator(currency_id, handle.code_address())?;
+		let digest = Self::permit_digest(domain_separator, owner, spender, value, nonce, deadline);
+
+		let mut signature = [0u8; 65];
+		signature[0..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] =
+			v.checked_sub(27).ok_or_else(|| revert("ERC20Permit: invalid signature"))?;
+		let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &digest)
+			.map(|public| H160::from(H256::from(keccak_256(&public))))
+			.map_err(|_| revert("ERC20Permit: invalid signature"))?;
+		if signer != owner {
+			return Err(revert("ERC20Permit: invalid signature"));
+		}
+
+		PermitNonces::insert(currency_id, owner, nonce.saturating_add(U256::one()));
+		Allowances::insert((currency_id, owner, spender), value);
+		Self::log_event(handle, APPROVAL_EVENT_SELECTOR, owner, spender, value)?;
+
+		Ok(succeed(EvmDataWriter::new().build()))
+	}
+
+	fn nonces(currency_id: CurrencyId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+
+		Ok(succeed(EvmDataWriter::new().write(PermitNonces::get(currency_id, owner)).build()))
+	}
+
+	fn domain_separator(
+		currency_id: CurrencyId,
+		handle: &mut impl PrecompileHandle,
+	) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let input = handle.read_input()?;
+		input.expect_arguments(0)?;
+
+		let domain_separator = Self::compute_domain_separator(currency_id, handle.code_address())?;
+
+		Ok(succeed(EvmDataWriter::new().write(domain_separator).build()))
+	}
+
+	/// The EIP-712 domain separator of the currency, named by its metadata.
+	pub fn compute_domain_separator(currency_id: CurrencyId, address: H160) -> EvmResult<H256> {
+		let metadata = AssetIdMaps::<Runtime>::get_currency_metadata(currency_id).ok_or(
+			PrecompileFailure::Error {
+				exit_status: pallet_evm::ExitError::Other("Non-existing asset.".into()),
+			},
+		)?;
+		let encoded = EvmDataWriter::new()
+			.write(H256::from(EIP712_DOMAIN_TYPEHASH))
+			.write(H256::from(keccak_256(metadata.name.as_slice())))
+			.write(H256::from(keccak_256(PERMIT_VERSION)))
+			.write(U256::from(<Runtime as pallet_evm::Config>::ChainId::get()))
+			.write(Address(address))
+			.build();
+
+		Ok(keccak_256(&encoded).into())
+	}
+
+	/// The EIP-712 digest that the owner signs to permit the spender.
+	pub fn permit_digest(
+		domain_separator: H256,
+		owner: H160,
+		spender: H160,
+		value: U256,
+		nonce: U256,
+		deadline: U256,
+	) -> [u8; 32] {
+		let struct_hash = keccak_256(
+			&EvmDataWriter::new()
+				.write(H256::from(PERMIT_TYPEHASH))
+				.write(Address(owner))
+				.write(Address(spender))
+				.write(value)
+				.write(nonce)
+				.write(deadline)
+				.build(),
+		);
+
+		let mut message = [0u8; 66];
+		message[0..2].copy_from_slice(&[0x19, 0x01]);
+		message[2..34].copy_from_slice(domain_separator.as_bytes());
+		message[34..66].copy_from_slice(&struct_hash);
+
+		keccak_256(&message)
+	}
+
+	fn do_transfer(currency_id: CurrencyId, from: H160, to: H160, amount: Balance) -> EvmResult {
+		let from = ExtendedAddressMapping::into_account_id(from);
 		let to = ExtendedAddressMapping::into_account_id(to);
 
-		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", origin, to, amount);
+		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", from, to, amount);
 
 		<bifrost_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
 			currency_id,
-			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(origin)),
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from)),
 			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
 			amount,
 		)
 		.map_err(|e| PrecompileFailure::Revert {
 			exit_status: ExitRevert::Reverted,
 			output: Into::<&str>::into(e).as_bytes().to_vec(),
-		})?;
-
-		Ok(succeed(EvmDataWriter::new().write(true).build()))
+		})
 	}
 
-	fn not_supported(_: CurrencyId, _: &mut impl PrecompileHandle) -> PrecompileResult {
-		Err(PrecompileFailure::Error {
-			exit_status: pallet_evm::ExitError::Other("not supported".into()),
-		})
+	// log an ERC-20 `Transfer` or `Approval` event
+	fn log_event(
+		handle: &mut impl PrecompileHandle,
+		selector: [u8; 32],
+		from: H160,
+		to: H160,
+		amount: U256,
+	) -> EvmResult {
+		let address = handle.code_address();
+		handle
+			.log(
+				address,
+				vec![H256::from(selector), H256::from(from), H256::from(to)],
+				EvmDataWriter::new().write(amount).build(),
+			)
+			.map_err(|e| PrecompileFailure::Error { exit_status: e })
 	}
 }
@@ -20,9 +20,15 @@
 //! - Substrate call dispatch.
 //! - Substrate DB read and write costs
 
+use crate::evm::precompiles::revert;
 use core::marker::PhantomData;
-use frame_support::{traits::Get, weights::Weight};
-use pallet_evm::GasWeightMapping;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::Get,
+	weights::Weight,
+};
+use pallet_evm::{GasWeightMapping, PrecompileFailure, PrecompileHandle};
+use sp_runtime::traits::Dispatchable;
 
 /// Helper functions requiring a Substrate runtime.
 /// This runtime must of course implement `pallet_evm::Config`.
@@ -33,6 +39,29 @@ impl<Runtime> RuntimeHelper<Runtime>
 where
 	Runtime: pallet_evm::Config + frame_system::Config,
 {
+	/// Try to dispatch a Substrate call.
+	/// Return an error if there are not enough gas, or if the call fails.
+	/// If successful returns the used gas using the Runtime GasWeightMapping.
+	pub fn try_dispatch<Call>(
+		handle: &mut impl PrecompileHandle,
+		origin: <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: Call,
+	) -> Result<PostDispatchInfo, PrecompileFailure>
+	where
+		Runtime::RuntimeCall:
+			From<Call> + Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	{
+		let call = Runtime::RuntimeCall::from(call);
+		handle.record_cost(Self::weight_to_gas(call.get_dispatch_info().weight))?;
+
+		call.dispatch(origin).map_err(|e| revert(Into::<&str>::into(e.error)))
+	}
+
+	/// Cost of a Substrate weight in gas.
+	pub fn weight_to_gas(weight: Weight) -> u64 {
+		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+	}
+
 	/// Cost of a Substrate DB read in gas.
 	pub fn db_read_gas_cost() -> u64 {
 		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(Weight::from_parts(
@@ -40,4 +69,12 @@ where
 			0,
 		))
 	}
+
+	/// Cost of a Substrate DB write in gas.
+	pub fn db_write_gas_cost() -> u64 {
+		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(Weight::from_parts(
+			<Runtime as frame_system::Config>::DbWeight::get().write,
+			0,
+		))
+	}
 }
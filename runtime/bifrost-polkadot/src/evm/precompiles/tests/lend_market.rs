@@ -0,0 +1,483 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::MockHandle;
+use crate::{
+	evm::{
+		precompiles::{
+			erc20_mapping::{BifrostErc20Mapping, Erc20Mapping},
+			handle::{EvmDataReader, EvmDataWriter},
+			lend_market::{
+				Action, FlashBorrowerAction, LendMarketPrecompile, BORROWED_EVENT_SELECTOR,
+				COLLATERAL_ASSET_ADDED_EVENT_SELECTOR, DEPOSITED_EVENT_SELECTOR,
+				FLASH_LOANED_EVENT_SELECTOR, FLASH_LOAN_CALLBACK_SUCCESS,
+				LIQUIDATED_BORROW_EVENT_SELECTOR, REDEEMED_EVENT_SELECTOR,
+				REPAID_BORROW_EVENT_SELECTOR,
+			},
+			Address, Bytes, LEND_MARKET_ADDR,
+		},
+		ExtendedAddressMapping,
+	},
+	Currencies, LendMarket, Runtime, RuntimeOrigin, System,
+};
+use bifrost_asset_registry::AssetMetadata;
+use bifrost_primitives::{Balance, CurrencyId, Price, Rate, Ratio, DOT, LDOT};
+use frame_support::assert_ok;
+use hex_literal::hex;
+use lend_market::{
+	AccountBorrows, AccountDeposits, InterestRateModel, JumpModel, Market, MarketState, Markets,
+	TotalReserves,
+};
+use orml_traits::MultiCurrency;
+use pallet_evm::{
+	AddressMapping, Context, ExitReason, ExitRevert, ExitSucceed, Log, Precompile,
+	PrecompileFailure, PrecompileResult,
+};
+use primitive_types::{H160, H256, U256};
+use sp_runtime::{BuildStorage, FixedPointNumber};
+
+const ALICE: H160 = H160(hex!("1000000000000000000000000000000000000001"));
+const BOB: H160 = H160(hex!("1000000000000000000000000000000000000002"));
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		bifrost_asset_registry::CurrencyMetadatas::<Runtime>::insert(
+			DOT,
+			AssetMetadata {
+				name: b"Polkadot DOT".to_vec(),
+				symbol: b"DOT".to_vec(),
+				decimals: 10,
+				minimal_balance: 1,
+			},
+		);
+	});
+	ext
+}
+
+fn dot_market() -> Market<Balance> {
+	Market {
+		close_factor: Ratio::from_percent(50),
+		collateral_factor: Ratio::from_percent(50),
+		liquidation_threshold: Ratio::from_percent(55),
+		liquidate_incentive: Rate::from_inner(Rate::DIV / 100 * 110),
+		liquidate_incentive_reserved_factor: Ratio::from_percent(3),
+		state: MarketState::Pending,
+		rate_model: InterestRateModel::Jump(JumpModel {
+			base_rate: Rate::from_inner(Rate::DIV / 100 * 2),
+			jump_rate: Rate::from_inner(Rate::DIV / 100 * 10),
+			full_rate: Rate::from_inner(Rate::DIV / 100 * 32),
+			jump_utilization: Ratio::from_percent(80),
+		}),
+		reserve_factor: Ratio::from_percent(15),
+		supply_cap: 1_000_000_000_000_000_000_000u128,
+		borrow_cap: 1_000_000_000_000_000_000_000u128,
+		lend_token_id: LDOT,
+	}
+}
+
+fn activate_dot_market() {
+	assert_ok!(LendMarket::add_market(RuntimeOrigin::root(), DOT, dot_market()));
+	assert_ok!(LendMarket::activate_market(RuntimeOrigin::root(), DOT));
+}
+
+// Alice deposits 400 DOT as collateral, 1 DOT is worth 1 unit of value
+fn supply_dot_collateral() {
+	activate_dot_market();
+	assert_ok!(LendMarket::add_market_bond(RuntimeOrigin::root(), DOT, vec![DOT]));
+	pallet_prices::EmergencyPrice::<Runtime>::insert(
+		DOT,
+		Price::saturating_from_integer(10_000_000_000u128),
+	);
+	assert_ok!(Currencies::deposit(DOT, &account(ALICE), 1_000));
+	assert_ok!(LendMarket::mint(RuntimeOrigin::signed(account(ALICE)), DOT, 400));
+	assert_ok!(LendMarket::collateral_asset(RuntimeOrigin::signed(account(ALICE)), DOT, true));
+}
+
+fn amount_log(selector: [u8; 32], caller: H160, amount: Balance) -> Log {
+	Log {
+		address: LEND_MARKET_ADDR,
+		topics: vec![
+			H256::from(selector),
+			H256::from(caller),
+			H256::from(H160::from(token_address(DOT))),
+		],
+		data: EvmDataWriter::new().write(amount).build(),
+	}
+}
+
+fn account(address: H160) -> crate::AccountId {
+	ExtendedAddressMapping::into_account_id(address)
+}
+
+fn token_address(currency_id: CurrencyId) -> Address {
+	BifrostErc20Mapping::encode_evm_address(currency_id).unwrap().into()
+}
+
+fn call(caller: H160, action: Action, args: EvmDataWriter) -> (PrecompileResult, Vec<Log>) {
+	call_with_subcall(caller, action, args, None)
+}
+
+fn call_with_subcall(
+	caller: H160,
+	action: Action,
+	args: EvmDataWriter,
+	subcall: Option<fn(H160, Vec<u8>) -> (ExitReason, Vec<u8>)>,
+) -> (PrecompileResult, Vec<Log>) {
+	let mut input = u32::from(action).to_be_bytes().to_vec();
+	input.extend(args.build());
+	let mut handle = MockHandle {
+		input,
+		context: Context { address: LEND_MARKET_ADDR, caller, apparent_value: U256::zero() },
+		code_address: LEND_MARKET_ADDR,
+		logs: vec![],
+		subcall,
+	};
+	let result = LendMarketPrecompile::<Runtime>::execute(&mut handle);
+	(result, handle.logs)
+}
+
+fn output(result: PrecompileResult) -> Vec<u8> {
+	result.unwrap().output
+}
+
+fn reverted_with(result: PrecompileResult, reason: &str) -> bool {
+	matches!(result, Err(PrecompileFailure::Revert { output, .. }) if output == reason.as_bytes())
+}
+
+#[test]
+fn mint_and_collateral_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		activate_dot_market();
+		assert_ok!(Currencies::deposit(DOT, &account(ALICE), 1_000));
+
+		let (result, logs) = call(
+			ALICE,
+			Action::Mint,
+			EvmDataWriter::new().write(token_address(DOT)).write(400u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(
+			logs,
+			vec![Log {
+				address: LEND_MARKET_ADDR,
+				topics: vec![
+					H256::from(DEPOSITED_EVENT_SELECTOR),
+					H256::from(ALICE),
+					H256::from(H160::from(token_address(DOT))),
+				],
+				data: EvmDataWriter::new().write(400u128).build(),
+			}]
+		);
+		assert_eq!(Currencies::free_balance(DOT, &account(ALICE)), 600);
+		assert!(AccountDeposits::<Runtime>::get(DOT, account(ALICE)).voucher_balance > 0);
+
+		let (result, logs) = call(
+			ALICE,
+			Action::CollateralAsset,
+			EvmDataWriter::new().write(token_address(DOT)).write(true),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs[0].topics[0], H256::from(COLLATERAL_ASSET_ADDED_EVENT_SELECTOR));
+		assert!(AccountDeposits::<Runtime>::get(DOT, account(ALICE)).is_collateral);
+
+		// the same as the extrinsic, enabling twice is rejected
+		let (result, _) = call(
+			ALICE,
+			Action::CollateralAsset,
+			EvmDataWriter::new().write(token_address(DOT)).write(true),
+		);
+		assert!(reverted_with(result, "DuplicateOperation"));
+	});
+}
+
+#[test]
+fn calls_to_inactive_market_should_revert() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::deposit(DOT, &account(ALICE), 1_000));
+
+		let (result, logs) = call(
+			ALICE,
+			Action::Mint,
+			EvmDataWriter::new().write(token_address(DOT)).write(400u128),
+		);
+		assert!(reverted_with(result, "MarketNotActivated"));
+		assert!(logs.is_empty());
+
+		let (result, _) = call(
+			ALICE,
+			Action::LiquidateBorrow,
+			EvmDataWriter::new()
+				.write(H256::repeat_byte(1))
+				.write(token_address(DOT))
+				.write(100u128)
+				.write(token_address(DOT)),
+		);
+		assert!(reverted_with(result, "MarketNotActivated"));
+	});
+}
+
+#[test]
+fn view_functions_should_work() {
+	new_test_ext().execute_with(|| {
+		let (result, _) =
+			call(ALICE, Action::GetMarketStatus, EvmDataWriter::new().write(token_address(DOT)));
+		assert!(reverted_with(result, "MarketDoesNotExist"));
+
+		activate_dot_market();
+		let (result, _) =
+			call(ALICE, Action::GetMarketStatus, EvmDataWriter::new().write(token_address(DOT)));
+		let (_, _, exchange_rate, utilization, total_borrows, total_reserves, borrow_index) =
+			LendMarket::get_market_status(DOT).unwrap();
+		assert_eq!(
+			&output(result)[64..224],
+			&EvmDataWriter::new()
+				.write(exchange_rate.into_inner())
+				.write(utilization.deconstruct())
+				.write(total_borrows)
+				.write(total_reserves)
+				.write(borrow_index.into_inner())
+				.build()[..]
+		);
+
+		let alice = <[u8; 32]>::from(account(ALICE));
+		let (result, _) =
+			call(ALICE, Action::SubstrateAccount, EvmDataWriter::new().write(Address(ALICE)));
+		assert_eq!(output(result), EvmDataWriter::new().write(H256::from(alice)).build());
+
+		let (result, _) =
+			call(ALICE, Action::GetAccountLiquidity, EvmDataWriter::new().write(H256::from(alice)));
+		assert_eq!(
+			output(result),
+			EvmDataWriter::new().write(0u128).write(0u128).write(0u128).write(0u128).build()
+		);
+	});
+}
+
+#[test]
+fn borrow_and_repay_borrow_should_work() {
+	new_test_ext().execute_with(|| {
+		supply_dot_collateral();
+
+		// 400 * 50% = 200 at most
+		let (result, logs) = call(
+			ALICE,
+			Action::Borrow,
+			EvmDataWriter::new().write(token_address(DOT)).write(201u128),
+		);
+		assert!(reverted_with(result, "InsufficientLiquidity"));
+		assert!(logs.is_empty());
+
+		let (result, logs) = call(
+			ALICE,
+			Action::Borrow,
+			EvmDataWriter::new().write(token_address(DOT)).write(100u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs, vec![amount_log(BORROWED_EVENT_SELECTOR, ALICE, 100)]);
+		assert_eq!(Currencies::free_balance(DOT, &account(ALICE)), 700);
+		assert_eq!(AccountBorrows::<Runtime>::get(DOT, account(ALICE)).principal, 100);
+
+		let (result, logs) = call(
+			ALICE,
+			Action::RepayBorrow,
+			EvmDataWriter::new().write(token_address(DOT)).write(101u128),
+		);
+		assert!(reverted_with(result, "TooMuchRepay"));
+		assert!(logs.is_empty());
+
+		let (result, logs) = call(
+			ALICE,
+			Action::RepayBorrow,
+			EvmDataWriter::new().write(token_address(DOT)).write(40u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs, vec![amount_log(REPAID_BORROW_EVENT_SELECTOR, ALICE, 40)]);
+		assert_eq!(Currencies::free_balance(DOT, &account(ALICE)), 660);
+		assert_eq!(AccountBorrows::<Runtime>::get(DOT, account(ALICE)).principal, 60);
+	});
+}
+
+#[test]
+fn redeem_should_work() {
+	new_test_ext().execute_with(|| {
+		supply_dot_collateral();
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(account(ALICE)), DOT, 100));
+
+		// the remaining collateral must cover the borrow: (400 - 300) * 50% < 100
+		let (result, logs) = call(
+			ALICE,
+			Action::Redeem,
+			EvmDataWriter::new().write(token_address(DOT)).write(300u128),
+		);
+		assert!(reverted_with(result, "InsufficientLiquidity"));
+		assert!(logs.is_empty());
+
+		let (result, logs) = call(
+			ALICE,
+			Action::Redeem,
+			EvmDataWriter::new().write(token_address(DOT)).write(200u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs, vec![amount_log(REDEEMED_EVENT_SELECTOR, ALICE, 200)]);
+		assert_eq!(Currencies::free_balance(DOT, &account(ALICE)), 900);
+
+		// Bob has nothing to redeem
+		let (result, _) =
+			call(BOB, Action::Redeem, EvmDataWriter::new().write(token_address(DOT)).write(1u128));
+		assert!(reverted_with(result, "InsufficientDeposit"));
+	});
+}
+
+#[test]
+fn liquidate_borrow_should_work() {
+	new_test_ext().execute_with(|| {
+		supply_dot_collateral();
+		assert_ok!(LendMarket::borrow(RuntimeOrigin::signed(account(ALICE)), DOT, 100));
+		assert_ok!(Currencies::deposit(DOT, &account(BOB), 1_000));
+		let alice = H256::from(<[u8; 32]>::from(account(ALICE)));
+		let liquidate = |caller: H160, repay_amount: u128| {
+			call(
+				caller,
+				Action::LiquidateBorrow,
+				EvmDataWriter::new()
+					.write(alice)
+					.write(token_address(DOT))
+					.write(repay_amount)
+					.write(token_address(DOT)),
+			)
+		};
+
+		// 400 * 55% covers the borrow of 100
+		let (result, _) = liquidate(BOB, 20);
+		assert!(reverted_with(result, "InsufficientShortfall"));
+
+		// 400 * 20% = 80 < 100
+		Markets::<Runtime>::mutate(DOT, |market| {
+			market.as_mut().unwrap().liquidation_threshold = Ratio::from_percent(20)
+		});
+		let (result, _) = liquidate(ALICE, 20);
+		assert!(reverted_with(result, "LiquidatorIsBorrower"));
+		// 100 * 50% = 50 at most
+		let (result, logs) = liquidate(BOB, 51);
+		assert!(reverted_with(result, "TooMuchRepay"));
+		assert!(logs.is_empty());
+
+		// 20 * 110% = 22 collateral is seized
+		let (result, logs) = liquidate(BOB, 20);
+		assert_eq!(output(result), EvmDataWriter::new().write(22u128).build());
+		assert_eq!(
+			logs,
+			vec![Log {
+				address: LEND_MARKET_ADDR,
+				topics: vec![H256::from(LIQUIDATED_BORROW_EVENT_SELECTOR), H256::from(BOB), alice],
+				data: EvmDataWriter::new()
+					.write(token_address(DOT))
+					.write(token_address(DOT))
+					.write(20u128)
+					.write(22u128)
+					.build(),
+			}]
+		);
+		assert_eq!(Currencies::free_balance(DOT, &account(BOB)), 980);
+		assert_eq!(AccountBorrows::<Runtime>::get(DOT, account(ALICE)).principal, 80);
+		assert!(AccountDeposits::<Runtime>::get(DOT, account(BOB)).voucher_balance > 0);
+	});
+}
+
+// `onFlashLoan` of a borrower contract which earns `profit` with the loan and approves it
+fn on_flash_loan(to: H160, input: Vec<u8>, profit: Balance) -> (ExitReason, Vec<u8>) {
+	assert_eq!(
+		EvmDataReader::read_selector::<FlashBorrowerAction>(&input).unwrap(),
+		FlashBorrowerAction::OnFlashLoan
+	);
+	let mut input = EvmDataReader::new_skip_selector(&input).unwrap();
+	assert_eq!(input.read::<Address>().unwrap(), Address(to));
+	assert_eq!(input.read::<Address>().unwrap(), token_address(DOT));
+	let amount = input.read::<Balance>().unwrap();
+	let fee = input.read::<Balance>().unwrap();
+	assert_eq!(input.read::<Bytes>().unwrap().as_bytes(), b"leverage");
+
+	assert_eq!(Currencies::free_balance(DOT, &account(to)), amount);
+	assert_eq!(fee, 90);
+	assert_ok!(Currencies::deposit(DOT, &account(to), profit));
+	(ExitReason::Succeed(ExitSucceed::Returned), FLASH_LOAN_CALLBACK_SUCCESS.to_vec())
+}
+
+#[test]
+fn flash_loan_should_work() {
+	new_test_ext().execute_with(|| {
+		activate_dot_market();
+		assert_ok!(Currencies::deposit(DOT, &account(ALICE), 1_000_000));
+		assert_ok!(LendMarket::mint(RuntimeOrigin::signed(account(ALICE)), DOT, 1_000_000));
+		let flash_loan = |subcall: fn(H160, Vec<u8>) -> (ExitReason, Vec<u8>)| {
+			call_with_subcall(
+				BOB,
+				Action::FlashLoan,
+				EvmDataWriter::new()
+					.write(token_address(DOT))
+					.write(100_000u128)
+					.write(Bytes::from("leverage")),
+				Some(subcall),
+			)
+		};
+
+		// the borrower reverts
+		let (result, logs) = flash_loan(|_, _| (ExitReason::Revert(ExitRevert::Reverted), vec![]));
+		assert!(reverted_with(result, "FlashLoanCallbackFailed"));
+		assert!(logs.is_empty());
+		// the borrower doesn't approve the loan, e.g. it is not a contract
+		let (result, _) = flash_loan(|_, _| (ExitReason::Succeed(ExitSucceed::Stopped), vec![]));
+		assert!(reverted_with(result, "FlashLoanCallbackFailed"));
+		// 100_000 * 0.09% = 90 fee can't be paid
+		let (result, _) = flash_loan(|to, input| on_flash_loan(to, input, 89));
+		assert!(reverted_with(result, "FlashLoanNotRepaid"));
+		assert_eq!(Currencies::free_balance(DOT, &account(BOB)), 0);
+		assert_eq!(TotalReserves::<Runtime>::get(DOT), 0);
+
+		let (result, logs) = flash_loan(|to, input| on_flash_loan(to, input, 100));
+		assert_eq!(output(result), EvmDataWriter::new().write(90u128).build());
+		assert_eq!(
+			logs,
+			vec![Log {
+				address: LEND_MARKET_ADDR,
+				topics: vec![
+					H256::from(FLASH_LOANED_EVENT_SELECTOR),
+					H256::from(BOB),
+					H256::from(H160::from(token_address(DOT))),
+				],
+				data: EvmDataWriter::new().write(100_000u128).write(90u128).build(),
+			}]
+		);
+		assert_eq!(Currencies::free_balance(DOT, &account(BOB)), 10);
+		assert_eq!(TotalReserves::<Runtime>::get(DOT), 90);
+
+		// the market has no cash for this
+		let (result, _) = call(
+			BOB,
+			Action::FlashLoan,
+			EvmDataWriter::new()
+				.write(token_address(DOT))
+				.write(2_000_000u128)
+				.write(Bytes::from("leverage")),
+		);
+		assert!(reverted_with(result, "InsufficientCash"));
+	});
+}
@@ -16,4 +16,77 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use pallet_evm::{Context, ExitError, ExitReason, Log, PrecompileHandle, Transfer};
+use primitive_types::{H160, H256};
+
 mod erc20_mapping;
+mod lend_market;
+mod multicurrency;
+mod vtoken_minting;
+
+struct MockHandle {
+	input: Vec<u8>,
+	context: Context,
+	code_address: H160,
+	logs: Vec<Log>,
+	// handles the calls of the precompile to `to` with `input`
+	subcall: Option<fn(to: H160, input: Vec<u8>) -> (ExitReason, Vec<u8>)>,
+}
+
+impl PrecompileHandle for MockHandle {
+	fn call(
+		&mut self,
+		to: H160,
+		_: Option<Transfer>,
+		input: Vec<u8>,
+		_: Option<u64>,
+		_: bool,
+		_: &Context,
+	) -> (ExitReason, Vec<u8>) {
+		(self.subcall.expect("unexpected subcall"))(to, input)
+	}
+
+	fn record_cost(&mut self, _: u64) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn record_external_cost(
+		&mut self,
+		_: Option<u64>,
+		_: Option<u64>,
+		_: Option<u64>,
+	) -> Result<(), ExitError> {
+		Ok(())
+	}
+
+	fn refund_external_cost(&mut self, _: Option<u64>, _: Option<u64>) {}
+
+	fn remaining_gas(&self) -> u64 {
+		u64::MAX
+	}
+
+	fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+		self.logs.push(Log { address, topics, data });
+		Ok(())
+	}
+
+	fn code_address(&self) -> H160 {
+		self.code_address
+	}
+
+	fn input(&self) -> &[u8] {
+		&self.input
+	}
+
+	fn context(&self) -> &Context {
+		&self.context
+	}
+
+	fn is_static(&self) -> bool {
+		false
+	}
+
+	fn gas_limit(&self) -> Option<u64> {
+		None
+	}
+}
@@ -0,0 +1,340 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::MockHandle;
+use crate::{
+	evm::{
+		precompiles::{
+			erc20_mapping::{BifrostErc20Mapping, Erc20Mapping},
+			handle::EvmDataWriter,
+			multicurrency::{
+				Action, Allowances, MultiCurrencyPrecompile, PermitNonces, APPROVAL_EVENT_SELECTOR,
+				TRANSFER_EVENT_SELECTOR,
+			},
+			Address,
+		},
+		ExtendedAddressMapping,
+	},
+	Currencies, Runtime, System,
+};
+use bifrost_asset_registry::AssetMetadata;
+use bifrost_primitives::{Balance, CurrencyId, DOT};
+use frame_support::assert_ok;
+use hex_literal::hex;
+use orml_traits::MultiCurrency;
+use pallet_evm::{AddressMapping, Context, Log, Precompile, PrecompileFailure, PrecompileResult};
+use primitive_types::{H160, H256, U256};
+use sp_core::{ecdsa, Pair};
+use sp_io::hashing::keccak_256;
+use sp_runtime::BuildStorage;
+
+const ALICE: H160 = H160(hex!("1000000000000000000000000000000000000001"));
+const BOB: H160 = H160(hex!("1000000000000000000000000000000000000002"));
+const CHARLIE: H160 = H160(hex!("1000000000000000000000000000000000000003"));
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		bifrost_asset_registry::CurrencyMetadatas::<Runtime>::insert(
+			DOT,
+			AssetMetadata {
+				name: b"Polkadot DOT".to_vec(),
+				symbol: b"DOT".to_vec(),
+				decimals: 10,
+				minimal_balance: 1,
+			},
+		);
+	});
+	ext
+}
+
+fn account(address: H160) -> crate::AccountId {
+	ExtendedAddressMapping::into_account_id(address)
+}
+
+fn token_address(currency_id: CurrencyId) -> H160 {
+	BifrostErc20Mapping::encode_evm_address(currency_id).unwrap()
+}
+
+fn call(caller: H160, action: Action, args: EvmDataWriter) -> (PrecompileResult, Vec<Log>) {
+	call_raw(caller, action, args.build())
+}
+
+fn call_raw(caller: H160, action: Action, args: Vec<u8>) -> (PrecompileResult, Vec<Log>) {
+	let address = token_address(DOT);
+	let mut input = u32::from(action).to_be_bytes().to_vec();
+	input.extend(args);
+	let mut handle = MockHandle {
+		input,
+		context: Context { address, caller, apparent_value: U256::zero() },
+		code_address: address,
+		logs: vec![],
+		subcall: None,
+	};
+	let result = MultiCurrencyPrecompile::<Runtime>::execute(&mut handle);
+	(result, handle.logs)
+}
+
+fn output(result: PrecompileResult) -> Vec<u8> {
+	result.unwrap().output
+}
+
+fn erc20_log(selector: [u8; 32], from: H160, to: H160, amount: U256) -> Log {
+	Log {
+		address: token_address(DOT),
+		topics: vec![H256::from(selector), H256::from(from), H256::from(to)],
+		data: EvmDataWriter::new().write(amount).build(),
+	}
+}
+
+fn reverted_with(result: PrecompileResult, reason: &str) -> bool {
+	matches!(result, Err(PrecompileFailure::Revert { output, .. }) if output == reason.as_bytes())
+}
+
+fn deposit(address: H160, amount: Balance) {
+	assert_ok!(Currencies::deposit(DOT, &account(address), amount));
+}
+
+fn free_balance(address: H160) -> Balance {
+	Currencies::free_balance(DOT, &account(address))
+}
+
+// the EVM address of an ECDSA key pair
+fn evm_address(pair: &ecdsa::Pair) -> H160 {
+	let message = [0u8; 32];
+	let mut signature = [0u8; 65];
+	signature.copy_from_slice(pair.sign_prehashed(&message).as_ref());
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &message).unwrap();
+	H160::from(H256::from(keccak_256(&public)))
+}
+
+fn sign_permit(
+	pair: &ecdsa::Pair,
+	spender: H160,
+	value: U256,
+	nonce: U256,
+	deadline: U256,
+) -> EvmDataWriter {
+	let owner = evm_address(pair);
+	let domain_separator =
+		MultiCurrencyPrecompile::<Runtime>::compute_domain_separator(DOT, token_address(DOT))
+			.unwrap();
+	let digest = MultiCurrencyPrecompile::<Runtime>::permit_digest(
+		domain_separator,
+		owner,
+		spender,
+		value,
+		nonce,
+		deadline,
+	);
+	let signature = pair.sign_prehashed(&digest);
+	let signature: &[u8] = signature.as_ref();
+
+	EvmDataWriter::new()
+		.write(Address(owner))
+		.write(Address(spender))
+		.write(value)
+		.write(deadline)
+		.write(signature[64] + 27)
+		.write(H256::from_slice(&signature[0..32]))
+		.write(H256::from_slice(&signature[32..64]))
+}
+
+#[test]
+fn approve_should_work() {
+	new_test_ext().execute_with(|| {
+		let (result, logs) = call(
+			ALICE,
+			Action::Approve,
+			EvmDataWriter::new().write(Address(BOB)).write(U256::from(100)),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs, vec![erc20_log(APPROVAL_EVENT_SELECTOR, ALICE, BOB, U256::from(100))]);
+		assert_eq!(Allowances::get((DOT, ALICE, BOB)), U256::from(100));
+
+		let (result, _) = call(
+			CHARLIE,
+			Action::Allowance,
+			EvmDataWriter::new().write(Address(ALICE)).write(Address(BOB)),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(U256::from(100)).build());
+
+		// approve overrides the allowance
+		call(ALICE, Action::Approve, EvmDataWriter::new().write(Address(BOB)).write(U256::zero()))
+			.0
+			.unwrap();
+		assert_eq!(Allowances::get((DOT, ALICE, BOB)), U256::zero());
+	});
+}
+
+#[test]
+fn transfer_should_log_event() {
+	new_test_ext().execute_with(|| {
+		deposit(ALICE, 1_000);
+		let (result, logs) =
+			call(ALICE, Action::Transfer, EvmDataWriter::new().write(Address(BOB)).write(300u128));
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs, vec![erc20_log(TRANSFER_EVENT_SELECTOR, ALICE, BOB, U256::from(300))]);
+		assert_eq!(free_balance(ALICE), 700);
+		assert_eq!(free_balance(BOB), 300);
+	});
+}
+
+#[test]
+fn transfer_from_should_work() {
+	new_test_ext().execute_with(|| {
+		deposit(ALICE, 1_000);
+		call(
+			ALICE,
+			Action::Approve,
+			EvmDataWriter::new().write(Address(BOB)).write(U256::from(100)),
+		)
+		.0
+		.unwrap();
+
+		let (result, logs) = call(
+			BOB,
+			Action::TransferFrom,
+			EvmDataWriter::new().write(Address(ALICE)).write(Address(CHARLIE)).write(60u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(true).build());
+		assert_eq!(logs, vec![erc20_log(TRANSFER_EVENT_SELECTOR, ALICE, CHARLIE, U256::from(60))]);
+		assert_eq!(free_balance(ALICE), 940);
+		assert_eq!(free_balance(CHARLIE), 60);
+		assert_eq!(Allowances::get((DOT, ALICE, BOB)), U256::from(40));
+
+		// exceeds the allowance
+		let (result, _) = call(
+			BOB,
+			Action::TransferFrom,
+			EvmDataWriter::new().write(Address(ALICE)).write(Address(CHARLIE)).write(50u128),
+		);
+		assert!(reverted_with(result, "ERC20: insufficient allowance"));
+		assert_eq!(free_balance(ALICE), 940);
+		assert_eq!(Allowances::get((DOT, ALICE, BOB)), U256::from(40));
+
+		// no allowance is required to spend the caller's own balance
+		let (result, _) = call(
+			ALICE,
+			Action::TransferFrom,
+			EvmDataWriter::new()
+				.write(Address(ALICE))
+				.write(Address(CHARLIE))
+				.write(500u128),
+		);
+		assert_ok!(result);
+		assert_eq!(free_balance(ALICE), 440);
+		assert_eq!(free_balance(CHARLIE), 560);
+	});
+}
+
+#[test]
+fn infinite_allowance_should_not_decrease() {
+	new_test_ext().execute_with(|| {
+		deposit(ALICE, 1_000);
+		call(ALICE, Action::Approve, EvmDataWriter::new().write(Address(BOB)).write(U256::MAX))
+			.0
+			.unwrap();
+
+		let (result, _) = call(
+			BOB,
+			Action::TransferFrom,
+			EvmDataWriter::new().write(Address(ALICE)).write(Address(BOB)).write(600u128),
+		);
+		assert_ok!(result);
+		assert_eq!(free_balance(BOB), 600);
+		assert_eq!(Allowances::get((DOT, ALICE, BOB)), U256::MAX);
+	});
+}
+
+#[test]
+fn transfer_from_should_not_change_allowance_on_failure() {
+	new_test_ext().execute_with(|| {
+		deposit(ALICE, 100);
+		call(
+			ALICE,
+			Action::Approve,
+			EvmDataWriter::new().write(Address(BOB)).write(U256::from(500)),
+		)
+		.0
+		.unwrap();
+
+		let (result, _) = call(
+			BOB,
+			Action::TransferFrom,
+			EvmDataWriter::new().write(Address(ALICE)).write(Address(BOB)).write(200u128),
+		);
+		assert!(result.is_err());
+		assert_eq!(free_balance(ALICE), 100);
+		assert_eq!(Allowances::get((DOT, ALICE, BOB)), U256::from(500));
+	});
+}
+
+#[test]
+fn permit_should_work() {
+	new_test_ext().execute_with(|| {
+		pallet_timestamp::Now::<Runtime>::put(1_000_000);
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let owner = evm_address(&pair);
+		let deadline = U256::from(1_000 + 60);
+
+		let (result, _) = call(CHARLIE, Action::DomainSeparator, EvmDataWriter::new());
+		let domain_separator =
+			MultiCurrencyPrecompile::<Runtime>::compute_domain_separator(DOT, token_address(DOT))
+				.unwrap();
+		assert_eq!(output(result), EvmDataWriter::new().write(domain_separator).build());
+
+		let args = sign_permit(&pair, BOB, U256::from(100), U256::zero(), deadline);
+		let (result, logs) = call(CHARLIE, Action::Permit, args.clone());
+		assert_ok!(result);
+		assert_eq!(logs, vec![erc20_log(APPROVAL_EVENT_SELECTOR, owner, BOB, U256::from(100))]);
+		assert_eq!(Allowances::get((DOT, owner, BOB)), U256::from(100));
+		assert_eq!(PermitNonces::get(DOT, owner), U256::one());
+
+		let (result, _) = call(CHARLIE, Action::Nonces, EvmDataWriter::new().write(Address(owner)));
+		assert_eq!(output(result), EvmDataWriter::new().write(U256::one()).build());
+
+		// the permit can't be replayed
+		let (result, _) = call(CHARLIE, Action::Permit, args);
+		assert!(reverted_with(result, "ERC20Permit: invalid signature"));
+
+		// the signed spender is replaced
+		let mut args = sign_permit(&pair, BOB, U256::from(100), U256::one(), deadline).build();
+		args[44..64].copy_from_slice(CHARLIE.as_bytes());
+		let (result, _) = call_raw(CHARLIE, Action::Permit, args);
+		assert!(reverted_with(result, "ERC20Permit: invalid signature"));
+		assert_eq!(Allowances::get((DOT, owner, CHARLIE)), U256::zero());
+	});
+}
+
+#[test]
+fn permit_should_not_work_after_deadline() {
+	new_test_ext().execute_with(|| {
+		pallet_timestamp::Now::<Runtime>::put(1_000_000);
+		let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+		let owner = evm_address(&pair);
+
+		let args = sign_permit(&pair, BOB, U256::from(100), U256::zero(), U256::from(999));
+		let (result, _) = call(CHARLIE, Action::Permit, args);
+		assert!(reverted_with(result, "ERC20Permit: expired deadline"));
+		assert_eq!(Allowances::get((DOT, owner, BOB)), U256::zero());
+		assert_eq!(PermitNonces::get(DOT, owner), U256::zero());
+	});
+}
@@ -0,0 +1,211 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::MockHandle;
+use crate::{
+	evm::{
+		precompiles::{
+			erc20_mapping::{BifrostErc20Mapping, Erc20Mapping},
+			handle::EvmDataWriter,
+			vtoken_minting::{Action, VtokenMintingPrecompile},
+			Address, Bytes, VTOKEN_MINTING_ADDR,
+		},
+		ExtendedAddressMapping,
+	},
+	Currencies, Runtime, System,
+};
+use bifrost_asset_registry::AssetMetadata;
+use bifrost_primitives::{Balance, CurrencyId, RedeemType, TimeUnit, DOT, VDOT};
+use bifrost_vtoken_minting::{MinimumMint, TokenPool, TokenUnlockLedger, UserUnlockLedger};
+use frame_support::assert_ok;
+use hex_literal::hex;
+use orml_traits::MultiCurrency;
+use pallet_evm::{AddressMapping, Context, Precompile, PrecompileFailure, PrecompileResult};
+use primitive_types::{H160, H256, U256};
+use sp_runtime::BuildStorage;
+
+const ALICE: H160 = H160(hex!("1000000000000000000000000000000000000001"));
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		for (currency_id, name, symbol) in [
+			(DOT, b"Polkadot DOT".to_vec(), b"DOT".to_vec()),
+			(VDOT, b"Voucher DOT".to_vec(), b"vDOT".to_vec()),
+		] {
+			bifrost_asset_registry::CurrencyMetadatas::<Runtime>::insert(
+				currency_id,
+				AssetMetadata { name, symbol, decimals: 10, minimal_balance: 1 },
+			);
+		}
+	});
+	ext
+}
+
+fn account(address: H160) -> crate::AccountId {
+	ExtendedAddressMapping::into_account_id(address)
+}
+
+fn token_address(currency_id: CurrencyId) -> Address {
+	BifrostErc20Mapping::encode_evm_address(currency_id).unwrap().into()
+}
+
+fn call(caller: H160, action: Action, args: EvmDataWriter) -> PrecompileResult {
+	let mut input = u32::from(action).to_be_bytes().to_vec();
+	input.extend(args.build());
+	let mut handle = MockHandle {
+		input,
+		context: Context { address: VTOKEN_MINTING_ADDR, caller, apparent_value: U256::zero() },
+		code_address: VTOKEN_MINTING_ADDR,
+		logs: vec![],
+		subcall: None,
+	};
+	VtokenMintingPrecompile::<Runtime>::execute(&mut handle)
+}
+
+fn output(result: PrecompileResult) -> Vec<u8> {
+	result.unwrap().output
+}
+
+fn reverted_with(result: PrecompileResult, reason: &str) -> bool {
+	matches!(result, Err(PrecompileFailure::Revert { output, .. }) if output == reason.as_bytes())
+}
+
+fn mint_args(currency_id: CurrencyId, amount: Balance, remark: &[u8]) -> EvmDataWriter {
+	EvmDataWriter::new()
+		.write(token_address(currency_id))
+		.write(amount)
+		.write(Bytes::from(remark))
+		.write(0u32)
+}
+
+#[test]
+fn mint_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::deposit(DOT, &account(ALICE), 1_000));
+
+		let result = call(ALICE, Action::Mint, mint_args(DOT, 400, b"evm"));
+
+		assert_eq!(output(result), EvmDataWriter::new().write(400u128).build());
+		assert_eq!(Currencies::free_balance(DOT, &account(ALICE)), 600);
+		assert_eq!(Currencies::free_balance(VDOT, &account(ALICE)), 400);
+		assert_eq!(TokenPool::<Runtime>::get(DOT), 400);
+	});
+}
+
+#[test]
+fn mint_should_revert_with_pallet_error() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Currencies::deposit(DOT, &account(ALICE), 1_000));
+		MinimumMint::<Runtime>::insert(DOT, 500);
+
+		let result = call(ALICE, Action::Mint, mint_args(DOT, 400, b""));
+		assert!(reverted_with(result, "BelowMinimumMint"));
+
+		let result = call(ALICE, Action::Mint, mint_args(DOT, 500, &[0u8; 33]));
+		assert!(reverted_with(result, "remark is too long"));
+	});
+}
+
+#[test]
+fn invalid_currency_address_should_revert() {
+	new_test_ext().execute_with(|| {
+		let result = call(
+			ALICE,
+			Action::MinimumMint,
+			EvmDataWriter::new().write(Address(H160::repeat_byte(0xff))),
+		);
+		assert!(reverted_with(result, "invalid currency id"));
+	});
+}
+
+#[test]
+fn exchange_rate_views_should_work() {
+	new_test_ext().execute_with(|| {
+		TokenPool::<Runtime>::insert(DOT, 200);
+		assert_ok!(Currencies::deposit(VDOT, &account(ALICE), 100));
+
+		let result = call(
+			ALICE,
+			Action::TokenToVtoken,
+			EvmDataWriter::new().write(token_address(DOT)).write(50u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(25u128).build());
+
+		let result = call(
+			ALICE,
+			Action::VtokenToToken,
+			EvmDataWriter::new().write(token_address(VDOT)).write(25u128),
+		);
+		assert_eq!(output(result), EvmDataWriter::new().write(50u128).build());
+
+		MinimumMint::<Runtime>::insert(DOT, 5);
+		let result =
+			call(ALICE, Action::MinimumMint, EvmDataWriter::new().write(token_address(DOT)));
+		assert_eq!(output(result), EvmDataWriter::new().write(5u128).build());
+	});
+}
+
+#[test]
+fn unlock_ledger_views_should_work() {
+	new_test_ext().execute_with(|| {
+		UserUnlockLedger::<Runtime>::insert(
+			account(ALICE),
+			DOT,
+			(100, vec![1u32, 3].try_into().unwrap()),
+		);
+		TokenUnlockLedger::<Runtime>::insert(
+			DOT,
+			3,
+			(account(ALICE), 60, TimeUnit::Era(7), RedeemType::Native),
+		);
+
+		let result = call(
+			ALICE,
+			Action::UserUnlockLedger,
+			EvmDataWriter::new().write(Address(ALICE)).write(token_address(DOT)),
+		);
+		assert_eq!(
+			output(result),
+			EvmDataWriter::new().write(100u128).write(vec![1u32, 3]).build()
+		);
+
+		let result = call(
+			ALICE,
+			Action::TokenUnlockLedger,
+			EvmDataWriter::new().write(token_address(DOT)).write(3u32),
+		);
+		assert_eq!(
+			output(result),
+			EvmDataWriter::new()
+				.write(H256::from(<[u8; 32]>::from(account(ALICE))))
+				.write(60u128)
+				.write(7u32)
+				.build()
+		);
+
+		let result = call(
+			ALICE,
+			Action::TokenUnlockLedger,
+			EvmDataWriter::new().write(token_address(DOT)).write(4u32),
+		);
+		assert!(reverted_with(result, "unlock ledger not found"));
+	});
+}
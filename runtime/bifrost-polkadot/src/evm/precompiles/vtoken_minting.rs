@@ -0,0 +1,325 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	evm::{
+		precompiles::{
+			erc20_mapping::{BifrostErc20Mapping, Erc20Mapping},
+			handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+			revert,
+			substrate::RuntimeHelper,
+			succeed, Address, Bytes, EvmResult,
+		},
+		ExtendedAddressMapping,
+	},
+	AccountId, Runtime as BifrostRuntime, VtokenMinting,
+};
+use bifrost_primitives::{Balance, CurrencyId, TimeUnit, VtokenMintingInterface};
+use bifrost_vtoken_minting::{TokenUnlockLedger, UserUnlockLedger, WeightInfo};
+use frame_support::{traits::ConstU32, BoundedVec};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{AddressMapping, Precompile, PrecompileHandle, PrecompileResult};
+use primitive_types::{H160, H256};
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+type VtokenMintingWeight = <BifrostRuntime as bifrost_vtoken_minting::Config>::WeightInfo;
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Mint = "mint(address,uint256,bytes,uint32)",
+	MintWithLock = "mintWithLock(address,uint256,bytes,uint32)",
+	Redeem = "redeem(address,uint256)",
+	Rebond = "rebond(address,uint256)",
+	TokenToVtoken = "tokenToVtoken(address,uint256)",
+	VtokenToToken = "vtokenToToken(address,uint256)",
+	MinimumMint = "minimumMint(address)",
+	MinimumRedeem = "minimumRedeem(address)",
+	UserUnlockLedger = "userUnlockLedger(address,address)",
+	TokenUnlockLedger = "tokenUnlockLedger(address,uint32)",
+}
+
+/// Liquid staking of vtoken-minting for EVM accounts, see `VtokenMinting.sol`.
+/// Tokens and vTokens are identified by their MultiCurrency precompile addresses.
+pub struct VtokenMintingPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for VtokenMintingPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::Mint | Action::MintWithLock | Action::Redeem | Action::Rebond =>
+				FunctionModifier::NonPayable,
+			_ => FunctionModifier::View,
+		})?;
+
+		match selector {
+			Action::Mint => Self::mint(handle, false),
+			Action::MintWithLock => Self::mint(handle, true),
+			Action::Redeem => Self::redeem(handle),
+			Action::Rebond => Self::rebond(handle),
+			Action::TokenToVtoken => Self::token_to_vtoken(handle),
+			Action::VtokenToToken => Self::vtoken_to_token(handle),
+			Action::MinimumMint => Self::minimum_mint(handle),
+			Action::MinimumRedeem => Self::minimum_redeem(handle),
+			Action::UserUnlockLedger => Self::user_unlock_ledger(handle),
+			Action::TokenUnlockLedger => Self::token_unlock_ledger(handle),
+		}
+	}
+}
+
+impl<Runtime> VtokenMintingPrecompile<Runtime>
+where
+	Runtime: frame_system::Config + pallet_evm::Config,
+{
+	fn mint(handle: &mut impl PrecompileHandle, with_lock: bool) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::weight_to_gas(if with_lock {
+			VtokenMintingWeight::mint_with_lock()
+		} else {
+			VtokenMintingWeight::mint()
+		}))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let token_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let token_amount = input.read::<Balance>()?;
+		let remark: BoundedVec<u8, ConstU32<32>> = Vec::<u8>::from(input.read::<Bytes>()?)
+			.try_into()
+			.map_err(|_| revert("remark is too long"))?;
+		let channel_id = input.read::<u32>()?;
+		let exchanger = Self::caller(handle);
+
+		log::debug!(target: "evm", "vtoken-minting: mint exchanger: {:?}, token: {:?}, amount: {:?}", exchanger, token_id, token_amount);
+
+		let vtoken_amount = if with_lock {
+			<VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::mint_with_lock(
+				exchanger,
+				token_id,
+				token_amount,
+				remark,
+				Some(channel_id),
+			)
+		} else {
+			<VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::mint(
+				exchanger,
+				token_id,
+				token_amount,
+				remark,
+				Some(channel_id),
+			)
+		}
+		.map_err(Self::dispatch_error)?;
+
+		Ok(succeed(EvmDataWriter::new().write(vtoken_amount).build()))
+	}
+
+	fn redeem(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle
+			.record_cost(RuntimeHelper::<Runtime>::weight_to_gas(VtokenMintingWeight::redeem()))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let vtoken_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let vtoken_amount = input.read::<Balance>()?;
+		let exchanger = Self::caller(handle);
+
+		log::debug!(target: "evm", "vtoken-minting: redeem exchanger: {:?}, vtoken: {:?}, amount: {:?}", exchanger, vtoken_id, vtoken_amount);
+
+		<VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::redeem(
+			exchanger,
+			vtoken_id,
+			vtoken_amount,
+		)
+		.map_err(|e| Self::dispatch_error(e.error))?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn rebond(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle
+			.record_cost(RuntimeHelper::<Runtime>::weight_to_gas(VtokenMintingWeight::rebond()))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let token_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let token_amount = input.read::<Balance>()?;
+		let exchanger = Self::caller(handle);
+
+		log::debug!(target: "evm", "vtoken-minting: rebond exchanger: {:?}, token: {:?}, amount: {:?}", exchanger, token_id, token_amount);
+
+		let vtoken_amount = <VtokenMinting as VtokenMintingInterface<
+			AccountId,
+			CurrencyId,
+			Balance,
+		>>::rebond(exchanger, token_id, token_amount)
+		.map_err(Self::dispatch_error)?;
+
+		Ok(succeed(EvmDataWriter::new().write(vtoken_amount).build()))
+	}
+
+	fn token_to_vtoken(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let token_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let token_amount = input.read::<Balance>()?;
+
+		let vtoken_id =
+			<VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::vtoken_id(
+				token_id,
+			)
+			.ok_or_else(|| revert("not supported token"))?;
+		let vtoken_amount = <VtokenMinting as VtokenMintingInterface<
+			AccountId,
+			CurrencyId,
+			Balance,
+		>>::token_to_vtoken(token_id, vtoken_id, token_amount)
+		.map_err(Self::dispatch_error)?;
+
+		Ok(succeed(EvmDataWriter::new().write(vtoken_amount).build()))
+	}
+
+	fn vtoken_to_token(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let vtoken_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let vtoken_amount = input.read::<Balance>()?;
+
+		let token_id =
+			<VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::token_id(
+				vtoken_id,
+			)
+			.ok_or_else(|| revert("not supported vtoken"))?;
+		let token_amount = <VtokenMinting as VtokenMintingInterface<
+			AccountId,
+			CurrencyId,
+			Balance,
+		>>::vtoken_to_token(token_id, vtoken_id, vtoken_amount)
+		.map_err(Self::dispatch_error)?;
+
+		Ok(succeed(EvmDataWriter::new().write(token_amount).build()))
+	}
+
+	fn minimum_mint(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let token_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let minimum = <VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::get_minimums_mint(token_id);
+
+		Ok(succeed(EvmDataWriter::new().write(minimum).build()))
+	}
+
+	fn minimum_redeem(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let vtoken_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let minimum = <VtokenMinting as VtokenMintingInterface<AccountId, CurrencyId, Balance>>::get_minimums_redeem(vtoken_id);
+
+		Ok(succeed(EvmDataWriter::new().write(minimum).build()))
+	}
+
+	fn user_unlock_ledger(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let who = ExtendedAddressMapping::into_account_id(input.read::<Address>()?.into());
+		let token_id = Self::read_currency_id(input.read::<Address>()?)?;
+
+		let (total, unlock_ids) = UserUnlockLedger::<BifrostRuntime>::get(who, token_id)
+			.map(|(total, unlock_ids)| (total, unlock_ids.into_inner()))
+			.unwrap_or_default();
+
+		Ok(succeed(EvmDataWriter::new().write(total).write(unlock_ids).build()))
+	}
+
+	fn token_unlock_ledger(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let token_id = Self::read_currency_id(input.read::<Address>()?)?;
+		let unlock_id = input.read::<u32>()?;
+
+		let (who, amount, time_unit, _) =
+			TokenUnlockLedger::<BifrostRuntime>::get(token_id, unlock_id)
+				.ok_or_else(|| revert("unlock ledger not found"))?;
+
+		Ok(succeed(
+			EvmDataWriter::new()
+				.write(H256::from(<[u8; 32]>::from(who)))
+				.write(amount)
+				.write(Self::time_unit_value(time_unit))
+				.build(),
+		))
+	}
+
+	fn caller(handle: &impl PrecompileHandle) -> AccountId {
+		ExtendedAddressMapping::into_account_id(handle.context().caller)
+	}
+
+	fn read_currency_id(address: Address) -> EvmResult<CurrencyId> {
+		BifrostErc20Mapping::decode_evm_address(H160::from(address))
+			.ok_or_else(|| revert("invalid currency id"))
+	}
+
+	// the era, round, hour etc. of a time unit
+	fn time_unit_value(time_unit: TimeUnit) -> u32 {
+		match time_unit {
+			TimeUnit::Era(n) |
+			TimeUnit::SlashingSpan(n) |
+			TimeUnit::Round(n) |
+			TimeUnit::Kblock(n) |
+			TimeUnit::Hour(n) => n,
+		}
+	}
+
+	fn dispatch_error(e: DispatchError) -> pallet_evm::PrecompileFailure {
+		revert(Into::<&str>::into(e))
+	}
+}
@@ -73,7 +73,7 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
     // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:0 w:1)
-    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(252), added: 2727, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
     fn add_delegator() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `341`
@@ -94,7 +94,7 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
     // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:0 w:1)
-    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(252), added: 2727, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
     // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:0 w:1)
     // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
     // Storage: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (r:0 w:1)
@@ -178,7 +178,7 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
     // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
     // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
-    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(252), added: 2727, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
     // Storage: `System::Number` (r:1 w:0)
     // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::ExecutionPhase` (r:1 w:0)
@@ -190,9 +190,9 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     fn set_ledger() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `545`
-        //  Estimated: `3717`
+        //  Estimated: `6046`
         // Minimum execution time: 47_747 nanoseconds.
-        Weight::from_parts(48_586_000, 3717)
+        Weight::from_parts(48_586_000, 6046)
             .saturating_add(T::DbWeight::get().reads(7))
             .saturating_add(T::DbWeight::get().writes(3))
     }
@@ -280,7 +280,7 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Storage: `VtokenMinting::TokenPool` (r:1 w:1)
     // Proof: `VtokenMinting::TokenPool` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
     // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
-    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(252), added: 2727, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
     // Storage: `System::Number` (r:1 w:0)
     // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::ExecutionPhase` (r:1 w:0)
@@ -292,9 +292,9 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     fn update_token_exchange_rate() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `755`
-        //  Estimated: `3717`
+        //  Estimated: `6046`
         // Minimum execution time: 62_204 nanoseconds.
-        Weight::from_parts(63_446_000, 3717)
+        Weight::from_parts(63_446_000, 6046)
             .saturating_add(T::DbWeight::get().reads(10))
             .saturating_add(T::DbWeight::get().writes(5))
     }
@@ -315,7 +315,7 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     // Storage: `System::Events` (r:1 w:1)
     // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
     // Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
-    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
     // Storage: `PolkadotXcm::Queries` (r:0 w:1)
     // Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
     fn astar_dapp_staking() -> Weight {
@@ -328,9 +328,9 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
             .saturating_add(T::DbWeight::get().writes(5))
     }
     // Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:0)
-    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
     // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
-    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(252), added: 2727, mode: `MaxEncodedLen`)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
     // Storage: `System::Number` (r:1 w:0)
     // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     // Storage: `System::ExecutionPhase` (r:1 w:0)
@@ -342,10 +342,120 @@ impl<T: frame_system::Config> bifrost_slp_v2::WeightInfo for BifrostWeight<T> {
     fn notify_astar_dapp_staking() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `567`
-        //  Estimated: `3717`
+        //  Estimated: `6046`
         // Minimum execution time: 40_876 nanoseconds.
-        Weight::from_parts(41_668_000, 3717)
+        Weight::from_parts(41_668_000, 6046)
             .saturating_add(T::DbWeight::get().reads(6))
             .saturating_add(T::DbWeight::get().writes(3))
     }
+    // Storage: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::ValidatorsByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(8772), added: 11247, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+    // Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+    // Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::Queries` (r:0 w:1)
+    // Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn moonbeam_parachain_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `836`
+        //  Estimated: `3567`
+        // Minimum execution time: 72_646 nanoseconds.
+        Weight::from_parts(73_892_000, 3567)
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
+    // Storage: `VtokenMinting::OngoingTimeUnit` (r:1 w:0)
+    // Proof: `VtokenMinting::OngoingTimeUnit` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+    // Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn notify_moonbeam_parachain_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `691`
+        //  Estimated: `6046`
+        // Minimum execution time: 57_384 nanoseconds.
+        Weight::from_parts(58_602_000, 6046)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    // Storage: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorIndexByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (r:1 w:0)
+    // Proof: `SlpV2::DelegatorByStakingProtocolAndDelegatorIndex` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+    // Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+    // Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:0 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+    // Storage: `PolkadotXcm::Queries` (r:0 w:1)
+    // Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+    fn polkadot_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `781`
+        //  Estimated: `3567`
+        // Minimum execution time: 72_646 nanoseconds.
+        Weight::from_parts(73_892_000, 3567)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    // Storage: `SlpV2::PendingStatusByQueryId` (r:1 w:1)
+    // Proof: `SlpV2::PendingStatusByQueryId` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::LedgerByStakingProtocolAndDelegator` (r:1 w:1)
+    // Proof: `SlpV2::LedgerByStakingProtocolAndDelegator` (`max_values`: None, `max_size`: Some(2581), added: 5056, mode: `MaxEncodedLen`)
+    // Storage: `VtokenMinting::OngoingTimeUnit` (r:1 w:0)
+    // Proof: `VtokenMinting::OngoingTimeUnit` (`max_values`: None, `max_size`: Some(27), added: 2502, mode: `MaxEncodedLen`)
+    // Storage: `SlpV2::ConfigurationByStakingProtocol` (r:1 w:0)
+    // Proof: `SlpV2::ConfigurationByStakingProtocol` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    // Storage: `System::Number` (r:1 w:0)
+    // Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::ExecutionPhase` (r:1 w:0)
+    // Proof: `System::ExecutionPhase` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+    // Storage: `System::EventCount` (r:1 w:1)
+    // Proof: `System::EventCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    // Storage: `System::Events` (r:1 w:1)
+    // Proof: `System::Events` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    fn notify_polkadot_staking() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `629`
+        //  Estimated: `6046`
+        // Minimum execution time: 54_917 nanoseconds.
+        Weight::from_parts(56_135_000, 6046)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
 }
\ No newline at end of file
@@ -37,10 +37,12 @@ pub mod bifrost_slpx;
 pub mod bifrost_stable_pool;
 pub mod bifrost_system_maker;
 pub mod bifrost_system_staking;
+pub mod bifrost_twap;
 pub mod bifrost_vesting;
 pub mod bifrost_vstoken_conversion;
 pub mod bifrost_vtoken_minting;
 pub mod bifrost_vtoken_voting;
+pub mod bifrost_xcm_interface;
 pub mod orml_oracle;
 pub mod orml_tokens;
 pub mod pallet_xcm;
@@ -43,7 +43,7 @@ pub use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use sp_core::bounded::BoundedVec;
 use sp_std::convert::TryFrom;
-use xcm::v4::{Asset, AssetId, Location};
+use xcm::v4::{Asset, AssetId, Location, Xcm};
 pub use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds,
@@ -329,7 +329,7 @@ impl xcm_executor::Config for XcmConfig {
 	type HrmpNewChannelOpenRequestHandler = ();
 	type HrmpChannelAcceptedHandler = ();
 	type HrmpChannelClosingHandler = ();
-	type XcmRecorder = ();
+	type XcmRecorder = PolkadotXcm;
 }
 
 /// Local origins on this chain are allowed to dispatch XCM sends/executions.
@@ -550,4 +550,21 @@ impl bifrost_xcm_interface::Config for Runtime {
 	type ParachainId = ParachainInfo;
 	type CallBackTimeOut = ConstU32<10>;
 	type CurrencyIdConvert = AssetIdMaps<Runtime>;
+	// The instruction and call weights of the relay chain and AssetHub are set by governance
+	// from the benchmarked weights of those runtimes.
+	type DestinationWeigher = bifrost_xcm_interface::ConfiguredDestinationWeigher<Runtime>;
+	type WeightInfo = weights::bifrost_xcm_interface::BifrostWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = XcmInterfaceBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct XcmInterfaceBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl bifrost_xcm_interface::BenchmarkHelper for XcmInterfaceBenchmarkHelper {
+	fn ensure_xcm_delivery(dest: &Location) {
+		if let (1, [xcm::v4::Junction::Parachain(para_id)]) = dest.unpack() {
+			ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests((*para_id).into());
+		}
+	}
 }
@@ -34,6 +34,7 @@ pub mod currency_adapter;
 pub mod currency_converter;
 pub mod price;
 pub mod ratio;
+pub mod xcm_fee;
 
 pub use ratio::Ratio;
 
@@ -0,0 +1,100 @@
+// This file is part of Bifrost.
+
+// Copyright (C) Liebi Technologies PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use bifrost_asset_registry::{BalanceOf, FixedRateOfAsset};
+use frame_support::{
+	traits::Get,
+	weights::{
+		constants::{WEIGHT_PROOF_SIZE_PER_MB, WEIGHT_REF_TIME_PER_SECOND},
+		Weight,
+	},
+};
+use sp_std::prelude::*;
+use xcm::v4::AssetId;
+use xcm_builder::{FixedRateOfFungible, TakeRevenue};
+
+/// Quotes the fees of an XCM `WeightTrader` without executing it, backs `XcmPaymentApi`.
+pub trait WeightToAssetFee {
+	/// The assets the trader accepts as payment for execution weight.
+	fn acceptable_assets() -> Vec<AssetId>;
+
+	/// The amount of `asset` the trader charges for `weight`, `None` if `asset` is not accepted.
+	fn weight_to_asset_fee(weight: &Weight, asset: &AssetId) -> Option<u128>;
+}
+
+/// Traders in a tuple are tried in order, so the first one accepting an asset quotes its fee.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl WeightToAssetFee for Tuple {
+	fn acceptable_assets() -> Vec<AssetId> {
+		let mut assets = Vec::new();
+		for_tuples!( #(
+			for asset in Tuple::acceptable_assets() {
+				if !assets.contains(&asset) {
+					assets.push(asset);
+				}
+			}
+		)* );
+		assets
+	}
+
+	fn weight_to_asset_fee(weight: &Weight, asset: &AssetId) -> Option<u128> {
+		for_tuples!( #(
+			if let Some(fee) = Tuple::weight_to_asset_fee(weight, asset) {
+				return Some(fee);
+			}
+		)* );
+		None
+	}
+}
+
+impl<T: Get<(AssetId, u128, u128)>, R: TakeRevenue> WeightToAssetFee for FixedRateOfFungible<T, R> {
+	fn acceptable_assets() -> Vec<AssetId> {
+		vec![T::get().0]
+	}
+
+	fn weight_to_asset_fee(weight: &Weight, asset: &AssetId) -> Option<u128> {
+		let (id, units_per_second, units_per_mb) = T::get();
+		if id != *asset {
+			return None;
+		}
+		// Same as `FixedRateOfFungible::buy_weight`.
+		Some(
+			units_per_second.saturating_mul(weight.ref_time() as u128) /
+				(WEIGHT_REF_TIME_PER_SECOND as u128) +
+				units_per_mb.saturating_mul(weight.proof_size() as u128) /
+					(WEIGHT_PROOF_SIZE_PER_MB as u128),
+		)
+	}
+}
+
+impl<T: bifrost_asset_registry::Config, FixedRate: Get<u128>, R: TakeRevenue> WeightToAssetFee
+	for FixedRateOfAsset<T, FixedRate, R>
+where
+	BalanceOf<T>: Into<u128>,
+{
+	fn acceptable_assets() -> Vec<AssetId> {
+		FixedRateOfAsset::<T, FixedRate, R>::acceptable_assets()
+			.into_iter()
+			.map(AssetId)
+			.collect()
+	}
+
+	fn weight_to_asset_fee(weight: &Weight, asset: &AssetId) -> Option<u128> {
+		FixedRateOfAsset::<T, FixedRate, R>::weight_to_fee(weight, &asset.0)
+	}
+}